@@ -0,0 +1,340 @@
+//! Builds the JSON ABI of the contract from its source.
+//!
+//! The `#[elrond_wasm_derive::contract]` trait in `src/lib.rs` is parsed, and its
+//! `#[init]`, `#[endpoint]`, `#[view]` and `#[event]` methods are described with their arguments and results.
+//! Types are named the way they are encoded, e.g. `BigUint`, `Address`, `bytes` or `optional<Address>`.
+//! The errors are read from `BUSDError` in `src/errors.rs`, in the order of `BUSDError::ALL`.
+
+use quote::ToTokens;
+use serde_json::{json, Map, Value};
+use syn::{Attribute, Expr, FnArg, GenericArgument, ImplItem, Lit, Meta, NestedMeta, Pat, PathArguments, ReturnType, Stmt, TraitItem, TraitItemMethod, Type};
+
+/// The ABI of the contract trait found in `source`, the contents of a contract `lib.rs`.
+pub fn contract_abi(source: &str) -> Result<Value, String> {
+    let file = syn::parse_file(source).map_err(|e| format!("cannot parse contract: {}", e))?;
+    let contract = file.items.iter()
+        .find_map(|item| match item {
+            syn::Item::Trait(item_trait) if has_attribute(&item_trait.attrs, "contract") => Some(item_trait),
+            _ => None,
+        })
+        .ok_or("no contract trait found")?;
+
+    let mut constructor = None;
+    let mut endpoints = Vec::new();
+    let mut events = Vec::new();
+    for item in contract.items.iter() {
+        let method = match item {
+            TraitItem::Method(method) => method,
+            _ => continue,
+        };
+        if has_attribute(&method.attrs, "init") {
+            constructor = Some(constructor_abi(method)?);
+        } else if let Some(name) = attribute_name(&method.attrs, "endpoint")? {
+            endpoints.push(endpoint_abi(method, name, "mutable")?);
+        } else if let Some(name) = attribute_name(&method.attrs, "view")? {
+            endpoints.push(endpoint_abi(method, name, "readonly")?);
+        } else if let Some(identifier) = attribute_name(&method.attrs, "event")? {
+            events.push(event_abi(method, identifier)?);
+        }
+    }
+
+    let mut abi = Map::new();
+    abi.insert("name".to_string(), json!(contract.ident.to_string()));
+    add_docs(&mut abi, &contract.attrs);
+    abi.insert("constructor".to_string(), constructor.ok_or("no #[init] method")?);
+    abi.insert("endpoints".to_string(), Value::Array(endpoints));
+    abi.insert("events".to_string(), Value::Array(events));
+    Ok(Value::Object(abi))
+}
+
+/// The ABI of the contract, with its errors: `contract_source` is the contents of `lib.rs`, `errors_source` of `errors.rs`.
+pub fn busd_abi(contract_source: &str, errors_source: &str) -> Result<Value, String> {
+    let mut abi = contract_abi(contract_source)?;
+    abi.as_object_mut().unwrap().insert("errors".to_string(), errors_abi(errors_source)?);
+    Ok(abi)
+}
+
+/// The `code`, `name` and `message` of every `BUSDError`, from `source`, the contents of `errors.rs`.
+pub fn errors_abi(source: &str) -> Result<Value, String> {
+    let file = syn::parse_file(source).map_err(|e| format!("cannot parse errors: {}", e))?;
+    let error_enum = file.items.iter()
+        .find_map(|item| match item {
+            syn::Item::Enum(item_enum) if item_enum.ident == "BUSDError" => Some(item_enum),
+            _ => None,
+        })
+        .ok_or("no BUSDError enum found")?;
+    let error_impl = file.items.iter()
+        .find_map(|item| match item {
+            syn::Item::Impl(item_impl) if item_impl.trait_.is_none() && item_impl.self_ty.to_token_stream().to_string() == "BUSDError" => Some(item_impl),
+            _ => None,
+        })
+        .ok_or("no impl BUSDError found")?;
+
+    let all = error_impl.items.iter()
+        .find_map(|item| match item {
+            ImplItem::Const(constant) if constant.ident == "ALL" => Some(&constant.expr),
+            _ => None,
+        })
+        .ok_or("no BUSDError::ALL found")?;
+    let all = match all {
+        Expr::Reference(reference) => match &*reference.expr {
+            Expr::Array(array) => array.elems.iter().map(path_name).collect::<Result<Vec<_>, _>>()?,
+            _ => return Err("BUSDError::ALL is not an array".to_string()),
+        },
+        _ => return Err("BUSDError::ALL is not an array".to_string()),
+    };
+
+    let messages = error_impl.items.iter()
+        .find_map(|item| match item {
+            ImplItem::Method(method) if method.sig.ident == "message" => method.block.stmts.last(),
+            _ => None,
+        })
+        .ok_or("no BUSDError::message found")?;
+    let messages = match messages {
+        Stmt::Expr(Expr::Match(expr_match)) => &expr_match.arms,
+        _ => return Err("BUSDError::message is not a single match".to_string()),
+    };
+
+    let mut errors = Vec::new();
+    for name in all {
+        let code = error_enum.variants.iter()
+            .find(|variant| variant.ident == name)
+            .and_then(|variant| match &variant.discriminant {
+                Some((_, Expr::Lit(expr_lit))) => match &expr_lit.lit {
+                    Lit::Int(code) => code.base10_parse::<u32>().ok(),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .ok_or_else(|| format!("{}: no error code", name))?;
+        let message = messages.iter()
+            .find(|arm| arm.pat.to_token_stream().to_string() == name)
+            .and_then(|arm| match &*arm.body {
+                Expr::Lit(expr_lit) => match &expr_lit.lit {
+                    Lit::Str(message) => Some(message.value()),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .ok_or_else(|| format!("{}: no error message", name))?;
+        errors.push(json!({ "code": code, "name": name, "message": message }));
+    }
+    if errors.len() != error_enum.variants.len() {
+        return Err("BUSDError::ALL does not list every error".to_string());
+    }
+    Ok(Value::Array(errors))
+}
+
+/// The variant name of a `BUSDError::Name` or `Name` path.
+fn path_name(expr: &Expr) -> Result<String, String> {
+    match expr {
+        Expr::Path(expr_path) => Ok(expr_path.path.segments.last().unwrap().ident.to_string()),
+        other => Err(format!("unsupported error {}", other.to_token_stream())),
+    }
+}
+
+/// Same formatting as the Mandos scenarios: 4-space indent and a final newline.
+pub fn to_json_string(abi: &Value) -> String {
+    let mut bytes = Vec::new();
+    let formatter = serde_json::ser::PrettyFormatter::with_indent(b"    ");
+    let mut serializer = serde_json::Serializer::with_formatter(&mut bytes, formatter);
+    serde::Serialize::serialize(abi, &mut serializer).unwrap();
+    String::from_utf8(bytes).unwrap() + "\n"
+}
+
+fn has_attribute(attrs: &[Attribute], name: &str) -> bool {
+    attrs.iter().any(|attr| attr.path.segments.last().is_some_and(|segment| segment.ident == name))
+}
+
+/// For `#[endpoint]`, `#[view]` and `#[event]`: the name in parentheses, or the method name when there is none.
+/// `None` if the attribute is not there.
+fn attribute_name(attrs: &[Attribute], name: &str) -> Result<Option<NameOrDefault>, String> {
+    let attr = match attrs.iter().find(|attr| attr.path.is_ident(name)) {
+        Some(attr) => attr,
+        None => return Ok(None),
+    };
+    match attr.parse_meta().map_err(|e| format!("malformed #[{}]: {}", name, e))? {
+        Meta::Path(_) => Ok(Some(NameOrDefault(None))),
+        Meta::List(list) => match list.nested.first() {
+            Some(NestedMeta::Meta(Meta::Path(path))) if list.nested.len() == 1 => {
+                Ok(Some(NameOrDefault(Some(path.to_token_stream().to_string()))))
+            },
+            Some(NestedMeta::Lit(Lit::Str(literal))) if list.nested.len() == 1 => {
+                Ok(Some(NameOrDefault(Some(literal.value()))))
+            },
+            _ => Err(format!("malformed #[{}]", name)),
+        },
+        Meta::NameValue(_) => Err(format!("malformed #[{}]", name)),
+    }
+}
+
+struct NameOrDefault(Option<String>);
+
+impl NameOrDefault {
+    fn or_method_name(self, method: &TraitItemMethod) -> String {
+        self.0.unwrap_or_else(|| method.sig.ident.to_string())
+    }
+}
+
+fn add_docs(map: &mut Map<String, Value>, attrs: &[Attribute]) {
+    let mut docs: Vec<String> = attrs.iter()
+        .filter(|attr| attr.path.is_ident("doc"))
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(Meta::NameValue(name_value)) => match name_value.lit {
+                Lit::Str(literal) => Some(literal.value()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .map(|line| line.strip_prefix(' ').unwrap_or(&line).trim_end().to_string())
+        .collect();
+    while docs.last().is_some_and(String::is_empty) {
+        docs.pop();
+    }
+    if !docs.is_empty() {
+        map.insert("docs".to_string(), json!(docs));
+    }
+}
+
+/// The named arguments of a method, without `&self`.
+fn arguments(method: &TraitItemMethod) -> Result<Vec<(String, &Type)>, String> {
+    method.sig.inputs.iter()
+        .filter_map(|input| match input {
+            FnArg::Receiver(_) => None,
+            FnArg::Typed(pat_type) => Some(pat_type),
+        })
+        .map(|pat_type| match &*pat_type.pat {
+            Pat::Ident(ident) => Ok((ident.ident.to_string(), &*pat_type.ty)),
+            _ => Err(format!("{}: unsupported argument pattern", method.sig.ident)),
+        })
+        .collect()
+}
+
+fn inputs_abi(method: &TraitItemMethod) -> Result<Vec<Value>, String> {
+    arguments(method)?.into_iter()
+        .map(|(name, ty)| Ok(json!({ "name": name, "type": abi_type(ty)? })))
+        .collect()
+}
+
+fn constructor_abi(method: &TraitItemMethod) -> Result<Value, String> {
+    let mut constructor = Map::new();
+    add_docs(&mut constructor, &method.attrs);
+    constructor.insert("inputs".to_string(), Value::Array(inputs_abi(method)?));
+    constructor.insert("outputs".to_string(), json!([]));
+    Ok(Value::Object(constructor))
+}
+
+fn endpoint_abi(method: &TraitItemMethod, name: NameOrDefault, mutability: &str) -> Result<Value, String> {
+    let mut endpoint = Map::new();
+    endpoint.insert("name".to_string(), json!(name.or_method_name(method)));
+    add_docs(&mut endpoint, &method.attrs);
+    endpoint.insert("mutability".to_string(), json!(mutability));
+    endpoint.insert("inputs".to_string(), Value::Array(inputs_abi(method)?));
+    let outputs = match &method.sig.output {
+        ReturnType::Default => Vec::new(),
+        ReturnType::Type(_, ty) => outputs_abi(ty)?,
+    };
+    endpoint.insert("outputs".to_string(), Value::Array(outputs));
+    Ok(Value::Object(endpoint))
+}
+
+/// Topics are all the arguments but the last one, which is the event data. A `()` data argument means no data.
+fn event_abi(method: &TraitItemMethod, identifier: NameOrDefault) -> Result<Value, String> {
+    let identifier = identifier.0.ok_or_else(|| format!("{}: event without identifier", method.sig.ident))?;
+    let mut arguments = arguments(method)?;
+    let data = arguments.pop().ok_or_else(|| format!("{}: event without data argument", method.sig.ident))?;
+
+    let mut inputs = Vec::new();
+    for (name, ty) in arguments {
+        inputs.push(json!({ "name": name, "type": abi_type(ty)?, "indexed": true }));
+    }
+    if !is_unit(data.1) {
+        inputs.push(json!({ "name": data.0, "type": abi_type(data.1)? }));
+    }
+
+    let mut event = Map::new();
+    event.insert("identifier".to_string(), json!(identifier));
+    event.insert("name".to_string(), json!(method.sig.ident.to_string()));
+    add_docs(&mut event, &method.attrs);
+    event.insert("inputs".to_string(), Value::Array(inputs));
+    Ok(Value::Object(event))
+}
+
+fn is_unit(ty: &Type) -> bool {
+    matches!(ty, Type::Tuple(tuple) if tuple.elems.is_empty())
+}
+
+/// The generic type arguments of the last path segment, e.g. `T` and `E` in `Result<T, E>`.
+fn type_arguments(segment: &syn::PathSegment) -> Vec<&Type> {
+    match &segment.arguments {
+        PathArguments::AngleBracketed(arguments) => arguments.args.iter()
+            .filter_map(|argument| match argument {
+                GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// A result type can stand for several results: nothing for `()`, one per type in a `MultiResultN`.
+fn outputs_abi(ty: &Type) -> Result<Vec<Value>, String> {
+    if is_unit(ty) {
+        return Ok(Vec::new());
+    }
+    if let Type::Path(type_path) = ty {
+        let segment = type_path.path.segments.last().unwrap();
+        let arguments = type_arguments(segment);
+        let name = segment.ident.to_string();
+        if name == "Result" {
+            // the error is signalled, never returned
+            return outputs_abi(arguments[0]);
+        }
+        if name.starts_with("MultiResult") && name != "MultiResultVec" {
+            return arguments.into_iter()
+                .map(|argument| Ok(json!({ "type": abi_type(argument)?, "multi_result": true })))
+                .collect();
+        }
+        if name == "OptionalResult" || name == "MultiResultVec" {
+            return Ok(vec![json!({ "type": abi_type(ty)?, "multi_result": true })]);
+        }
+    }
+    Ok(vec![json!({ "type": abi_type(ty)? })])
+}
+
+/// The ABI name of an argument or result type.
+pub fn abi_type(ty: &Type) -> Result<String, String> {
+    match ty {
+        Type::Reference(reference) => abi_type(&reference.elem),
+        Type::Slice(slice) if abi_type(&slice.elem)? == "u8" => Ok("bytes".to_string()),
+        Type::Macro(type_macro) if type_macro.mac.path.is_ident("mut_storage") => {
+            // `mut_storage!(T)` getters are views on a `T`
+            let inner: Type = type_macro.mac.parse_body().map_err(|e| e.to_string())?;
+            abi_type(&inner)
+        },
+        Type::Path(type_path) => {
+            let segment = type_path.path.segments.last().unwrap();
+            let arguments = type_arguments(segment);
+            let generic = |name: &str| -> Result<String, String> {
+                let arguments: Result<Vec<String>, String> = arguments.iter().map(|ty| abi_type(ty)).collect();
+                Ok(format!("{}<{}>", name, arguments?.join(",")))
+            };
+            match segment.ident.to_string().as_str() {
+                "Address" | "H256" | "BigUint" | "BigInt" | "bool" | "u8" | "u16" | "u32" | "u64" | "i64" => {
+                    Ok(segment.ident.to_string())
+                },
+                // 32 bits on Arwen
+                "usize" => Ok("u32".to_string()),
+                "Vec" if abi_type(arguments[0])? == "u8" => Ok("bytes".to_string()),
+                "Vec" => generic("List"),
+                "Option" => generic("Option"),
+                "OptionalResult" | "OptionalArg" => generic("optional"),
+                "MultiResultVec" | "VarArgs" => generic("variadic"),
+                // a tuple of consecutive arguments or results, e.g. each item of a `VarArgs`
+                name if name.starts_with("MultiResult") || name.starts_with("MultiArg") => generic("multi"),
+                other => Err(format!("unsupported type {}", other)),
+            }
+        },
+        other => Err(format!("unsupported type {}", other.to_token_stream())),
+    }
+}
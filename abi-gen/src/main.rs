@@ -0,0 +1,45 @@
+//! Writes the JSON ABI of the contract to `busd.abi.json`, next to `Cargo.toml`.
+//!
+//! Usage: `busd-abi-gen [--check]`
+//!
+//! With `--check`, nothing is written: the command fails if `busd.abi.json` differs from the generated one.
+
+use std::fs;
+use std::process;
+
+const CONTRACT_SOURCE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../src/lib.rs");
+const ERRORS_SOURCE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../src/errors.rs");
+const ABI_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../busd.abi.json");
+
+fn main() {
+    let mut check = false;
+    for arg in std::env::args().skip(1) {
+        match arg.as_str() {
+            "--check" => check = true,
+            other => {
+                eprintln!("unknown argument {}\n\nUsage: busd-abi-gen [--check]", other);
+                process::exit(1);
+            }
+        }
+    }
+
+    let source = fs::read_to_string(CONTRACT_SOURCE).unwrap();
+    let errors_source = fs::read_to_string(ERRORS_SOURCE).unwrap();
+    let abi = match busd_abi_gen::busd_abi(&source, &errors_source) {
+        Ok(abi) => abi,
+        Err(error) => {
+            eprintln!("error: {}", error);
+            process::exit(1);
+        }
+    };
+    let contents = busd_abi_gen::to_json_string(&abi);
+    if fs::read_to_string(ABI_FILE).ok().as_ref() == Some(&contents) {
+        println!("busd.abi.json up to date");
+    } else if check {
+        eprintln!("out of date: busd.abi.json");
+        process::exit(1);
+    } else {
+        fs::write(ABI_FILE, contents).unwrap();
+        println!("wrote busd.abi.json");
+    }
+}
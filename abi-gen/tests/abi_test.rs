@@ -0,0 +1,157 @@
+use serde_json::{json, Value};
+
+use busd_abi_gen::*;
+
+const CONTRACT_SOURCE: &str = include_str!("../../src/lib.rs");
+const ERRORS_SOURCE: &str = include_str!("../../src/errors.rs");
+const ABI_FILE: &str = include_str!("../../busd.abi.json");
+
+fn busd_abi() -> Value {
+    busd_abi_gen::busd_abi(CONTRACT_SOURCE, ERRORS_SOURCE).unwrap()
+}
+
+fn endpoint(abi: &Value, name: &str) -> Value {
+    abi["endpoints"].as_array().unwrap().iter()
+        .find(|endpoint| endpoint["name"] == name)
+        .unwrap_or_else(|| panic!("no endpoint {}", name))
+        .clone()
+}
+
+#[test]
+fn test_committed_abi_up_to_date() {
+    assert!(to_json_string(&busd_abi()) == ABI_FILE, "busd.abi.json is out of date, run `cargo run` in abi-gen");
+}
+
+#[test]
+fn test_endpoints() {
+    let abi = busd_abi();
+    assert_eq!(abi["name"], "BUSDCoin");
+    assert_eq!(abi["constructor"]["inputs"], json!([
+        { "name": "name", "type": "bytes" },
+        { "name": "symbol", "type": "bytes" },
+        { "name": "decimals", "type": "u32" },
+        { "name": "owner", "type": "optional<Address>" },
+        { "name": "supply_controller", "type": "optional<Address>" },
+        { "name": "asset_protection_role", "type": "optional<Address>" },
+        { "name": "pauser", "type": "optional<Address>" },
+        { "name": "initial_holder", "type": "optional<Address>" },
+        { "name": "initial_supply", "type": "optional<BigUint>" },
+    ]));
+
+    let transfer_from = endpoint(&abi, "transferFrom");
+    assert_eq!(transfer_from["mutability"], "mutable");
+    assert_eq!(transfer_from["inputs"], json!([
+        { "name": "sender", "type": "Address" },
+        { "name": "recipient", "type": "Address" },
+        { "name": "amount", "type": "BigUint" },
+    ]));
+    assert_eq!(transfer_from["outputs"], json!([]));
+    assert_eq!(transfer_from["docs"][0], "Use allowance to transfer funds between two accounts.");
+
+    let total_supply = endpoint(&abi, "totalSupply");
+    assert_eq!(total_supply["mutability"], "readonly");
+    assert_eq!(total_supply["outputs"], json!([{ "type": "BigUint" }]));
+
+    assert_eq!(endpoint(&abi, "getProposedOwner")["outputs"], json!([{ "type": "optional<Address>", "multi_result": true }]));
+    assert_eq!(endpoint(&abi, "getPendingTransferIds")["outputs"], json!([{ "type": "variadic<u64>", "multi_result": true }]));
+    assert_eq!(endpoint(&abi, "getPendingTransfer")["outputs"].as_array().unwrap().len(), 4);
+    assert_eq!(endpoint(&abi, "exportState")["outputs"], json!([{ "type": "variadic<multi<Address,BigUint,bool>>", "multi_result": true }]));
+    assert_eq!(endpoint(&abi, "importState")["inputs"], json!([{ "name": "holders", "type": "variadic<multi<Address,BigUint,bool>>" }]));
+    assert_eq!(endpoint(&abi, "getErrorCode")["inputs"], json!([{ "name": "message", "type": "bytes" }]));
+    assert_eq!(endpoint(&abi, "decimals")["outputs"], json!([{ "type": "u32" }]));
+}
+
+#[test]
+fn test_events() {
+    let abi = busd_abi();
+    let events = abi["events"].as_array().unwrap();
+    let (legacy, named): (Vec<&Value>, Vec<&Value>) = events.iter()
+        .partition(|event| event["identifier"].as_str().unwrap().starts_with("0x00"));
+    for (i, event) in legacy.iter().enumerate() {
+        assert_eq!(event["identifier"], format!("0x{:064x}", i + 1));
+    }
+    // named events are identified by their name in ASCII, padded with zeros
+    let mut names = Vec::new();
+    for event in named.iter() {
+        let identifier = event["identifier"].as_str().unwrap();
+        let identifier: Vec<u8> = (2..identifier.len()).step_by(2)
+            .map(|i| u8::from_str_radix(&identifier[i..i + 2], 16).unwrap())
+            .collect();
+        assert_eq!(identifier.len(), 32);
+        let name: Vec<u8> = identifier.iter().cloned().take_while(|b| *b != 0).collect();
+        assert!(identifier[name.len()..].iter().all(|b| *b == 0));
+        let name = String::from_utf8(name).unwrap();
+        assert!(!names.contains(&name));
+        names.push(name);
+    }
+    assert_eq!(names[0], "Initialized");
+    assert_eq!(names[1], "Transfer");
+    assert_eq!(names[7], "Pause");
+
+    assert_eq!(events[0]["name"], "initialized_event");
+    assert_eq!(events[0]["inputs"], json!([
+        { "name": "owner", "type": "Address", "indexed": true },
+        { "name": "supply_controller", "type": "Address", "indexed": true },
+        { "name": "asset_protection_role", "type": "Address", "indexed": true },
+        { "name": "pauser", "type": "Address", "indexed": true },
+        { "name": "paused", "type": "bool" },
+    ]));
+    assert_eq!(events[1]["name"], "transfer_event");
+    assert_eq!(events[1]["inputs"], json!([
+        { "name": "sender", "type": "Address", "indexed": true },
+        { "name": "recipient", "type": "Address", "indexed": true },
+        { "name": "amount", "type": "BigUint" },
+    ]));
+    assert_eq!(events[7]["name"], "pause_event");
+    assert_eq!(events[7]["inputs"], json!([
+        { "name": "caller", "type": "Address", "indexed": true },
+    ]));
+
+    let queued = events.iter().find(|event| event["name"] == "large_transfer_queued_event").unwrap();
+    assert_eq!(queued["inputs"], json!([
+        { "name": "sender", "type": "Address", "indexed": true },
+        { "name": "recipient", "type": "Address", "indexed": true },
+        { "name": "transfer_id", "type": "BigUint", "indexed": true },
+        { "name": "amount", "type": "BigUint" },
+    ]));
+}
+
+#[test]
+fn test_error_codes() {
+    let abi = busd_abi();
+    let errors = abi["errors"].as_array().unwrap();
+    assert_eq!(errors[0], json!({ "code": 1, "name": "Paused", "message": "paused" }));
+    assert!(errors.contains(&json!({ "code": 141, "name": "SnapshotNotFound", "message": "snapshot not found" })));
+    let codes: Vec<u64> = errors.iter().map(|error| error["code"].as_u64().unwrap()).collect();
+    assert!(codes.windows(2).all(|pair| pair[0] < pair[1]));
+
+    let missing = "pub enum BUSDError { A = 1, B = 2 } impl BUSDError { pub const ALL: &'static [BUSDError] = &[A]; \
+        pub fn message(self) -> &'static str { match self { A => \"a\", B => \"b\" } } }";
+    assert_eq!(errors_abi(missing), Err("BUSDError::ALL does not list every error".to_string()));
+}
+
+#[test]
+fn test_types() {
+    let cases = [
+        ("&Address", "Address"),
+        ("&'static [u8]", "bytes"),
+        ("Vec<u8>", "bytes"),
+        ("Vec<u64>", "List<u64>"),
+        ("Option<H256>", "Option<H256>"),
+        ("OptionalResult<BigUint>", "optional<BigUint>"),
+        ("mut_storage!(BigUint)", "BigUint"),
+        ("usize", "u32"),
+    ];
+    for (ty, expected) in cases.iter() {
+        assert_eq!(abi_type(&syn::parse_str(ty).unwrap()).as_deref(), Ok(*expected));
+    }
+    assert!(abi_type(&syn::parse_str("HashMap<u8, u8>").unwrap()).is_err());
+}
+
+#[test]
+fn test_errors() {
+    assert_eq!(contract_abi("pub trait NotAContract {}"), Err("no contract trait found".to_string()));
+    assert!(contract_abi("not rust").is_err());
+    let unsupported = "#[elrond_wasm_derive::contract(C)] pub trait C { #[init] fn init(&self) {} #[endpoint] fn f(&self, x: f64) {} }";
+    assert_eq!(contract_abi(unsupported), Err("unsupported type f64".to_string()));
+}
@@ -0,0 +1,189 @@
+use crate::wallet::decode_address;
+
+/// Kinds of endpoint arguments, with the name shown in the usage.
+#[derive(Clone, Copy, Debug)]
+pub enum Arg {
+    /// bech32 or hex address
+    Address(&'static str),
+    /// BigUint, in decimal
+    Amount(&'static str),
+    /// u64, in decimal
+    Number(&'static str),
+    /// H256, in hex
+    Hash(&'static str),
+    /// bool, `true` or `false`
+    Flag(&'static str),
+    /// bytes, given as text, e.g. a token identifier
+    Text(&'static str),
+    /// a group of arguments given any number of times, only as the last argument
+    Repeated(&'static [Arg]),
+}
+
+impl Arg {
+    pub fn name(self) -> &'static str {
+        match self {
+            Arg::Address(name) | Arg::Amount(name) | Arg::Number(name) | Arg::Hash(name) | Arg::Flag(name) | Arg::Text(name) => name,
+            Arg::Repeated(_) => "...",
+        }
+    }
+
+    fn usage(self) -> String {
+        match self {
+            Arg::Repeated(group) => {
+                let names: Vec<String> = group.iter().map(|arg| arg.usage()).collect();
+                format!("[{}]...", names.join(" "))
+            },
+            _ => format!("<{}>", self.name()),
+        }
+    }
+
+    /// Hex of the top-encoded argument, as it goes in the transaction data.
+    pub fn encode(self, value: &str) -> Result<String, String> {
+        match self {
+            Arg::Address(_) => Ok(hex::encode(decode_address(value)?)),
+            Arg::Amount(name) => decimal_to_bytes(value)
+                .map(hex::encode)
+                .ok_or_else(|| format!("invalid {}: {} is not a non-negative integer", name, value)),
+            Arg::Number(name) => value.parse::<u64>()
+                .map(|number| hex::encode(trim_leading_zeros(&number.to_be_bytes())))
+                .map_err(|_| format!("invalid {}: {} is not a 64-bit unsigned integer", name, value)),
+            Arg::Hash(name) => match hex::decode(value.trim_start_matches("0x")) {
+                Ok(bytes) if bytes.len() == 32 => Ok(hex::encode(bytes)),
+                _ => Err(format!("invalid {}: {} is not 32 bytes of hex", name, value)),
+            },
+            Arg::Flag(name) => match value {
+                "true" => Ok("01".to_string()),
+                "false" => Ok(String::new()),
+                _ => Err(format!("invalid {}: {} is not true or false", name, value)),
+            },
+            Arg::Text(name) if value.is_empty() => Err(format!("invalid {}: empty", name)),
+            Arg::Text(_) => Ok(hex::encode(value)),
+            Arg::Repeated(_) => Err("a repeated group is encoded argument by argument".to_string()),
+        }
+    }
+}
+
+/// Big-endian bytes of an arbitrarily large decimal number, without leading zeros, so 0 is empty.
+fn decimal_to_bytes(decimal: &str) -> Option<Vec<u8>> {
+    if decimal.is_empty() {
+        return None;
+    }
+    let mut bytes: Vec<u8> = Vec::new();
+    for c in decimal.chars() {
+        let mut carry = c.to_digit(10)?;
+        for byte in bytes.iter_mut().rev() {
+            let value = *byte as u32 * 10 + carry;
+            *byte = value as u8;
+            carry = value >> 8;
+        }
+        while carry > 0 {
+            bytes.insert(0, carry as u8);
+            carry >>= 8;
+        }
+    }
+    Some(bytes)
+}
+
+fn trim_leading_zeros(bytes: &[u8]) -> &[u8] {
+    let start = bytes.iter().position(|byte| *byte != 0).unwrap_or(bytes.len());
+    &bytes[start..]
+}
+
+pub struct Endpoint {
+    pub name: &'static str,
+    pub args: &'static [Arg],
+}
+
+impl Endpoint {
+    pub fn find(name: &str) -> Option<&'static Endpoint> {
+        ENDPOINTS.iter().find(|endpoint| endpoint.name == name)
+    }
+
+    pub fn usage(&self) -> String {
+        let mut usage = self.name.to_string();
+        for arg in self.args {
+            usage.push(' ');
+            usage.push_str(&arg.usage());
+        }
+        usage
+    }
+
+    /// The arguments the values are given for, with a trailing repeated group expanded as many times as needed.
+    fn expand_args(&self, count: usize) -> Option<Vec<Arg>> {
+        match self.args.split_last() {
+            Some((Arg::Repeated(group), fixed)) => {
+                let repeated = count.checked_sub(fixed.len())?;
+                if repeated % group.len() != 0 {
+                    return None;
+                }
+                let mut args = fixed.to_vec();
+                for _ in 0..repeated / group.len() {
+                    args.extend_from_slice(group);
+                }
+                Some(args)
+            },
+            _ if count == self.args.len() => Some(self.args.to_vec()),
+            _ => None,
+        }
+    }
+
+    /// The transaction data: the endpoint name followed by the hex-encoded arguments, separated by `@`.
+    pub fn call_data(&self, values: &[String]) -> Result<String, String> {
+        let args = self.expand_args(values.len())
+            .ok_or_else(|| format!("wrong number of arguments, usage: {}", self.usage()))?;
+        let mut data = self.name.to_string();
+        for (arg, value) in args.into_iter().zip(values) {
+            data.push('@');
+            data.push_str(&arg.encode(value)?);
+        }
+        Ok(data)
+    }
+}
+
+/// Every endpoint of the contract, in the order they appear in `src/lib.rs`.
+pub const ENDPOINTS: &[Endpoint] = &[
+    Endpoint { name: "transfer", args: &[Arg::Address("to"), Arg::Amount("amount")] },
+    Endpoint { name: "transferFrom", args: &[Arg::Address("from"), Arg::Address("to"), Arg::Amount("amount")] },
+    Endpoint { name: "approve", args: &[Arg::Address("spender"), Arg::Amount("amount")] },
+    Endpoint { name: "proposeOwner", args: &[Arg::Address("proposed_owner")] },
+    Endpoint { name: "disregardProposedOwner", args: &[] },
+    Endpoint { name: "claimOwnership", args: &[] },
+    Endpoint { name: "reclaimBUSD", args: &[] },
+    Endpoint { name: "setPauser", args: &[Arg::Address("pauser")] },
+    Endpoint { name: "pause", args: &[] },
+    Endpoint { name: "unpause", args: &[] },
+    Endpoint { name: "setAssetProtectionRole", args: &[Arg::Address("asset_protection_role")] },
+    Endpoint { name: "freeze", args: &[Arg::Address("address")] },
+    Endpoint { name: "unfreeze", args: &[Arg::Address("address")] },
+    Endpoint { name: "wipeFrozenAddress", args: &[Arg::Address("address")] },
+    Endpoint { name: "setDailyLimit", args: &[Arg::Address("address"), Arg::Amount("limit")] },
+    Endpoint { name: "removeDailyLimit", args: &[Arg::Address("address")] },
+    Endpoint { name: "setDefaultDailyLimit", args: &[Arg::Amount("limit")] },
+    Endpoint { name: "removeDefaultDailyLimit", args: &[] },
+    Endpoint { name: "setLargeTransferThreshold", args: &[Arg::Amount("threshold")] },
+    Endpoint { name: "removeLargeTransferThreshold", args: &[] },
+    Endpoint { name: "setLargeTransferReviewPeriod", args: &[Arg::Number("review_period")] },
+    Endpoint { name: "executePendingTransfer", args: &[Arg::Number("id")] },
+    Endpoint { name: "cancelPendingTransfer", args: &[Arg::Number("id")] },
+    Endpoint { name: "registerVasp", args: &[Arg::Address("address"), Arg::Hash("vasp_id")] },
+    Endpoint { name: "unregisterVasp", args: &[Arg::Address("address")] },
+    Endpoint { name: "setTravelRuleThreshold", args: &[Arg::Amount("threshold")] },
+    Endpoint { name: "removeTravelRuleThreshold", args: &[] },
+    Endpoint { name: "transferWithTravelRule", args: &[Arg::Address("to"), Arg::Amount("amount"), Arg::Hash("payload_hash"), Arg::Hash("vasp_id")] },
+    Endpoint { name: "setSupplyController", args: &[Arg::Address("supply_controller")] },
+    Endpoint { name: "increaseSupply", args: &[Arg::Amount("amount")] },
+    Endpoint { name: "decreaseSupply", args: &[Arg::Amount("amount")] },
+    Endpoint { name: "enableLegacyEvents", args: &[] },
+    Endpoint { name: "disableLegacyEvents", args: &[] },
+    Endpoint { name: "upgrade", args: &[] },
+    Endpoint { name: "registerHolders", args: &[Arg::Repeated(&[Arg::Address("address")])] },
+    Endpoint { name: "registerSpenders", args: &[Arg::Address("owner"), Arg::Repeated(&[Arg::Address("spender")])] },
+    Endpoint { name: "beginImport", args: &[Arg::Amount("total_supply")] },
+    Endpoint { name: "importState", args: &[Arg::Repeated(&[Arg::Address("address"), Arg::Amount("balance"), Arg::Flag("frozen")])] },
+    Endpoint { name: "importFrozen", args: &[Arg::Repeated(&[Arg::Address("address")])] },
+    Endpoint { name: "importAllowances", args: &[Arg::Repeated(&[Arg::Address("owner"), Arg::Address("spender"), Arg::Amount("amount")])] },
+    Endpoint { name: "enableEsdtMigration", args: &[Arg::Text("token_identifier")] },
+    Endpoint { name: "disableEsdtMigration", args: &[] },
+    Endpoint { name: "migrateToEsdt", args: &[Arg::Amount("value")] },
+    Endpoint { name: "snapshot", args: &[] },
+];
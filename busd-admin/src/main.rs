@@ -0,0 +1,127 @@
+//! Builds and signs BUSD contract transactions offline, e.g. on an air-gapped machine.
+//! The signed transaction is written as JSON, ready to be broadcast from a connected machine.
+//!
+//! Built with `cargo build` in `busd-admin`, a separate crate so that its `std` dependencies stay out of the contract.
+
+mod endpoints;
+mod transaction;
+mod wallet;
+
+use std::fs;
+use std::process;
+
+use endpoints::{Endpoint, ENDPOINTS};
+use transaction::{Transaction, TRANSACTION_VERSION};
+use wallet::{decode_address, encode_address, Wallet};
+
+const DEFAULT_GAS_LIMIT: u64 = 5_000_000;
+const DEFAULT_GAS_PRICE: u64 = 1_000_000_000;
+
+const USAGE: &str = "\
+Usage: busd-admin --pem FILE --contract ADDRESS --nonce N --chain ID [OPTIONS] <ENDPOINT> [ARGS...]
+       busd-admin endpoints
+
+Options:
+    --pem FILE         wallet key to sign with, e.g. alice.pem
+    --contract ADDRESS address of the BUSD contract
+    --nonce N          current nonce of the signing account
+    --chain ID         chain ID, e.g. 1 for mainnet
+    --gas-limit N      default 5000000
+    --gas-price N      default 1000000000
+    --outfile FILE     where to write the signed transaction, default standard output
+
+Addresses are bech32 (erd1...) or hex, amounts and numbers are decimal, hashes are hex.";
+
+#[derive(Default)]
+struct Options {
+    pem: Option<String>,
+    contract: Option<String>,
+    nonce: Option<u64>,
+    chain: Option<String>,
+    gas_limit: Option<u64>,
+    gas_price: Option<u64>,
+    outfile: Option<String>,
+    positional: Vec<String>,
+}
+
+fn parse_number(option: &str, value: Option<String>) -> Result<u64, String> {
+    let value = value.ok_or_else(|| format!("{} needs a value", option))?;
+    value.parse().map_err(|_| format!("{} must be a number, got {}", option, value))
+}
+
+fn parse_options<I: Iterator<Item = String>>(mut args: I) -> Result<Options, String> {
+    let mut options = Options::default();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--pem" => options.pem = Some(args.next().ok_or("--pem needs a file")?),
+            "--contract" => options.contract = Some(args.next().ok_or("--contract needs an address")?),
+            "--nonce" => options.nonce = Some(parse_number("--nonce", args.next())?),
+            "--chain" => options.chain = Some(args.next().ok_or("--chain needs an ID")?),
+            "--gas-limit" => options.gas_limit = Some(parse_number("--gas-limit", args.next())?),
+            "--gas-price" => options.gas_price = Some(parse_number("--gas-price", args.next())?),
+            "--outfile" => options.outfile = Some(args.next().ok_or("--outfile needs a file")?),
+            _ if arg.starts_with("--") => return Err(format!("unknown option {}", arg)),
+            _ => options.positional.push(arg),
+        }
+    }
+    Ok(options)
+}
+
+fn required<T>(value: Option<T>, option: &str) -> Result<T, String> {
+    value.ok_or_else(|| format!("missing {}", option))
+}
+
+fn build(options: Options) -> Result<Option<String>, String> {
+    let (name, values) = match options.positional.split_first() {
+        Some((name, values)) => (name, values),
+        None => return Err("missing endpoint".to_string()),
+    };
+    if name == "endpoints" {
+        let list: Vec<String> = ENDPOINTS.iter().map(Endpoint::usage).collect();
+        return Ok(Some(list.join("\n")));
+    }
+    let endpoint = Endpoint::find(name)
+        .ok_or_else(|| format!("unknown endpoint {}, run `busd-admin endpoints` for the list", name))?;
+    let data = endpoint.call_data(values)?;
+
+    let pem_file = required(options.pem, "--pem")?;
+    let pem = fs::read_to_string(&pem_file).map_err(|e| format!("cannot read {}: {}", pem_file, e))?;
+    let wallet = Wallet::from_pem(&pem)?;
+    let contract = decode_address(&required(options.contract, "--contract")?)?;
+
+    let mut transaction = Transaction {
+        nonce: required(options.nonce, "--nonce")?,
+        value: "0".to_string(),
+        receiver: encode_address(&contract),
+        sender: wallet.address(),
+        gas_price: options.gas_price.unwrap_or(DEFAULT_GAS_PRICE),
+        gas_limit: options.gas_limit.unwrap_or(DEFAULT_GAS_LIMIT),
+        data,
+        chain_id: required(options.chain, "--chain")?,
+        version: TRANSACTION_VERSION,
+        signature: None,
+    };
+    transaction.sign(&wallet);
+
+    let json = serde_json::to_string_pretty(&transaction.to_json()).unwrap();
+    match options.outfile {
+        Some(outfile) => {
+            fs::write(&outfile, json + "\n").map_err(|e| format!("cannot write {}: {}", outfile, e))?;
+            eprintln!("signed {} transaction written to {}", endpoint.name, outfile);
+            Ok(None)
+        }
+        None => Ok(Some(json)),
+    }
+}
+
+fn main() {
+    let result = parse_options(std::env::args().skip(1)).and_then(build);
+    match result {
+        Ok(Some(output)) => println!("{}", output),
+        Ok(None) => {}
+        Err(error) => {
+            eprintln!("error: {}\n\n{}", error, USAGE);
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use serde_json::{json, Map, Value};
+
+use crate::wallet::Wallet;
+
+pub const TRANSACTION_VERSION: u32 = 1;
+
+/// A contract call transaction, in the format accepted by the `/transaction/send` endpoint of the proxy.
+pub struct Transaction {
+    pub nonce: u64,
+    pub value: String,
+    pub receiver: String,
+    pub sender: String,
+    pub gas_price: u64,
+    pub gas_limit: u64,
+    pub data: String,
+    pub chain_id: String,
+    pub version: u32,
+    pub signature: Option<String>,
+}
+
+impl Transaction {
+    /// Field order matters: the signature is over this exact serialization.
+    fn to_json_map(&self) -> Map<String, Value> {
+        let mut map = Map::new();
+        map.insert("nonce".to_string(), json!(self.nonce));
+        map.insert("value".to_string(), json!(self.value));
+        map.insert("receiver".to_string(), json!(self.receiver));
+        map.insert("sender".to_string(), json!(self.sender));
+        map.insert("gasPrice".to_string(), json!(self.gas_price));
+        map.insert("gasLimit".to_string(), json!(self.gas_limit));
+        if !self.data.is_empty() {
+            map.insert("data".to_string(), json!(base64::encode(&self.data)));
+        }
+        map.insert("chainID".to_string(), json!(self.chain_id));
+        map.insert("version".to_string(), json!(self.version));
+        map
+    }
+
+    /// The bytes that get signed: the compact JSON of the transaction without its signature.
+    pub fn signing_bytes(&self) -> Vec<u8> {
+        serde_json::to_vec(&Value::Object(self.to_json_map())).unwrap()
+    }
+
+    pub fn sign(&mut self, wallet: &Wallet) {
+        assert_eq!(self.sender, wallet.address(), "signing with the key of another account");
+        self.signature = Some(hex::encode(&wallet.sign(&self.signing_bytes())[..]));
+    }
+
+    pub fn to_json(&self) -> Value {
+        let mut map = self.to_json_map();
+        if let Some(signature) = &self.signature {
+            map.insert("signature".to_string(), json!(signature));
+        }
+        Value::Object(map)
+    }
+}
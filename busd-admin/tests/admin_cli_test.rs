@@ -0,0 +1,122 @@
+//! Runs the `busd-admin` binary.
+
+use std::path::Path;
+use std::process::{Command, Output};
+
+use bech32::FromBase32;
+use ed25519_dalek::{PublicKey, Signature, Verifier};
+use serde_json::{Map, Value};
+
+const ALICE: &str = "erd1l453hd0gt5gzdp7czpuall8ggt2dcv5zwmfdf3sd3lguxseux2fsmsgldz";
+const CONTRACT: &str = "0x000000000000000005000c6f0d0f54e0ad0c1d3a7d1ff4e1d8a6a2a8e4e6b3a1";
+
+fn busd_admin(args: &[&str]) -> Output {
+    let pem = Path::new(env!("CARGO_MANIFEST_DIR")).join("../alice.pem");
+    Command::new(env!("CARGO_BIN_EXE_busd-admin"))
+        .args(["--pem", pem.to_str().unwrap(), "--contract", CONTRACT, "--nonce", "7", "--chain", "T"])
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+fn signed_transaction(args: &[&str]) -> Map<String, Value> {
+    let output = busd_admin(args);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    match serde_json::from_slice(&output.stdout).unwrap() {
+        Value::Object(map) => map,
+        other => panic!("not a transaction: {}", other),
+    }
+}
+
+fn data(transaction: &Map<String, Value>) -> String {
+    let data = base64::decode(transaction["data"].as_str().unwrap()).unwrap();
+    String::from_utf8(data).unwrap()
+}
+
+fn verify_signature(transaction: &Map<String, Value>) {
+    let mut unsigned = transaction.clone();
+    let signature = unsigned.remove("signature").unwrap();
+    let signature = hex::decode(signature.as_str().unwrap()).unwrap();
+    let message = serde_json::to_vec(&Value::Object(unsigned)).unwrap();
+
+    let (_, sender) = bech32::decode(transaction["sender"].as_str().unwrap()).unwrap();
+    let public_key = PublicKey::from_bytes(&Vec::<u8>::from_base32(&sender).unwrap()).unwrap();
+    let signature = Signature::from_bytes(&signature).unwrap();
+    public_key.verify(&message, &signature).expect("invalid signature");
+}
+
+#[test]
+fn test_pause() {
+    let transaction = signed_transaction(&["pause"]);
+    let fields: Vec<&str> = transaction.keys().map(String::as_str).collect();
+    assert_eq!(fields, vec!["nonce", "value", "receiver", "sender", "gasPrice", "gasLimit", "data", "chainID", "version", "signature"]);
+    assert_eq!(transaction["nonce"], 7);
+    assert_eq!(transaction["value"], "0");
+    assert_eq!(transaction["sender"], ALICE);
+    assert_eq!(transaction["gasLimit"], 5_000_000);
+    assert_eq!(transaction["chainID"], "T");
+    assert_eq!(data(&transaction), "pause");
+    verify_signature(&transaction);
+}
+
+#[test]
+fn test_argument_encoding() {
+    let transaction = signed_transaction(&["freeze", ALICE, "--gas-limit", "6000000"]);
+    assert_eq!(data(&transaction), "freeze@fd691bb5e85d102687d81079dffce842d4dc328276d2d4c60d8fd1c3433c3293");
+    assert_eq!(transaction["gasLimit"], 6_000_000);
+    verify_signature(&transaction);
+
+    let transaction = signed_transaction(&["increaseSupply", "1000000000000000000000"]);
+    assert_eq!(data(&transaction), "increaseSupply@3635c9adc5dea00000");
+
+    let transaction = signed_transaction(&["setLargeTransferThreshold", "0"]);
+    assert_eq!(data(&transaction), "setLargeTransferThreshold@");
+
+    let transaction = signed_transaction(&["executePendingTransfer", "258"]);
+    assert_eq!(data(&transaction), "executePendingTransfer@0102");
+
+    let transaction = signed_transaction(&["registerVasp", CONTRACT, &format!("0x{}", "ab".repeat(32))]);
+    assert_eq!(data(&transaction), format!("registerVasp@{}@{}", &CONTRACT[2..], "ab".repeat(32)));
+
+    let transaction = signed_transaction(&["importState", CONTRACT, "256", "true", CONTRACT, "0", "false"]);
+    assert_eq!(data(&transaction), format!("importState@{0}@0100@01@{0}@@", &CONTRACT[2..]));
+
+    let transaction = signed_transaction(&["registerHolders"]);
+    assert_eq!(data(&transaction), "registerHolders");
+
+    let transaction = signed_transaction(&["enableEsdtMigration", "BUSD-a1b2c3"]);
+    assert_eq!(data(&transaction), "enableEsdtMigration@425553442d613162326333");
+}
+
+#[test]
+fn test_outfile() {
+    let outfile = std::env::temp_dir().join("busd_admin_test_tx.json");
+    let output = busd_admin(&["unpause", "--outfile", outfile.to_str().unwrap()]);
+    assert!(output.status.success());
+    assert!(output.stdout.is_empty());
+    let transaction: Value = serde_json::from_str(&std::fs::read_to_string(&outfile).unwrap()).unwrap();
+    assert_eq!(data(transaction.as_object().unwrap()), "unpause");
+    std::fs::remove_file(outfile).unwrap();
+}
+
+#[test]
+fn test_errors() {
+    let cases: &[(&[&str], &str)] = &[
+        (&["unknownEndpoint"], "unknown endpoint unknownEndpoint"),
+        (&["freeze"], "wrong number of arguments, usage: freeze <address>"),
+        (&["freeze", "erd1notanaddress"], "invalid address erd1notanaddress"),
+        (&["increaseSupply", "-5"], "invalid amount: -5 is not a non-negative integer"),
+        (&["registerVasp", ALICE, "0x1234"], "invalid vasp_id: 0x1234 is not 32 bytes of hex"),
+        (&["importState", ALICE, "5"], "wrong number of arguments, usage: importState [<address> <balance> <frozen>]..."),
+        (&["importState", ALICE, "5", "yes"], "invalid frozen: yes is not true or false"),
+        (&["registerSpenders"], "wrong number of arguments, usage: registerSpenders <owner> [<spender>]..."),
+        (&["enableEsdtMigration", ""], "invalid token_identifier: empty"),
+        (&["pause", "--gas-limit", "lots"], "--gas-limit must be a number, got lots"),
+    ];
+    for (args, error) in cases {
+        let output = busd_admin(args);
+        assert!(!output.status.success());
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(stderr.starts_with(&format!("error: {}", error)), "{}", stderr);
+    }
+}
@@ -0,0 +1,47 @@
+//! Checks the endpoints `busd-admin` builds transactions for against the ABI generated by abi-gen.
+
+#[allow(dead_code)]
+#[path = "../src/wallet.rs"]
+mod wallet;
+#[allow(dead_code)]
+#[path = "../src/endpoints.rs"]
+mod endpoints;
+
+use endpoints::{Arg, ENDPOINTS};
+use serde_json::Value;
+
+const ABI_FILE: &str = include_str!("../../busd.abi.json");
+
+fn abi_type(arg: Arg) -> String {
+    match arg {
+        Arg::Address(_) => "Address".to_string(),
+        Arg::Amount(_) => "BigUint".to_string(),
+        Arg::Number(_) => "u64".to_string(),
+        Arg::Hash(_) => "H256".to_string(),
+        Arg::Flag(_) => "bool".to_string(),
+        Arg::Text(_) => "bytes".to_string(),
+        Arg::Repeated(&[single]) => format!("variadic<{}>", abi_type(single)),
+        Arg::Repeated(group) => {
+            let types: Vec<String> = group.iter().copied().map(abi_type).collect();
+            format!("variadic<multi<{}>>", types.join(","))
+        },
+    }
+}
+
+#[test]
+fn test_endpoints_match_abi() {
+    let abi: Value = serde_json::from_str(ABI_FILE).unwrap();
+    let mutable: Vec<(String, Vec<String>)> = abi["endpoints"].as_array().unwrap().iter()
+        .filter(|endpoint| endpoint["mutability"] == "mutable")
+        .map(|endpoint| {
+            let inputs = endpoint["inputs"].as_array().unwrap().iter()
+                .map(|input| input["type"].as_str().unwrap().to_string())
+                .collect();
+            (endpoint["name"].as_str().unwrap().to_string(), inputs)
+        })
+        .collect();
+    let known: Vec<(String, Vec<String>)> = ENDPOINTS.iter()
+        .map(|endpoint| (endpoint.name.to_string(), endpoint.args.iter().copied().map(abi_type).collect()))
+        .collect();
+    assert_eq!(known, mutable, "ENDPOINTS is out of date with busd.abi.json");
+}
@@ -0,0 +1,105 @@
+//! Example contract calling BUSD through `sc-busd-proxy`.
+//!
+//! It holds BUSD and pays it out on request of its owner,
+//! keeping track of the payments that went through, the last failure, and its last known BUSD balance.
+
+#![no_std]
+#![no_main]
+#![allow(non_snake_case)]
+#![allow(unused_attributes)]
+// storage setters take `&Vec<u8>`, the storage macro only saves sized values
+#![allow(clippy::ptr_arg)]
+
+imports!();
+
+use sc_busd_proxy::BUSDCoin;
+
+#[elrond_wasm_derive::contract(BUSDConsumerImpl)]
+pub trait BUSDConsumer {
+
+    #[init]
+    fn init(&self, busd_address: &Address) {
+        let owner = self.get_caller();
+        self.set_owner(&owner);
+        self.set_busd_address(busd_address);
+    }
+
+    // STORAGE
+
+    #[view(getOwner)]
+    #[storage_get("owner")]
+    fn get_owner(&self) -> Address;
+
+    #[storage_set("owner")]
+    fn set_owner(&self, owner: &Address);
+
+    #[view(getBusdAddress)]
+    #[storage_get("busd")]
+    fn get_busd_address(&self) -> Address;
+
+    #[storage_set("busd")]
+    fn set_busd_address(&self, busd_address: &Address);
+
+    #[view(getPaymentCount)]
+    #[storage_get("payments")]
+    fn get_payment_count(&self) -> u64;
+
+    #[storage_set("payments")]
+    fn set_payment_count(&self, count: u64);
+
+    #[view(getLastError)]
+    #[storage_get("last_error")]
+    fn get_last_error(&self) -> Vec<u8>;
+
+    #[storage_set("last_error")]
+    fn set_last_error(&self, message: &Vec<u8>);
+
+    #[view(getKnownBalance)]
+    #[storage_get("balance")]
+    fn get_known_balance(&self) -> BigUint;
+
+    #[storage_set("balance")]
+    fn set_known_balance(&self, balance: &BigUint);
+
+    // ENDPOINTS
+
+    /// Sends BUSD from this contract to `to`. The outcome is only known in the callback.
+    #[endpoint]
+    fn pay(&self, to: &Address, amount: &BigUint) -> Result<(), SCError> {
+        if self.get_caller() != self.get_owner() {
+            return sc_error!("only owner can pay");
+        }
+
+        let busd = contract_proxy!(self, &self.get_busd_address(), BUSDCoin);
+        busd.transfer(to, amount);
+        Ok(())
+    }
+
+    /// Asks BUSD for the balance of this contract, saved by the callback.
+    #[endpoint(refreshBalance)]
+    fn refresh_balance(&self) {
+        let busd = contract_proxy!(self, &self.get_busd_address(), BUSDCoin);
+        busd.balanceOf(&self.get_sc_address());
+    }
+
+    // CALLBACKS
+
+    #[callback]
+    fn busd_transfer_callback(&self, result: AsyncCallResult<()>) {
+        match result {
+            AsyncCallResult::Ok(()) => {
+                self.set_payment_count(self.get_payment_count() + 1);
+            },
+            AsyncCallResult::Err(error) => {
+                self.set_last_error(&error.err_msg);
+            },
+        }
+    }
+
+    #[callback]
+    fn busd_balance_of_callback(&self, result: AsyncCallResult<BigUint>) {
+        if let AsyncCallResult::Ok(balance) = result {
+            self.set_known_balance(&balance);
+        }
+    }
+}
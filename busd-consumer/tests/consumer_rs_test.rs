@@ -0,0 +1,155 @@
+//! Runs the consumer against the BUSD contract, both on the mock.
+//!
+//! The async calls recorded on the consumer side are decoded and executed on BUSD,
+//! then their result is passed to the consumer callback, as Arwen would do.
+
+#[path = "../../tests/mock/mod.rs"]
+mod mock;
+use mock::*;
+
+use std::convert::TryInto;
+
+use busd_consumer::*;
+use elrond_wasm::{Address, AsyncCallError, AsyncCallResult, BigUintApi};
+use sc_busd_rs::*;
+
+/// Arwen error code for errors signalled by the called contract.
+const USER_ERROR: i32 = 4;
+
+type MockConsumer = BUSDConsumerImpl<MockApi, MockBigInt, MockBigUint>;
+
+struct ConsumerTest {
+    busd: BUSDTest,
+    api: MockApi,
+    consumer: MockConsumer,
+}
+
+fn decode_hex(hex: &str) -> Vec<u8> {
+    (0..hex.len()).step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+fn decode_address(hex: &str) -> Address {
+    let bytes: [u8; 32] = decode_hex(hex).as_slice().try_into().unwrap();
+    bytes.into()
+}
+
+impl ConsumerTest {
+    /// Deploys BUSD and the consumer, and mints `balance` to the consumer.
+    fn deploy(balance: u64) -> Self {
+        let busd = BUSDTest::deploy_unpaused();
+        busd.mint("busd_consumer", balance);
+
+        let api = MockApi::default();
+        {
+            let mut state = api.state.borrow_mut();
+            state.sc_address = address("busd_consumer").into();
+            state.caller = address("consumer_owner").into();
+        }
+        let consumer = BUSDConsumerImpl::new(api.clone());
+        consumer.init(&address("busd_contract"));
+        ConsumerTest { busd, api, consumer }
+    }
+
+    fn call_as(&self, name: &str) -> &MockConsumer {
+        self.api.state.borrow_mut().caller = address(name).into();
+        &self.consumer
+    }
+
+    /// Takes the only async call made by the consumer, checks it targets BUSD, and splits its data.
+    fn take_async_call(&self) -> (String, Vec<String>) {
+        let calls: Vec<MockAsyncCall> = self.api.state.borrow_mut().async_calls.drain(..).collect();
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].to, address("busd_contract"));
+        let data = String::from_utf8(calls[0].data.clone()).unwrap();
+        let mut parts = data.split('@').map(str::to_string);
+        (parts.next().unwrap(), parts.collect())
+    }
+
+    /// The callback the consumer registered for its last async call.
+    fn registered_callback(&self) -> Vec<u8> {
+        let tx_hash = self.api.state.borrow().tx_hash;
+        self.api.state.borrow().storage[&tx_hash.to_vec()].clone()
+    }
+
+    /// Executes the pending transfer on BUSD, as the consumer, and passes the result to the callback.
+    fn relay_transfer(&self) {
+        let (function, args) = self.take_async_call();
+        assert_eq!(function, "transfer");
+        assert_eq!(self.registered_callback(), b"busd_transfer_callback".to_vec());
+
+        let to = decode_address(&args[0]);
+        let amount = MockBigUint::from_bytes_be(&decode_hex(&args[1]));
+        let result = match self.busd.execute("busd_consumer", |c| c.transfer(to, amount)) {
+            Ok(()) => AsyncCallResult::Ok(()),
+            Err(error) => AsyncCallResult::Err(AsyncCallError {
+                err_code: USER_ERROR,
+                err_msg: error.message().as_bytes().to_vec(),
+            }),
+        };
+        self.consumer.busd_transfer_callback(result);
+    }
+
+    /// Executes the pending balance query on BUSD and passes the result to the callback.
+    fn relay_balance_of(&self) {
+        let (function, args) = self.take_async_call();
+        assert_eq!(function, "balanceOf");
+        assert_eq!(self.registered_callback(), b"busd_balance_of_callback".to_vec());
+
+        let balance = self.busd.contract.balance_of(&decode_address(&args[0]));
+        self.consumer.busd_balance_of_callback(AsyncCallResult::Ok(balance));
+    }
+}
+
+#[test]
+fn test_init() {
+    let test = ConsumerTest::deploy(0);
+    assert_eq!(test.consumer.get_owner(), address("consumer_owner"));
+    assert_eq!(test.consumer.get_busd_address(), address("busd_contract"));
+    assert_eq!(test.consumer.get_payment_count(), 0);
+}
+
+#[test]
+fn test_pay() {
+    let test = ConsumerTest::deploy(1000);
+    test.call_as("consumer_owner").pay(&address("holder_1"), &big(300)).unwrap();
+    test.relay_transfer();
+
+    assert_eq!(test.busd.balance("busd_consumer"), big(700));
+    assert_eq!(test.busd.balance("holder_1"), big(300));
+    assert_eq!(test.consumer.get_payment_count(), 1);
+    assert!(test.consumer.get_last_error().is_empty());
+}
+
+#[test]
+fn test_pay_failure_reaches_callback() {
+    let test = ConsumerTest::deploy(1000);
+    test.call_as("consumer_owner").pay(&address("holder_1"), &big(1001)).unwrap();
+    test.relay_transfer();
+
+    assert_eq!(test.busd.balance("busd_consumer"), big(1000));
+    assert_eq!(test.consumer.get_payment_count(), 0);
+    assert_eq!(test.consumer.get_last_error(), b"insufficient funds".to_vec());
+}
+
+#[test]
+fn test_pay_only_owner() {
+    let test = ConsumerTest::deploy(1000);
+    assert!(test.call_as("holder_1").pay(&address("holder_1"), &big(300)).is_err());
+    assert!(test.api.state.borrow().async_calls.is_empty());
+}
+
+#[test]
+fn test_refresh_balance() {
+    let test = ConsumerTest::deploy(1000);
+    test.call_as("holder_1").refresh_balance();
+    test.relay_balance_of();
+    assert_eq!(test.consumer.get_known_balance(), big(1000));
+
+    test.call_as("consumer_owner").pay(&address("holder_1"), &big(400)).unwrap();
+    test.relay_transfer();
+    test.consumer.refresh_balance();
+    test.relay_balance_of();
+    assert_eq!(test.consumer.get_known_balance(), big(600));
+}
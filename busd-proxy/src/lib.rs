@@ -0,0 +1,380 @@
+//! Typed proxy for calling the BUSD contract from other contracts.
+//!
+//! Mirrors every endpoint and view of `BUSDCoin`, named after the endpoint so the call data matches.
+//! Cast a contract address to the proxy with `contract_proxy!(self, &busd_address, BUSDCoin)`,
+//! then call it like a local method:
+//!
+//! ```ignore
+//! let busd = contract_proxy!(self, &self.get_busd_address(), BUSDCoin);
+//! busd.transfer(&to, &amount);
+//! ```
+//!
+//! Calls are asynchronous. Each one comes back to a callback of the calling contract,
+//! named after the endpoint in snake case: `transferFrom` calls back `busd_transfer_from_callback`.
+//! The calling contract declares the callbacks of the endpoints it uses, with a single `result` argument:
+//!
+//! ```ignore
+//! #[callback]
+//! fn busd_balance_of_callback(&self, result: AsyncCallResult<BigUint>) {
+//!     ...
+//! }
+//! ```
+//!
+//! Endpoints other than `snapshot` have no result, so their callbacks take an `AsyncCallResult<()>`.
+//! The result types of the views, and of `snapshot`, are listed below.
+//! Optional results of the contract are received as `OptionalArg`, multi-results as `MultiArgN` and `VarArgs`.
+//!
+//! Arguments are never named `amount`, which the generated code uses for the EGLD value sent along.
+//!
+//! Keep in sync with `src/lib.rs` when endpoints change.
+
+#![no_std]
+#![allow(non_snake_case)]
+// byte arguments are `&Vec<u8>`: the callable macro only pushes sized arguments as call data
+#![allow(clippy::ptr_arg)]
+
+elrond_wasm::imports!();
+
+#[elrond_wasm_derive::callable(BUSDCoinProxy)]
+pub trait BUSDCoin {
+
+    // TOKEN METADATA
+
+    /// Result: `Vec<u8>`
+    #[callback(busd_name_callback)]
+    fn name(&self);
+
+    /// Result: `Vec<u8>`
+    #[callback(busd_symbol_callback)]
+    fn symbol(&self);
+
+    /// Result: `u32`
+    #[callback(busd_decimals_callback)]
+    fn decimals(&self);
+
+    // ERC20 LOGIC
+
+    /// Result: `BigUint`
+    #[callback(busd_total_supply_callback)]
+    fn totalSupply(&self);
+
+    #[callback(busd_transfer_callback)]
+    fn transfer(&self, to: &Address, value: &BigUint);
+
+    /// Result: `BigUint`
+    #[callback(busd_balance_of_callback)]
+    fn balanceOf(&self, address: &Address);
+
+    #[callback(busd_transfer_from_callback)]
+    fn transferFrom(&self, sender: &Address, recipient: &Address, value: &BigUint);
+
+    #[callback(busd_approve_callback)]
+    fn approve(&self, spender: &Address, value: &BigUint);
+
+    /// Result: `BigUint`
+    #[callback(busd_allowance_callback)]
+    fn allowance(&self, owner: &Address, spender: &Address);
+
+    // TRANSFER RESTRICTION FUNCTIONALITY
+
+    /// Result: `u8`
+    #[callback(busd_detect_transfer_restriction_callback)]
+    fn detectTransferRestriction(&self, from: &Address, to: &Address, value: &BigUint);
+
+    /// Result: `Vec<u8>`
+    #[callback(busd_message_for_transfer_restriction_callback)]
+    fn messageForTransferRestriction(&self, restriction_code: u8);
+
+    // ERROR CODES
+
+    /// Result: `Vec<u8>`
+    #[callback(busd_get_error_message_callback)]
+    fn getErrorMessage(&self, error_code: u32);
+
+    /// Result: `u32`
+    #[callback(busd_get_error_code_callback)]
+    fn getErrorCode(&self, message: &Vec<u8>);
+
+    // OWNER FUNCTIONALITY
+
+    /// Result: `Address`
+    #[callback(busd_get_contract_owner_callback)]
+    fn getContractOwner(&self);
+
+    /// Result: `OptionalArg<Address>`
+    #[callback(busd_get_proposed_owner_callback)]
+    fn getProposedOwner(&self);
+
+    #[callback(busd_propose_owner_callback)]
+    fn proposeOwner(&self, proposed_owner: &Address);
+
+    #[callback(busd_disregard_proposed_owner_callback)]
+    fn disregardProposedOwner(&self);
+
+    #[callback(busd_claim_ownership_callback)]
+    fn claimOwnership(&self);
+
+    #[callback(busd_reclaim_busd_callback)]
+    fn reclaimBUSD(&self);
+
+    // PAUSABILITY FUNCTIONALITY
+
+    /// Result: `bool`
+    #[callback(busd_is_paused_callback)]
+    fn isPaused(&self);
+
+    #[callback(busd_pause_callback)]
+    fn pause(&self);
+
+    #[callback(busd_unpause_callback)]
+    fn unpause(&self);
+
+    /// Result: `OptionalArg<Address>`
+    #[callback(busd_get_pauser_callback)]
+    fn getPauser(&self);
+
+    #[callback(busd_set_pauser_callback)]
+    fn setPauser(&self, pauser: &Address);
+
+    // ASSET PROTECTION FUNCTIONALITY
+
+    /// Result: `OptionalArg<Address>`
+    #[callback(busd_get_asset_protection_role_callback)]
+    fn getAssetProtectionRole(&self);
+
+    #[callback(busd_set_asset_protection_role_callback)]
+    fn setAssetProtectionRole(&self, asset_protection_role: &Address);
+
+    #[callback(busd_freeze_callback)]
+    fn freeze(&self, address: &Address);
+
+    #[callback(busd_unfreeze_callback)]
+    fn unfreeze(&self, address: &Address);
+
+    #[callback(busd_wipe_frozen_address_callback)]
+    fn wipeFrozenAddress(&self, address: &Address);
+
+    /// Result: `bool`
+    #[callback(busd_is_frozen_callback)]
+    fn isFrozen(&self, address: &Address);
+
+    // VELOCITY LIMIT FUNCTIONALITY
+
+    /// Result: `OptionalArg<BigUint>`
+    #[callback(busd_get_daily_limit_callback)]
+    fn getDailyLimit(&self, address: &Address);
+
+    /// Result: `OptionalArg<BigUint>`
+    #[callback(busd_get_default_daily_limit_callback)]
+    fn getDefaultDailyLimit(&self);
+
+    /// Result: `BigUint`
+    #[callback(busd_get_daily_spent_callback)]
+    fn getDailySpent(&self, address: &Address);
+
+    /// Result: `OptionalArg<BigUint>`
+    #[callback(busd_get_remaining_daily_capacity_callback)]
+    fn getRemainingDailyCapacity(&self, address: &Address);
+
+    #[callback(busd_set_daily_limit_callback)]
+    fn setDailyLimit(&self, address: &Address, limit: &BigUint);
+
+    #[callback(busd_remove_daily_limit_callback)]
+    fn removeDailyLimit(&self, address: &Address);
+
+    #[callback(busd_set_default_daily_limit_callback)]
+    fn setDefaultDailyLimit(&self, limit: &BigUint);
+
+    #[callback(busd_remove_default_daily_limit_callback)]
+    fn removeDefaultDailyLimit(&self);
+
+    // LARGE TRANSFER FUNCTIONALITY
+
+    /// Result: `OptionalArg<BigUint>`
+    #[callback(busd_get_large_transfer_threshold_callback)]
+    fn getLargeTransferThreshold(&self);
+
+    /// Result: `u64`
+    #[callback(busd_get_large_transfer_review_period_callback)]
+    fn getLargeTransferReviewPeriod(&self);
+
+    /// Result: `VarArgs<u64>`
+    #[callback(busd_get_pending_transfer_ids_callback)]
+    fn getPendingTransferIds(&self);
+
+    /// Result: `BigUint`
+    #[callback(busd_get_pending_transfers_total_callback)]
+    fn getPendingTransfersTotal(&self);
+
+    /// Result: `MultiArg4<Address, Address, BigUint, u64>`, sender, recipient, amount and queue timestamp
+    #[callback(busd_get_pending_transfer_callback)]
+    fn getPendingTransfer(&self, id: u64);
+
+    #[callback(busd_set_large_transfer_threshold_callback)]
+    fn setLargeTransferThreshold(&self, threshold: &BigUint);
+
+    #[callback(busd_remove_large_transfer_threshold_callback)]
+    fn removeLargeTransferThreshold(&self);
+
+    #[callback(busd_set_large_transfer_review_period_callback)]
+    fn setLargeTransferReviewPeriod(&self, review_period: u64);
+
+    #[callback(busd_execute_pending_transfer_callback)]
+    fn executePendingTransfer(&self, id: u64);
+
+    #[callback(busd_cancel_pending_transfer_callback)]
+    fn cancelPendingTransfer(&self, id: u64);
+
+    // TRAVEL RULE FUNCTIONALITY
+
+    /// Result: `OptionalArg<H256>`
+    #[callback(busd_get_vasp_id_callback)]
+    fn getVaspId(&self, address: &Address);
+
+    /// Result: `OptionalArg<BigUint>`
+    #[callback(busd_get_travel_rule_threshold_callback)]
+    fn getTravelRuleThreshold(&self);
+
+    #[callback(busd_register_vasp_callback)]
+    fn registerVasp(&self, address: &Address, vasp_id: &H256);
+
+    #[callback(busd_unregister_vasp_callback)]
+    fn unregisterVasp(&self, address: &Address);
+
+    #[callback(busd_set_travel_rule_threshold_callback)]
+    fn setTravelRuleThreshold(&self, threshold: &BigUint);
+
+    #[callback(busd_remove_travel_rule_threshold_callback)]
+    fn removeTravelRuleThreshold(&self);
+
+    #[callback(busd_transfer_with_travel_rule_callback)]
+    fn transferWithTravelRule(&self, to: &Address, value: &BigUint, payload_hash: &H256, vasp_id: &H256);
+
+    // SUPPLY CONTROL FUNCTIONALITY
+
+    /// Result: `Address`
+    #[callback(busd_get_supply_controller_callback)]
+    fn getSupplyController(&self);
+
+    #[callback(busd_set_supply_controller_callback)]
+    fn setSupplyController(&self, supply_controller: &Address);
+
+    #[callback(busd_increase_supply_callback)]
+    fn increaseSupply(&self, value: &BigUint);
+
+    #[callback(busd_decrease_supply_callback)]
+    fn decreaseSupply(&self, value: &BigUint);
+
+    // LEGACY EVENTS FUNCTIONALITY
+
+    /// Result: `bool`
+    #[callback(busd_legacy_events_enabled_callback)]
+    fn legacyEventsEnabled(&self);
+
+    #[callback(busd_enable_legacy_events_callback)]
+    fn enableLegacyEvents(&self);
+
+    #[callback(busd_disable_legacy_events_callback)]
+    fn disableLegacyEvents(&self);
+
+    // UPGRADE FUNCTIONALITY
+
+    /// Result: `u32`
+    #[callback(busd_get_storage_version_callback)]
+    fn getStorageVersion(&self);
+
+    /// Result: `u32`
+    #[callback(busd_get_latest_storage_version_callback)]
+    fn getLatestStorageVersion(&self);
+
+    #[callback(busd_upgrade_callback)]
+    fn upgrade(&self);
+
+    // STATE MIGRATION FUNCTIONALITY
+
+    /// Result: `u64`
+    #[callback(busd_holder_count_callback)]
+    fn holderCount(&self);
+
+    /// Result: `VarArgs<Address>`
+    #[callback(busd_get_holders_callback)]
+    fn getHolders(&self, from: u64, count: u64);
+
+    #[callback(busd_register_holders_callback)]
+    fn registerHolders(&self, addresses: &VarArgs<Address>);
+
+    #[callback(busd_register_spenders_callback)]
+    fn registerSpenders(&self, owner: &Address, spenders: &VarArgs<Address>);
+
+    /// Result: `VarArgs<MultiArg3<Address, BigUint, bool>>`, address, balance and frozen flag
+    #[callback(busd_export_state_callback)]
+    fn exportState(&self, from: u64, count: u64);
+
+    /// Result: `u64`
+    #[callback(busd_frozen_count_callback)]
+    fn frozenCount(&self);
+
+    /// Result: `VarArgs<Address>`
+    #[callback(busd_export_frozen_callback)]
+    fn exportFrozen(&self, from: u64, count: u64);
+
+    /// Result: `VarArgs<MultiArg2<Address, BigUint>>`, spender and allowance
+    #[callback(busd_export_allowances_callback)]
+    fn exportAllowances(&self, owner: &Address);
+
+    /// Result: `OptionalArg<BigUint>`
+    #[callback(busd_get_import_total_supply_callback)]
+    fn getImportTotalSupply(&self);
+
+    #[callback(busd_begin_import_callback)]
+    fn beginImport(&self, total_supply: &BigUint);
+
+    /// Each holder is an address, balance and frozen flag, given as a `MultiResult3` so it can be pushed as call data.
+    #[callback(busd_import_state_callback)]
+    fn importState(&self, holders: &VarArgs<MultiResult3<Address, BigUint, bool>>);
+
+    #[callback(busd_import_frozen_callback)]
+    fn importFrozen(&self, addresses: &VarArgs<Address>);
+
+    /// Each allowance is an owner, spender and amount.
+    #[callback(busd_import_allowances_callback)]
+    fn importAllowances(&self, allowances: &VarArgs<MultiResult3<Address, Address, BigUint>>);
+
+    // ESDT MIGRATION FUNCTIONALITY
+
+    /// Result: `Vec<u8>`
+    #[callback(busd_get_esdt_token_callback)]
+    fn getEsdtToken(&self);
+
+    /// Result: `BigUint`
+    #[callback(busd_get_esdt_migrated_supply_callback)]
+    fn getEsdtMigratedSupply(&self);
+
+    #[callback(busd_enable_esdt_migration_callback)]
+    fn enableEsdtMigration(&self, token_identifier: &Vec<u8>);
+
+    #[callback(busd_disable_esdt_migration_callback)]
+    fn disableEsdtMigration(&self);
+
+    /// The ESDT is sent to the calling contract.
+    #[callback(busd_migrate_to_esdt_callback)]
+    fn migrateToEsdt(&self, value: &BigUint);
+
+    // SNAPSHOT FUNCTIONALITY
+
+    /// Result: `u64`
+    #[callback(busd_get_current_snapshot_id_callback)]
+    fn getCurrentSnapshotId(&self);
+
+    /// Result: `u64`, the id of the new snapshot
+    #[callback(busd_snapshot_callback)]
+    fn snapshot(&self);
+
+    /// Result: `BigUint`
+    #[callback(busd_balance_of_at_callback)]
+    fn balanceOfAt(&self, address: &Address, snapshot_id: u64);
+
+    /// Result: `BigUint`
+    #[callback(busd_total_supply_at_callback)]
+    fn totalSupplyAt(&self, snapshot_id: u64);
+}
@@ -0,0 +1,101 @@
+#[path = "../../tests/mock/mod.rs"]
+mod mock;
+use mock::*;
+
+use std::collections::BTreeSet;
+
+use elrond_wasm::{BigUintApi, OtherContractHandle, H256};
+use sc_busd_proxy::BUSDCoin;
+
+type MockProxy = OtherContractHandle<MockApi, MockBigInt, MockBigUint>;
+
+fn proxy(api: &MockApi) -> MockProxy {
+    OtherContractHandle::new(api.clone(), &address("busd_contract"))
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn async_calls(api: &MockApi) -> Vec<String> {
+    api.state.borrow().async_calls.iter()
+        .map(|call| {
+            assert_eq!(call.to, address("busd_contract"));
+            assert_eq!(call.amount, MockBigUint::zero());
+            String::from_utf8(call.data.clone()).unwrap()
+        })
+        .collect()
+}
+
+#[test]
+fn test_call_data() {
+    let api = MockApi::default();
+    let busd = proxy(&api);
+    busd.transfer(&address("holder_1"), &big(1000));
+    busd.transferFrom(&address("holder_1"), &address("holder_2"), &big(0));
+    busd.balanceOf(&address("holder_1"));
+    busd.totalSupply();
+    busd.executePendingTransfer(258);
+    busd.messageForTransferRestriction(5);
+    busd.getErrorCode(&b"paused".to_vec());
+    busd.registerVasp(&address("holder_1"), &H256::from([0xab; 32]));
+
+    let holder_1 = hex(address("holder_1").as_bytes());
+    let holder_2 = hex(address("holder_2").as_bytes());
+    assert_eq!(async_calls(&api), vec![
+        format!("transfer@{}@03e8", holder_1),
+        format!("transferFrom@{}@{}@", holder_1, holder_2),
+        format!("balanceOf@{}", holder_1),
+        "totalSupply".to_string(),
+        "executePendingTransfer@0102".to_string(),
+        "messageForTransferRestriction@05".to_string(),
+        format!("getErrorCode@{}", hex(b"paused")),
+        format!("registerVasp@{}@{}", holder_1, "ab".repeat(32)),
+    ]);
+}
+
+#[test]
+fn test_callback_data() {
+    let api = MockApi::default();
+    api.state.borrow_mut().tx_hash = [7u8; 32];
+    proxy(&api).transferFrom(&address("holder_1"), &address("holder_2"), &big(1));
+
+    let storage = api.state.borrow().storage.clone();
+    assert_eq!(storage.len(), 1);
+    assert_eq!(storage[&vec![7u8; 32]], b"busd_transfer_from_callback".to_vec());
+}
+
+/// Endpoint and view names declared in the contract source, e.g. `#[endpoint(transferFrom)]` or `#[view]` before `fn name`.
+fn contract_endpoint_names(source: &str) -> BTreeSet<String> {
+    let mut names = BTreeSet::new();
+    let mut lines = source.lines().map(str::trim);
+    while let Some(line) = lines.next() {
+        let rest = match line.strip_prefix("#[endpoint").or_else(|| line.strip_prefix("#[view")) {
+            Some(rest) => rest,
+            None => continue,
+        };
+        if let Some(name) = rest.strip_prefix('(') {
+            names.insert(name.trim_end_matches(")]").to_string());
+        } else {
+            let function = lines.find(|line| line.starts_with("fn ")).unwrap();
+            names.insert(function_name(function));
+        }
+    }
+    names
+}
+
+fn function_name(line: &str) -> String {
+    let name = line.trim_start_matches("fn ");
+    name[..name.find('(').unwrap()].to_string()
+}
+
+#[test]
+fn test_proxy_mirrors_every_endpoint() {
+    let contract = include_str!("../../src/lib.rs");
+    let proxy = include_str!("../src/lib.rs");
+    let proxied: BTreeSet<String> = proxy.lines().map(str::trim)
+        .filter(|line| line.starts_with("fn "))
+        .map(function_name)
+        .collect();
+    assert_eq!(proxied, contract_endpoint_names(contract));
+}
@@ -0,0 +1,405 @@
+use std::convert::TryInto;
+
+use num_bigint::BigUint;
+use serde_json::Value;
+
+use busd_storage_inspector::dump::mandos_value;
+use busd_storage_inspector::Address;
+
+/// A raw log entry, as written by the contract: the event identifier,
+/// one 32-byte topic per indexed argument and the top-encoded data argument.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LogEntry {
+    pub address: Address,
+    pub identifier: [u8; 32],
+    pub topics: Vec<Vec<u8>>,
+    pub data: Vec<u8>,
+}
+
+impl LogEntry {
+    /// `{"address", "identifier", "topics", "data"}`, the address in bech32 or hex, everything else in hex.
+    pub fn from_json(log: &Value) -> Result<LogEntry, String> {
+        let field = |name: &str| log[name].as_str().ok_or_else(|| format!("log {} is not a string", name));
+        let topics = log["topics"].as_array().ok_or("log topics is not an array")?;
+        let topics: Result<Vec<Vec<u8>>, String> = topics.iter()
+            .map(|topic| topic.as_str().ok_or_else(|| "topic is not a string".to_string()).and_then(decode_hex))
+            .collect();
+        LogEntry::new(field("address")?.parse()?, &decode_hex(field("identifier")?)?, topics?, decode_hex(field("data")?)?)
+    }
+
+    /// A log of the `expect` section of a Mandos step, all fields in Mandos notation.
+    pub fn from_mandos(log: &Value) -> Result<LogEntry, String> {
+        let field = |name: &str| log[name].as_str().ok_or_else(|| format!("log {} is not a string", name)).and_then(mandos_value);
+        let topics = log["topics"].as_array().ok_or("log topics is not an array")?;
+        let topics: Result<Vec<Vec<u8>>, String> = topics.iter()
+            .map(|topic| topic.as_str().ok_or_else(|| "topic is not a string".to_string()).and_then(mandos_value))
+            .collect();
+        let address = field("address")?;
+        let address = Address::from_slice(&address).ok_or("log address is not 32 bytes")?;
+        LogEntry::new(address, &field("identifier")?, topics?, field("data")?)
+    }
+
+    fn new(address: Address, identifier: &[u8], topics: Vec<Vec<u8>>, data: Vec<u8>) -> Result<LogEntry, String> {
+        let identifier = identifier.try_into().map_err(|_| format!("identifier of {} bytes, expected 32", identifier.len()))?;
+        Ok(LogEntry { address, identifier, topics, data })
+    }
+}
+
+fn decode_hex(value: &str) -> Result<Vec<u8>, String> {
+    hex::decode(value.strip_prefix("0x").unwrap_or(value)).map_err(|e| format!("invalid hex {}: {}", value, e))
+}
+
+/// A contract event, named as in its identifier, see `Event::name`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Event {
+    /// Roles that are not set are logged as the zero address, decoded as `None`.
+    Initialized {
+        owner: Address,
+        supply_controller: Address,
+        asset_protection_role: Option<Address>,
+        pauser: Option<Address>,
+        paused: bool,
+    },
+    Transfer { sender: Address, recipient: Address, amount: BigUint },
+    /// `sender` is the owner of the funds.
+    Approval { sender: Address, spender: Address, amount: BigUint },
+    OwnershipTransferProposed { current_owner: Address, proposed_owner: Address },
+    OwnershipTransferDisregarded { old_proposed_owner: Address },
+    OwnershipTransferred { old_owner: Address, new_owner: Address },
+    /// Followed by the transfer from the contract to the owner.
+    Reclaimed { owner: Address, amount: BigUint },
+    /// The caller is not logged by the legacy events.
+    Pause { caller: Option<Address> },
+    Unpause { caller: Option<Address> },
+    /// The old pauser is the zero address when it was not set.
+    PauserSet { old_pauser: Address, new_pauser: Address },
+    AddressFrozen { address: Address },
+    AddressUnfrozen { address: Address },
+    FrozenAddressWiped { address: Address },
+    /// The old role is the zero address when it was not set.
+    AssetProtectionRoleSet { old_asset_protection_role: Address, new_asset_protection_role: Address },
+    SupplyIncreased { to: Address, amount: BigUint },
+    SupplyDecreased { from: Address, amount: BigUint },
+    SupplyControllerSet { old_supply_controller: Address, new_supply_controller: Address },
+    DailyLimitSet { address: Address, limit: BigUint },
+    DailyLimitRemoved { address: Address },
+    DefaultDailyLimitSet { limit: BigUint },
+    DefaultDailyLimitRemoved,
+    LargeTransferQueued { sender: Address, recipient: Address, transfer_id: u64, amount: BigUint },
+    LargeTransferExecuted { sender: Address, recipient: Address, transfer_id: u64, amount: BigUint },
+    LargeTransferCancelled { sender: Address, recipient: Address, transfer_id: u64, amount: BigUint },
+    LargeTransferThresholdSet { threshold: BigUint },
+    LargeTransferThresholdRemoved,
+    LargeTransferReviewPeriodSet { review_period: u64 },
+    TravelRule { sender: Address, recipient: Address, payload_hash: [u8; 32], vasp_id: [u8; 32], amount: BigUint },
+    VaspRegistered { address: Address, vasp_id: [u8; 32] },
+    VaspUnregistered { address: Address },
+    TravelRuleThresholdSet { threshold: BigUint },
+    TravelRuleThresholdRemoved,
+    /// Logged by `upgrade` after each migration step, with the storage version migrated to.
+    StorageMigrated { version: u64 },
+    /// Logged by `beginImport`, with the total supply the imported balances must add up to.
+    ImportStarted { total_supply: BigUint },
+    EsdtMigrationEnabled { token_identifier: Vec<u8> },
+    EsdtMigrationDisabled,
+    /// Logged by `migrateToEsdt` before the `Transfer` burning the balance.
+    MigratedToEsdt { holder: Address, value: BigUint },
+    /// Logged when the ESDT transfer failed, before the `Transfer` giving the balance back.
+    EsdtMigrationReverted { holder: Address, value: BigUint },
+    /// Logged by `snapshot`, with the id of the new snapshot.
+    Snapshot { id: u64 },
+    LegacyEventsEnabled,
+    LegacyEventsDisabled,
+}
+
+/// The events that also have a legacy identifier, numbered from 0x01 in this order.
+const LEGACY_EVENTS: &[&str] = &[
+    "Transfer",
+    "Approval",
+    "OwnershipTransferProposed",
+    "OwnershipTransferDisregarded",
+    "OwnershipTransferred",
+    "Pause",
+    "Unpause",
+    "AddressFrozen",
+    "AddressUnfrozen",
+    "FrozenAddressWiped",
+    "AssetProtectionRoleSet",
+    "SupplyIncreased",
+    "SupplyDecreased",
+    "SupplyControllerSet",
+];
+
+/// The identifier of a named event: its name, padded with zeros.
+fn identifier_name(identifier: &[u8; 32]) -> Option<&str> {
+    let length = identifier.iter().position(|byte| *byte == 0).unwrap_or(32);
+    if length == 0 || identifier[length..].iter().any(|byte| *byte != 0) {
+        return None;
+    }
+    std::str::from_utf8(&identifier[..length]).ok()
+}
+
+/// The identifier of a legacy event: a small number.
+fn identifier_number(identifier: &[u8; 32]) -> Option<u8> {
+    match identifier.split_at(31) {
+        (zeros, [number]) if zeros.iter().all(|byte| *byte == 0) => Some(*number),
+        _ => None,
+    }
+}
+
+impl LogEntry {
+    /// Whether the log is one of the legacy events, which the contract only emits
+    /// after the named ones, while legacy events are enabled.
+    pub fn is_legacy(&self) -> bool {
+        identifier_number(&self.identifier).is_some()
+    }
+}
+
+/// Reads the topics and data of a log entry in order, checking that nothing is left over.
+struct Reader<'a> {
+    log: &'a LogEntry,
+    topic: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn topic(&mut self) -> Result<&'a [u8; 32], String> {
+        let topic = self.log.topics.get(self.topic).ok_or("missing topic")?;
+        self.topic += 1;
+        topic.as_slice().try_into().map_err(|_| format!("topic of {} bytes, expected 32", topic.len()))
+    }
+
+    fn address(&mut self) -> Result<Address, String> {
+        Ok(Address(*self.topic()?))
+    }
+
+    /// A role, `None` for the zero address.
+    fn role(&mut self) -> Result<Option<Address>, String> {
+        let address = self.address()?;
+        Ok(if address.0 == [0u8; 32] { None } else { Some(address) })
+    }
+
+    fn h256(&mut self) -> Result<[u8; 32], String> {
+        Ok(*self.topic()?)
+    }
+
+    /// Ids are logged as `BigUint` topics, big-endian and padded with zeros on the left.
+    fn id(&mut self) -> Result<u64, String> {
+        let topic = self.topic()?;
+        if topic[..24].iter().any(|byte| *byte != 0) {
+            return Err("id topic too large for a u64".to_string());
+        }
+        Ok(u64::from_be_bytes(topic[24..].try_into().unwrap()))
+    }
+
+    /// Sender, recipient, id and amount of the large transfer events.
+    fn large_transfer(&mut self) -> Result<(Address, Address, u64, BigUint), String> {
+        Ok((self.address()?, self.address()?, self.id()?, self.data_big_uint()?))
+    }
+
+    fn data_big_uint(&self) -> Result<BigUint, String> {
+        Ok(BigUint::from_bytes_be(&self.log.data))
+    }
+
+    fn data_bytes(&self) -> Result<Vec<u8>, String> {
+        Ok(self.log.data.clone())
+    }
+
+    fn data_u64(&self) -> Result<u64, String> {
+        if self.log.data.len() > 8 {
+            return Err(format!("data of {} bytes is too long for a u64", self.log.data.len()));
+        }
+        Ok(self.log.data.iter().fold(0u64, |number, byte| number << 8 | *byte as u64))
+    }
+
+    fn data_bool(&self) -> Result<bool, String> {
+        match self.log.data.as_slice() {
+            [] => Ok(false),
+            [1] => Ok(true),
+            data => Err(format!("invalid bool data 0x{}", hex::encode(data))),
+        }
+    }
+
+    fn no_data(&self) -> Result<(), String> {
+        if !self.log.data.is_empty() {
+            return Err("unexpected data".to_string());
+        }
+        Ok(())
+    }
+
+    fn finish(&self) -> Result<(), String> {
+        if self.topic != self.log.topics.len() {
+            return Err(format!("{} topics, expected {}", self.log.topics.len(), self.topic));
+        }
+        Ok(())
+    }
+}
+
+impl Event {
+    /// Decodes a log entry of the BUSD contract, either a named or a legacy event.
+    pub fn decode(log: &LogEntry) -> Result<Event, String> {
+        let unknown = || format!("unknown event 0x{}", hex::encode(log.identifier));
+        let (name, legacy) = match identifier_number(&log.identifier) {
+            Some(number) => (*LEGACY_EVENTS.get((number as usize).wrapping_sub(1)).ok_or_else(unknown)?, true),
+            None => (identifier_name(&log.identifier).ok_or_else(unknown)?, false),
+        };
+        let event = Event::decode_arguments(&mut Reader { log, topic: 0 }, name, legacy)
+            .map_err(|e| match identifier_number(&log.identifier) {
+                Some(number) => format!("event 0x{:02x}: {}", number, e),
+                None => format!("event {}: {}", name, e),
+            })?;
+        event.ok_or_else(unknown)
+    }
+
+    /// `None` for an unknown name. The legacy layouts only differ for `Pause` and `Unpause`, without the caller.
+    fn decode_arguments(r: &mut Reader, name: &str, legacy: bool) -> Result<Option<Event>, String> {
+        let event = match name {
+            "Initialized" => Event::Initialized {
+                owner: r.address()?,
+                supply_controller: r.address()?,
+                asset_protection_role: r.role()?,
+                pauser: r.role()?,
+                paused: r.data_bool()?,
+            },
+            "Transfer" => Event::Transfer { sender: r.address()?, recipient: r.address()?, amount: r.data_big_uint()? },
+            "Approval" => Event::Approval { sender: r.address()?, spender: r.address()?, amount: r.data_big_uint()? },
+            "OwnershipTransferProposed" => {
+                r.no_data()?;
+                Event::OwnershipTransferProposed { current_owner: r.address()?, proposed_owner: r.address()? }
+            },
+            "OwnershipTransferDisregarded" => {
+                r.no_data()?;
+                Event::OwnershipTransferDisregarded { old_proposed_owner: r.address()? }
+            },
+            "OwnershipTransferred" => {
+                r.no_data()?;
+                Event::OwnershipTransferred { old_owner: r.address()?, new_owner: r.address()? }
+            },
+            "Reclaimed" => Event::Reclaimed { owner: r.address()?, amount: r.data_big_uint()? },
+            "Pause" | "Unpause" => {
+                r.no_data()?;
+                let caller = if legacy { None } else { Some(r.address()?) };
+                if name == "Pause" { Event::Pause { caller } } else { Event::Unpause { caller } }
+            },
+            "PauserSet" => {
+                r.no_data()?;
+                Event::PauserSet { old_pauser: r.address()?, new_pauser: r.address()? }
+            },
+            "AddressFrozen" => {
+                r.no_data()?;
+                Event::AddressFrozen { address: r.address()? }
+            },
+            "AddressUnfrozen" => {
+                r.no_data()?;
+                Event::AddressUnfrozen { address: r.address()? }
+            },
+            "FrozenAddressWiped" => {
+                r.no_data()?;
+                Event::FrozenAddressWiped { address: r.address()? }
+            },
+            "AssetProtectionRoleSet" => {
+                r.no_data()?;
+                Event::AssetProtectionRoleSet { old_asset_protection_role: r.address()?, new_asset_protection_role: r.address()? }
+            },
+            "SupplyIncreased" => Event::SupplyIncreased { to: r.address()?, amount: r.data_big_uint()? },
+            "SupplyDecreased" => Event::SupplyDecreased { from: r.address()?, amount: r.data_big_uint()? },
+            "SupplyControllerSet" => {
+                r.no_data()?;
+                Event::SupplyControllerSet { old_supply_controller: r.address()?, new_supply_controller: r.address()? }
+            },
+            "DailyLimitSet" => Event::DailyLimitSet { address: r.address()?, limit: r.data_big_uint()? },
+            "DailyLimitRemoved" => {
+                r.no_data()?;
+                Event::DailyLimitRemoved { address: r.address()? }
+            },
+            "DefaultDailyLimitSet" => Event::DefaultDailyLimitSet { limit: r.data_big_uint()? },
+            "DefaultDailyLimitRemoved" => r.no_data().map(|_| Event::DefaultDailyLimitRemoved)?,
+            "LargeTransferQueued" => {
+                let (sender, recipient, transfer_id, amount) = r.large_transfer()?;
+                Event::LargeTransferQueued { sender, recipient, transfer_id, amount }
+            },
+            "LargeTransferExecuted" => {
+                let (sender, recipient, transfer_id, amount) = r.large_transfer()?;
+                Event::LargeTransferExecuted { sender, recipient, transfer_id, amount }
+            },
+            "LargeTransferCancelled" => {
+                let (sender, recipient, transfer_id, amount) = r.large_transfer()?;
+                Event::LargeTransferCancelled { sender, recipient, transfer_id, amount }
+            },
+            "LargeTransferThresholdSet" => Event::LargeTransferThresholdSet { threshold: r.data_big_uint()? },
+            "LargeTransferThresholdRemoved" => r.no_data().map(|_| Event::LargeTransferThresholdRemoved)?,
+            "LargeTransferReviewPeriodSet" => Event::LargeTransferReviewPeriodSet { review_period: r.data_u64()? },
+            "TravelRule" => Event::TravelRule {
+                sender: r.address()?, recipient: r.address()?, payload_hash: r.h256()?, vasp_id: r.h256()?, amount: r.data_big_uint()?,
+            },
+            "VaspRegistered" => {
+                r.no_data()?;
+                Event::VaspRegistered { address: r.address()?, vasp_id: r.h256()? }
+            },
+            "VaspUnregistered" => {
+                r.no_data()?;
+                Event::VaspUnregistered { address: r.address()? }
+            },
+            "TravelRuleThresholdSet" => Event::TravelRuleThresholdSet { threshold: r.data_big_uint()? },
+            "TravelRuleThresholdRemoved" => r.no_data().map(|_| Event::TravelRuleThresholdRemoved)?,
+            "StorageMigrated" => Event::StorageMigrated { version: r.data_u64()? },
+            "ImportStarted" => Event::ImportStarted { total_supply: r.data_big_uint()? },
+            "EsdtMigrationEnabled" => Event::EsdtMigrationEnabled { token_identifier: r.data_bytes()? },
+            "EsdtMigrationDisabled" => r.no_data().map(|_| Event::EsdtMigrationDisabled)?,
+            "MigratedToEsdt" => Event::MigratedToEsdt { holder: r.address()?, value: r.data_big_uint()? },
+            "EsdtMigrationReverted" => Event::EsdtMigrationReverted { holder: r.address()?, value: r.data_big_uint()? },
+            "Snapshot" => Event::Snapshot { id: r.data_u64()? },
+            "LegacyEventsEnabled" => r.no_data().map(|_| Event::LegacyEventsEnabled)?,
+            "LegacyEventsDisabled" => r.no_data().map(|_| Event::LegacyEventsDisabled)?,
+            _ => return Ok(None),
+        };
+        r.finish()?;
+        Ok(Some(event))
+    }
+
+    /// The name of the event, as in its identifier.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Event::Initialized { .. } => "Initialized",
+            Event::Transfer { .. } => "Transfer",
+            Event::Approval { .. } => "Approval",
+            Event::OwnershipTransferProposed { .. } => "OwnershipTransferProposed",
+            Event::OwnershipTransferDisregarded { .. } => "OwnershipTransferDisregarded",
+            Event::OwnershipTransferred { .. } => "OwnershipTransferred",
+            Event::Reclaimed { .. } => "Reclaimed",
+            Event::Pause { .. } => "Pause",
+            Event::Unpause { .. } => "Unpause",
+            Event::PauserSet { .. } => "PauserSet",
+            Event::AddressFrozen { .. } => "AddressFrozen",
+            Event::AddressUnfrozen { .. } => "AddressUnfrozen",
+            Event::FrozenAddressWiped { .. } => "FrozenAddressWiped",
+            Event::AssetProtectionRoleSet { .. } => "AssetProtectionRoleSet",
+            Event::SupplyIncreased { .. } => "SupplyIncreased",
+            Event::SupplyDecreased { .. } => "SupplyDecreased",
+            Event::SupplyControllerSet { .. } => "SupplyControllerSet",
+            Event::DailyLimitSet { .. } => "DailyLimitSet",
+            Event::DailyLimitRemoved { .. } => "DailyLimitRemoved",
+            Event::DefaultDailyLimitSet { .. } => "DefaultDailyLimitSet",
+            Event::DefaultDailyLimitRemoved => "DefaultDailyLimitRemoved",
+            Event::LargeTransferQueued { .. } => "LargeTransferQueued",
+            Event::LargeTransferExecuted { .. } => "LargeTransferExecuted",
+            Event::LargeTransferCancelled { .. } => "LargeTransferCancelled",
+            Event::LargeTransferThresholdSet { .. } => "LargeTransferThresholdSet",
+            Event::LargeTransferThresholdRemoved => "LargeTransferThresholdRemoved",
+            Event::LargeTransferReviewPeriodSet { .. } => "LargeTransferReviewPeriodSet",
+            Event::TravelRule { .. } => "TravelRule",
+            Event::VaspRegistered { .. } => "VaspRegistered",
+            Event::VaspUnregistered { .. } => "VaspUnregistered",
+            Event::TravelRuleThresholdSet { .. } => "TravelRuleThresholdSet",
+            Event::TravelRuleThresholdRemoved => "TravelRuleThresholdRemoved",
+            Event::StorageMigrated { .. } => "StorageMigrated",
+            Event::ImportStarted { .. } => "ImportStarted",
+            Event::EsdtMigrationEnabled { .. } => "EsdtMigrationEnabled",
+            Event::EsdtMigrationDisabled => "EsdtMigrationDisabled",
+            Event::MigratedToEsdt { .. } => "MigratedToEsdt",
+            Event::EsdtMigrationReverted { .. } => "EsdtMigrationReverted",
+            Event::Snapshot { .. } => "Snapshot",
+            Event::LegacyEventsEnabled => "LegacyEventsEnabled",
+            Event::LegacyEventsDisabled => "LegacyEventsDisabled",
+        }
+    }
+}
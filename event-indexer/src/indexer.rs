@@ -0,0 +1,479 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Display;
+
+use num_bigint::BigUint;
+use num_traits::Zero;
+use serde_json::Value;
+
+use busd_storage_inspector::dump::mandos_value;
+use busd_storage_inspector::{Address, BusdState, PendingTransfer};
+
+use crate::events::{Event, LogEntry};
+
+/// A successful call to the contract, with the logs it wrote.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Transaction {
+    pub hash: String,
+    pub caller: Address,
+    /// The called endpoint, `init` for the deployment.
+    pub function: String,
+    pub timestamp: u64,
+    pub logs: Vec<LogEntry>,
+}
+
+impl Transaction {
+    /// `{"hash", "caller", "function", "timestamp", "logs"}`, see `LogEntry::from_json` for the logs.
+    pub fn from_json(transaction: &Value) -> Result<Transaction, String> {
+        let field = |name: &str| transaction[name].as_str().ok_or_else(|| format!("transaction {} is not a string", name));
+        let logs: Result<Vec<LogEntry>, String> = transaction["logs"].as_array()
+            .ok_or("transaction logs is not an array")?
+            .iter()
+            .map(LogEntry::from_json)
+            .collect();
+        Ok(Transaction {
+            hash: field("hash")?.to_string(),
+            caller: field("caller")?.parse()?,
+            function: field("function")?.to_string(),
+            timestamp: transaction["timestamp"].as_u64().ok_or("transaction timestamp is not a number")?,
+            logs: logs?,
+        })
+    }
+}
+
+/// The successful `scDeploy` and `scCall` steps of a Mandos scenario, in order.
+/// Their logs must be listed, since a `"*"` could hide any state change.
+pub fn transactions_from_mandos(scenario: &Value) -> Result<Vec<Transaction>, String> {
+    let steps = scenario["steps"].as_array().ok_or("steps is not an array")?;
+    let mut timestamp = 0;
+    let mut transactions = Vec::new();
+    for step in steps.iter() {
+        if let Some(block_timestamp) = step.pointer("/currentBlockInfo/blockTimestamp").and_then(Value::as_str) {
+            timestamp = mandos_value(block_timestamp)?.iter().fold(0u64, |number, byte| number << 8 | *byte as u64);
+        }
+        let function = match step["step"].as_str() {
+            Some("scDeploy") => "init",
+            Some("scCall") => step["tx"]["function"].as_str().ok_or("tx function is not a string")?,
+            _ => continue,
+        };
+        let hash = step["txId"].as_str().unwrap_or_default().to_string();
+        let status = step["expect"]["status"].as_str().unwrap_or("0");
+        if !status.is_empty() && status != "0" {
+            continue;
+        }
+        let logs: Result<Vec<LogEntry>, String> = step["expect"]["logs"].as_array()
+            .ok_or_else(|| format!("step {}: logs are not listed", hash))?
+            .iter()
+            .map(LogEntry::from_mandos)
+            .collect();
+        let caller = mandos_value(step["tx"]["from"].as_str().ok_or("tx from is not a string")?)?;
+        transactions.push(Transaction {
+            caller: Address::from_slice(&caller).ok_or_else(|| format!("step {}: tx from is not 32 bytes", hash))?,
+            function: function.to_string(),
+            timestamp,
+            logs: logs.map_err(|e| format!("step {}: {}", hash, e))?,
+            hash,
+        });
+    }
+    Ok(transactions)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Role {
+    Owner,
+    ProposedOwner,
+    AssetProtection,
+    SupplyController,
+    Pauser,
+}
+
+/// A role given to an address, or taken away when `address` is `None`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RoleChange {
+    pub transaction: String,
+    pub role: Role,
+    pub address: Option<Address>,
+}
+
+/// How the next transfer event moves funds, when it is not simply from the sender to the recipient.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Settlement {
+    /// Follows the supply increase or a reverted ESDT migration, the funds come from nowhere.
+    Mint,
+    /// Follows the supply decrease, a wipe or a migration to ESDT, the funds go nowhere.
+    Burn,
+    /// Follows the execution of a large transfer, the funds were taken from the sender when it was queued.
+    Pending,
+}
+
+/// Rebuilds the contract state from its logs, one transaction at a time.
+///
+/// Storage that no event reveals is not rebuilt: the daily spending, the token metadata,
+/// the storage version until the first migration, and the settings of the contract before the first indexed transaction when starting from scratch.
+#[derive(Clone, Debug, Default)]
+pub struct Indexer {
+    pub state: BusdState,
+    pub role_history: Vec<RoleChange>,
+    settlement: Option<Settlement>,
+}
+
+impl Indexer {
+    pub fn new() -> Indexer {
+        Indexer::default()
+    }
+
+    /// Continues from a known state, e.g. decoded from a storage dump.
+    pub fn from_state(state: BusdState) -> Indexer {
+        Indexer { state, ..Indexer::default() }
+    }
+
+    pub fn index(&mut self, transaction: &Transaction) -> Result<(), String> {
+        // while legacy events are enabled, each named event is followed by its legacy counterpart,
+        // which only counts for transactions to a contract that predates the named events
+        let named = transaction.logs.iter().any(|log| !log.is_legacy());
+        if transaction.function == "init" && !named {
+            // the constructor of a contract that predates the named events logs nothing
+            self.initialize(transaction, transaction.caller, transaction.caller, None, None, true);
+        }
+        for log in transaction.logs.iter().filter(|log| !(named && log.is_legacy())) {
+            let event = Event::decode(log).map_err(|e| format!("transaction {}: {}", transaction.hash, e))?;
+            self.apply(transaction, &event).map_err(|e| format!("transaction {}: {}: {}", transaction.hash, event.name(), e))?;
+        }
+        if self.settlement.take().is_some() {
+            return Err(format!("transaction {}: missing transfer event", transaction.hash));
+        }
+        Ok(())
+    }
+
+    /// Roles that are not set are not recorded in the role history.
+    fn initialize(&mut self, transaction: &Transaction, owner: Address, supply_controller: Address,
+            asset_protection_role: Option<Address>, pauser: Option<Address>, paused: bool) {
+        self.state.owner = Some(owner);
+        self.state.supply_controller = Some(supply_controller);
+        self.state.asset_protection_role = asset_protection_role;
+        self.state.pauser = pauser;
+        self.state.paused = paused;
+        self.role_change(transaction, Role::Owner, Some(owner));
+        self.role_change(transaction, Role::SupplyController, Some(supply_controller));
+        if asset_protection_role.is_some() {
+            self.role_change(transaction, Role::AssetProtection, asset_protection_role);
+        }
+        if pauser.is_some() {
+            self.role_change(transaction, Role::Pauser, pauser);
+        }
+    }
+
+    fn role_change(&mut self, transaction: &Transaction, role: Role, address: Option<Address>) {
+        self.role_history.push(RoleChange { transaction: transaction.hash.clone(), role, address });
+    }
+
+    /// Funds moved by `transferFrom` come out of the allowance of the caller.
+    fn spend_allowance(&mut self, transaction: &Transaction, sender: &Address, amount: &BigUint) -> Result<(), String> {
+        if transaction.function != "transferFrom" {
+            return Ok(());
+        }
+        let key = (*sender, transaction.caller);
+        let allowance = self.state.allowances.remove(&key).unwrap_or_default();
+        if &allowance < amount {
+            return Err(format!("allowance of {} for {} below {}", sender, transaction.caller, amount));
+        }
+        let allowance = allowance - amount;
+        if !allowance.is_zero() {
+            self.state.allowances.insert(key, allowance);
+        }
+        Ok(())
+    }
+
+    fn apply(&mut self, transaction: &Transaction, event: &Event) -> Result<(), String> {
+        if self.settlement.is_some() && !matches!(event, Event::Transfer { .. }) {
+            return Err("expected a transfer event".to_string());
+        }
+        match event {
+            Event::Initialized { owner, supply_controller, asset_protection_role, pauser, paused } => {
+                self.initialize(transaction, *owner, *supply_controller, *asset_protection_role, *pauser, *paused);
+            },
+            Event::Transfer { sender, recipient, amount } => match self.settlement.take() {
+                Some(Settlement::Mint) => self.credit(recipient, amount),
+                Some(Settlement::Burn) => self.debit(sender, amount)?,
+                Some(Settlement::Pending) => self.credit(recipient, amount),
+                None => {
+                    self.spend_allowance(transaction, sender, amount)?;
+                    self.debit(sender, amount)?;
+                    self.credit(recipient, amount);
+                },
+            },
+            Event::Approval { sender, spender, amount } => {
+                if amount.is_zero() {
+                    self.state.allowances.remove(&(*sender, *spender));
+                } else {
+                    self.state.allowances.insert((*sender, *spender), amount.clone());
+                }
+            },
+            Event::OwnershipTransferProposed { proposed_owner, .. } => {
+                self.state.proposed_owner = Some(*proposed_owner);
+                self.role_change(transaction, Role::ProposedOwner, Some(*proposed_owner));
+            },
+            Event::OwnershipTransferDisregarded { .. } => {
+                self.state.proposed_owner = None;
+                self.role_change(transaction, Role::ProposedOwner, None);
+            },
+            Event::OwnershipTransferred { new_owner, .. } => {
+                self.state.owner = Some(*new_owner);
+                self.state.proposed_owner = None;
+                self.role_change(transaction, Role::Owner, Some(*new_owner));
+                self.role_change(transaction, Role::ProposedOwner, None);
+            },
+            // the balance moves with the transfer that follows
+            Event::Reclaimed { .. } => {},
+            Event::Pause { .. } => self.state.paused = true,
+            Event::Unpause { .. } => {
+                self.state.paused = false;
+                self.state.import_total_supply = None;
+            },
+            Event::PauserSet { new_pauser, .. } => {
+                self.state.pauser = Some(*new_pauser);
+                self.role_change(transaction, Role::Pauser, Some(*new_pauser));
+            },
+            Event::AddressFrozen { address } => {
+                self.state.frozen.insert(*address);
+            },
+            Event::AddressUnfrozen { address } => {
+                self.state.frozen.remove(address);
+            },
+            // the balance is burned by the events that follow
+            Event::FrozenAddressWiped { .. } => {},
+            Event::AssetProtectionRoleSet { new_asset_protection_role, .. } => {
+                self.state.asset_protection_role = Some(*new_asset_protection_role);
+                self.role_change(transaction, Role::AssetProtection, Some(*new_asset_protection_role));
+            },
+            Event::SupplyIncreased { amount, .. } => {
+                self.snapshot_total_supply();
+                self.state.total_supply += amount;
+                self.settlement = Some(Settlement::Mint);
+            },
+            Event::SupplyDecreased { amount, .. } => {
+                if &self.state.total_supply < amount {
+                    return Err(format!("total supply below {}", amount));
+                }
+                self.snapshot_total_supply();
+                self.state.total_supply -= amount;
+                self.settlement = Some(Settlement::Burn);
+            },
+            Event::SupplyControllerSet { new_supply_controller, .. } => {
+                self.state.supply_controller = Some(*new_supply_controller);
+                self.role_change(transaction, Role::SupplyController, Some(*new_supply_controller));
+            },
+            Event::DailyLimitSet { address, limit } => {
+                self.state.daily_limits.insert(*address, limit.clone());
+            },
+            Event::DailyLimitRemoved { address } => {
+                self.state.daily_limits.remove(address);
+            },
+            Event::DefaultDailyLimitSet { limit } => self.state.default_daily_limit = Some(limit.clone()),
+            Event::DefaultDailyLimitRemoved => self.state.default_daily_limit = None,
+            Event::LargeTransferQueued { sender, recipient, amount, transfer_id } => {
+                self.spend_allowance(transaction, sender, amount)?;
+                self.debit(sender, amount)?;
+                self.state.last_pending_transfer_id = *transfer_id;
+                self.state.pending_transfer_ids.push(*transfer_id);
+                self.state.pending_transfers_total += amount;
+                self.state.pending_transfers.insert(*transfer_id, PendingTransfer {
+                    sender: Some(*sender),
+                    recipient: Some(*recipient),
+                    amount: amount.clone(),
+                    timestamp: transaction.timestamp,
+                    // the travel rule metadata, if any, is only logged once the transfer is executed
+                    ..PendingTransfer::default()
+                });
+            },
+            Event::LargeTransferExecuted { amount, transfer_id, .. } => {
+                self.clear_pending_transfer(*transfer_id, amount)?;
+                self.settlement = Some(Settlement::Pending);
+            },
+            Event::LargeTransferCancelled { sender, amount, transfer_id, .. } => {
+                self.clear_pending_transfer(*transfer_id, amount)?;
+                self.credit(sender, amount);
+            },
+            Event::LargeTransferThresholdSet { threshold } => self.state.large_transfer_threshold = Some(threshold.clone()),
+            Event::LargeTransferThresholdRemoved => self.state.large_transfer_threshold = None,
+            Event::LargeTransferReviewPeriodSet { review_period } => self.state.large_transfer_review_period = *review_period,
+            // the transfer itself is logged next
+            Event::TravelRule { .. } => {},
+            Event::VaspRegistered { address, vasp_id } => {
+                self.state.vasp_ids.insert(*address, *vasp_id);
+            },
+            Event::VaspUnregistered { address } => {
+                self.state.vasp_ids.remove(address);
+            },
+            Event::TravelRuleThresholdSet { threshold } => self.state.travel_rule_threshold = Some(threshold.clone()),
+            Event::TravelRuleThresholdRemoved => self.state.travel_rule_threshold = None,
+            Event::StorageMigrated { version } => self.state.storage_version = *version,
+            Event::ImportStarted { total_supply } => self.state.import_total_supply = Some(total_supply.clone()),
+            Event::EsdtMigrationEnabled { token_identifier } => self.state.esdt_token = token_identifier.clone(),
+            Event::EsdtMigrationDisabled => self.state.esdt_token.clear(),
+            Event::MigratedToEsdt { value, .. } => {
+                if &self.state.total_supply < value {
+                    return Err(format!("total supply below {}", value));
+                }
+                self.snapshot_total_supply();
+                self.state.total_supply -= value;
+                self.state.esdt_migrated_supply += value;
+                self.settlement = Some(Settlement::Burn);
+            },
+            Event::EsdtMigrationReverted { value, .. } => {
+                if &self.state.esdt_migrated_supply < value {
+                    return Err(format!("migrated supply below {}", value));
+                }
+                self.state.esdt_migrated_supply -= value;
+                self.snapshot_total_supply();
+                self.state.total_supply += value;
+                self.settlement = Some(Settlement::Mint);
+            },
+            Event::Snapshot { id } => self.state.current_snapshot_id = *id,
+            Event::LegacyEventsEnabled => self.state.legacy_events = true,
+            Event::LegacyEventsDisabled => self.state.legacy_events = false,
+        }
+        Ok(())
+    }
+
+    fn credit(&mut self, address: &Address, amount: &BigUint) {
+        self.snapshot_balance(address);
+        if !amount.is_zero() {
+            *self.state.balances.entry(*address).or_default() += amount;
+        }
+    }
+
+    fn debit(&mut self, address: &Address, amount: &BigUint) -> Result<(), String> {
+        self.snapshot_balance(address);
+        let balance = self.state.balances.remove(address).unwrap_or_default();
+        if &balance < amount {
+            return Err(format!("balance of {} below {}", address, amount));
+        }
+        let balance = balance - amount;
+        if !balance.is_zero() {
+            self.state.balances.insert(*address, balance);
+        }
+        Ok(())
+    }
+
+    /// Saves the balance for the latest snapshot before its first change since, as the contract does.
+    fn snapshot_balance(&mut self, address: &Address) {
+        let id = self.state.current_snapshot_id;
+        if id == 0 {
+            return;
+        }
+        let ids = self.state.balance_snapshot_ids.entry(*address).or_default();
+        if ids.last() == Some(&id) {
+            return;
+        }
+        ids.push(id);
+        match self.state.balances.get(address) {
+            Some(balance) => self.state.balance_snapshots.insert((*address, id), balance.clone()),
+            None => self.state.balance_snapshots.remove(&(*address, id)),
+        };
+    }
+
+    /// Saves the total supply for the latest snapshot before its first change since, as the contract does.
+    fn snapshot_total_supply(&mut self) {
+        let id = self.state.current_snapshot_id;
+        if id == 0 || self.state.total_supply_snapshot_ids.last() == Some(&id) {
+            return;
+        }
+        self.state.total_supply_snapshot_ids.push(id);
+        if !self.state.total_supply.is_zero() {
+            self.state.total_supply_snapshots.insert(id, self.state.total_supply.clone());
+        }
+    }
+
+    fn clear_pending_transfer(&mut self, transfer_id: u64, amount: &BigUint) -> Result<(), String> {
+        let transfer = self.state.pending_transfers.get_mut(&transfer_id)
+            .filter(|transfer| &transfer.amount == amount)
+            .ok_or_else(|| format!("no pending transfer {} of {}", transfer_id, amount))?;
+        transfer.amount = BigUint::zero();
+        self.state.pending_transfer_ids.retain(|id| *id != transfer_id);
+        self.state.pending_transfers_total -= amount;
+        Ok(())
+    }
+}
+
+fn option<T: Display>(value: &Option<T>) -> String {
+    match value {
+        Some(value) => value.to_string(),
+        None => "-".to_string(),
+    }
+}
+
+fn pending_transfer(transfer: &PendingTransfer) -> String {
+    format!("{} -> {} {} queued at {}",
+        option(&transfer.sender), option(&transfer.recipient), transfer.amount, transfer.timestamp)
+}
+
+/// Where the indexed state differs from the state decoded from storage, skipping the daily spending, the holder and frozen address registries
+/// and the travel rule metadata of queued transfers that logs do not reveal.
+pub fn compare(indexed: &BusdState, on_chain: &BusdState) -> Vec<String> {
+    let mut differences = Vec::new();
+    let mut check = |name: &str, indexed: String, on_chain: String| {
+        if indexed != on_chain {
+            differences.push(format!("{}: indexed {}, on chain {}", name, indexed, on_chain));
+        }
+    };
+    check("total supply", indexed.total_supply.to_string(), on_chain.total_supply.to_string());
+    check("owner", option(&indexed.owner), option(&on_chain.owner));
+    check("proposed owner", option(&indexed.proposed_owner), option(&on_chain.proposed_owner));
+    check("paused", indexed.paused.to_string(), on_chain.paused.to_string());
+    check("pauser", option(&indexed.pauser), option(&on_chain.pauser));
+    check("asset protection role", option(&indexed.asset_protection_role), option(&on_chain.asset_protection_role));
+    check("supply controller", option(&indexed.supply_controller), option(&on_chain.supply_controller));
+    check("default daily limit", option(&indexed.default_daily_limit), option(&on_chain.default_daily_limit));
+    check("large transfer threshold", option(&indexed.large_transfer_threshold), option(&on_chain.large_transfer_threshold));
+    check("large transfer review period", indexed.large_transfer_review_period.to_string(), on_chain.large_transfer_review_period.to_string());
+    check("last pending transfer id", indexed.last_pending_transfer_id.to_string(), on_chain.last_pending_transfer_id.to_string());
+    check("pending transfer ids", format!("{:?}", indexed.pending_transfer_ids), format!("{:?}", on_chain.pending_transfer_ids));
+    check("pending transfers total", indexed.pending_transfers_total.to_string(), on_chain.pending_transfers_total.to_string());
+    check("travel rule threshold", option(&indexed.travel_rule_threshold), option(&on_chain.travel_rule_threshold));
+    check("import total supply", option(&indexed.import_total_supply), option(&on_chain.import_total_supply));
+    check("ESDT token", String::from_utf8_lossy(&indexed.esdt_token).to_string(), String::from_utf8_lossy(&on_chain.esdt_token).to_string());
+    check("migrated to ESDT", indexed.esdt_migrated_supply.to_string(), on_chain.esdt_migrated_supply.to_string());
+    check("latest snapshot", indexed.current_snapshot_id.to_string(), on_chain.current_snapshot_id.to_string());
+    check("total supply snapshots", format!("{:?}", indexed.total_supply_snapshot_ids), format!("{:?}", on_chain.total_supply_snapshot_ids));
+    check("legacy events", indexed.legacy_events.to_string(), on_chain.legacy_events.to_string());
+
+    compare_maps(&mut differences, "balance of", &indexed.balances, &on_chain.balances,
+        |address| address.to_string(), |balance| balance.to_string());
+    compare_maps(&mut differences, "allowance of", &indexed.allowances, &on_chain.allowances,
+        |(owner, spender)| format!("{} for {}", owner, spender), |allowance| allowance.to_string());
+    compare_maps(&mut differences, "daily limit of", &indexed.daily_limits, &on_chain.daily_limits,
+        |address| address.to_string(), |limit| limit.to_string());
+    compare_maps(&mut differences, "pending transfer", &indexed.pending_transfers, &on_chain.pending_transfers,
+        |id| id.to_string(), pending_transfer);
+    compare_maps(&mut differences, "VASP id of", &indexed.vasp_ids, &on_chain.vasp_ids,
+        |address| address.to_string(), |vasp_id| hex::encode(vasp_id));
+    compare_maps(&mut differences, "snapshots of", &indexed.balance_snapshot_ids, &on_chain.balance_snapshot_ids,
+        |address| address.to_string(), |ids| format!("{:?}", ids));
+    compare_maps(&mut differences, "balance at snapshot", &indexed.balance_snapshots, &on_chain.balance_snapshots,
+        |(address, id)| format!("{} of {}", id, address), |balance| balance.to_string());
+    compare_maps(&mut differences, "total supply at snapshot", &indexed.total_supply_snapshots, &on_chain.total_supply_snapshots,
+        |id| id.to_string(), |total_supply| total_supply.to_string());
+    for address in indexed.frozen.symmetric_difference(&on_chain.frozen) {
+        differences.push(format!("frozen {}: indexed {}, on chain {}",
+            address, indexed.frozen.contains(address), on_chain.frozen.contains(address)));
+    }
+    differences
+}
+
+fn compare_maps<K: Ord, V: PartialEq>(
+    differences: &mut Vec<String>,
+    name: &str,
+    indexed: &BTreeMap<K, V>,
+    on_chain: &BTreeMap<K, V>,
+    key_name: impl Fn(&K) -> String,
+    value_name: impl Fn(&V) -> String,
+) {
+    let keys: BTreeSet<&K> = indexed.keys().chain(on_chain.keys()).collect();
+    for key in keys {
+        let (indexed, on_chain) = (indexed.get(key), on_chain.get(key));
+        if indexed != on_chain {
+            let value = |value: Option<&V>| value.map(&value_name).unwrap_or_else(|| "-".to_string());
+            differences.push(format!("{} {}: indexed {}, on chain {}", name, key_name(key), value(indexed), value(on_chain)));
+        }
+    }
+}
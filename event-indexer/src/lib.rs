@@ -0,0 +1,26 @@
+//! Decodes the logs of the BUSD contract, and rebuilds the contract state from them.
+//!
+//! `Event::decode` turns a raw log entry into a typed event. `Indexer` folds the transactions
+//! to the contract into balances, allowances, freezes and the other settings, keeping a history of role changes.
+//! `compare` then checks the rebuilt state against the one decoded from a storage dump:
+//!
+//! ```no_run
+//! use busd_event_indexer::*;
+//! use busd_storage_inspector::{BusdState, StorageDump};
+//!
+//! # fn load_transactions() -> Vec<Transaction> { Vec::new() }
+//! let mut indexer = Indexer::new();
+//! for transaction in load_transactions().iter() {
+//!     indexer.index(transaction).unwrap();
+//! }
+//! let dump = StorageDump::parse(&std::fs::read_to_string("dump.json").unwrap()).unwrap();
+//! for difference in compare(&indexer.state, &BusdState::decode(&dump)) {
+//!     println!("{}", difference);
+//! }
+//! ```
+
+pub mod events;
+pub mod indexer;
+
+pub use events::{Event, LogEntry};
+pub use indexer::{compare, transactions_from_mandos, Indexer, Role, RoleChange, Transaction};
@@ -0,0 +1,418 @@
+use std::convert::TryInto;
+use std::fs;
+
+use num_bigint::BigUint;
+use serde_json::{json, Value};
+
+use busd_event_indexer::*;
+use busd_storage_inspector::{Address, BusdState, StorageDump};
+
+const ABI_FILE: &str = include_str!("../../busd.abi.json");
+
+fn address(name: &str) -> Address {
+    let mut address = [b'_'; 32];
+    address[..name.len()].copy_from_slice(name.as_bytes());
+    Address(address)
+}
+
+fn big(value: u64) -> BigUint {
+    BigUint::from(value)
+}
+
+/// A `BigUint` topic, left-padded to 32 bytes.
+fn number_topic(value: u64) -> Vec<u8> {
+    let mut topic = vec![0u8; 24];
+    topic.extend_from_slice(&value.to_be_bytes());
+    topic
+}
+
+/// The identifier of a named event, padded with zeros.
+fn identifier(name: &str) -> [u8; 32] {
+    let mut identifier = [0u8; 32];
+    identifier[..name.len()].copy_from_slice(name.as_bytes());
+    identifier
+}
+
+fn log(name: &str, topics: Vec<Vec<u8>>, data: Vec<u8>) -> LogEntry {
+    LogEntry { address: address("busd"), identifier: identifier(name), topics, data }
+}
+
+fn legacy_log(number: u8, topics: Vec<Vec<u8>>, data: Vec<u8>) -> LogEntry {
+    let mut identifier = [0u8; 32];
+    identifier[31] = number;
+    LogEntry { address: address("busd"), identifier, topics, data }
+}
+
+fn transfer(sender: &Address, recipient: &Address, amount: u64) -> LogEntry {
+    log("Transfer", vec![sender.0.to_vec(), recipient.0.to_vec()], big(amount).to_bytes_be())
+}
+
+fn transaction(hash: &str, caller: &Address, function: &str, logs: Vec<LogEntry>) -> Transaction {
+    Transaction { hash: hash.to_string(), caller: *caller, function: function.to_string(), timestamp: 100, logs }
+}
+
+#[test]
+fn test_decode_every_abi_event() {
+    let abi: Value = serde_json::from_str(ABI_FILE).unwrap();
+    let mut names = Vec::new();
+    let mut legacy_names = Vec::new();
+    for event in abi["events"].as_array().unwrap().iter() {
+        let mut topics = Vec::new();
+        let mut data = Vec::new();
+        for input in event["inputs"].as_array().unwrap().iter() {
+            if input["indexed"] == true {
+                topics.push(match input["type"].as_str().unwrap() {
+                    "BigUint" => number_topic(1),
+                    _ => vec![1u8; 32],
+                });
+            } else {
+                data = match input["type"].as_str().unwrap() {
+                    "H256" => vec![2u8; 32],
+                    "bool" => vec![1u8],
+                    _ => vec![3u8],
+                };
+            }
+        }
+        let id = hex::decode(&event["identifier"].as_str().unwrap()[2..]).unwrap();
+        let log = LogEntry { address: address("busd"), identifier: id.as_slice().try_into().unwrap(), topics, data };
+        let decoded = Event::decode(&log).unwrap_or_else(|e| panic!("{}: {}", event["name"], e));
+        if log.is_legacy() {
+            legacy_names.push(decoded.name());
+        } else {
+            assert_eq!(identifier(decoded.name()), log.identifier);
+            names.push(decoded.name());
+        }
+    }
+    // every legacy event has a named counterpart
+    assert_eq!(legacy_names.len(), 14);
+    assert!(legacy_names.iter().all(|name| names.contains(name)));
+}
+
+#[test]
+fn test_decode() {
+    let alice = address("alice");
+    let bob = address("bob");
+    assert_eq!(Event::decode(&transfer(&alice, &bob, 300)), Ok(Event::Transfer { sender: alice, recipient: bob, amount: big(300) }));
+    assert_eq!(
+        Event::decode(&log("LargeTransferQueued", vec![alice.0.to_vec(), bob.0.to_vec(), number_topic(7)], big(2000).to_bytes_be())),
+        Ok(Event::LargeTransferQueued { sender: alice, recipient: bob, transfer_id: 7, amount: big(2000) }));
+    assert_eq!(
+        Event::decode(&log("VaspRegistered", vec![alice.0.to_vec(), vec![2; 32]], vec![])),
+        Ok(Event::VaspRegistered { address: alice, vasp_id: [2; 32] }));
+    assert_eq!(Event::decode(&log("Pause", vec![alice.0.to_vec()], vec![])), Ok(Event::Pause { caller: Some(alice) }));
+    assert_eq!(
+        Event::decode(&log("Initialized", vec![alice.0.to_vec(), bob.0.to_vec(), [0u8; 32].to_vec(), bob.0.to_vec()], vec![1])),
+        Ok(Event::Initialized { owner: alice, supply_controller: bob, asset_protection_role: None, pauser: Some(bob), paused: true }));
+    assert_eq!(
+        Event::decode(&log("PauserSet", vec![[0u8; 32].to_vec(), bob.0.to_vec()], vec![])),
+        Ok(Event::PauserSet { old_pauser: Address([0u8; 32]), new_pauser: bob }));
+    assert_eq!(Event::decode(&log("StorageMigrated", vec![], vec![1])), Ok(Event::StorageMigrated { version: 1 }));
+    assert_eq!(
+        Event::decode(&log("EsdtMigrationEnabled", vec![], b"BUSD-a1b2c3".to_vec())),
+        Ok(Event::EsdtMigrationEnabled { token_identifier: b"BUSD-a1b2c3".to_vec() }));
+    assert_eq!(
+        Event::decode(&log("ImportStarted", vec![], big(5000).to_bytes_be())),
+        Ok(Event::ImportStarted { total_supply: big(5000) }));
+    assert_eq!(
+        Event::decode(&log("Reclaimed", vec![alice.0.to_vec()], big(40).to_bytes_be())),
+        Ok(Event::Reclaimed { owner: alice, amount: big(40) }));
+
+    // legacy events decode to the same events, from their own layout
+    assert_eq!(
+        Event::decode(&legacy_log(0x01, vec![alice.0.to_vec(), bob.0.to_vec()], vec![1])),
+        Ok(Event::Transfer { sender: alice, recipient: bob, amount: big(1) }));
+    assert_eq!(Event::decode(&legacy_log(0x06, vec![], vec![])), Ok(Event::Pause { caller: None }));
+
+    // v0.3.0 had no event past 0x0e
+    assert_eq!(Event::decode(&legacy_log(0x0f, vec![], vec![])), Err(format!("unknown event 0x{:064x}", 0x0f)));
+    assert_eq!(Event::decode(&log("Mint", vec![], vec![])), Err(format!("unknown event 0x{}", hex::encode(identifier("Mint")))));
+    assert_eq!(Event::decode(&legacy_log(0x01, vec![alice.0.to_vec()], vec![1])), Err("event 0x01: missing topic".to_string()));
+    assert_eq!(Event::decode(&log("Transfer", vec![alice.0.to_vec()], vec![1])), Err("event Transfer: missing topic".to_string()));
+    assert_eq!(Event::decode(&log("Pause", vec![], vec![])), Err("event Pause: missing topic".to_string()));
+    assert_eq!(Event::decode(&legacy_log(0x06, vec![alice.0.to_vec()], vec![])), Err("event 0x06: 1 topics, expected 0".to_string()));
+    assert_eq!(Event::decode(&log("Pause", vec![alice.0.to_vec()], vec![1])), Err("event Pause: unexpected data".to_string()));
+    assert_eq!(
+        Event::decode(&log("Initialized", vec![alice.0.to_vec(), bob.0.to_vec(), [0u8; 32].to_vec(), [0u8; 32].to_vec()], vec![2])),
+        Err("event Initialized: invalid bool data 0x02".to_string()));
+    assert_eq!(Event::decode(&log("AddressFrozen", vec![vec![1]], vec![])), Err("event AddressFrozen: topic of 1 bytes, expected 32".to_string()));
+    assert_eq!(
+        Event::decode(&log("LargeTransferReviewPeriodSet", vec![], vec![1; 9])),
+        Err("event LargeTransferReviewPeriodSet: data of 9 bytes is too long for a u64".to_string()));
+    assert_eq!(
+        Event::decode(&log("LargeTransferExecuted", vec![alice.0.to_vec(), bob.0.to_vec(), vec![1; 32]], vec![])),
+        Err("event LargeTransferExecuted: id topic too large for a u64".to_string()));
+}
+
+#[test]
+fn test_log_formats() {
+    let scenario: Value = serde_json::from_str(include_str!("../../tests/large_transfer/large_transfer_cancel.scen.json")).unwrap();
+    let queued = scenario["steps"].as_array().unwrap().iter()
+        .flat_map(|step| step["expect"]["logs"].as_array().cloned().unwrap_or_default())
+        .find(|log| log["identifier"] == format!("0x{}", hex::encode(identifier("LargeTransferQueued"))))
+        .unwrap();
+    assert_eq!(Event::decode(&LogEntry::from_mandos(&queued).unwrap()), Ok(Event::LargeTransferQueued {
+        sender: address("holder_1______________________s1"),
+        recipient: address("holder_2______________________s1"),
+        transfer_id: 1,
+        amount: big(2000),
+    }));
+
+    let alice = address("alice");
+    let transaction = Transaction::from_json(&json!({
+        "hash": "ab01",
+        "caller": alice.to_string(),
+        "function": "freeze",
+        "timestamp": 1600000000,
+        "logs": [{
+            "address": hex::encode(address("busd").0),
+            "identifier": hex::encode(identifier("AddressFrozen")),
+            "topics": [hex::encode(alice.0)],
+            "data": "",
+        }],
+    })).unwrap();
+    assert_eq!(transaction.caller, alice);
+    assert_eq!(transaction.timestamp, 1_600_000_000);
+    assert_eq!(Event::decode(&transaction.logs[0]), Ok(Event::AddressFrozen { address: alice }));
+    assert!(Transaction::from_json(&json!({ "hash": "ab01" })).is_err());
+}
+
+#[test]
+fn test_erc20_scenarios() {
+    let mut indexed = 0;
+    for entry in fs::read_dir("../tests/erc20").unwrap() {
+        let path = entry.unwrap().path();
+        let scenario: Value = serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        let initial = json!({ "steps": [scenario["steps"][0]] });
+        let mut indexer = Indexer::from_state(BusdState::decode(&StorageDump::from_mandos(&initial, None).unwrap()));
+        for transaction in transactions_from_mandos(&scenario).unwrap().iter() {
+            indexer.index(transaction).unwrap();
+        }
+        let on_chain = BusdState::decode(&StorageDump::from_mandos(&scenario, None).unwrap());
+        assert_eq!(compare(&indexer.state, &on_chain), Vec::<String>::new(), "{}", path.display());
+        indexed += 1;
+    }
+    assert!(indexed > 50);
+}
+
+#[test]
+fn test_index() {
+    let owner = address("owner");
+    let ap_role = address("ap_role");
+    let alice = address("alice");
+    let bob = address("bob");
+    let carol = address("carol");
+    let zero = Address([0; 32]);
+    let queued = |name: &str, sender: &Address, recipient: &Address, amount: u64, transfer_id: u64| {
+        log(name, vec![sender.0.to_vec(), recipient.0.to_vec(), number_topic(transfer_id)], big(amount).to_bytes_be())
+    };
+
+    let transactions = vec![
+        transaction("deploy", &owner, "init", vec![log("Initialized", vec![owner.0.to_vec(), owner.0.to_vec(), [0u8; 32].to_vec(), [0u8; 32].to_vec()], vec![1])]),
+        transaction("role", &owner, "setAssetProtectionRole", vec![log("AssetProtectionRoleSet", vec![zero.0.to_vec(), ap_role.0.to_vec()], vec![])]),
+        transaction("unpause", &owner, "unpause", vec![log("Unpause", vec![owner.0.to_vec()], vec![])]),
+        transaction("mint", &owner, "increaseSupply", vec![
+            log("SupplyIncreased", vec![owner.0.to_vec()], big(1000).to_bytes_be()),
+            transfer(&zero, &owner, 1000),
+        ]),
+        transaction("legacy on", &owner, "enableLegacyEvents", vec![log("LegacyEventsEnabled", vec![], vec![])]),
+        // the legacy counterpart of a named event is skipped
+        transaction("pay alice", &owner, "transfer", vec![
+            transfer(&owner, &alice, 300),
+            legacy_log(0x01, vec![owner.0.to_vec(), alice.0.to_vec()], big(300).to_bytes_be()),
+        ]),
+        transaction("approve", &alice, "approve", vec![log("Approval", vec![alice.0.to_vec(), bob.0.to_vec()], big(100).to_bytes_be())]),
+        // a contract that predates the named events only logs legacy ones
+        transaction("spend", &bob, "transferFrom", vec![legacy_log(0x01, vec![alice.0.to_vec(), carol.0.to_vec()], vec![40])]),
+        transaction("threshold", &owner, "setLargeTransferThreshold", vec![log("LargeTransferThresholdSet", vec![], big(200).to_bytes_be())]),
+        transaction("queue 1", &owner, "transfer", vec![queued("LargeTransferQueued", &owner, &alice, 250, 1)]),
+        transaction("execute 1", &carol, "executePendingTransfer", vec![queued("LargeTransferExecuted", &owner, &alice, 250, 1), transfer(&owner, &alice, 250)]),
+        transaction("queue 2", &owner, "transfer", vec![queued("LargeTransferQueued", &owner, &carol, 250, 2)]),
+        transaction("cancel 2", &ap_role, "cancelPendingTransfer", vec![queued("LargeTransferCancelled", &owner, &carol, 250, 2)]),
+        transaction("queue 3", &owner, "transfer", vec![queued("LargeTransferQueued", &owner, &bob, 300, 3)]),
+        transaction("freeze", &ap_role, "freeze", vec![log("AddressFrozen", vec![alice.0.to_vec()], vec![])]),
+        transaction("wipe", &ap_role, "wipeFrozenAddress", vec![
+            log("FrozenAddressWiped", vec![alice.0.to_vec()], vec![]),
+            log("SupplyDecreased", vec![alice.0.to_vec()], big(510).to_bytes_be()),
+            transfer(&alice, &zero, 510),
+        ]),
+        transaction("propose", &owner, "proposeOwner", vec![log("OwnershipTransferProposed", vec![owner.0.to_vec(), bob.0.to_vec()], vec![])]),
+        transaction("claim", &bob, "claimOwnership", vec![log("OwnershipTransferred", vec![owner.0.to_vec(), bob.0.to_vec()], vec![])]),
+    ];
+    let mut indexer = Indexer::new();
+    for transaction in transactions.iter() {
+        indexer.index(transaction).unwrap();
+    }
+
+    let state = &indexer.state;
+    assert_eq!(state.total_supply, big(490));
+    assert_eq!(state.balances.get(&owner), Some(&big(150)));
+    assert_eq!(state.balances.get(&alice), None);
+    assert_eq!(state.balances.get(&carol), Some(&big(40)));
+    assert_eq!(state.allowances.get(&(alice, bob)), Some(&big(60)));
+    assert!(state.frozen.contains(&alice));
+    assert!(!state.paused);
+    assert_eq!(state.owner, Some(bob));
+    assert_eq!(state.proposed_owner, None);
+    assert_eq!(state.asset_protection_role, Some(ap_role));
+    assert_eq!(state.supply_controller, Some(owner));
+    assert_eq!(state.large_transfer_threshold, Some(big(200)));
+    assert_eq!(state.last_pending_transfer_id, 3);
+    assert_eq!(state.pending_transfer_ids, vec![3]);
+    assert_eq!(state.pending_transfers_total, big(300));
+    assert_eq!(state.pending_transfers[&1].amount, big(0));
+    assert_eq!(state.pending_transfers[&3].timestamp, 100);
+    assert!(state.legacy_events);
+    assert_eq!(state.inconsistencies(), Vec::<String>::new());
+
+    let roles: Vec<(&str, Role, Option<Address>)> = indexer.role_history.iter()
+        .map(|change| (change.transaction.as_str(), change.role, change.address))
+        .collect();
+    assert_eq!(roles, vec![
+        ("deploy", Role::Owner, Some(owner)),
+        ("deploy", Role::SupplyController, Some(owner)),
+        ("role", Role::AssetProtection, Some(ap_role)),
+        ("propose", Role::ProposedOwner, Some(bob)),
+        ("claim", Role::Owner, Some(bob)),
+        ("claim", Role::ProposedOwner, None),
+    ]);
+}
+
+#[test]
+fn test_index_deploy() {
+    let owner = address("owner");
+    let controller = address("controller");
+    let pauser = address("pauser");
+
+    let mut indexer = Indexer::new();
+    let topics = vec![owner.0.to_vec(), controller.0.to_vec(), [0u8; 32].to_vec(), pauser.0.to_vec()];
+    let deploy = transaction("deploy", &owner, "init", vec![log("Initialized", topics, vec![])]);
+    indexer.index(&deploy).unwrap();
+    assert_eq!(indexer.state.owner, Some(owner));
+    assert_eq!(indexer.state.supply_controller, Some(controller));
+    assert_eq!(indexer.state.asset_protection_role, None);
+    assert_eq!(indexer.state.pauser, Some(pauser));
+    assert!(!indexer.state.paused);
+    // roles that are not set are not recorded
+    let roles: Vec<Role> = indexer.role_history.iter().map(|change| change.role).collect();
+    assert_eq!(roles, vec![Role::Owner, Role::SupplyController, Role::Pauser]);
+
+    // the constructor of a contract that predates the named events logs nothing:
+    // the caller is the owner and the supply controller, and the contract starts paused
+    let mut indexer = Indexer::new();
+    indexer.index(&transaction("deploy", &owner, "init", vec![])).unwrap();
+    assert_eq!(indexer.state.owner, Some(owner));
+    assert_eq!(indexer.state.supply_controller, Some(owner));
+    assert!(indexer.state.paused);
+}
+
+#[test]
+fn test_index_esdt_migration() {
+    let owner = address("owner");
+    let alice = address("alice");
+    let zero = Address([0; 32]);
+
+    let transactions = [
+        transaction("mint", &owner, "increaseSupply", vec![
+            log("SupplyIncreased", vec![alice.0.to_vec()], big(1000).to_bytes_be()),
+            transfer(&zero, &alice, 1000),
+        ]),
+        transaction("enable", &owner, "enableEsdtMigration", vec![log("EsdtMigrationEnabled", vec![], b"BUSD-a1b2c3".to_vec())]),
+        transaction("migrate", &alice, "migrateToEsdt", vec![
+            log("MigratedToEsdt", vec![alice.0.to_vec()], big(400).to_bytes_be()),
+            transfer(&alice, &zero, 400),
+        ]),
+        // the ESDT transfer failed, the callback gives the balance back
+        transaction("revert", &alice, "callBack", vec![
+            log("EsdtMigrationReverted", vec![alice.0.to_vec()], big(100).to_bytes_be()),
+            transfer(&zero, &alice, 100),
+        ]),
+    ];
+    let mut indexer = Indexer::new();
+    for transaction in transactions.iter() {
+        indexer.index(transaction).unwrap();
+    }
+    assert_eq!(indexer.state.esdt_token, b"BUSD-a1b2c3".to_vec());
+    assert_eq!(indexer.state.total_supply, big(700));
+    assert_eq!(indexer.state.esdt_migrated_supply, big(300));
+    assert_eq!(indexer.state.balances.get(&alice), Some(&big(700)));
+
+    indexer.index(&transaction("disable", &owner, "disableEsdtMigration", vec![log("EsdtMigrationDisabled", vec![], vec![])])).unwrap();
+    assert!(indexer.state.esdt_token.is_empty());
+}
+
+#[test]
+fn test_index_snapshots() {
+    let owner = address("owner");
+    let alice = address("alice");
+    let bob = address("bob");
+    let zero = Address([0; 32]);
+
+    let transactions = [
+        transaction("mint", &owner, "increaseSupply", vec![
+            log("SupplyIncreased", vec![alice.0.to_vec()], big(1000).to_bytes_be()),
+            transfer(&zero, &alice, 1000),
+        ]),
+        transaction("snapshot", &owner, "snapshot", vec![log("Snapshot", vec![], vec![1])]),
+        transaction("transfer", &alice, "transfer", vec![transfer(&alice, &bob, 100)]),
+        transaction("transfer again", &alice, "transfer", vec![transfer(&alice, &bob, 100)]),
+        transaction("mint again", &owner, "increaseSupply", vec![
+            log("SupplyIncreased", vec![alice.0.to_vec()], big(500).to_bytes_be()),
+            transfer(&zero, &alice, 500),
+        ]),
+    ];
+    let mut indexer = Indexer::new();
+    for transaction in transactions.iter() {
+        indexer.index(transaction).unwrap();
+    }
+    // only the first change after the snapshot is saved, and zero values are not stored
+    assert_eq!(indexer.state.current_snapshot_id, 1);
+    assert_eq!(indexer.state.balance_snapshot_ids.get(&alice), Some(&vec![1]));
+    assert_eq!(indexer.state.balance_snapshot_ids.get(&bob), Some(&vec![1]));
+    assert_eq!(indexer.state.balance_snapshots.get(&(alice, 1)), Some(&big(1000)));
+    assert_eq!(indexer.state.balance_snapshots.get(&(bob, 1)), None);
+    assert_eq!(indexer.state.total_supply_snapshot_ids, vec![1]);
+    assert_eq!(indexer.state.total_supply_snapshots.get(&1), Some(&big(1000)));
+    assert_eq!(indexer.state.balances.get(&alice), Some(&big(1300)));
+}
+
+#[test]
+fn test_index_errors() {
+    let owner = address("owner");
+    let alice = address("alice");
+    let zero = Address([0; 32]);
+
+    let mut indexer = Indexer::new();
+    let overdrawn = transaction("1", &owner, "transfer", vec![transfer(&owner, &alice, 1)]);
+    assert_eq!(indexer.index(&overdrawn), Err(format!("transaction 1: Transfer: balance of {} below 1", owner)));
+
+    let no_transfer = transaction("2", &owner, "increaseSupply", vec![log("SupplyIncreased", vec![owner.0.to_vec()], vec![5])]);
+    assert_eq!(indexer.index(&no_transfer), Err("transaction 2: missing transfer event".to_string()));
+
+    let mut indexer = Indexer::new();
+    let unknown_transfer = transaction("3", &owner, "executePendingTransfer", vec![
+        log("LargeTransferExecuted", vec![owner.0.to_vec(), alice.0.to_vec(), number_topic(1)], vec![5]),
+        transfer(&zero, &alice, 5),
+    ]);
+    assert_eq!(indexer.index(&unknown_transfer), Err("transaction 3: LargeTransferExecuted: no pending transfer 1 of 5".to_string()));
+}
+
+#[test]
+fn test_compare() {
+    let owner = address("owner");
+    let alice = address("alice");
+    let mut indexed = BusdState { owner: Some(owner), ..BusdState::default() };
+    indexed.balances.insert(alice, big(5));
+    indexed.frozen.insert(alice);
+    let mut on_chain = indexed.clone();
+    assert_eq!(compare(&indexed, &on_chain), Vec::<String>::new());
+
+    on_chain.owner = None;
+    on_chain.balances.insert(alice, big(6));
+    on_chain.frozen.clear();
+    on_chain.daily_buckets.insert((alice, 0), Default::default());
+    assert_eq!(compare(&indexed, &on_chain), vec![
+        format!("owner: indexed {}, on chain -", owner),
+        format!("balance of {}: indexed 5, on chain 6", alice),
+        format!("frozen {}: indexed true, on chain false", alice),
+    ]);
+}
@@ -0,0 +1,253 @@
+//! Gas benchmark cases, and the baseline costs they are compared against.
+//!
+//! Each case sets up a representative contract state and makes a single call.
+//! Mandos runners only report whether a scenario passed, so the gas used by a call is measured
+//! as the smallest gas limit for which it still succeeds, see `busd-gas-bench`.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use serde_json::{json, Map, Value};
+
+use crate::suite::BusdStorage;
+use crate::*;
+
+/// A single benchmarked call, in a given contract state.
+#[derive(Clone, Debug)]
+pub struct BenchCase {
+    pub name: &'static str,
+    pub setup: SetState,
+    pub call: Tx,
+}
+
+impl BenchCase {
+    /// The scenario that runs the call with the given gas limit, and only passes if the call succeeds.
+    pub fn scenario(&self, gas_limit: u64) -> Scenario {
+        Scenario::new(format!("gas benchmark: {}", self.name))
+            .set_state(self.setup.clone())
+            .sc_call(ScCall::new("bench", self.call.clone().gas_limit(gas_limit.to_string()))
+                .expect(Expect::ok().any_logs()))
+    }
+}
+
+fn contract() -> String {
+    address("busd_contract")
+}
+
+fn call(from: &str, function: &str) -> Tx {
+    Tx::call(address(from), contract(), function)
+}
+
+/// Unpaused contract, with 1000 tokens for `holder_1`, `holder_2` and the owner, who is also the supply controller.
+/// `holder_3` has never held any tokens.
+fn contract_account() -> Account {
+    Account::contract()
+        .storage(key("owner", &[]), address("busd_owner"))
+        .storage(key("paused", &[]), "false")
+        .storage(key("ap_role", &[]), some(&address("asset_protection_role")))
+        .storage(key("supply_c", &[]), address("busd_owner"))
+        .total_supply("3000")
+        .busd_balance(&address("busd_owner"), "1000")
+        .busd_balance(&address("holder_1"), "1000")
+        .busd_balance(&address("holder_2"), "1000")
+}
+
+fn state<F: FnOnce(Account) -> Account>(contract_storage: F) -> SetState {
+    let mut state = SetState::new();
+    for name in &["busd_owner", "asset_protection_role", "holder_1", "holder_2", "holder_3"] {
+        state = state.account(address(name), Account::new().balance("100,000,000,000"));
+    }
+    state
+        .account(contract(), contract_storage(contract_account()))
+        .block_timestamp("1,000,000")
+}
+
+fn base_state() -> SetState {
+    state(|account| account)
+}
+
+/// The entire balance of `holder_1` held for `holder_2` since timestamp 0, with a review period of 3600.
+fn pending_transfer(account: Account) -> Account {
+    account
+        .without_storage(&key("balance", &[&address("holder_1")]))
+        .storage(key("lt_threshold", &[]), some("500"))
+        .storage(key("lt_review", &[]), "3600")
+        .storage(key("pt_last_id", &[]), "1")
+        .storage(key("pt_ids", &[]), "0x0000000000000001")
+        .storage(key("pt_total", &[]), "1000")
+        .storage(key("pt_sender", &["1"]), address("holder_1"))
+        .storage(key("pt_recipient", &["1"]), address("holder_2"))
+        .storage(key("pt_amount", &["1"]), "1000")
+        .storage(key("pt_time", &["1"]), "0")
+}
+
+fn frozen_holder_1(account: Account) -> Account {
+    account.storage(key("frozen", &[&address("holder_1")]), "true")
+}
+
+fn case(name: &'static str, setup: SetState, call: Tx) -> BenchCase {
+    BenchCase { name, setup, call }
+}
+
+/// All benchmark cases. Names are stable, they are the keys of the baseline file.
+pub fn cases() -> Vec<BenchCase> {
+    vec![
+        case("transfer/fresh_recipient", base_state(),
+            call("holder_1", "transfer").arg(address("holder_3")).arg("100")),
+        case("transfer/existing_recipient", base_state(),
+            call("holder_1", "transfer").arg(address("holder_2")).arg("100")),
+        case("transfer/entire_balance", base_state(),
+            call("holder_1", "transfer").arg(address("holder_2")).arg("1000")),
+        case("transfer/daily_limit", state(|account| account
+                .storage(key("vel_limit", &[&address("holder_1")]), some("500"))),
+            call("holder_1", "transfer").arg(address("holder_2")).arg("100")),
+        case("transfer/queued_large_transfer", state(|account| account
+                .storage(key("lt_threshold", &[]), some("50"))
+                .storage(key("lt_review", &[]), "3600")),
+            call("holder_1", "transfer").arg(address("holder_2")).arg("100")),
+        case("transferWithTravelRule/between_vasps", state(|account| account
+                .storage(key("tr_threshold", &[]), some("50"))
+                .storage(key("vasp_id", &[&address("holder_1")]), some(&bytes32("vasp_a_identifier")))
+                .storage(key("vasp_id", &[&address("holder_2")]), some(&bytes32("vasp_b_identifier")))),
+            call("holder_1", "transferWithTravelRule")
+                .arg(address("holder_2"))
+                .arg("100")
+                .arg(bytes32("payload_hash"))
+                .arg(bytes32("vasp_a_identifier"))),
+        case("transferFrom/fresh_recipient", state(|account| account
+                .busd_allowance(&address("holder_1"), &address("holder_2"), "500")),
+            call("holder_2", "transferFrom").arg(address("holder_1")).arg(address("holder_3")).arg("100")),
+        case("transferFrom/existing_recipient", state(|account| account
+                .busd_allowance(&address("holder_1"), &address("holder_2"), "500")),
+            call("holder_2", "transferFrom").arg(address("holder_1")).arg(address("holder_2")).arg("100")),
+        case("transferFrom/entire_allowance", state(|account| account
+                .busd_allowance(&address("holder_1"), &address("holder_2"), "500")),
+            call("holder_2", "transferFrom").arg(address("holder_1")).arg(address("holder_2")).arg("500")),
+        case("approve/new_allowance", base_state(),
+            call("holder_1", "approve").arg(address("holder_2")).arg("100")),
+        case("approve/existing_allowance", state(|account| account
+                .busd_allowance(&address("holder_1"), &address("holder_2"), "500")),
+            call("holder_1", "approve").arg(address("holder_2")).arg("100")),
+        case("increaseSupply", base_state(),
+            call("busd_owner", "increaseSupply").arg("100")),
+        case("decreaseSupply", base_state(),
+            call("busd_owner", "decreaseSupply").arg("100")),
+        case("freeze", base_state(),
+            call("asset_protection_role", "freeze").arg(address("holder_1"))),
+        case("unfreeze", state(frozen_holder_1),
+            call("asset_protection_role", "unfreeze").arg(address("holder_1"))),
+        case("wipeFrozenAddress", state(frozen_holder_1),
+            call("asset_protection_role", "wipeFrozenAddress").arg(address("holder_1"))),
+        case("pause", base_state(),
+            call("busd_owner", "pause")),
+        case("unpause", state(|account| account.storage(key("paused", &[]), "true")),
+            call("busd_owner", "unpause")),
+        case("proposeOwner", base_state(),
+            call("busd_owner", "proposeOwner").arg(address("holder_1"))),
+        case("claimOwnership", state(|account| account
+                .storage(key("prop_owner", &[]), some(&address("holder_1")))),
+            call("holder_1", "claimOwnership")),
+        case("reclaimBUSD", state(|account| account
+                .busd_balance(&contract(), "500")
+                .total_supply("3500")),
+            call("busd_owner", "reclaimBUSD")),
+        case("setAssetProtectionRole", base_state(),
+            call("busd_owner", "setAssetProtectionRole").arg(address("holder_3"))),
+        case("setSupplyController", base_state(),
+            call("busd_owner", "setSupplyController").arg(address("holder_3"))),
+        case("setDailyLimit", base_state(),
+            call("asset_protection_role", "setDailyLimit").arg(address("holder_1")).arg("500")),
+        case("setLargeTransferThreshold", base_state(),
+            call("busd_owner", "setLargeTransferThreshold").arg("1000")),
+        case("executePendingTransfer", state(pending_transfer),
+            call("holder_3", "executePendingTransfer").arg("1")),
+        case("cancelPendingTransfer", state(pending_transfer),
+            call("asset_protection_role", "cancelPendingTransfer").arg("1")),
+        case("registerVasp", base_state(),
+            call("busd_owner", "registerVasp").arg(address("holder_1")).arg(bytes32("vasp_a_identifier"))),
+    ]
+}
+
+/// Measured costs: the size of the compiled contract and the gas used by each case.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Costs {
+    pub wasm_size: Option<u64>,
+    pub gas: BTreeMap<String, u64>,
+}
+
+impl Costs {
+    pub fn parse(json: &str) -> Result<Self, String> {
+        let value: Value = serde_json::from_str(json).map_err(|e| e.to_string())?;
+        let wasm_size = match &value["wasm_size"] {
+            Value::Null => None,
+            size => Some(size.as_u64().ok_or("wasm_size is not a number")?),
+        };
+        let mut gas = BTreeMap::new();
+        if let Some(entries) = value["gas"].as_object() {
+            for (name, used) in entries {
+                let used = used.as_u64().ok_or_else(|| format!("gas of {} is not a number", name))?;
+                gas.insert(name.clone(), used);
+            }
+        }
+        Ok(Costs { wasm_size, gas })
+    }
+
+    pub fn to_json_string(&self) -> String {
+        let mut gas = Map::new();
+        for (name, used) in self.gas.iter() {
+            gas.insert(name.clone(), json!(used));
+        }
+        to_pretty_string(&json!({
+            "wasm_size": self.wasm_size,
+            "gas": gas,
+        }))
+    }
+}
+
+/// A cost that grew past the tolerance.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Regression {
+    pub what: String,
+    pub baseline: u64,
+    pub measured: u64,
+}
+
+impl fmt::Display for Regression {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let change = (self.measured as f64 - self.baseline as f64) * 100.0 / self.baseline as f64;
+        write!(f, "{}: {} -> {} (+{:.1}%)", self.what, self.baseline, self.measured, change)
+    }
+}
+
+fn regressed(baseline: u64, measured: u64, tolerance_percent: u64) -> bool {
+    measured as u128 * 100 > baseline as u128 * (100 + tolerance_percent) as u128
+}
+
+/// Costs that grew by more than `tolerance_percent` since the baseline.
+/// Cases missing from either side are not regressions, see `missing` for those without a baseline.
+pub fn regressions(baseline: &Costs, measured: &Costs, tolerance_percent: u64) -> Vec<Regression> {
+    let mut result = Vec::new();
+    if let (Some(baseline), Some(measured)) = (baseline.wasm_size, measured.wasm_size) {
+        if regressed(baseline, measured, tolerance_percent) {
+            result.push(Regression { what: "wasm size".to_string(), baseline, measured });
+        }
+    }
+    for (name, &measured) in measured.gas.iter() {
+        if let Some(&baseline) = baseline.gas.get(name) {
+            if regressed(baseline, measured, tolerance_percent) {
+                result.push(Regression { what: name.clone(), baseline, measured });
+            }
+        }
+    }
+    result
+}
+
+/// Measured costs that the baseline does not record, which must be recorded with `--update`.
+pub fn missing(baseline: &Costs, measured: &Costs) -> Vec<String> {
+    let mut result = Vec::new();
+    if baseline.wasm_size.is_none() && measured.wasm_size.is_some() {
+        result.push("wasm size".to_string());
+    }
+    result.extend(measured.gas.keys().filter(|name| !baseline.gas.contains_key(*name)).cloned());
+    result
+}
@@ -0,0 +1,149 @@
+//! Measures the gas used by each benchmark case and the size of the compiled contract,
+//! and compares them against the committed baseline.
+//!
+//! Usage: `busd-gas-bench [--update] [--tolerance PERCENT] [--runner COMMAND] [CASE_PREFIX]`
+//!
+//! The contract must have been built with `compile-wasm.sh` beforehand.
+//! Each case is run through a Mandos runner, by default `erdpy test --directory`,
+//! bisecting on the gas limit until the smallest limit for which the call succeeds is found.
+//! Fails if any cost grew by more than the tolerance, 5% by default, or has no baseline yet.
+//! With `--update`, the baseline is overwritten with the measured costs instead.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command, Stdio};
+
+use busd_scenario_gen::bench::{self, BenchCase, Costs};
+
+const DEFAULT_RUNNER: &str = "erdpy test --directory";
+const DEFAULT_TOLERANCE_PERCENT: u64 = 5;
+
+/// No benchmarked call should come anywhere near this.
+const MAX_GAS_LIMIT: u64 = 10_000_000;
+
+struct Runner {
+    command: Vec<String>,
+    scenario_dir: PathBuf,
+}
+
+impl Runner {
+    /// Whether the call of the case succeeds with the given gas limit.
+    fn passes(&self, case: &BenchCase, gas_limit: u64) -> bool {
+        let file = self.scenario_dir.join(format!("{}.scen.json", case.name.replace('/', "_")));
+        fs::write(&file, case.scenario(gas_limit).to_json_string()).unwrap();
+        let status = Command::new(&self.command[0])
+            .args(&self.command[1..])
+            .arg(&file)
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status();
+        match status {
+            Ok(status) => status.success(),
+            Err(e) => {
+                eprintln!("could not run `{}`: {}", self.command.join(" "), e);
+                process::exit(2);
+            }
+        }
+    }
+
+    /// The smallest gas limit for which the call succeeds, which is the gas it uses.
+    fn measure(&self, case: &BenchCase) -> Option<u64> {
+        if !self.passes(case, MAX_GAS_LIMIT) {
+            return None;
+        }
+        let (mut failing, mut passing) = (0, MAX_GAS_LIMIT);
+        while passing - failing > 1 {
+            let middle = failing + (passing - failing) / 2;
+            if self.passes(case, middle) {
+                passing = middle;
+            } else {
+                failing = middle;
+            }
+        }
+        Some(passing)
+    }
+}
+
+fn main() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    let baseline_file = Path::new(env!("CARGO_MANIFEST_DIR")).join("gas-baseline.json");
+
+    let mut update = false;
+    let mut tolerance = DEFAULT_TOLERANCE_PERCENT;
+    let mut runner = DEFAULT_RUNNER.to_string();
+    let mut prefix = String::new();
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--update" => update = true,
+            "--tolerance" => tolerance = args.next().and_then(|t| t.parse().ok()).expect("--tolerance needs a percentage"),
+            "--runner" => runner = args.next().expect("--runner needs a command"),
+            _ => prefix = arg,
+        }
+    }
+
+    let wasm_file = root.join("output/busd.wasm");
+    let wasm_size = match fs::metadata(&wasm_file) {
+        Ok(metadata) => metadata.len(),
+        Err(_) => {
+            eprintln!("{} not found, run compile-wasm.sh first", wasm_file.display());
+            process::exit(2);
+        }
+    };
+
+    // two folders below the root, so that the contract code path in the scenarios resolves
+    let scenario_dir = root.join("target/gas-bench");
+    fs::create_dir_all(&scenario_dir).unwrap();
+    let runner = Runner {
+        command: runner.split_whitespace().map(String::from).collect(),
+        scenario_dir,
+    };
+
+    let baseline = match fs::read_to_string(&baseline_file) {
+        Ok(json) => Costs::parse(&json).unwrap_or_else(|e| panic!("invalid {}: {}", baseline_file.display(), e)),
+        Err(_) => Costs::default(),
+    };
+
+    let mut measured = Costs { wasm_size: Some(wasm_size), gas: Default::default() };
+    let mut failed = false;
+    println!("{:<40} {:>10} {:>10}", "case", "gas", "baseline");
+    println!("{:<40} {:>10} {:>10}", "wasm size", wasm_size, show(baseline.wasm_size));
+    for case in bench::cases().iter().filter(|case| case.name.starts_with(&prefix)) {
+        match runner.measure(case) {
+            Some(gas) => {
+                println!("{:<40} {:>10} {:>10}", case.name, gas, show(baseline.gas.get(case.name).cloned()));
+                measured.gas.insert(case.name.to_string(), gas);
+            }
+            None => {
+                eprintln!("{}: call fails even with gas limit {}", case.name, MAX_GAS_LIMIT);
+                failed = true;
+            }
+        }
+    }
+
+    if update {
+        let mut updated = baseline;
+        updated.wasm_size = measured.wasm_size;
+        updated.gas.extend(measured.gas);
+        fs::write(&baseline_file, updated.to_json_string()).unwrap();
+        println!("baseline written to {}", baseline_file.display());
+    } else {
+        let missing = bench::missing(&baseline, &measured);
+        for name in missing.iter() {
+            eprintln!("{}: no baseline, run with --update to record it", name);
+        }
+        failed |= !missing.is_empty();
+        let regressions = bench::regressions(&baseline, &measured, tolerance);
+        for regression in regressions.iter() {
+            eprintln!("regression past {}% tolerance: {}", tolerance, regression);
+        }
+        failed |= !regressions.is_empty();
+    }
+    if failed {
+        process::exit(1);
+    }
+}
+
+fn show(value: Option<u64>) -> String {
+    value.map_or("-".to_string(), |value| value.to_string())
+}
@@ -0,0 +1,593 @@
+//! Builder API for Mandos scenarios.
+//!
+//! Scenarios are described in Rust and serialised to `.scen.json`,
+//! so that common states and calls can be shared instead of copy-pasted between files.
+//!
+//! ```
+//! use busd_scenario_gen::*;
+//!
+//! let scenario = Scenario::new("transfer")
+//!     .set_state(SetState::new()
+//!         .account(address("holder_1"), Account::new())
+//!         .account(address("busd_contract"), Account::contract()
+//!             .storage(key("balance", &[&address("holder_1")]), "500")))
+//!     .sc_call(ScCall::new("1", Tx::call(address("holder_1"), address("busd_contract"), "transfer")
+//!             .arg(address("holder_2"))
+//!             .arg("100"))
+//!         .expect(Expect::ok()
+//!             .log(Log::event(address("busd_contract"), "Transfer")
+//!                 .topic(address("holder_1"))
+//!                 .topic(address("holder_2"))
+//!                 .data("100"))));
+//! assert!(scenario.to_json_string().contains("\"function\": \"transfer\""));
+//! ```
+
+use serde_json::{json, Map, Value};
+
+pub mod bench;
+pub mod suite;
+
+/// Path of the compiled contract, relative to the scenario file.
+pub const CONTRACT_CODE: &str = "file:../../output/busd.wasm";
+
+pub const DEFAULT_GAS_LIMIT: &str = "1,000,000";
+pub const DEFAULT_GAS_PRICE: &str = "0x01";
+
+/// A 32-byte address, padded with underscores and ending in `s1`, the way all scenarios write them.
+pub fn address(name: &str) -> String {
+    address_with_suffix(name, "s1")
+}
+
+/// Same as `address`, with a custom 2-character suffix.
+pub fn address_with_suffix(name: &str, suffix: &str) -> String {
+    assert!(name.len() + suffix.len() <= 32, "address name too long: {}", name);
+    format!("``{}{}{}", name, "_".repeat(32 - name.len() - suffix.len()), suffix)
+}
+
+/// A 32-byte value (e.g. an `H256`), padded with underscores.
+pub fn bytes32(name: &str) -> String {
+    address_with_suffix(name, "")
+}
+
+/// The all-zero address, used as the other party when minting and burning.
+pub fn zero_address() -> String {
+    format!("0x{}", "0".repeat(64))
+}
+
+/// A storage key: the key name followed by its arguments, separated by `|`.
+pub fn key(name: &str, args: &[&str]) -> String {
+    let mut result = format!("``{}", name);
+    for arg in args {
+        result.push('|');
+        result.push_str(arg);
+    }
+    result
+}
+
+/// Encoding of a `Some` value in storage.
+pub fn some(value: &str) -> String {
+    format!("1|{}", value)
+}
+
+/// The 32-byte identifier of a BUSD event, as declared in `#[event("0x...")]`: its name, padded with zeros.
+pub fn event_identifier(name: &str) -> String {
+    assert!(name.len() <= 32, "event name longer than 32 bytes: {}", name);
+    let hex: String = name.bytes().map(|byte| format!("{:02x}", byte)).collect();
+    format!("0x{:0<64}", hex)
+}
+
+/// The identifier of a legacy BUSD event, a small number.
+pub fn legacy_event_identifier(id: u8) -> String {
+    format!("0x{:064x}", id)
+}
+
+/// JSON indented with 4 spaces and ending with a newline, same as the committed files.
+pub(crate) fn to_pretty_string(value: &Value) -> String {
+    let mut bytes = Vec::new();
+    let formatter = serde_json::ser::PrettyFormatter::with_indent(b"    ");
+    let mut serializer = serde_json::Serializer::with_formatter(&mut bytes, formatter);
+    serde::Serialize::serialize(value, &mut serializer).unwrap();
+    let mut result = String::from_utf8(bytes).unwrap();
+    result.push('\n');
+    result
+}
+
+fn strings(values: &[String]) -> Value {
+    Value::Array(values.iter().map(|s| Value::String(s.clone())).collect())
+}
+
+fn string_map(entries: &[(String, String)]) -> Value {
+    let mut map = Map::new();
+    for (k, v) in entries {
+        map.insert(k.clone(), Value::String(v.clone()));
+    }
+    Value::Object(map)
+}
+
+#[derive(Clone, Debug)]
+pub struct Scenario {
+    name: String,
+    steps: Vec<Step>,
+}
+
+#[derive(Clone, Debug)]
+pub enum Step {
+    SetState(SetState),
+    ScDeploy(ScCall),
+    ScCall(ScCall),
+    CheckState(CheckState),
+}
+
+impl Scenario {
+    pub fn new<S: Into<String>>(name: S) -> Self {
+        Scenario { name: name.into(), steps: Vec::new() }
+    }
+
+    pub fn step(mut self, step: Step) -> Self {
+        self.steps.push(step);
+        self
+    }
+
+    pub fn steps<I: IntoIterator<Item = Step>>(mut self, steps: I) -> Self {
+        self.steps.extend(steps);
+        self
+    }
+
+    pub fn set_state(self, set_state: SetState) -> Self {
+        self.step(Step::SetState(set_state))
+    }
+
+    pub fn sc_deploy(self, sc_deploy: ScCall) -> Self {
+        self.step(Step::ScDeploy(sc_deploy))
+    }
+
+    pub fn sc_call(self, sc_call: ScCall) -> Self {
+        self.step(Step::ScCall(sc_call))
+    }
+
+    pub fn check_state(self, check_state: CheckState) -> Self {
+        self.step(Step::CheckState(check_state))
+    }
+
+    pub fn to_json(&self) -> Value {
+        json!({
+            "name": self.name,
+            "steps": self.steps.iter().map(Step::to_json).collect::<Vec<_>>(),
+        })
+    }
+
+    /// The JSON text, indented with 4 spaces and ending in a newline, same as the hand-written files.
+    pub fn to_json_string(&self) -> String {
+        to_pretty_string(&self.to_json())
+    }
+
+    /// Whether a committed file holds this scenario. Only the JSON content is compared,
+    /// so the files kept from before the generator keep their own layout.
+    pub fn matches(&self, committed: &str) -> bool {
+        serde_json::from_str::<Value>(committed).ok() == Some(self.to_json())
+    }
+}
+
+impl Step {
+    pub fn to_json(&self) -> Value {
+        match self {
+            Step::SetState(set_state) => set_state.to_json(),
+            Step::ScDeploy(sc_deploy) => sc_deploy.to_json("scDeploy"),
+            Step::ScCall(sc_call) => sc_call.to_json("scCall"),
+            Step::CheckState(check_state) => check_state.to_json(),
+        }
+    }
+}
+
+impl From<SetState> for Step {
+    fn from(set_state: SetState) -> Self {
+        Step::SetState(set_state)
+    }
+}
+
+impl From<CheckState> for Step {
+    fn from(check_state: CheckState) -> Self {
+        Step::CheckState(check_state)
+    }
+}
+
+impl From<ScCall> for Step {
+    fn from(sc_call: ScCall) -> Self {
+        Step::ScCall(sc_call)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Account {
+    nonce: String,
+    balance: String,
+    storage: Vec<(String, String)>,
+    code: String,
+}
+
+impl Account {
+    /// A user account with nonce and balance 0.
+    pub fn new() -> Self {
+        Account {
+            nonce: "0".to_string(),
+            balance: "0".to_string(),
+            storage: Vec::new(),
+            code: String::new(),
+        }
+    }
+
+    /// The BUSD contract account.
+    pub fn contract() -> Self {
+        Account::new().code(CONTRACT_CODE)
+    }
+
+    pub fn nonce<S: Into<String>>(mut self, nonce: S) -> Self {
+        self.nonce = nonce.into();
+        self
+    }
+
+    pub fn balance<S: Into<String>>(mut self, balance: S) -> Self {
+        self.balance = balance.into();
+        self
+    }
+
+    /// Adds a storage entry, or replaces it if the key is already there.
+    pub fn storage<K: Into<String>, V: Into<String>>(mut self, key: K, value: V) -> Self {
+        let key = key.into();
+        let value = value.into();
+        match self.storage.iter_mut().find(|(k, _)| *k == key) {
+            Some(entry) => entry.1 = value,
+            None => self.storage.push((key, value)),
+        }
+        self
+    }
+
+    /// Removes a storage entry, if present.
+    pub fn without_storage(mut self, key: &str) -> Self {
+        self.storage.retain(|(k, _)| k != key);
+        self
+    }
+
+    pub fn code<S: Into<String>>(mut self, code: S) -> Self {
+        self.code = code.into();
+        self
+    }
+
+    pub fn to_json(&self) -> Value {
+        json!({
+            "nonce": self.nonce,
+            "balance": self.balance,
+            "storage": string_map(&self.storage),
+            "code": self.code,
+        })
+    }
+}
+
+impl Default for Account {
+    fn default() -> Self {
+        Account::new()
+    }
+}
+
+fn accounts_json(accounts: &[(String, Account)]) -> Value {
+    let mut map = Map::new();
+    for (address, account) in accounts {
+        map.insert(address.clone(), account.to_json());
+    }
+    Value::Object(map)
+}
+
+fn set_account(accounts: &mut Vec<(String, Account)>, address: String, account: Account) {
+    match accounts.iter_mut().find(|(a, _)| *a == address) {
+        Some(entry) => entry.1 = account,
+        None => accounts.push((address, account)),
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct SetState {
+    accounts: Option<Vec<(String, Account)>>,
+    new_addresses: Vec<(String, String, String)>,
+    block_timestamp: Option<String>,
+}
+
+impl SetState {
+    pub fn new() -> Self {
+        SetState::default()
+    }
+
+    /// Adds an account, or replaces it if the address is already there.
+    pub fn account<S: Into<String>>(mut self, address: S, account: Account) -> Self {
+        set_account(self.accounts.get_or_insert_with(Vec::new), address.into(), account);
+        self
+    }
+
+    /// Changes an account that was already added.
+    pub fn update_account<F: FnOnce(Account) -> Account>(mut self, address: &str, update: F) -> Self {
+        let accounts = self.accounts.as_mut().expect("no accounts");
+        let entry = accounts.iter_mut().find(|(a, _)| a == address).expect("no such account");
+        entry.1 = update(entry.1.clone());
+        self
+    }
+
+    /// Declares the address of a contract deployed by `creator`.
+    pub fn new_address<S: Into<String>, N: Into<String>, A: Into<String>>(mut self, creator: S, creator_nonce: N, new_address: A) -> Self {
+        self.new_addresses.push((creator.into(), creator_nonce.into(), new_address.into()));
+        self
+    }
+
+    pub fn block_timestamp<S: Into<String>>(mut self, timestamp: S) -> Self {
+        self.block_timestamp = Some(timestamp.into());
+        self
+    }
+
+    pub fn to_json(&self) -> Value {
+        let mut map = Map::new();
+        map.insert("step".to_string(), json!("setState"));
+        if let Some(accounts) = &self.accounts {
+            map.insert("accounts".to_string(), accounts_json(accounts));
+        }
+        if !self.new_addresses.is_empty() {
+            let new_addresses = self.new_addresses.iter()
+                .map(|(creator, nonce, new_address)| json!({
+                    "creatorAddress": creator,
+                    "creatorNonce": nonce,
+                    "newAddress": new_address,
+                }))
+                .collect::<Vec<_>>();
+            map.insert("newAddresses".to_string(), Value::Array(new_addresses));
+        }
+        if let Some(timestamp) = &self.block_timestamp {
+            map.insert("currentBlockInfo".to_string(), json!({ "blockTimestamp": timestamp }));
+        }
+        Value::Object(map)
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct CheckState {
+    accounts: Vec<(String, Account)>,
+}
+
+impl CheckState {
+    pub fn new() -> Self {
+        CheckState::default()
+    }
+
+    /// Changes an account that was already added.
+    pub fn update_account<F: FnOnce(Account) -> Account>(mut self, address: &str, update: F) -> Self {
+        let entry = self.accounts.iter_mut().find(|(a, _)| a == address).expect("no such account");
+        entry.1 = update(entry.1.clone());
+        self
+    }
+
+    /// Adds an account, or replaces it if the address is already there.
+    pub fn account<S: Into<String>>(mut self, address: S, account: Account) -> Self {
+        set_account(&mut self.accounts, address.into(), account);
+        self
+    }
+
+    pub fn to_json(&self) -> Value {
+        json!({
+            "step": "checkState",
+            "accounts": accounts_json(&self.accounts),
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Tx {
+    from: String,
+    to: String,
+    value: String,
+    function: String,
+    contract_code: Option<String>,
+    arguments: Vec<String>,
+    gas_limit: String,
+    gas_price: String,
+}
+
+impl Tx {
+    pub fn call<F: Into<String>, T: Into<String>, N: Into<String>>(from: F, to: T, function: N) -> Self {
+        Tx {
+            from: from.into(),
+            to: to.into(),
+            value: "0".to_string(),
+            function: function.into(),
+            contract_code: None,
+            arguments: Vec::new(),
+            gas_limit: DEFAULT_GAS_LIMIT.to_string(),
+            gas_price: DEFAULT_GAS_PRICE.to_string(),
+        }
+    }
+
+    /// Deploys the BUSD contract.
+    pub fn deploy<F: Into<String>>(from: F) -> Self {
+        let mut tx = Tx::call(from, "", "");
+        tx.contract_code = Some(CONTRACT_CODE.to_string());
+        tx
+    }
+
+    pub fn value<S: Into<String>>(mut self, value: S) -> Self {
+        self.value = value.into();
+        self
+    }
+
+    pub fn arg<S: Into<String>>(mut self, arg: S) -> Self {
+        self.arguments.push(arg.into());
+        self
+    }
+
+    pub fn gas_limit<S: Into<String>>(mut self, gas_limit: S) -> Self {
+        self.gas_limit = gas_limit.into();
+        self
+    }
+
+    pub fn to_json(&self) -> Value {
+        let mut map = Map::new();
+        map.insert("from".to_string(), json!(self.from));
+        map.insert("to".to_string(), json!(self.to));
+        map.insert("value".to_string(), json!(self.value));
+        map.insert("function".to_string(), json!(self.function));
+        if let Some(contract_code) = &self.contract_code {
+            map.insert("contractCode".to_string(), json!(contract_code));
+        }
+        map.insert("arguments".to_string(), strings(&self.arguments));
+        map.insert("gasLimit".to_string(), json!(self.gas_limit));
+        map.insert("gasPrice".to_string(), json!(self.gas_price));
+        Value::Object(map)
+    }
+}
+
+/// A `scCall` or `scDeploy` step.
+#[derive(Clone, Debug)]
+pub struct ScCall {
+    tx_id: String,
+    comment: Option<String>,
+    tx: Tx,
+    expect: Expect,
+}
+
+impl ScCall {
+    /// A call expected to succeed, with no output and no logs, unless `expect` says otherwise.
+    pub fn new<S: Into<String>>(tx_id: S, tx: Tx) -> Self {
+        ScCall {
+            tx_id: tx_id.into(),
+            comment: None,
+            tx,
+            expect: Expect::ok(),
+        }
+    }
+
+    pub fn comment<S: Into<String>>(mut self, comment: S) -> Self {
+        self.comment = Some(comment.into());
+        self
+    }
+
+    pub fn expect(mut self, expect: Expect) -> Self {
+        self.expect = expect;
+        self
+    }
+
+    fn to_json(&self, step: &str) -> Value {
+        let mut map = Map::new();
+        map.insert("step".to_string(), json!(step));
+        map.insert("txId".to_string(), json!(self.tx_id));
+        if let Some(comment) = &self.comment {
+            map.insert("comment".to_string(), json!(comment));
+        }
+        map.insert("tx".to_string(), self.tx.to_json());
+        map.insert("expect".to_string(), self.expect.to_json());
+        Value::Object(map)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Expect {
+    out: Vec<String>,
+    status: String,
+    message: Option<String>,
+    logs: Option<Vec<Log>>,
+}
+
+impl Expect {
+    /// Successful call, no output, no logs.
+    pub fn ok() -> Self {
+        Expect {
+            out: Vec::new(),
+            status: String::new(),
+            message: None,
+            logs: Some(Vec::new()),
+        }
+    }
+
+    /// Failed call, with the given status code and message.
+    pub fn error<S: Into<String>, M: Into<String>>(status: S, message: M) -> Self {
+        Expect {
+            status: status.into(),
+            message: Some(message.into()),
+            ..Expect::ok()
+        }
+    }
+
+    pub fn out<S: Into<String>>(mut self, out: S) -> Self {
+        self.out.push(out.into());
+        self
+    }
+
+    pub fn log(mut self, log: Log) -> Self {
+        self.logs.get_or_insert_with(Vec::new).push(log);
+        self
+    }
+
+    /// Does not check the logs.
+    pub fn any_logs(mut self) -> Self {
+        self.logs = None;
+        self
+    }
+
+    pub fn to_json(&self) -> Value {
+        let mut map = Map::new();
+        map.insert("out".to_string(), strings(&self.out));
+        map.insert("status".to_string(), json!(self.status));
+        if let Some(message) = &self.message {
+            map.insert("message".to_string(), json!(message));
+        }
+        let logs = match &self.logs {
+            Some(logs) => Value::Array(logs.iter().map(Log::to_json).collect()),
+            None => json!("*"),
+        };
+        map.insert("logs".to_string(), logs);
+        map.insert("gas".to_string(), json!("*"));
+        map.insert("refund".to_string(), json!("*"));
+        Value::Object(map)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Log {
+    address: String,
+    identifier: String,
+    topics: Vec<String>,
+    data: String,
+}
+
+impl Log {
+    pub fn new<A: Into<String>, I: Into<String>>(address: A, identifier: I) -> Self {
+        Log {
+            address: address.into(),
+            identifier: identifier.into(),
+            topics: Vec::new(),
+            data: String::new(),
+        }
+    }
+
+    /// A log written by one of the BUSD events, e.g. `Transfer`.
+    pub fn event<A: Into<String>>(address: A, name: &str) -> Self {
+        Log::new(address, event_identifier(name))
+    }
+
+    /// A log written by one of the legacy BUSD events, while they are enabled.
+    pub fn legacy_event<A: Into<String>>(address: A, event_id: u8) -> Self {
+        Log::new(address, legacy_event_identifier(event_id))
+    }
+
+    pub fn topic<S: Into<String>>(mut self, topic: S) -> Self {
+        self.topics.push(topic.into());
+        self
+    }
+
+    pub fn data<S: Into<String>>(mut self, data: S) -> Self {
+        self.data = data.into();
+        self
+    }
+
+    pub fn to_json(&self) -> Value {
+        json!({
+            "address": self.address,
+            "identifier": self.identifier,
+            "topics": strings(&self.topics),
+            "data": self.data,
+        })
+    }
+}
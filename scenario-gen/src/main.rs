@@ -0,0 +1,80 @@
+//! Writes the BUSD Mandos scenarios to the `tests` folder.
+//!
+//! Usage: `busd-scenario-gen [--check] [TESTS_DIR]`
+//!
+//! With `--check`, nothing is written: the command fails if the content of any scenario file
+//! differs from the generated one, or if a `.scen.json` file is not generated at all.
+//! Files are only rewritten when their content changed, whatever their layout.
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+
+use busd_scenario_gen::suite;
+
+const SCENARIO_EXTENSION: &str = ".scen.json";
+
+fn existing_scenarios(dir: &Path, prefix: &str, found: &mut BTreeSet<String>) {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+    for entry in entries {
+        let path = entry.unwrap().path();
+        let name = path.file_name().unwrap().to_string_lossy().to_string();
+        if path.is_dir() {
+            existing_scenarios(&path, &format!("{}{}/", prefix, name), found);
+        } else if name.ends_with(SCENARIO_EXTENSION) {
+            found.insert(format!("{}{}", prefix, &name[..name.len() - SCENARIO_EXTENSION.len()]));
+        }
+    }
+}
+
+fn main() {
+    let mut check = false;
+    let mut tests_dir = PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/../tests"));
+    for arg in std::env::args().skip(1) {
+        match arg.as_str() {
+            "--check" => check = true,
+            _ => tests_dir = PathBuf::from(arg),
+        }
+    }
+
+    let mut not_generated = BTreeSet::new();
+    existing_scenarios(&tests_dir, "", &mut not_generated);
+
+    let mut outdated = Vec::new();
+    let mut written = 0;
+    let scenarios = suite::all();
+    for (path, scenario) in scenarios.iter() {
+        not_generated.remove(*path);
+        let file = tests_dir.join(format!("{}{}", path, SCENARIO_EXTENSION));
+        if fs::read_to_string(&file).is_ok_and(|committed| scenario.matches(&committed)) {
+            continue;
+        }
+        if check {
+            outdated.push(path);
+        } else {
+            fs::create_dir_all(file.parent().unwrap()).unwrap();
+            fs::write(&file, scenario.to_json_string()).unwrap();
+            println!("wrote {}", file.display());
+            written += 1;
+        }
+    }
+
+    for path in not_generated.iter() {
+        eprintln!("not generated: {}{}", path, SCENARIO_EXTENSION);
+    }
+    for path in outdated.iter() {
+        eprintln!("out of date: {}{}", path, SCENARIO_EXTENSION);
+    }
+    if !outdated.is_empty() || (check && !not_generated.is_empty()) {
+        process::exit(1);
+    }
+    if written > 0 {
+        println!("{} of {} scenarios written", written, scenarios.len());
+    } else {
+        println!("{} scenarios up to date", scenarios.len());
+    }
+}
@@ -0,0 +1,1034 @@
+use super::*;
+
+pub fn scenarios() -> Vec<(&'static str, Scenario)> {
+    vec![
+        ("erc20/allowance_CallerCaller", allowance_caller_caller()),
+        ("erc20/allowance_CallerOther", allowance_caller_other()),
+        ("erc20/allowance_OtherCaller", allowance_other_caller()),
+        ("erc20/allowance_OtherEqOther", allowance_other_eq_other()),
+        ("erc20/allowance_OtherNEqOther", allowance_other_neq_other()),
+        ("erc20/approve_Caller-Positive", approve_caller_positive()),
+        ("erc20/approve_Caller-Zero", approve_caller_zero()),
+        ("erc20/approve_Other-Positive", approve_other_positive()),
+        ("erc20/approve_Other-Zero", approve_other_zero()),
+        ("erc20/approve_SwitchCaller", approve_switch_caller()),
+        ("erc20/balanceOf_Caller", balance_of_caller()),
+        ("erc20/balanceOf_NonCaller", balance_of_non_caller()),
+        ("erc20/not_payable", not_payable()),
+        ("erc20/totalSupply_Positive", total_supply_positive()),
+        ("erc20/totalSupply_Zero", total_supply_zero()),
+        ("erc20/transferFrom_AllDistinct-BalanceEqAllowance", transfer_from_all_distinct_balance_eq_allowance()),
+        ("erc20/transferFrom_AllDistinct-BalanceNEqAllowance", transfer_from_all_distinct_balance_neq_allowance()),
+        ("erc20/transferFrom_AllDistinct-EntireAllowanceMoreThanBalance", transfer_from_all_distinct_entire_allowance_more_than_balance()),
+        ("erc20/transferFrom_AllDistinct-EntireBalanceEqAllowance", transfer_from_all_distinct_entire_balance_eq_allowance()),
+        ("erc20/transferFrom_AllDistinct-EntireBalanceMoreThanAllowance", transfer_from_all_distinct_entire_balance_more_than_allowance()),
+        ("erc20/transferFrom_AllDistinct-MoreThanAllowanceLessThanBalance", transfer_from_all_distinct_more_than_allowance_less_than_balance()),
+        ("erc20/transferFrom_AllDistinct-MoreThanBalanceLessThanAllowance", transfer_from_all_distinct_more_than_balance_less_than_allowance()),
+        ("erc20/transferFrom_AllDistinct-NoOverflow", transfer_from_all_distinct_no_overflow()),
+        ("erc20/transferFrom_AllDistinct-StillNoOverflow", transfer_from_all_distinct_still_no_overflow()),
+        ("erc20/transferFrom_AllEqual-AllowanceRelevant", transfer_from_all_equal_allowance_relevant()),
+        ("erc20/transferFrom_AllEqual-EntireBalance", transfer_from_all_equal_entire_balance()),
+        ("erc20/transferFrom_CallerEqFrom-AllowanceRelevant", transfer_from_caller_eq_from_allowance_relevant()),
+        ("erc20/transferFrom_CallerEqFrom-EntireBalance", transfer_from_caller_eq_from_entire_balance()),
+        ("erc20/transferFrom_CallerEqFrom-MoreThanBalance", transfer_from_caller_eq_from_more_than_balance()),
+        ("erc20/transferFrom_CallerEqTo-BalanceNEqAllowance", transfer_from_caller_eq_to_balance_neq_allowance()),
+        ("erc20/transferFrom_CallerEqTo-MoreThanAllowanceLessThanBalance", transfer_from_caller_eq_to_more_than_allowance_less_than_balance()),
+        ("erc20/transferFrom_CallerEqTo-MoreThanBalanceLessThanAllowance", transfer_from_caller_eq_to_more_than_balance_less_than_allowance()),
+        ("erc20/transferFrom_Exploratory-MultipleTransfersSucceed", transfer_from_exploratory_multiple_transfers_succeed()),
+        ("erc20/transferFrom_Exploratory-MultipleTransfersThrow", transfer_from_exploratory_multiple_transfers_throw()),
+        ("erc20/transferFrom_FromEqTo-BalanceEqAllowance", transfer_from_from_eq_to_balance_eq_allowance()),
+        ("erc20/transferFrom_FromEqTo-BalanceNEqAllowance", transfer_from_from_eq_to_balance_neq_allowance()),
+        ("erc20/transferFrom_FromEqTo-EntireAllowanceMoreThanBalance", transfer_from_from_eq_to_entire_allowance_more_than_balance()),
+        ("erc20/transferFrom_FromEqTo-EntireBalanceEqAllowance", transfer_from_from_eq_to_entire_balance_eq_allowance()),
+        ("erc20/transferFrom_FromEqTo-EntireBalanceMoreThanAllowance", transfer_from_from_eq_to_entire_balance_more_than_allowance()),
+        ("erc20/transferFrom_FromEqTo-MoreThanAllowanceLessThanBalance", transfer_from_from_eq_to_more_than_allowance_less_than_balance()),
+        ("erc20/transferFrom_FromEqTo-MoreThanBalanceLessThanAllowance", transfer_from_from_eq_to_more_than_balance_less_than_allowance()),
+        ("erc20/transferFrom_FromEqTo-NoOverflow", transfer_from_from_eq_to_no_overflow()),
+        ("erc20/transfer_Caller-AllowanceIrrelevant", transfer_caller_allowance_irrelevant()),
+        ("erc20/transfer_Caller-EntireBalance", transfer_caller_entire_balance()),
+        ("erc20/transfer_Caller-MoreThanBalance", transfer_caller_more_than_balance()),
+        ("erc20/transfer_Caller-NoOverflow", transfer_caller_no_overflow()),
+        ("erc20/transfer_Caller-Positive", transfer_caller_positive()),
+        ("erc20/transfer_Caller-StillNoOverflow", transfer_caller_still_no_overflow()),
+        ("erc20/transfer_Caller-Zero", transfer_caller_zero()),
+        ("erc20/transfer_Other-AllowanceIrrelevant", transfer_other_allowance_irrelevant()),
+        ("erc20/transfer_Other-EntireBalance", transfer_other_entire_balance()),
+        ("erc20/transfer_Other-MoreThanBalance", transfer_other_more_than_balance()),
+        ("erc20/transfer_Other-NoOverflow", transfer_other_no_overflow()),
+        ("erc20/transfer_Other-Positive", transfer_other_positive()),
+        ("erc20/transfer_Other-StillNoOverflow", transfer_other_still_no_overflow()),
+        ("erc20/transfer_Other-Zero", transfer_other_zero()),
+    ]
+}
+
+fn contract() -> String {
+    address("busd")
+}
+
+fn coin_holder_1() -> String {
+    address_with_suffix("coin_holder_1", "sx")
+}
+
+fn coin_holder_2() -> String {
+    address_with_suffix("coin_holder_2", "sx")
+}
+
+fn account_7() -> String {
+    address_with_suffix("account_7", "sz")
+}
+
+fn call<S: Into<String>>(from: S, function: &str) -> Tx {
+    Tx::call(from, contract(), function).gas_limit("0x100000")
+}
+
+fn caller_balance_state() -> SetState {
+    SetState::new()
+        .account(address("account_1"), Account::new().balance("0xe8d4a51000"))
+        .account(contract(), Account::contract()
+            .busd_balance(&address("account_1"), "0x2710")
+            .total_supply("0x2710"))
+}
+
+fn check_caller_balance_unchanged() -> CheckState {
+    CheckState::new()
+        .account(address("account_1"), Account::new().nonce("1").balance("0xe8d4951000"))
+        .account(contract(), Account::contract()
+            .busd_balance(&address("account_1"), "0x2710")
+            .total_supply("0x2710"))
+}
+
+fn holder_allowance_10000_state() -> SetState {
+    SetState::new()
+        .account(address("account_1"), Account::new().balance("0xe8d4a51000"))
+        .account(contract(), Account::contract()
+            .busd_allowance(&coin_holder_1(), &address("account_1"), "0x2710")
+            .busd_balance(&coin_holder_1(), "0x2710")
+            .total_supply("0x2710"))
+}
+
+fn transfer_from_23() -> ScCall {
+    ScCall::new("1", call(address("account_1"), "transferFrom")
+            .arg(coin_holder_1())
+            .arg(coin_holder_2())
+            .arg("0x17"))
+        .expect(Expect::ok()
+            .log(transfer_log(contract(), coin_holder_1(), coin_holder_2(), "0x17")))
+}
+
+fn holder_allowance_40_state() -> SetState {
+    SetState::new()
+        .account(address("account_1"), Account::new().balance("0xe8d4a51000"))
+        .account(contract(), Account::contract()
+            .busd_allowance(&coin_holder_1(), &address("account_1"), "0x28")
+            .busd_balance(&coin_holder_1(), "0x2710")
+            .total_supply("0x2710"))
+}
+
+fn holder_allowance_10001_state() -> SetState {
+    SetState::new()
+        .account(address("account_1"), Account::new().balance("0xe8d4a51000"))
+        .account(contract(), Account::contract()
+            .busd_allowance(&coin_holder_1(), &address("account_1"), "0x2711")
+            .busd_balance(&coin_holder_1(), "0x2710")
+            .total_supply("0x2710"))
+}
+
+fn transfer_from_10001() -> ScCall {
+    ScCall::new("1", call(address("account_1"), "transferFrom")
+            .arg(coin_holder_1())
+            .arg(coin_holder_2())
+            .arg("0x2711"))
+        .expect(Expect::error("0x04", "insufficient funds"))
+}
+
+fn check_holder_allowance_10001_unchanged() -> CheckState {
+    CheckState::new()
+        .account(address("account_1"), Account::new().nonce("1").balance("0xe8d4951000"))
+        .account(contract(), Account::contract()
+            .busd_allowance(&coin_holder_1(), &address("account_1"), "0x2711")
+            .busd_balance(&coin_holder_1(), "0x2710")
+            .total_supply("0x2710"))
+}
+
+fn holder_allowance_9999_state() -> SetState {
+    SetState::new()
+        .account(address("account_1"), Account::new().balance("0xe8d4a51000"))
+        .account(contract(), Account::contract()
+            .busd_allowance(&coin_holder_1(), &address("account_1"), "0x270f")
+            .busd_balance(&coin_holder_1(), "0x2710")
+            .total_supply("0x2710"))
+}
+
+fn check_holder_allowance_9999_unchanged() -> CheckState {
+    CheckState::new()
+        .account(address("account_1"), Account::new().nonce("1").balance("0xe8d4951000"))
+        .account(contract(), Account::contract()
+            .busd_allowance(&coin_holder_1(), &address("account_1"), "0x270f")
+            .busd_balance(&coin_holder_1(), "0x2710")
+            .total_supply("0x2710"))
+}
+
+fn check_holder_allowance_40_unchanged() -> CheckState {
+    CheckState::new()
+        .account(address("account_1"), Account::new().nonce("1").balance("0xe8d4951000"))
+        .account(contract(), Account::contract()
+            .busd_allowance(&coin_holder_1(), &address("account_1"), "0x28")
+            .busd_balance(&coin_holder_1(), "0x2710")
+            .total_supply("0x2710"))
+}
+
+fn holder_allowance_12288_state() -> SetState {
+    SetState::new()
+        .account(address("account_1"), Account::new().balance("0xe8d4a51000"))
+        .account(contract(), Account::contract()
+            .busd_allowance(&coin_holder_1(), &address("account_1"), "0x3000")
+            .busd_balance(&coin_holder_1(), "0x2710")
+            .total_supply("0x2710"))
+}
+
+fn check_holder_allowance_12288_unchanged() -> CheckState {
+    CheckState::new()
+        .account(address("account_1"), Account::new().nonce("1").balance("0xe8d4951000"))
+        .account(contract(), Account::contract()
+            .busd_allowance(&coin_holder_1(), &address("account_1"), "0x3000")
+            .busd_balance(&coin_holder_1(), "0x2710")
+            .total_supply("0x2710"))
+}
+
+fn holder_near_max_supply_state() -> SetState {
+    SetState::new()
+        .account(address("account_1"), Account::new().balance("0xe8d4a51000"))
+        .account(contract(), Account::contract()
+            .busd_allowance(&coin_holder_1(), &address("account_1"), "0xfffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff5")
+            .busd_balance(&coin_holder_1(), "0xfffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff5")
+            .busd_balance(&address("account_2"), "0x0a")
+            .total_supply("0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"))
+}
+
+fn transfer_from_near_max() -> ScCall {
+    ScCall::new("1", call(address("account_1"), "transferFrom")
+            .arg(coin_holder_1())
+            .arg(address("account_2"))
+            .arg("+0xfffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff5"))
+        .expect(Expect::ok()
+            .log(transfer_log(contract(), coin_holder_1(), address("account_2"), "0xfffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff5")))
+}
+
+fn check_account_2_max_supply() -> CheckState {
+    CheckState::new()
+        .account(address("account_1"), Account::new().nonce("1").balance("0xe8d4951000"))
+        .account(contract(), Account::contract()
+            .busd_balance(&address("account_2"), "0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff")
+            .total_supply("0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"))
+}
+
+fn caller_allowance_20_state() -> SetState {
+    SetState::new()
+        .account(address("account_1"), Account::new().balance("0xe8d4a51000"))
+        .account(contract(), Account::contract()
+            .busd_allowance(&address("account_1"), &address("account_1"), "0x14")
+            .busd_balance(&address("account_1"), "0x2710")
+            .total_supply("0x2710"))
+}
+
+fn check_caller_allowance_20_unchanged() -> CheckState {
+    CheckState::new()
+        .account(address("account_1"), Account::new().nonce("1").balance("0xe8d4951000"))
+        .account(contract(), Account::contract()
+            .busd_allowance(&address("account_1"), &address("account_1"), "0x14")
+            .busd_balance(&address("account_1"), "0x2710")
+            .total_supply("0x2710"))
+}
+
+fn caller_allowance_10000_state() -> SetState {
+    SetState::new()
+        .account(address("account_1"), Account::new().balance("0xe8d4a51000"))
+        .account(contract(), Account::contract()
+            .busd_allowance(&address("account_1"), &address("account_1"), "0x2710")
+            .busd_balance(&address("account_1"), "0x2710")
+            .total_supply("0x2710"))
+}
+
+fn check_account_7_entire_balance() -> CheckState {
+    CheckState::new()
+        .account(address("account_1"), Account::new().nonce("1").balance("0xe8d4951000"))
+        .account(contract(), Account::contract()
+            .busd_balance(&account_7(), "0x2710")
+            .busd_holders(&[&account_7()])
+            .total_supply("0x2710"))
+}
+
+fn transfer_from_10_to_holder_2() -> ScCall {
+    ScCall::new("1", call(address("account_1"), "transferFrom")
+            .arg(coin_holder_1())
+            .arg(coin_holder_2())
+            .arg("0x0a"))
+        .expect(Expect::ok()
+            .log(transfer_log(contract(), coin_holder_1(), coin_holder_2(), "0x0a")))
+}
+
+fn transfer_from_10_to_account_6() -> ScCall {
+    ScCall::new("2", call(address("account_1"), "transferFrom")
+            .arg(coin_holder_1())
+            .arg(address("account_6"))
+            .arg("0x0a"))
+        .expect(Expect::ok()
+            .log(transfer_log(contract(), coin_holder_1(), address("account_6"), "0x0a")))
+}
+
+fn transfer_from_10_to_holder_1() -> ScCall {
+    ScCall::new("3", call(address("account_1"), "transferFrom")
+            .arg(coin_holder_1())
+            .arg(coin_holder_1())
+            .arg("0x0a"))
+        .expect(Expect::ok()
+            .log(transfer_log(contract(), coin_holder_1(), coin_holder_1(), "0x0a")))
+}
+
+fn transfer_from_23_to_self() -> ScCall {
+    ScCall::new("1", call(address("account_1"), "transferFrom")
+            .arg(coin_holder_1())
+            .arg(coin_holder_1())
+            .arg("0x17"))
+        .expect(Expect::ok()
+            .log(transfer_log(contract(), coin_holder_1(), coin_holder_1(), "0x17")))
+}
+
+fn transfer_from_10001_to_self() -> ScCall {
+    ScCall::new("1", call(address("account_1"), "transferFrom")
+            .arg(coin_holder_1())
+            .arg(coin_holder_1())
+            .arg("0x2711"))
+        .expect(Expect::error("0x04", "insufficient funds"))
+}
+
+fn approve_caller_20() -> ScCall {
+    ScCall::new("1", call(address("account_1"), "approve").arg(address("account_1")).arg("0x14"))
+        .expect(Expect::ok()
+            .log(approve_log(contract(), address("account_1"), address("account_1"), "0x14")))
+}
+
+fn caller_near_max_supply_state() -> SetState {
+    SetState::new()
+        .account(address("account_1"), Account::new().balance("0xe8d4a51000"))
+        .account(contract(), Account::contract()
+            .busd_balance(&address("account_1"), "0xfffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff5")
+            .total_supply("0xfffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff5"))
+}
+
+fn check_caller_near_max_supply() -> CheckState {
+    CheckState::new()
+        .account(address("account_1"), Account::new().nonce("1").balance("0xe8d4951000"))
+        .account(contract(), Account::contract()
+            .busd_balance(&address("account_1"), "0xfffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff5")
+            .total_supply("0xfffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff5"))
+}
+
+fn allowance_caller_caller() -> Scenario {
+    Scenario::new("allowance_CallerCaller")
+        .set_state(caller_balance_state())
+        .sc_call(ScCall::new("1", call(address("account_1"), "allowance").arg(address("account_1")).arg(address("account_1")))
+            .expect(Expect::ok().out("0")))
+}
+
+fn allowance_caller_other() -> Scenario {
+    Scenario::new("allowance_CallerOther")
+        .set_state(caller_balance_state())
+        .sc_call(ScCall::new("1", call(address("account_1"), "allowance").arg(address("account_1")).arg(account_7()))
+            .expect(Expect::ok().out("0")))
+}
+
+fn allowance_other_caller() -> Scenario {
+    Scenario::new("allowance_OtherCaller")
+        .set_state(caller_balance_state())
+        .sc_call(ScCall::new("1", call(address("account_1"), "allowance").arg(address("account_other")).arg(address("account_1")))
+            .expect(Expect::ok().out("0")))
+}
+
+fn allowance_other_eq_other() -> Scenario {
+    Scenario::new("allowance_OtherEqOther")
+        .set_state(caller_balance_state())
+        .sc_call(ScCall::new("1", call(address("account_1"), "allowance").arg(address("account_2")).arg(address("account_2")))
+            .expect(Expect::ok().out("0")))
+}
+
+fn allowance_other_neq_other() -> Scenario {
+    Scenario::new("allowance_OtherNEqOther")
+        .set_state(caller_balance_state())
+        .sc_call(ScCall::new("1", call(address("account_1"), "allowance").arg(account_7()).arg(coin_holder_2()))
+            .expect(Expect::ok().out("0")))
+}
+
+fn approve_caller_positive() -> Scenario {
+    Scenario::new("approve_Caller-Positive")
+        .set_state(caller_balance_state())
+        .sc_call(ScCall::new("1", call(address("account_1"), "approve").arg(address("account_1")).arg("0x25"))
+            .expect(Expect::ok()
+                .log(approve_log(contract(), address("account_1"), address("account_1"), "0x25"))))
+        .check_state(CheckState::new()
+            .account(address("account_1"), Account::new().nonce("1").balance("0xe8d4951000"))
+            .account(contract(), Account::contract()
+                .busd_balance(&address("account_1"), "0x2710")
+                .busd_allowance(&address("account_1"), &address("account_1"), "0x25")
+                .busd_spenders(&address("account_1"), &[&address("account_1")])
+                .total_supply("0x2710")))
+}
+
+fn approve_caller_zero() -> Scenario {
+    Scenario::new("approve_Caller-Zero")
+        .set_state(caller_balance_state())
+        .sc_call(ScCall::new("1", call(address("account_1"), "approve").arg(address("account_1")).arg("0"))
+            .expect(Expect::ok()
+                .log(approve_log(contract(), address("account_1"), address("account_1"), "0"))))
+        .check_state(check_caller_balance_unchanged())
+}
+
+fn approve_other_positive() -> Scenario {
+    Scenario::new("approve_Other-Positive")
+        .set_state(caller_balance_state())
+        .sc_call(ScCall::new("1", call(address("account_1"), "approve").arg(address("account_2")).arg("0x2a"))
+            .expect(Expect::ok()
+                .log(approve_log(contract(), address("account_1"), address("account_2"), "0x2a"))))
+        .check_state(CheckState::new()
+            .account(address("account_1"), Account::new().nonce("1").balance("0xe8d4951000"))
+            .account(contract(), Account::contract()
+                .busd_balance(&address("account_1"), "0x2710")
+                .busd_allowance(&address("account_1"), &address("account_2"), "0x2a")
+                .busd_spenders(&address("account_1"), &[&address("account_2")])
+                .total_supply("0x2710")))
+}
+
+fn approve_other_zero() -> Scenario {
+    Scenario::new("approve_Other-Zero")
+        .set_state(caller_balance_state())
+        .sc_call(ScCall::new("1", call(address("account_1"), "approve").arg(address("account_2")).arg("0"))
+            .expect(Expect::ok()
+                .log(approve_log(contract(), address("account_1"), address("account_2"), "0"))))
+        .check_state(check_caller_balance_unchanged())
+}
+
+fn approve_switch_caller() -> Scenario {
+    Scenario::new("approve_SwitchCaller")
+        .set_state(SetState::new()
+            .account("0x82a978b3f5962a5b0957d9ee9eef472ee55b42f1000000000000000000000000", Account::new().balance("0xe8d4a51000"))
+            .account(address("account_1"), Account::new().balance("0xe8d4a51000"))
+            .account(contract(), Account::contract()
+                .busd_balance(&address("account_1"), "0x2710")
+                .total_supply("0x2710")))
+        .sc_call(ScCall::new("1", call(address("account_1"), "allowance")
+                .arg("0x82a978b3f5962a5b0957d9ee9eef472ee55b42f1000000000000000000000000")
+                .arg(address("account_1")))
+            .expect(Expect::ok().out("0")))
+        .sc_call(ScCall::new("2", call("0x82a978b3f5962a5b0957d9ee9eef472ee55b42f1000000000000000000000000", "approve")
+                .arg(address("account_1"))
+                .arg("0x19"))
+            .expect(Expect::ok()
+                .log(approve_log(contract(), "0x82a978b3f5962a5b0957d9ee9eef472ee55b42f1000000000000000000000000", address("account_1"), "0x19"))))
+        .sc_call(ScCall::new("3", call(address("account_1"), "allowance")
+                .arg("0x82a978b3f5962a5b0957d9ee9eef472ee55b42f1000000000000000000000000")
+                .arg(address("account_1")))
+            .expect(Expect::ok().out("0x19")))
+        .check_state(CheckState::new()
+            .account("0x82a978b3f5962a5b0957d9ee9eef472ee55b42f1000000000000000000000000", Account::new().nonce("1").balance("0xe8d4951000"))
+            .account(address("account_1"), Account::new().nonce("2").balance("0xe8d4851000"))
+            .account(contract(), Account::contract()
+                .busd_balance(&address("account_1"), "0x2710")
+                .busd_allowance("0x82a978b3f5962a5b0957d9ee9eef472ee55b42f1000000000000000000000000", &address("account_1"), "0x19")
+                .busd_spenders("0x82a978b3f5962a5b0957d9ee9eef472ee55b42f1000000000000000000000000", &[&address("account_1")])
+                .total_supply("0x2710")))
+}
+
+fn balance_of_caller() -> Scenario {
+    Scenario::new("balanceOf_Caller")
+        .set_state(caller_balance_state())
+        .sc_call(ScCall::new("1", call(address("account_1"), "balanceOf").arg(address("account_1")))
+            .expect(Expect::ok().out("0x2710")))
+        .check_state(check_caller_balance_unchanged())
+}
+
+fn balance_of_non_caller() -> Scenario {
+    Scenario::new("balanceOf_NonCaller")
+        .set_state(caller_balance_state())
+        .sc_call(ScCall::new("1", call(address("account_1"), "balanceOf")
+                .arg("0x1234123400000000000000000000000000000000000000000000000000000009"))
+            .expect(Expect::ok().out("0")))
+        .check_state(check_caller_balance_unchanged())
+}
+
+fn not_payable() -> Scenario {
+    Scenario::new("not_payable")
+        .set_state(SetState::new()
+            .account(address("account_1"), Account::new().balance("0x10000000"))
+            .account(contract(), Account::contract()
+                .busd_balance(&address("account_1"), "0x2710")
+                .total_supply("0x2710")))
+        .sc_call(ScCall::new("1", call(address("account_1"), "totalSupply").value("0x01"))
+            .expect(Expect::error("0x04", "attempted to transfer funds via a non-payable function")))
+        .sc_call(ScCall::new("2", call(address("account_1"), "balanceOf").value("0x01").arg(address("account_1")))
+            .expect(Expect::error("0x04", "attempted to transfer funds via a non-payable function")))
+        .sc_call(ScCall::new("3", call(address("account_1"), "allowance")
+                .value("0x01")
+                .arg(address("account_1"))
+                .arg(address("account_1")))
+            .expect(Expect::error("0x04", "attempted to transfer funds via a non-payable function")))
+        .sc_call(ScCall::new("4", call(address("account_1"), "transfer")
+                .value("0x01")
+                .arg(address("account_1"))
+                .arg("0x01"))
+            .expect(Expect::error("0x04", "attempted to transfer funds via a non-payable function")))
+        .sc_call(ScCall::new("5", call(address("account_1"), "approve")
+                .value("0x01")
+                .arg(address("account_1"))
+                .arg("0x01"))
+            .expect(Expect::error("0x04", "attempted to transfer funds via a non-payable function")))
+        .sc_call(ScCall::new("6", call(address("account_1"), "transferFrom")
+                .value("0x01")
+                .arg(address("account_1"))
+                .arg(address("account_1"))
+                .arg("0x01"))
+            .expect(Expect::error("0x04", "attempted to transfer funds via a non-payable function")))
+        .check_state(CheckState::new()
+            .account(address("account_1"), Account::new().nonce("6").balance("0xfa00000"))
+            .account(contract(), Account::contract()
+                .busd_balance(&address("account_1"), "0x2710")
+                .total_supply("0x2710")))
+}
+
+fn total_supply_positive() -> Scenario {
+    Scenario::new("totalSupply_Positive")
+        .set_state(caller_balance_state())
+        .sc_call(ScCall::new("1", call(address("account_1"), "totalSupply"))
+            .expect(Expect::ok().out("0x2710")))
+        .check_state(check_caller_balance_unchanged())
+}
+
+fn total_supply_zero() -> Scenario {
+    Scenario::new("totalSupply_Zero")
+        .set_state(SetState::new()
+            .account(address("account_1"), Account::new().balance("0xe8d4a51000"))
+            .account(contract(), Account::contract()))
+        .sc_call(ScCall::new("1", call(address("account_1"), "totalSupply"))
+            .expect(Expect::ok().out("0")))
+        .check_state(CheckState::new()
+            .account(address("account_1"), Account::new().nonce("1").balance("0xe8d4951000"))
+            .account(contract(), Account::contract()))
+}
+
+fn transfer_from_all_distinct_balance_eq_allowance() -> Scenario {
+    Scenario::new("transferFrom_AllDistinct-BalanceEqAllowance")
+        .set_state(holder_allowance_10000_state())
+        .sc_call(transfer_from_23())
+        .check_state(CheckState::new()
+            .account(address("account_1"), Account::new().nonce("1").balance("0xe8d4951000"))
+            .account(contract(), Account::contract()
+                .busd_allowance(&coin_holder_1(), &address("account_1"), "0x26f9")
+                .busd_balance(&coin_holder_1(), "0x26f9")
+                .busd_balance(&coin_holder_2(), "0x17")
+                .busd_holders(&[&coin_holder_2()])
+                .total_supply("0x2710")))
+}
+
+fn transfer_from_all_distinct_balance_neq_allowance() -> Scenario {
+    Scenario::new("transferFrom_AllDistinct-BalanceNEqAllowance")
+        .set_state(holder_allowance_40_state())
+        .sc_call(transfer_from_23())
+        .check_state(CheckState::new()
+            .account(address("account_1"), Account::new().nonce("1").balance("0xe8d4951000"))
+            .account(contract(), Account::contract()
+                .busd_allowance(&coin_holder_1(), &address("account_1"), "0x11")
+                .busd_balance(&coin_holder_1(), "0x26f9")
+                .busd_balance(&coin_holder_2(), "0x17")
+                .busd_holders(&[&coin_holder_2()])
+                .total_supply("0x2710")))
+}
+
+fn transfer_from_all_distinct_entire_allowance_more_than_balance() -> Scenario {
+    Scenario::new("transferFrom_AllDistinct-EntireAllowanceMoreThanBalance")
+        .set_state(holder_allowance_10001_state())
+        .sc_call(transfer_from_10001())
+        .check_state(check_holder_allowance_10001_unchanged())
+}
+
+fn transfer_from_all_distinct_entire_balance_eq_allowance() -> Scenario {
+    Scenario::new("transferFrom_AllDistinct-EntireBalanceEqAllowance")
+        .set_state(holder_allowance_10000_state())
+        .sc_call(ScCall::new("1", call(address("account_1"), "transferFrom")
+                .arg(coin_holder_1())
+                .arg(coin_holder_2())
+                .arg("0x2710"))
+            .expect(Expect::ok()
+                .log(transfer_log(contract(), coin_holder_1(), coin_holder_2(), "0x2710"))))
+        .check_state(CheckState::new()
+            .account(address("account_1"), Account::new().nonce("1").balance("0xe8d4951000"))
+            .account(contract(), Account::contract()
+                .busd_balance(&coin_holder_2(), "0x2710")
+                .busd_holders(&[&coin_holder_2()])
+                .total_supply("0x2710")))
+}
+
+fn transfer_from_all_distinct_entire_balance_more_than_allowance() -> Scenario {
+    Scenario::new("transferFrom_AllDistinct-EntireBalanceMoreThanAllowance")
+        .set_state(holder_allowance_9999_state())
+        .sc_call(ScCall::new("1", call(address("account_1"), "transferFrom")
+                .arg(coin_holder_1())
+                .arg(coin_holder_2())
+                .arg("0x2710"))
+            .expect(Expect::error("0x04", "allowance exceeded")))
+        .check_state(check_holder_allowance_9999_unchanged())
+}
+
+fn transfer_from_all_distinct_more_than_allowance_less_than_balance() -> Scenario {
+    Scenario::new("transferFrom_AllDistinct-MoreThanAllowanceLessThanBalance")
+        .set_state(holder_allowance_40_state())
+        .sc_call(ScCall::new("1", call(address("account_1"), "transferFrom")
+                .arg(coin_holder_1())
+                .arg(coin_holder_2())
+                .arg("0x29"))
+            .expect(Expect::error("0x04", "allowance exceeded")))
+        .check_state(check_holder_allowance_40_unchanged())
+}
+
+fn transfer_from_all_distinct_more_than_balance_less_than_allowance() -> Scenario {
+    Scenario::new("transferFrom_AllDistinct-MoreThanBalanceLessThanAllowance")
+        .set_state(holder_allowance_12288_state())
+        .sc_call(transfer_from_10001())
+        .check_state(check_holder_allowance_12288_unchanged())
+}
+
+fn transfer_from_all_distinct_no_overflow() -> Scenario {
+    Scenario::new("transferFrom_AllDistinct-NoOverflow")
+        .set_state(holder_near_max_supply_state())
+        .sc_call(transfer_from_near_max())
+        .check_state(check_account_2_max_supply())
+}
+
+fn transfer_from_all_distinct_still_no_overflow() -> Scenario {
+    Scenario::new("transferFrom_AllDistinct-StillNoOverflow")
+        .set_state(SetState::new()
+            .account(address("account_1"), Account::new().balance("0xe8d4a51000"))
+            .account(contract(), Account::contract()
+                .busd_allowance(&coin_holder_1(), &address("account_1"), "0xfffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff6")
+                .busd_balance(&coin_holder_1(), "0xfffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff6")
+                .busd_balance(&address("account_2"), "0x0a")
+                .total_supply("0x010000000000000000000000000000000000000000000000000000000000000000")))
+        .sc_call(ScCall::new("1", call(address("account_1"), "transferFrom")
+                .arg(coin_holder_1())
+                .arg(address("account_2"))
+                .arg("+0xfffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff6"))
+            .expect(Expect::ok()
+                .log(transfer_log(contract(), coin_holder_1(), address("account_2"), "0xfffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff6"))))
+        .check_state(CheckState::new()
+            .account(address("account_1"), Account::new().nonce("1").balance("0xe8d4951000"))
+            .account(contract(), Account::contract()
+                .busd_balance(&address("account_2"), "0x010000000000000000000000000000000000000000000000000000000000000000")
+                .total_supply("0x010000000000000000000000000000000000000000000000000000000000000000")))
+}
+
+fn transfer_from_all_equal_allowance_relevant() -> Scenario {
+    Scenario::new("transferFrom_AllEqual-AllowanceRelevant")
+        .set_state(caller_allowance_20_state())
+        .sc_call(ScCall::new("1", call(address("account_1"), "transferFrom")
+                .arg(address("account_1"))
+                .arg(address("account_1"))
+                .arg("0x17"))
+            .expect(Expect::error("0x04", "allowance exceeded")))
+        .check_state(check_caller_allowance_20_unchanged())
+}
+
+fn transfer_from_all_equal_entire_balance() -> Scenario {
+    Scenario::new("transferFrom_AllEqual-EntireBalance")
+        .set_state(caller_allowance_10000_state())
+        .sc_call(ScCall::new("1", call(address("account_1"), "transferFrom")
+                .arg(address("account_1"))
+                .arg(address("account_1"))
+                .arg("0x2710"))
+            .expect(Expect::ok()
+                .log(transfer_log(contract(), address("account_1"), address("account_1"), "0x2710"))))
+        .check_state(check_caller_balance_unchanged()
+            .update_account(&contract(), |contract| contract.busd_holders(&[&address("account_1")])))
+}
+
+fn transfer_from_caller_eq_from_allowance_relevant() -> Scenario {
+    Scenario::new("transferFrom_CallerEqFrom-AllowanceRelevant")
+        .set_state(caller_allowance_20_state())
+        .sc_call(ScCall::new("1", call(address("account_1"), "transferFrom")
+                .arg(address("account_1"))
+                .arg(account_7())
+                .arg("0x17"))
+            .expect(Expect::error("0x04", "allowance exceeded")))
+        .check_state(check_caller_allowance_20_unchanged())
+}
+
+fn transfer_from_caller_eq_from_entire_balance() -> Scenario {
+    Scenario::new("transferFrom_CallerEqFrom-EntireBalance")
+        .set_state(caller_allowance_10000_state())
+        .sc_call(ScCall::new("1", call(address("account_1"), "transferFrom")
+                .arg(address("account_1"))
+                .arg(account_7())
+                .arg("0x2710"))
+            .expect(Expect::ok()
+                .log(transfer_log(contract(), address("account_1"), account_7(), "0x2710"))))
+        .check_state(check_account_7_entire_balance())
+}
+
+fn transfer_from_caller_eq_from_more_than_balance() -> Scenario {
+    Scenario::new("transferFrom_CallerEqFrom-MoreThanBalance")
+        .set_state(caller_allowance_10000_state())
+        .sc_call(ScCall::new("1", call(address("account_1"), "transferFrom")
+                .arg(address("account_1"))
+                .arg(account_7())
+                .arg("0x2711"))
+            .expect(Expect::error("0x04", "allowance exceeded")))
+        .check_state(CheckState::new()
+            .account(address("account_1"), Account::new().nonce("1").balance("0xe8d4951000"))
+            .account(contract(), Account::contract()
+                .busd_allowance(&address("account_1"), &address("account_1"), "0x2710")
+                .busd_balance(&address("account_1"), "0x2710")
+                .total_supply("0x2710")))
+}
+
+fn transfer_from_caller_eq_to_balance_neq_allowance() -> Scenario {
+    Scenario::new("transferFrom_CallerEqTo-BalanceNEqAllowance")
+        .set_state(holder_allowance_40_state())
+        .sc_call(ScCall::new("1", call(address("account_1"), "transferFrom")
+                .arg(coin_holder_1())
+                .arg(address("account_1"))
+                .arg("0x17"))
+            .expect(Expect::ok()
+                .log(transfer_log(contract(), coin_holder_1(), address("account_1"), "0x17"))))
+        .check_state(CheckState::new()
+            .account(address("account_1"), Account::new().nonce("1").balance("0xe8d4951000"))
+            .account(contract(), Account::contract()
+                .busd_allowance(&coin_holder_1(), &address("account_1"), "0x11")
+                .busd_balance(&coin_holder_1(), "0x26f9")
+                .busd_balance(&address("account_1"), "0x17")
+                .busd_holders(&[&address("account_1")])
+                .total_supply("0x2710")))
+}
+
+fn transfer_from_caller_eq_to_more_than_allowance_less_than_balance() -> Scenario {
+    Scenario::new("transferFrom_CallerEqTo-MoreThanAllowanceLessThanBalance")
+        .set_state(holder_allowance_40_state())
+        .sc_call(ScCall::new("1", call(address("account_1"), "transferFrom")
+                .arg(coin_holder_1())
+                .arg(address("account_1"))
+                .arg("0x29"))
+            .expect(Expect::error("0x04", "allowance exceeded")))
+        .check_state(check_holder_allowance_40_unchanged())
+}
+
+fn transfer_from_caller_eq_to_more_than_balance_less_than_allowance() -> Scenario {
+    Scenario::new("transferFrom_CallerEqTo-MoreThanBalanceLessThanAllowance")
+        .set_state(SetState::new()
+            .account(address("account_1"), Account::new().balance("0xe8d4a51000"))
+            .account(contract(), Account::contract()
+                .busd_allowance(&coin_holder_1(), &address("account_1"), "0x030000")
+                .busd_balance(&coin_holder_1(), "0x2710")
+                .total_supply("0x2710")))
+        .sc_call(ScCall::new("1", call(address("account_1"), "transferFrom")
+                .arg(coin_holder_1())
+                .arg(address("account_1"))
+                .arg("0x027100"))
+            .expect(Expect::error("0x04", "insufficient funds")))
+        .check_state(CheckState::new()
+            .account(address("account_1"), Account::new().nonce("1").balance("0xe8d4951000"))
+            .account(contract(), Account::contract()
+                .busd_allowance(&coin_holder_1(), &address("account_1"), "0x030000")
+                .busd_balance(&coin_holder_1(), "0x2710")
+                .total_supply("0x2710")))
+}
+
+fn transfer_from_exploratory_multiple_transfers_succeed() -> Scenario {
+    Scenario::new("transferFrom_Exploratory-MultipleTransfersSucceed")
+        .set_state(holder_allowance_40_state())
+        .sc_call(transfer_from_10_to_holder_2())
+        .sc_call(transfer_from_10_to_account_6())
+        .sc_call(transfer_from_10_to_holder_1())
+        .sc_call(ScCall::new("4", call(address("account_1"), "transferFrom")
+                .arg(coin_holder_1())
+                .arg(address("account_1"))
+                .arg("0x0a"))
+            .expect(Expect::ok()
+                .log(transfer_log(contract(), coin_holder_1(), address("account_1"), "0x0a"))))
+        .check_state(CheckState::new()
+            .account(address("account_1"), Account::new().nonce("4").balance("0xe8d4651000"))
+            .account(contract(), Account::contract()
+                .busd_balance(&coin_holder_1(), "0x26f2")
+                .busd_balance(&coin_holder_2(), "0x0a")
+                .busd_balance(&address("account_6"), "0x0a")
+                .busd_balance(&address("account_1"), "0x0a")
+                .busd_holders(&[&coin_holder_2(), &address("account_6"), &address("account_1")])
+                .total_supply("0x2710")))
+}
+
+fn transfer_from_exploratory_multiple_transfers_throw() -> Scenario {
+    Scenario::new("transferFrom_Exploratory-MultipleTransfersThrow")
+        .set_state(holder_allowance_40_state())
+        .sc_call(transfer_from_10_to_holder_2())
+        .sc_call(transfer_from_10_to_account_6())
+        .sc_call(transfer_from_10_to_holder_1())
+        .sc_call(ScCall::new("4", call(address("account_1"), "transferFrom")
+                .arg(coin_holder_1())
+                .arg(address("account_1"))
+                .arg("0x0b"))
+            .expect(Expect::error("0x04", "allowance exceeded")))
+        .check_state(CheckState::new()
+            .account(address("account_1"), Account::new().nonce("4").balance("0xe8d4651000"))
+            .account(contract(), Account::contract()
+                .busd_allowance(&coin_holder_1(), &address("account_1"), "0x0a")
+                .busd_balance(&coin_holder_1(), "0x26fc")
+                .busd_balance(&coin_holder_2(), "0x0a")
+                .busd_balance(&address("account_6"), "0x0a")
+                .busd_holders(&[&coin_holder_2(), &address("account_6")])
+                .total_supply("0x2710")))
+}
+
+fn transfer_from_from_eq_to_balance_eq_allowance() -> Scenario {
+    Scenario::new("transferFrom_FromEqTo-BalanceEqAllowance")
+        .set_state(holder_allowance_10000_state())
+        .sc_call(transfer_from_23_to_self())
+        .check_state(CheckState::new()
+            .account(address("account_1"), Account::new().nonce("1").balance("0xe8d4951000"))
+            .account(contract(), Account::contract()
+                .busd_allowance(&coin_holder_1(), &address("account_1"), "0x26f9")
+                .busd_balance(&coin_holder_1(), "0x2710")
+                .total_supply("0x2710")))
+}
+
+fn transfer_from_from_eq_to_balance_neq_allowance() -> Scenario {
+    Scenario::new("transferFrom_FromEqTo-BalanceNEqAllowance")
+        .set_state(holder_allowance_40_state())
+        .sc_call(transfer_from_23_to_self())
+        .check_state(CheckState::new()
+            .account(address("account_1"), Account::new().nonce("1").balance("0xe8d4951000"))
+            .account(contract(), Account::contract()
+                .busd_allowance(&coin_holder_1(), &address("account_1"), "0x11")
+                .busd_balance(&coin_holder_1(), "0x2710")
+                .total_supply("0x2710")))
+}
+
+fn transfer_from_from_eq_to_entire_allowance_more_than_balance() -> Scenario {
+    Scenario::new("transferFrom_FromEqTo-EntireAllowanceMoreThanBalance")
+        .set_state(holder_allowance_10001_state())
+        .sc_call(transfer_from_10001_to_self())
+        .check_state(check_holder_allowance_10001_unchanged())
+}
+
+fn transfer_from_from_eq_to_entire_balance_eq_allowance() -> Scenario {
+    Scenario::new("transferFrom_FromEqTo-EntireBalanceEqAllowance")
+        .set_state(holder_allowance_10000_state())
+        .sc_call(ScCall::new("1", call(address("account_1"), "transferFrom")
+                .arg(coin_holder_1())
+                .arg(coin_holder_1())
+                .arg("0x2710"))
+            .expect(Expect::ok()
+                .log(transfer_log(contract(), coin_holder_1(), coin_holder_1(), "0x2710"))))
+        .check_state(CheckState::new()
+            .account(address("account_1"), Account::new().nonce("1").balance("0xe8d4951000"))
+            .account(contract(), Account::contract()
+                .busd_balance(&coin_holder_1(), "0x2710")
+                .busd_holders(&[&coin_holder_1()])
+                .total_supply("0x2710")))
+}
+
+fn transfer_from_from_eq_to_entire_balance_more_than_allowance() -> Scenario {
+    Scenario::new("transferFrom_FromEqTo-EntireBalanceMoreThanAllowance")
+        .set_state(holder_allowance_9999_state())
+        .sc_call(ScCall::new("1", call(address("account_1"), "transferFrom")
+                .arg(coin_holder_1())
+                .arg(coin_holder_1())
+                .arg("0x2710"))
+            .expect(Expect::error("0x04", "allowance exceeded")))
+        .check_state(check_holder_allowance_9999_unchanged())
+}
+
+fn transfer_from_from_eq_to_more_than_allowance_less_than_balance() -> Scenario {
+    Scenario::new("transferFrom_FromEqTo-MoreThanAllowanceLessThanBalance")
+        .set_state(holder_allowance_40_state())
+        .sc_call(ScCall::new("1", call(address("account_1"), "transferFrom")
+                .arg(coin_holder_1())
+                .arg(coin_holder_1())
+                .arg("0x29"))
+            .expect(Expect::error("0x04", "allowance exceeded")))
+        .check_state(check_holder_allowance_40_unchanged())
+}
+
+fn transfer_from_from_eq_to_more_than_balance_less_than_allowance() -> Scenario {
+    Scenario::new("transferFrom_FromEqTo-MoreThanBalanceLessThanAllowance")
+        .set_state(holder_allowance_12288_state())
+        .sc_call(transfer_from_10001_to_self())
+        .check_state(check_holder_allowance_12288_unchanged())
+}
+
+fn transfer_from_from_eq_to_no_overflow() -> Scenario {
+    Scenario::new("transferFrom_FromEqTo-NoOverflow")
+        .set_state(holder_near_max_supply_state())
+        .sc_call(transfer_from_near_max())
+        .check_state(check_account_2_max_supply())
+}
+
+fn transfer_caller_allowance_irrelevant() -> Scenario {
+    Scenario::new("transfer_Caller-AllowanceIrrelevant")
+        .set_state(caller_balance_state())
+        .sc_call(approve_caller_20())
+        .sc_call(ScCall::new("2", call(address("account_1"), "transfer").arg(address("account_1")).arg("0x17"))
+            .expect(Expect::ok()
+                .log(transfer_log(contract(), address("account_1"), address("account_1"), "0x17"))))
+        .check_state(CheckState::new()
+            .account(address("account_1"), Account::new().nonce("2").balance("0xe8d4851000"))
+            .account(contract(), Account::contract()
+                .busd_balance(&address("account_1"), "0x2710")
+                .busd_allowance(&address("account_1"), &address("account_1"), "0x14")
+                .busd_spenders(&address("account_1"), &[&address("account_1")])
+                .total_supply("0x2710")))
+}
+
+fn transfer_caller_entire_balance() -> Scenario {
+    Scenario::new("transfer_Caller-EntireBalance")
+        .set_state(caller_balance_state())
+        .sc_call(ScCall::new("1", call(address("account_1"), "transfer").arg(address("account_1")).arg("0x2710"))
+            .expect(Expect::ok()
+                .log(transfer_log(contract(), address("account_1"), address("account_1"), "0x2710"))))
+        .check_state(check_caller_balance_unchanged()
+            .update_account(&contract(), |contract| contract.busd_holders(&[&address("account_1")])))
+}
+
+fn transfer_caller_more_than_balance() -> Scenario {
+    Scenario::new("transfer_Caller-MoreThanBalance")
+        .set_state(caller_balance_state())
+        .sc_call(ScCall::new("1", call(address("account_1"), "transfer").arg(address("account_1")).arg("0x2711"))
+            .expect(Expect::error("0x04", "insufficient funds")))
+        .check_state(check_caller_balance_unchanged())
+}
+
+fn transfer_caller_no_overflow() -> Scenario {
+    Scenario::new("transfer_Caller-NoOverflow")
+        .set_state(caller_near_max_supply_state())
+        .sc_call(ScCall::new("1", call(address("account_1"), "transfer").arg(address("account_1")).arg("0x0a"))
+            .expect(Expect::ok()
+                .log(transfer_log(contract(), address("account_1"), address("account_1"), "0x0a"))))
+        .check_state(check_caller_near_max_supply())
+}
+
+fn transfer_caller_positive() -> Scenario {
+    Scenario::new("transfer_Caller-Positive")
+        .set_state(caller_balance_state())
+        .sc_call(ScCall::new("1", call(address("account_1"), "transfer").arg(address("account_1")).arg("0x17"))
+            .expect(Expect::ok()
+                .log(transfer_log(contract(), address("account_1"), address("account_1"), "0x17"))))
+        .check_state(check_caller_balance_unchanged())
+}
+
+fn transfer_caller_still_no_overflow() -> Scenario {
+    Scenario::new("transfer_Caller-StillNoOverflow")
+        .set_state(caller_near_max_supply_state())
+        .sc_call(ScCall::new("1", call(address("account_1"), "transfer").arg(address("account_1")).arg("0x0b"))
+            .expect(Expect::ok()
+                .log(transfer_log(contract(), address("account_1"), address("account_1"), "0x0b"))))
+        .check_state(check_caller_near_max_supply())
+}
+
+fn transfer_caller_zero() -> Scenario {
+    Scenario::new("transfer_Caller-Zero")
+        .set_state(caller_balance_state())
+        .sc_call(ScCall::new("1", call(address("account_1"), "transfer").arg(address("account_1")).arg("0"))
+            .expect(Expect::ok()
+                .log(transfer_log(contract(), address("account_1"), address("account_1"), "0"))))
+        .check_state(check_caller_balance_unchanged())
+}
+
+fn transfer_other_allowance_irrelevant() -> Scenario {
+    Scenario::new("transfer_Other-AllowanceIrrelevant")
+        .set_state(caller_balance_state())
+        .sc_call(approve_caller_20())
+        .sc_call(ScCall::new("2", call(address("account_1"), "transfer").arg(account_7()).arg("0x17"))
+            .expect(Expect::ok()
+                .log(transfer_log(contract(), address("account_1"), account_7(), "0x17"))))
+        .check_state(CheckState::new()
+            .account(address("account_1"), Account::new().nonce("2").balance("0xe8d4851000"))
+            .account(contract(), Account::contract()
+                .busd_balance(&address("account_1"), "0x26f9")
+                .busd_balance(&account_7(), "0x17")
+                .busd_allowance(&address("account_1"), &address("account_1"), "0x14")
+                .busd_spenders(&address("account_1"), &[&address("account_1")])
+                .busd_holders(&[&account_7()])
+                .total_supply("0x2710")))
+}
+
+fn transfer_other_entire_balance() -> Scenario {
+    Scenario::new("transfer_Other-EntireBalance")
+        .set_state(caller_balance_state())
+        .sc_call(ScCall::new("1", call(address("account_1"), "transfer").arg(account_7()).arg("0x2710"))
+            .expect(Expect::ok()
+                .log(transfer_log(contract(), address("account_1"), account_7(), "0x2710"))))
+        .check_state(check_account_7_entire_balance())
+}
+
+fn transfer_other_more_than_balance() -> Scenario {
+    Scenario::new("transfer_Other-MoreThanBalance")
+        .set_state(caller_balance_state())
+        .sc_call(ScCall::new("1", call(address("account_1"), "transfer").arg(account_7()).arg("0x2711"))
+            .expect(Expect::error("0x04", "insufficient funds")))
+        .check_state(check_caller_balance_unchanged())
+}
+
+fn transfer_other_no_overflow() -> Scenario {
+    Scenario::new("transfer_Other-NoOverflow")
+        .set_state(SetState::new()
+            .account(address("account_1"), Account::new().balance("0xe8d4a51000"))
+            .account(contract(), Account::contract()
+                .busd_balance(&address("account_1"), "0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff")
+                .total_supply("0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff")))
+        .sc_call(ScCall::new("1", call(address("account_1"), "transfer")
+                .arg(address("account_2"))
+                .arg("+0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"))
+            .expect(Expect::ok()
+                .log(transfer_log(contract(), address("account_1"), address("account_2"), "0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"))))
+        .check_state(check_account_2_max_supply()
+            .update_account(&contract(), |contract| contract.busd_holders(&[&address("account_2")])))
+}
+
+fn transfer_other_positive() -> Scenario {
+    Scenario::new("transfer_Other-Positive")
+        .set_state(caller_balance_state())
+        .sc_call(ScCall::new("1", call(address("account_1"), "transfer").arg(account_7()).arg("0x17"))
+            .expect(Expect::ok()
+                .log(transfer_log(contract(), address("account_1"), account_7(), "0x17"))))
+        .check_state(CheckState::new()
+            .account(address("account_1"), Account::new().nonce("1").balance("0xe8d4951000"))
+            .account(contract(), Account::contract()
+                .busd_balance(&address("account_1"), "0x26f9")
+                .busd_balance(&account_7(), "0x17")
+                .busd_holders(&[&account_7()])
+                .total_supply("0x2710")))
+}
+
+fn transfer_other_still_no_overflow() -> Scenario {
+    Scenario::new("transfer_Other-StillNoOverflow")
+        .set_state(SetState::new()
+            .account(address("account_1"), Account::new().balance("0xe8d4a51000"))
+            .account(contract(), Account::contract()
+                .busd_balance(&address("account_1"), "0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff")
+                .busd_balance(&address("account_2"), "0x0a")
+                .total_supply("0x010000000000000000000000000000000000000000000000000000000000000009")))
+        .sc_call(ScCall::new("1", call(address("account_1"), "transfer")
+                .arg(address("account_2"))
+                .arg("+0xfffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff5"))
+            .expect(Expect::ok()
+                .log(transfer_log(contract(), address("account_1"), address("account_2"), "0xfffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff5"))))
+        .check_state(CheckState::new()
+            .account(address("account_1"), Account::new().nonce("1").balance("0xe8d4951000"))
+            .account(contract(), Account::contract()
+                .busd_balance(&address("account_1"), "0x0a")
+                .busd_balance(&address("account_2"), "0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff")
+                .total_supply("0x010000000000000000000000000000000000000000000000000000000000000009")))
+}
+
+fn transfer_other_zero() -> Scenario {
+    Scenario::new("transfer_Other-Zero")
+        .set_state(caller_balance_state())
+        .sc_call(ScCall::new("1", call(address("account_1"), "transfer").arg(account_7()).arg("0"))
+            .expect(Expect::ok()
+                .log(transfer_log(contract(), address("account_1"), account_7(), "0"))))
+        .check_state(check_caller_balance_unchanged())
+}
@@ -0,0 +1,314 @@
+use super::*;
+
+pub fn scenarios() -> Vec<(&'static str, Scenario)> {
+    vec![
+        ("errors/error_codes", error_codes()),
+        ("errors/error_messages", error_messages()),
+    ]
+}
+
+fn contract() -> String {
+    address("busd_contract")
+}
+
+fn call<S: Into<String>>(from: S, function: &str) -> Tx {
+    Tx::call(from, contract(), function)
+}
+
+fn deployed_state() -> SetState {
+    SetState::new()
+        .account(address("busd_owner"), Account::new().balance("100,000,000,000"))
+        .account(address("someone_else"), Account::new().balance("100,000,000,000"))
+        .account(contract(), Account::contract()
+            .storage(key("owner", &[]), address("busd_owner"))
+            .storage(key("paused", &[]), "false")
+            .storage(key("supply_c", &[]), address("busd_owner")))
+}
+
+fn error_codes() -> Scenario {
+    Scenario::new("error codes")
+        .set_state(deployed_state())
+        .sc_call(ScCall::new("code_1", call(address("someone_else"), "getErrorCode").arg("``paused"))
+            .expect(Expect::ok().out("1")))
+        .sc_call(ScCall::new("code_2", call(address("someone_else"), "getErrorCode").arg("``address frozen"))
+            .expect(Expect::ok().out("2")))
+        .sc_call(ScCall::new("code_3", call(address("someone_else"), "getErrorCode").arg("``allowance exceeded"))
+            .expect(Expect::ok().out("3")))
+        .sc_call(ScCall::new("code_4", call(address("someone_else"), "getErrorCode").arg("``insufficient funds"))
+            .expect(Expect::ok().out("4")))
+        .sc_call(ScCall::new("code_5", call(address("someone_else"), "getErrorCode").arg("``daily transfer limit exceeded"))
+            .expect(Expect::ok().out("5")))
+        .sc_call(ScCall::new("code_6", call(address("someone_else"), "getErrorCode").arg("``travel rule metadata required"))
+            .expect(Expect::ok().out("6")))
+        .sc_call(ScCall::new("code_10", call(address("someone_else"), "getErrorCode").arg("``only owner can propose another owner"))
+            .expect(Expect::ok().out("10")))
+        .sc_call(ScCall::new("code_11", call(address("someone_else"), "getErrorCode").arg("``current owner cannot propose itself"))
+            .expect(Expect::ok().out("11")))
+        .sc_call(ScCall::new("code_12", call(address("someone_else"), "getErrorCode").arg("``caller already is proposed owner"))
+            .expect(Expect::ok().out("12")))
+        .sc_call(ScCall::new("code_13", call(address("someone_else"), "getErrorCode")
+                .arg("``can only disregard a proposed owner that was previously set"))
+            .expect(Expect::ok().out("13")))
+        .sc_call(ScCall::new("code_14", call(address("someone_else"), "getErrorCode")
+                .arg("``only proposedOwner or owner can disregard proposed owner"))
+            .expect(Expect::ok().out("14")))
+        .sc_call(ScCall::new("code_15", call(address("someone_else"), "getErrorCode").arg("``no owner proposed"))
+            .expect(Expect::ok().out("15")))
+        .sc_call(ScCall::new("code_16", call(address("someone_else"), "getErrorCode").arg("``only proposed owner can claim ownership"))
+            .expect(Expect::ok().out("16")))
+        .sc_call(ScCall::new("code_17", call(address("someone_else"), "getErrorCode").arg("``only owner can reclaim"))
+            .expect(Expect::ok().out("17")))
+        .sc_call(ScCall::new("code_20", call(address("someone_else"), "getErrorCode").arg("``already paused"))
+            .expect(Expect::ok().out("20")))
+        .sc_call(ScCall::new("code_21", call(address("someone_else"), "getErrorCode").arg("``already unpaused"))
+            .expect(Expect::ok().out("21")))
+        .sc_call(ScCall::new("code_22", call(address("someone_else"), "getErrorCode").arg("``only pauser can pause"))
+            .expect(Expect::ok().out("22")))
+        .sc_call(ScCall::new("code_23", call(address("someone_else"), "getErrorCode").arg("``only pauser can unpause"))
+            .expect(Expect::ok().out("23")))
+        .sc_call(ScCall::new("code_24", call(address("someone_else"), "getErrorCode").arg("``only owner can change pauser"))
+            .expect(Expect::ok().out("24")))
+        .sc_call(ScCall::new("code_30", call(address("someone_else"), "getErrorCode")
+                .arg("``only asset protection role or owner can change asset protection role"))
+            .expect(Expect::ok().out("30")))
+        .sc_call(ScCall::new("code_31", call(address("someone_else"), "getErrorCode").arg("``only asset protection role can freeze"))
+            .expect(Expect::ok().out("31")))
+        .sc_call(ScCall::new("code_32", call(address("someone_else"), "getErrorCode").arg("``address already frozen"))
+            .expect(Expect::ok().out("32")))
+        .sc_call(ScCall::new("code_33", call(address("someone_else"), "getErrorCode").arg("``only asset protection role can unfreeze"))
+            .expect(Expect::ok().out("33")))
+        .sc_call(ScCall::new("code_34", call(address("someone_else"), "getErrorCode").arg("``address already unfrozen"))
+            .expect(Expect::ok().out("34")))
+        .sc_call(ScCall::new("code_35", call(address("someone_else"), "getErrorCode").arg("``only asset protection role can wipe"))
+            .expect(Expect::ok().out("35")))
+        .sc_call(ScCall::new("code_36", call(address("someone_else"), "getErrorCode").arg("``address is not frozen"))
+            .expect(Expect::ok().out("36")))
+        .sc_call(ScCall::new("code_40", call(address("someone_else"), "getErrorCode")
+                .arg("``only supply controller or owner can change supply controller"))
+            .expect(Expect::ok().out("40")))
+        .sc_call(ScCall::new("code_41", call(address("someone_else"), "getErrorCode").arg("``only supply controller can increase supply"))
+            .expect(Expect::ok().out("41")))
+        .sc_call(ScCall::new("code_42", call(address("someone_else"), "getErrorCode").arg("``only supply controller can decrease supply"))
+            .expect(Expect::ok().out("42")))
+        .sc_call(ScCall::new("code_43", call(address("someone_else"), "getErrorCode").arg("``not enough supply to decrease"))
+            .expect(Expect::ok().out("43")))
+        .sc_call(ScCall::new("code_50", call(address("someone_else"), "getErrorCode")
+                .arg("``only asset protection role can set daily limits"))
+            .expect(Expect::ok().out("50")))
+        .sc_call(ScCall::new("code_51", call(address("someone_else"), "getErrorCode")
+                .arg("``only asset protection role can remove daily limits"))
+            .expect(Expect::ok().out("51")))
+        .sc_call(ScCall::new("code_52", call(address("someone_else"), "getErrorCode").arg("``address has no daily limit"))
+            .expect(Expect::ok().out("52")))
+        .sc_call(ScCall::new("code_53", call(address("someone_else"), "getErrorCode").arg("``no default daily limit"))
+            .expect(Expect::ok().out("53")))
+        .sc_call(ScCall::new("code_60", call(address("someone_else"), "getErrorCode")
+                .arg("``only owner can change large transfer settings"))
+            .expect(Expect::ok().out("60")))
+        .sc_call(ScCall::new("code_61", call(address("someone_else"), "getErrorCode").arg("``no large transfer threshold"))
+            .expect(Expect::ok().out("61")))
+        .sc_call(ScCall::new("code_62", call(address("someone_else"), "getErrorCode").arg("``pending transfer not found"))
+            .expect(Expect::ok().out("62")))
+        .sc_call(ScCall::new("code_63", call(address("someone_else"), "getErrorCode").arg("``review period not over"))
+            .expect(Expect::ok().out("63")))
+        .sc_call(ScCall::new("code_64", call(address("someone_else"), "getErrorCode")
+                .arg("``only asset protection role can cancel pending transfers"))
+            .expect(Expect::ok().out("64")))
+        .sc_call(ScCall::new("code_70", call(address("someone_else"), "getErrorCode").arg("``only owner can register VASPs"))
+            .expect(Expect::ok().out("70")))
+        .sc_call(ScCall::new("code_71", call(address("someone_else"), "getErrorCode").arg("``address is not a registered VASP"))
+            .expect(Expect::ok().out("71")))
+        .sc_call(ScCall::new("code_72", call(address("someone_else"), "getErrorCode").arg("``only owner can change travel rule threshold"))
+            .expect(Expect::ok().out("72")))
+        .sc_call(ScCall::new("code_73", call(address("someone_else"), "getErrorCode").arg("``no travel rule threshold"))
+            .expect(Expect::ok().out("73")))
+        .sc_call(ScCall::new("code_74", call(address("someone_else"), "getErrorCode").arg("``VASP id mismatch"))
+            .expect(Expect::ok().out("74")))
+        .sc_call(ScCall::new("code_80", call(address("someone_else"), "getErrorCode").arg("``only owner can change legacy events"))
+            .expect(Expect::ok().out("80")))
+        .sc_call(ScCall::new("code_90", call(address("someone_else"), "getErrorCode").arg("``invalid symbol length"))
+            .expect(Expect::ok().out("90")))
+        .sc_call(ScCall::new("code_91", call(address("someone_else"), "getErrorCode").arg("``decimals out of range"))
+            .expect(Expect::ok().out("91")))
+        .sc_call(ScCall::new("code_92", call(address("someone_else"), "getErrorCode").arg("``initial holder and initial supply go together"))
+            .expect(Expect::ok().out("92")))
+        .sc_call(ScCall::new("code_100", call(address("someone_else"), "getErrorCode").arg("``only owner can upgrade"))
+            .expect(Expect::ok().out("100")))
+        .sc_call(ScCall::new("code_101", call(address("someone_else"), "getErrorCode").arg("``storage already up to date"))
+            .expect(Expect::ok().out("101")))
+        .sc_call(ScCall::new("code_102", call(address("someone_else"), "getErrorCode").arg("``storage version newer than code"))
+            .expect(Expect::ok().out("102")))
+        .sc_call(ScCall::new("code_110", call(address("someone_else"), "getErrorCode").arg("``only owner can register holders"))
+            .expect(Expect::ok().out("110")))
+        .sc_call(ScCall::new("code_111", call(address("someone_else"), "getErrorCode").arg("``only owner can export state"))
+            .expect(Expect::ok().out("111")))
+        .sc_call(ScCall::new("code_112", call(address("someone_else"), "getErrorCode").arg("``only owner can import state"))
+            .expect(Expect::ok().out("112")))
+        .sc_call(ScCall::new("code_113", call(address("someone_else"), "getErrorCode").arg("``can only import into a fresh, paused contract"))
+            .expect(Expect::ok().out("113")))
+        .sc_call(ScCall::new("code_114", call(address("someone_else"), "getErrorCode").arg("``no import in progress"))
+            .expect(Expect::ok().out("114")))
+        .sc_call(ScCall::new("code_115", call(address("someone_else"), "getErrorCode").arg("``imported balances exceed declared total supply"))
+            .expect(Expect::ok().out("115")))
+        .sc_call(ScCall::new("code_116", call(address("someone_else"), "getErrorCode").arg("``imported balances do not add up to declared total supply"))
+            .expect(Expect::ok().out("116")))
+        .sc_call(ScCall::new("code_120", call(address("someone_else"), "getErrorCode").arg("``only owner can change ESDT migration"))
+            .expect(Expect::ok().out("120")))
+        .sc_call(ScCall::new("code_121", call(address("someone_else"), "getErrorCode").arg("``ESDT migration not enabled"))
+            .expect(Expect::ok().out("121")))
+        .sc_call(ScCall::new("code_122", call(address("someone_else"), "getErrorCode").arg("``ESDT migration already enabled"))
+            .expect(Expect::ok().out("122")))
+        .sc_call(ScCall::new("code_123", call(address("someone_else"), "getErrorCode").arg("``invalid ESDT token identifier"))
+            .expect(Expect::ok().out("123")))
+        .sc_call(ScCall::new("code_124", call(address("someone_else"), "getErrorCode").arg("``ESDT migration above large transfer threshold"))
+            .expect(Expect::ok().out("124")))
+        .sc_call(ScCall::new("code_140", call(address("someone_else"), "getErrorCode").arg("``only supply controller can take snapshots"))
+            .expect(Expect::ok().out("140")))
+        .sc_call(ScCall::new("code_141", call(address("someone_else"), "getErrorCode").arg("``snapshot not found"))
+            .expect(Expect::ok().out("141")))
+        .sc_call(ScCall::new("code_unknown", call(address("someone_else"), "getErrorCode").arg("``no such error"))
+            .expect(Expect::ok().out("0")))
+}
+
+fn error_messages() -> Scenario {
+    Scenario::new("error messages")
+        .set_state(deployed_state())
+        .sc_call(ScCall::new("message_1", call(address("someone_else"), "getErrorMessage").arg("1"))
+            .expect(Expect::ok().out("``paused")))
+        .sc_call(ScCall::new("message_2", call(address("someone_else"), "getErrorMessage").arg("2"))
+            .expect(Expect::ok().out("``address frozen")))
+        .sc_call(ScCall::new("message_3", call(address("someone_else"), "getErrorMessage").arg("3"))
+            .expect(Expect::ok().out("``allowance exceeded")))
+        .sc_call(ScCall::new("message_4", call(address("someone_else"), "getErrorMessage").arg("4"))
+            .expect(Expect::ok().out("``insufficient funds")))
+        .sc_call(ScCall::new("message_5", call(address("someone_else"), "getErrorMessage").arg("5"))
+            .expect(Expect::ok().out("``daily transfer limit exceeded")))
+        .sc_call(ScCall::new("message_6", call(address("someone_else"), "getErrorMessage").arg("6"))
+            .expect(Expect::ok().out("``travel rule metadata required")))
+        .sc_call(ScCall::new("message_10", call(address("someone_else"), "getErrorMessage").arg("10"))
+            .expect(Expect::ok().out("``only owner can propose another owner")))
+        .sc_call(ScCall::new("message_11", call(address("someone_else"), "getErrorMessage").arg("11"))
+            .expect(Expect::ok().out("``current owner cannot propose itself")))
+        .sc_call(ScCall::new("message_12", call(address("someone_else"), "getErrorMessage").arg("12"))
+            .expect(Expect::ok().out("``caller already is proposed owner")))
+        .sc_call(ScCall::new("message_13", call(address("someone_else"), "getErrorMessage").arg("13"))
+            .expect(Expect::ok()
+                .out("``can only disregard a proposed owner that was previously set")))
+        .sc_call(ScCall::new("message_14", call(address("someone_else"), "getErrorMessage").arg("14"))
+            .expect(Expect::ok()
+                .out("``only proposedOwner or owner can disregard proposed owner")))
+        .sc_call(ScCall::new("message_15", call(address("someone_else"), "getErrorMessage").arg("15"))
+            .expect(Expect::ok().out("``no owner proposed")))
+        .sc_call(ScCall::new("message_16", call(address("someone_else"), "getErrorMessage").arg("16"))
+            .expect(Expect::ok().out("``only proposed owner can claim ownership")))
+        .sc_call(ScCall::new("message_17", call(address("someone_else"), "getErrorMessage").arg("17"))
+            .expect(Expect::ok().out("``only owner can reclaim")))
+        .sc_call(ScCall::new("message_20", call(address("someone_else"), "getErrorMessage").arg("20"))
+            .expect(Expect::ok().out("``already paused")))
+        .sc_call(ScCall::new("message_21", call(address("someone_else"), "getErrorMessage").arg("21"))
+            .expect(Expect::ok().out("``already unpaused")))
+        .sc_call(ScCall::new("message_22", call(address("someone_else"), "getErrorMessage").arg("22"))
+            .expect(Expect::ok().out("``only pauser can pause")))
+        .sc_call(ScCall::new("message_23", call(address("someone_else"), "getErrorMessage").arg("23"))
+            .expect(Expect::ok().out("``only pauser can unpause")))
+        .sc_call(ScCall::new("message_24", call(address("someone_else"), "getErrorMessage").arg("24"))
+            .expect(Expect::ok().out("``only owner can change pauser")))
+        .sc_call(ScCall::new("message_30", call(address("someone_else"), "getErrorMessage").arg("30"))
+            .expect(Expect::ok()
+                .out("``only asset protection role or owner can change asset protection role")))
+        .sc_call(ScCall::new("message_31", call(address("someone_else"), "getErrorMessage").arg("31"))
+            .expect(Expect::ok().out("``only asset protection role can freeze")))
+        .sc_call(ScCall::new("message_32", call(address("someone_else"), "getErrorMessage").arg("32"))
+            .expect(Expect::ok().out("``address already frozen")))
+        .sc_call(ScCall::new("message_33", call(address("someone_else"), "getErrorMessage").arg("33"))
+            .expect(Expect::ok().out("``only asset protection role can unfreeze")))
+        .sc_call(ScCall::new("message_34", call(address("someone_else"), "getErrorMessage").arg("34"))
+            .expect(Expect::ok().out("``address already unfrozen")))
+        .sc_call(ScCall::new("message_35", call(address("someone_else"), "getErrorMessage").arg("35"))
+            .expect(Expect::ok().out("``only asset protection role can wipe")))
+        .sc_call(ScCall::new("message_36", call(address("someone_else"), "getErrorMessage").arg("36"))
+            .expect(Expect::ok().out("``address is not frozen")))
+        .sc_call(ScCall::new("message_40", call(address("someone_else"), "getErrorMessage").arg("40"))
+            .expect(Expect::ok()
+                .out("``only supply controller or owner can change supply controller")))
+        .sc_call(ScCall::new("message_41", call(address("someone_else"), "getErrorMessage").arg("41"))
+            .expect(Expect::ok().out("``only supply controller can increase supply")))
+        .sc_call(ScCall::new("message_42", call(address("someone_else"), "getErrorMessage").arg("42"))
+            .expect(Expect::ok().out("``only supply controller can decrease supply")))
+        .sc_call(ScCall::new("message_43", call(address("someone_else"), "getErrorMessage").arg("43"))
+            .expect(Expect::ok().out("``not enough supply to decrease")))
+        .sc_call(ScCall::new("message_50", call(address("someone_else"), "getErrorMessage").arg("50"))
+            .expect(Expect::ok().out("``only asset protection role can set daily limits")))
+        .sc_call(ScCall::new("message_51", call(address("someone_else"), "getErrorMessage").arg("51"))
+            .expect(Expect::ok()
+                .out("``only asset protection role can remove daily limits")))
+        .sc_call(ScCall::new("message_52", call(address("someone_else"), "getErrorMessage").arg("52"))
+            .expect(Expect::ok().out("``address has no daily limit")))
+        .sc_call(ScCall::new("message_53", call(address("someone_else"), "getErrorMessage").arg("53"))
+            .expect(Expect::ok().out("``no default daily limit")))
+        .sc_call(ScCall::new("message_60", call(address("someone_else"), "getErrorMessage").arg("60"))
+            .expect(Expect::ok().out("``only owner can change large transfer settings")))
+        .sc_call(ScCall::new("message_61", call(address("someone_else"), "getErrorMessage").arg("61"))
+            .expect(Expect::ok().out("``no large transfer threshold")))
+        .sc_call(ScCall::new("message_62", call(address("someone_else"), "getErrorMessage").arg("62"))
+            .expect(Expect::ok().out("``pending transfer not found")))
+        .sc_call(ScCall::new("message_63", call(address("someone_else"), "getErrorMessage").arg("63"))
+            .expect(Expect::ok().out("``review period not over")))
+        .sc_call(ScCall::new("message_64", call(address("someone_else"), "getErrorMessage").arg("64"))
+            .expect(Expect::ok()
+                .out("``only asset protection role can cancel pending transfers")))
+        .sc_call(ScCall::new("message_70", call(address("someone_else"), "getErrorMessage").arg("70"))
+            .expect(Expect::ok().out("``only owner can register VASPs")))
+        .sc_call(ScCall::new("message_71", call(address("someone_else"), "getErrorMessage").arg("71"))
+            .expect(Expect::ok().out("``address is not a registered VASP")))
+        .sc_call(ScCall::new("message_72", call(address("someone_else"), "getErrorMessage").arg("72"))
+            .expect(Expect::ok().out("``only owner can change travel rule threshold")))
+        .sc_call(ScCall::new("message_73", call(address("someone_else"), "getErrorMessage").arg("73"))
+            .expect(Expect::ok().out("``no travel rule threshold")))
+        .sc_call(ScCall::new("message_74", call(address("someone_else"), "getErrorMessage").arg("74"))
+            .expect(Expect::ok().out("``VASP id mismatch")))
+        .sc_call(ScCall::new("message_80", call(address("someone_else"), "getErrorMessage").arg("80"))
+            .expect(Expect::ok().out("``only owner can change legacy events")))
+        .sc_call(ScCall::new("message_90", call(address("someone_else"), "getErrorMessage").arg("90"))
+            .expect(Expect::ok().out("``invalid symbol length")))
+        .sc_call(ScCall::new("message_91", call(address("someone_else"), "getErrorMessage").arg("91"))
+            .expect(Expect::ok().out("``decimals out of range")))
+        .sc_call(ScCall::new("message_92", call(address("someone_else"), "getErrorMessage").arg("92"))
+            .expect(Expect::ok().out("``initial holder and initial supply go together")))
+        .sc_call(ScCall::new("message_100", call(address("someone_else"), "getErrorMessage").arg("100"))
+            .expect(Expect::ok().out("``only owner can upgrade")))
+        .sc_call(ScCall::new("message_101", call(address("someone_else"), "getErrorMessage").arg("101"))
+            .expect(Expect::ok().out("``storage already up to date")))
+        .sc_call(ScCall::new("message_102", call(address("someone_else"), "getErrorMessage").arg("102"))
+            .expect(Expect::ok().out("``storage version newer than code")))
+        .sc_call(ScCall::new("message_110", call(address("someone_else"), "getErrorMessage").arg("110"))
+            .expect(Expect::ok().out("``only owner can register holders")))
+        .sc_call(ScCall::new("message_111", call(address("someone_else"), "getErrorMessage").arg("111"))
+            .expect(Expect::ok().out("``only owner can export state")))
+        .sc_call(ScCall::new("message_112", call(address("someone_else"), "getErrorMessage").arg("112"))
+            .expect(Expect::ok().out("``only owner can import state")))
+        .sc_call(ScCall::new("message_113", call(address("someone_else"), "getErrorMessage").arg("113"))
+            .expect(Expect::ok().out("``can only import into a fresh, paused contract")))
+        .sc_call(ScCall::new("message_114", call(address("someone_else"), "getErrorMessage").arg("114"))
+            .expect(Expect::ok().out("``no import in progress")))
+        .sc_call(ScCall::new("message_115", call(address("someone_else"), "getErrorMessage").arg("115"))
+            .expect(Expect::ok().out("``imported balances exceed declared total supply")))
+        .sc_call(ScCall::new("message_116", call(address("someone_else"), "getErrorMessage").arg("116"))
+            .expect(Expect::ok().out("``imported balances do not add up to declared total supply")))
+        .sc_call(ScCall::new("message_120", call(address("someone_else"), "getErrorMessage").arg("120"))
+            .expect(Expect::ok().out("``only owner can change ESDT migration")))
+        .sc_call(ScCall::new("message_121", call(address("someone_else"), "getErrorMessage").arg("121"))
+            .expect(Expect::ok().out("``ESDT migration not enabled")))
+        .sc_call(ScCall::new("message_122", call(address("someone_else"), "getErrorMessage").arg("122"))
+            .expect(Expect::ok().out("``ESDT migration already enabled")))
+        .sc_call(ScCall::new("message_123", call(address("someone_else"), "getErrorMessage").arg("123"))
+            .expect(Expect::ok().out("``invalid ESDT token identifier")))
+        .sc_call(ScCall::new("message_124", call(address("someone_else"), "getErrorMessage").arg("124"))
+            .expect(Expect::ok().out("``ESDT migration above large transfer threshold")))
+        .sc_call(ScCall::new("message_140", call(address("someone_else"), "getErrorMessage").arg("140"))
+            .expect(Expect::ok().out("``only supply controller can take snapshots")))
+        .sc_call(ScCall::new("message_141", call(address("someone_else"), "getErrorMessage").arg("141"))
+            .expect(Expect::ok().out("``snapshot not found")))
+        .sc_call(ScCall::new("message_unknown", call(address("someone_else"), "getErrorMessage").arg("9999"))
+            .expect(Expect::ok().out("``unknown error code")))
+}
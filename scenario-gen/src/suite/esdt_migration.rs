@@ -0,0 +1,126 @@
+use super::*;
+
+// A successful `migrateToEsdt` ends in the `ESDTTransfer` built-in function, which the scenarios cannot run,
+// so it is covered by `tests/esdt_migration_rs_test.rs`.
+pub fn scenarios() -> Vec<(&'static str, Scenario)> {
+    vec![
+        ("esdt_migration/enable_esdt_migration", enable_esdt_migration()),
+        ("esdt_migration/migrate_to_esdt_errors", migrate_to_esdt_errors()),
+    ]
+}
+
+fn contract() -> String {
+    address("busd_contract")
+}
+
+fn call<S: Into<String>>(from: S, function: &str) -> Tx {
+    Tx::call(from, contract(), function)
+}
+
+fn busd_state() -> SetState {
+    SetState::new()
+        .account(address("busd_owner"), Account::new().balance("100,000,000,000"))
+        .account(address("holder_1"), Account::new().balance("100,000,000,000"))
+        .account(address("holder_2"), Account::new().balance("100,000,000,000"))
+        .account(contract(), Account::contract()
+            .storage(key("owner", &[]), address("busd_owner"))
+            .storage(key("paused", &[]), "false")
+            .storage(key("supply_c", &[]), address("busd_owner"))
+            .busd_metadata()
+            .storage(key("schema_version", &[]), "2")
+            .busd_balance(&address("holder_1"), "5000")
+            .storage(key("frozen", &[&address("holder_2")]), "true")
+            .total_supply("5000"))
+}
+
+fn enable_esdt_migration() -> Scenario {
+    Scenario::new("enable and disable the ESDT migration")
+        .set_state(busd_state())
+        .sc_call(ScCall::new("1", call(address("holder_1"), "enableEsdtMigration").arg("``BUSD-a1b2c3"))
+            .expect(Expect::error("4", "only owner can change ESDT migration")))
+        .sc_call(ScCall::new("2", call(address("busd_owner"), "enableEsdtMigration").arg(""))
+            .expect(Expect::error("4", "invalid ESDT token identifier")))
+        .sc_call(ScCall::new("3", call(address("busd_owner"), "enableEsdtMigration").arg("``BUSD-a1b2c3"))
+            .expect(Expect::ok().log(Log::event(contract(), "EsdtMigrationEnabled").data("``BUSD-a1b2c3"))))
+        .sc_call(ScCall::new("4", call(address("busd_owner"), "enableEsdtMigration").arg("``OTHER-000000"))
+            .expect(Expect::error("4", "ESDT migration already enabled")))
+        .sc_call(ScCall::new("5", call(address("holder_1"), "getEsdtToken"))
+            .expect(Expect::ok().out("``BUSD-a1b2c3")))
+        .sc_call(ScCall::new("6", call(address("holder_1"), "disableEsdtMigration"))
+            .expect(Expect::error("4", "only owner can change ESDT migration")))
+        .sc_call(ScCall::new("7", call(address("busd_owner"), "disableEsdtMigration"))
+            .expect(Expect::ok().log(Log::event(contract(), "EsdtMigrationDisabled"))))
+        .sc_call(ScCall::new("8", call(address("busd_owner"), "disableEsdtMigration"))
+            .expect(Expect::error("4", "ESDT migration not enabled")))
+        .check_state(CheckState::new()
+            .account(address("busd_owner"), Account::new().nonce("*").balance("*"))
+            .account(address("holder_1"), Account::new().nonce("*").balance("*"))
+            .account(address("holder_2"), Account::new().nonce("*").balance("*"))
+            .account(contract(), Account::contract()
+                .storage(key("owner", &[]), address("busd_owner"))
+                .storage(key("paused", &[]), "false")
+                .storage(key("supply_c", &[]), address("busd_owner"))
+                .busd_metadata()
+                .storage(key("schema_version", &[]), "2")
+                .busd_balance(&address("holder_1"), "5000")
+                .storage(key("frozen", &[&address("holder_2")]), "true")
+                .total_supply("5000")
+                .storage(key("esdt_token", &[]), "")))
+}
+
+fn migrate_to_esdt_errors() -> Scenario {
+    Scenario::new("migrating to ESDT follows the transfer restrictions")
+        .set_state(busd_state())
+        .sc_call(ScCall::new("1", call(address("holder_1"), "migrateToEsdt").arg("1"))
+            .expect(Expect::error("4", "ESDT migration not enabled")))
+        .sc_call(ScCall::new("2", call(address("busd_owner"), "enableEsdtMigration").arg("``BUSD-a1b2c3"))
+            .expect(Expect::ok().log(Log::event(contract(), "EsdtMigrationEnabled").data("``BUSD-a1b2c3"))))
+        .sc_call(ScCall::new("3", call(address("holder_1"), "migrateToEsdt").arg("5001"))
+            .expect(Expect::error("4", "insufficient funds")))
+        .sc_call(ScCall::new("4", call(address("holder_2"), "migrateToEsdt").arg("0"))
+            .expect(Expect::error("4", "address frozen")))
+        .sc_call(ScCall::new("5", call(address("busd_owner"), "setLargeTransferThreshold").arg("1000"))
+            .expect(Expect::ok()
+                .log(Log::event(contract(), "LargeTransferThresholdSet")
+                    .data("1000"))))
+        .sc_call(ScCall::new("6", call(address("holder_1"), "migrateToEsdt").arg("1001"))
+            .comment("a migration cannot be queued for review")
+            .expect(Expect::error("4", "ESDT migration above large transfer threshold")))
+        .sc_call(ScCall::new("7", call(address("busd_owner"), "registerVasp")
+                .arg(address("holder_1"))
+                .arg(bytes32("vasp_identifier")))
+            .expect(Expect::ok()
+                .log(Log::event(contract(), "VaspRegistered")
+                    .topic(address("holder_1"))
+                    .topic(bytes32("vasp_identifier")))))
+        .sc_call(ScCall::new("8", call(address("busd_owner"), "setTravelRuleThreshold").arg("100"))
+            .expect(Expect::ok()
+                .log(Log::event(contract(), "TravelRuleThresholdSet")
+                    .data("100"))))
+        .sc_call(ScCall::new("9", call(address("holder_1"), "migrateToEsdt").arg("101"))
+            .comment("a VASP has no counterparty to send the travel rule metadata to")
+            .expect(Expect::error("4", "travel rule metadata required")))
+        .sc_call(ScCall::new("10", call(address("busd_owner"), "pause"))
+            .expect(Expect::ok().log(Log::event(contract(), "Pause").topic(address("busd_owner")))))
+        .sc_call(ScCall::new("11", call(address("holder_1"), "migrateToEsdt").arg("1"))
+            .expect(Expect::error("4", "paused")))
+        .sc_call(ScCall::new("12", call(address("holder_1"), "getEsdtMigratedSupply"))
+            .expect(Expect::ok().out("0")))
+        .check_state(CheckState::new()
+            .account(address("busd_owner"), Account::new().nonce("*").balance("*"))
+            .account(address("holder_1"), Account::new().nonce("*").balance("*"))
+            .account(address("holder_2"), Account::new().nonce("*").balance("*"))
+            .account(contract(), Account::contract()
+                .storage(key("owner", &[]), address("busd_owner"))
+                .storage(key("paused", &[]), "true")
+                .storage(key("supply_c", &[]), address("busd_owner"))
+                .busd_metadata()
+                .storage(key("schema_version", &[]), "2")
+                .busd_balance(&address("holder_1"), "5000")
+                .storage(key("frozen", &[&address("holder_2")]), "true")
+                .total_supply("5000")
+                .storage(key("esdt_token", &[]), "``BUSD-a1b2c3")
+                .storage(key("lt_threshold", &[]), some("1000"))
+                .storage(key("vasp_id", &[&address("holder_1")]), some(&bytes32("vasp_identifier")))
+                .storage(key("tr_threshold", &[]), some("100"))))
+}
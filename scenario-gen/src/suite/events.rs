@@ -0,0 +1,80 @@
+use super::*;
+
+pub fn scenarios() -> Vec<(&'static str, Scenario)> {
+    vec![
+        ("events/legacy_events", legacy_events()),
+    ]
+}
+
+fn contract() -> String {
+    address("busd_contract")
+}
+
+fn call<S: Into<String>>(from: S, function: &str) -> Tx {
+    Tx::call(from, contract(), function)
+}
+
+fn legacy_events() -> Scenario {
+    Scenario::new("legacy events")
+        .set_state(SetState::new()
+            .account(address("busd_owner"), Account::new().balance("100,000,000,000"))
+            .account(address("holder_1"), Account::new().balance("100,000,000,000"))
+            .account(address("holder_2"), Account::new().balance("100,000,000,000"))
+            .account(address("someone_else"), Account::new().balance("100,000,000,000"))
+            .account(contract(), Account::contract()
+                .storage(key("owner", &[]), address("busd_owner"))
+                .storage(key("paused", &[]), "false")
+                .storage(key("supply_c", &[]), address("busd_owner"))
+                .storage(key("lt_threshold", &[]), some("1000"))
+                .busd_balance(&address("holder_1"), "5000")
+                .total_supply("5000")))
+        .sc_call(ScCall::new("1", call(address("holder_1"), "enableLegacyEvents"))
+            .comment("only the owner can enable legacy events")
+            .expect(Expect::error("4", "only owner can change legacy events")))
+        .sc_call(ScCall::new("2", call(address("busd_owner"), "enableLegacyEvents"))
+            .expect(Expect::ok().log(Log::event(contract(), "LegacyEventsEnabled"))))
+        .sc_call(ScCall::new("3", call(address("someone_else"), "legacyEventsEnabled"))
+            .expect(Expect::ok().out("true")))
+        .sc_call(ScCall::new("4", call(address("holder_1"), "transfer").arg(address("holder_2")).arg("100"))
+            .comment("each named event is followed by its legacy counterpart")
+            .expect(Expect::ok()
+                .log(transfer_log(contract(), address("holder_1"), address("holder_2"), "100"))
+                .log(Log::legacy_event(contract(), 0x01)
+                    .topic(address("holder_1"))
+                    .topic(address("holder_2"))
+                    .data("100"))))
+        .sc_call(ScCall::new("5", call(address("holder_1"), "transfer").arg(address("holder_2")).arg("2000"))
+            .comment("events added since v0.3.0 have no legacy counterpart")
+            .expect(Expect::ok()
+                .log(Log::event(contract(), "LargeTransferQueued")
+                    .topic(address("holder_1"))
+                    .topic(address("holder_2"))
+                    .topic("0x0000000000000000000000000000000000000000000000000000000000000001")
+                    .data("2000"))))
+        .sc_call(ScCall::new("6", call(address("busd_owner"), "disableLegacyEvents"))
+            .expect(Expect::ok().log(Log::event(contract(), "LegacyEventsDisabled"))))
+        .sc_call(ScCall::new("7", call(address("holder_1"), "transfer").arg(address("holder_2")).arg("100"))
+            .expect(Expect::ok()
+                .log(transfer_log(contract(), address("holder_1"), address("holder_2"), "100"))))
+        .check_state(CheckState::new()
+            .account(address("busd_owner"), Account::new().nonce("*").balance("*"))
+            .account(address("holder_1"), Account::new().nonce("*").balance("*"))
+            .account(address("holder_2"), Account::new().nonce("*").balance("*"))
+            .account(address("someone_else"), Account::new().nonce("*").balance("*"))
+            .account(contract(), Account::contract()
+                .storage(key("owner", &[]), address("busd_owner"))
+                .storage(key("paused", &[]), "false")
+                .storage(key("supply_c", &[]), address("busd_owner"))
+                .storage(key("lt_threshold", &[]), some("1000"))
+                .busd_balance(&address("holder_1"), "2800")
+                .busd_balance(&address("holder_2"), "200")
+                .busd_holders(&[&address("holder_2")])
+                .total_supply("5000")
+                .storage(key("pt_last_id", &[]), "1")
+                .storage(key("pt_ids", &[]), "0x0000000000000001")
+                .storage(key("pt_total", &[]), "2000")
+                .storage(key("pt_sender", &["1"]), address("holder_1"))
+                .storage(key("pt_recipient", &["1"]), address("holder_2"))
+                .storage(key("pt_amount", &["1"]), "2000")
+                .storage(key("pt_time", &["1"]), "0")))
+}
@@ -0,0 +1,315 @@
+use super::*;
+
+pub fn scenarios() -> Vec<(&'static str, Scenario)> {
+    vec![
+        ("freeze/asset_prot_role_not_set", asset_prot_role_not_set()),
+        ("freeze/asset_prot_role_set", asset_prot_role_set()),
+        ("freeze/asset_prot_role_set_again", asset_prot_role_set_again()),
+        ("freeze/asset_prot_role_set_bad_caller", asset_prot_role_set_bad_caller()),
+        ("freeze/transfer_vs_freeze", transfer_vs_freeze()),
+        ("freeze/wipe", wipe()),
+        ("freeze/wipe_bad_caller", wipe_bad_caller()),
+        ("freeze/wipe_not_frozen", wipe_not_frozen()),
+    ]
+}
+
+fn contract() -> String {
+    address("busd_contract")
+}
+
+fn call<S: Into<String>>(from: S, function: &str) -> Tx {
+    Tx::call(from, contract(), function)
+}
+
+fn accounts_before_deploy() -> SetState {
+    SetState::new()
+        .account(address("busd_owner"), Account::new().balance("100,000,000,000"))
+        .account(address("asset_protection_role"), Account::new().balance("100,000,000,000"))
+        .account(address("someone_else"), Account::new().balance("100,000,000,000"))
+        .new_address(address("busd_owner"), "0", address("new_busd_contract"))
+}
+
+fn deploy() -> ScCall {
+    ScCall::new("1", deploy_busd(address("busd_owner")).gas_limit("100,000"))
+        .comment("deploy contract")
+        .expect(Expect::ok().log(initialized_log(address("new_busd_contract"), &address("busd_owner"))))
+}
+
+fn check_no_asset_protection_role() -> ScCall {
+    ScCall::new("check_before", Tx::call(address("someone_else"), address("new_busd_contract"), "getAssetProtectionRole")
+            .gas_limit("100,000"))
+        .comment("initial asset protection role should be none")
+}
+
+fn set_asset_protection_role() -> ScCall {
+    ScCall::new("2", Tx::call(address("busd_owner"), address("new_busd_contract"), "setAssetProtectionRole")
+            .arg(address("asset_protection_role"))
+            .gas_limit("100,000"))
+        .comment("set new asset protection role")
+        .expect(Expect::ok()
+            .log(Log::event(address("new_busd_contract"), "AssetProtectionRoleSet")
+                .topic(zero_address())
+                .topic(address("asset_protection_role"))))
+}
+
+fn check_asset_protection_role_set() -> ScCall {
+    ScCall::new("check_after", Tx::call(address("someone_else"), address("new_busd_contract"), "getAssetProtectionRole")
+            .gas_limit("100,000"))
+        .comment("get new asset protection role")
+        .expect(Expect::ok().out(address("asset_protection_role")))
+}
+
+fn wipe_state() -> SetState {
+    SetState::new()
+        .account(address("busd_owner"), Account::new().balance("100,000,000,000"))
+        .account(address("asset_protection_role"), Account::new().balance("100,000,000,000"))
+        .account(address("holder_no_freeze"), Account::new().balance("100,000,000,000"))
+        .account(address("holder_gets_wiped"), Account::new().balance("100,000,000,000"))
+        .account(address("another_holder"), Account::new().balance("100,000,000,000"))
+        .account(contract(), Account::contract()
+            .total_supply("23,000")
+            .storage(key("owner", &[]), address("busd_owner"))
+            .storage(key("paused", &[]), "false")
+            .storage(key("ap_role", &[]), some(&address("asset_protection_role")))
+            .storage(key("supply_c", &[]), address("busd_owner"))
+            .busd_balance(&address("holder_gets_wiped"), "500")
+            .busd_balance(&address("holder_no_freeze"), "500")
+            .busd_allowance(&address("holder_no_freeze"), &address("another_holder"), "1000")
+            .busd_allowance(&address("holder_gets_wiped"), &address("another_holder"), "1000"))
+}
+
+fn check_balance_not_wiped() -> ScCall {
+    ScCall::new("4", Tx::call(address("busd_owner"), contract(), "balanceOf")
+            .arg(address("holder_gets_wiped"))
+            .gas_limit("100,000"))
+        .comment("check balance after")
+        .expect(Expect::ok().out("500"))
+}
+
+fn check_total_supply_unchanged() -> ScCall {
+    ScCall::new("5", Tx::call(address("busd_owner"), contract(), "totalSupply").gas_limit("0x100000"))
+        .comment("check total supply after")
+        .expect(Expect::ok().out("23,000"))
+}
+
+fn asset_prot_role_not_set() -> Scenario {
+    Scenario::new("asset protection role test")
+        .set_state(accounts_before_deploy())
+        .sc_deploy(deploy())
+        .sc_call(check_no_asset_protection_role())
+        .sc_call(ScCall::new("3-freeze", Tx::call(address("asset_protection_role"), address("new_busd_contract"), "freeze")
+                .arg(address("holder_gets_frozen"))
+                .gas_limit("100,000"))
+            .comment("freeze")
+            .expect(Expect::error("4", "only asset protection role can freeze")))
+        .sc_call(ScCall::new("3-freeze", Tx::call(address("asset_protection_role"), address("new_busd_contract"), "unfreeze")
+                .arg(address("holder_gets_frozen"))
+                .gas_limit("100,000"))
+            .comment("freeze")
+            .expect(Expect::error("4", "only asset protection role can unfreeze")))
+        .sc_call(ScCall::new("3-freeze", Tx::call(address("asset_protection_role"), address("new_busd_contract"), "wipeFrozenAddress")
+                .arg(address("holder_gets_frozen"))
+                .gas_limit("100,000"))
+            .comment("freeze")
+            .expect(Expect::error("4", "only asset protection role can wipe")))
+}
+
+fn asset_prot_role_set() -> Scenario {
+    Scenario::new("asset protection role test")
+        .set_state(accounts_before_deploy())
+        .sc_deploy(deploy())
+        .sc_call(check_no_asset_protection_role())
+        .sc_call(set_asset_protection_role())
+        .sc_call(check_asset_protection_role_set())
+}
+
+fn asset_prot_role_set_again() -> Scenario {
+    Scenario::new("asset protection role test")
+        .set_state(accounts_before_deploy())
+        .sc_deploy(deploy())
+        .sc_call(check_no_asset_protection_role())
+        .sc_call(set_asset_protection_role())
+        .sc_call(check_asset_protection_role_set())
+        .sc_call(ScCall::new("4", Tx::call(address("busd_owner"), address("new_busd_contract"), "setAssetProtectionRole")
+                .arg(address("asset_protection_role"))
+                .gas_limit("100,000"))
+            .comment("owner sets asset protection role again")
+            .expect(Expect::ok()
+                .log(Log::event(address("new_busd_contract"), "AssetProtectionRoleSet")
+                    .topic(address("asset_protection_role"))
+                    .topic(address("asset_protection_role")))))
+        .sc_call(ScCall::new("5", Tx::call(address("asset_protection_role"), address("new_busd_contract"), "setAssetProtectionRole")
+                .arg(address("asset_protection_role"))
+                .gas_limit("100,000"))
+            .comment("asset protection role sets asset protection role again")
+            .expect(Expect::ok()
+                .log(Log::event(address("new_busd_contract"), "AssetProtectionRoleSet")
+                    .topic(address("asset_protection_role"))
+                    .topic(address("asset_protection_role")))))
+}
+
+fn asset_prot_role_set_bad_caller() -> Scenario {
+    Scenario::new("asset protection role test")
+        .set_state(accounts_before_deploy())
+        .sc_deploy(deploy())
+        .sc_call(ScCall::new("2", Tx::call(address("someone_else"), address("new_busd_contract"), "setAssetProtectionRole")
+                .arg(address("asset_protection_role"))
+                .gas_limit("100,000"))
+            .comment("set new asset protection role")
+            .expect(Expect::error("4", "only asset protection role or owner can change asset protection role")))
+}
+
+fn transfer_vs_freeze() -> Scenario {
+    Scenario::new("freeze test")
+        .set_state(SetState::new()
+            .account(address("busd_owner"), Account::new().balance("100,000,000,000"))
+            .account(address("asset_protection_role"), Account::new().balance("100,000,000,000"))
+            .account(address("holder_no_freeze"), Account::new().balance("100,000,000,000"))
+            .account(address("holder_gets_frozen"), Account::new().balance("100,000,000,000"))
+            .account(address("another_holder"), Account::new().balance("100,000,000,000"))
+            .account(contract(), Account::contract()
+                .storage(key("owner", &[]), address("busd_owner"))
+                .storage(key("paused", &[]), "false")
+                .storage(key("ap_role", &[]), some(&address("asset_protection_role")))
+                .storage(key("supply_c", &[]), address("busd_owner"))
+                .storage(key("prop_owner", &[]), "")
+                .busd_balance(&address("holder_gets_frozen"), "500")
+                .busd_balance(&address("holder_no_freeze"), "500")
+                .busd_allowance(&address("holder_no_freeze"), &address("another_holder"), "1000")
+                .busd_allowance(&address("holder_gets_frozen"), &address("another_holder"), "1000")))
+        .sc_call(ScCall::new("1", call(address("holder_no_freeze"), "transfer").arg(address("holder_gets_frozen")).arg("10"))
+            .comment("not yet frozen, transfer ok")
+            .expect(Expect::ok()
+                .log(transfer_log(contract(), address("holder_no_freeze"), address("holder_gets_frozen"), "10"))))
+        .sc_call(ScCall::new("2-from", call(address("holder_gets_frozen"), "approve").arg(address("holder_no_freeze")).arg("10"))
+            .comment("not yet frozen, allowance ok")
+            .expect(Expect::ok()
+                .log(approve_log(contract(), address("holder_gets_frozen"), address("holder_no_freeze"), "10"))))
+        .sc_call(ScCall::new("2-to", call(address("holder_no_freeze"), "approve").arg(address("holder_gets_frozen")).arg("10"))
+            .comment("not yet frozen, allowance ok, we need the allowance for later")
+            .expect(Expect::ok()
+                .log(approve_log(contract(), address("holder_no_freeze"), address("holder_gets_frozen"), "10"))))
+        .sc_call(ScCall::new("3-freeze", call(address("asset_protection_role"), "freeze").arg(address("holder_gets_frozen")))
+            .comment("freeze")
+            .expect(Expect::ok()
+                .log(Log::event(contract(), "AddressFrozen")
+                    .topic(address("holder_gets_frozen")))))
+        .sc_call(ScCall::new("3-freeze-again", call(address("asset_protection_role"), "freeze").arg(address("holder_gets_frozen")))
+            .comment("re-freeze not allowed")
+            .expect(Expect::error("4", "address already frozen")))
+        .sc_call(ScCall::new("4-from", call(address("holder_gets_frozen"), "transfer").arg(address("holder_no_freeze")).arg("10"))
+            .comment("no transfer from frozen")
+            .expect(Expect::error("4", "address frozen")))
+        .sc_call(ScCall::new("4-to", call(address("holder_no_freeze"), "transfer").arg(address("holder_gets_frozen")).arg("10"))
+            .comment("no transfer to frozen")
+            .expect(Expect::error("4", "address frozen")))
+        .sc_call(ScCall::new("5-from", call(address("holder_gets_frozen"), "approve").arg(address("holder_no_freeze")).arg("10"))
+            .comment("no allowance from frozen")
+            .expect(Expect::error("4", "address frozen")))
+        .sc_call(ScCall::new("5-to", call(address("holder_no_freeze"), "approve").arg(address("holder_gets_frozen")).arg("10"))
+            .comment("no allowance to frozen")
+            .expect(Expect::error("4", "address frozen")))
+        .sc_call(ScCall::new("6-from", call(address("another_holder"), "transferFrom")
+                .arg(address("holder_gets_frozen"))
+                .arg(address("holder_no_freeze"))
+                .arg("10"))
+            .comment("no transferFrom from frozen")
+            .expect(Expect::error("4", "address frozen")))
+        .sc_call(ScCall::new("6-to", call(address("another_holder"), "transferFrom")
+                .arg(address("holder_no_freeze"))
+                .arg(address("holder_gets_frozen"))
+                .arg("10"))
+            .comment("no transferFrom to frozen")
+            .expect(Expect::error("4", "address frozen")))
+        .sc_call(ScCall::new("6-by", call(address("holder_gets_frozen"), "transferFrom")
+                .arg(address("holder_no_freeze"))
+                .arg(address("another_holder"))
+                .arg("10"))
+            .comment("no transferFrom called by frozen, even if frozen is neither sender not recipient")
+            .expect(Expect::error("4", "address frozen")))
+        .sc_call(ScCall::new("7-unfreeze", call(address("asset_protection_role"), "unfreeze").arg(address("holder_gets_frozen")))
+            .comment("unfreeze")
+            .expect(Expect::ok()
+                .log(Log::event(contract(), "AddressUnfrozen")
+                    .topic(address("holder_gets_frozen")))))
+        .sc_call(ScCall::new("7-unfreeze-again", call(address("asset_protection_role"), "unfreeze").arg(address("holder_gets_frozen")))
+            .comment("re-unfreeze not allowed")
+            .expect(Expect::error("4", "address already unfrozen")))
+        .sc_call(ScCall::new("8", call(address("holder_no_freeze"), "transfer").arg(address("holder_gets_frozen")).arg("10"))
+            .comment("not frozen, transfer ok again")
+            .expect(Expect::ok()
+                .log(transfer_log(contract(), address("holder_no_freeze"), address("holder_gets_frozen"), "10"))))
+        .sc_call(ScCall::new("9", call(address("holder_gets_frozen"), "approve").arg(address("holder_no_freeze")).arg("10"))
+            .comment("not frozen, allowance ok again")
+            .expect(Expect::ok()
+                .log(approve_log(contract(), address("holder_gets_frozen"), address("holder_no_freeze"), "10"))))
+        .sc_call(ScCall::new("10", call(address("holder_no_freeze"), "transferFrom")
+                .arg(address("holder_gets_frozen"))
+                .arg(address("holder_no_freeze"))
+                .arg("10"))
+            .comment("not frozen, transferFrom ok again")
+            .expect(Expect::ok()
+                .log(transfer_log(contract(), address("holder_gets_frozen"), address("holder_no_freeze"), "10"))))
+}
+
+fn wipe() -> Scenario {
+    Scenario::new("wipe test")
+        .set_state(wipe_state())
+        .sc_call(ScCall::new("1-freeze", call(address("asset_protection_role"), "freeze").arg(address("holder_gets_wiped")))
+            .comment("freeze")
+            .expect(Expect::ok()
+                .log(Log::event(contract(), "AddressFrozen")
+                    .topic(address("holder_gets_wiped")))))
+        .sc_call(ScCall::new("2", call(address("busd_owner"), "balanceOf").arg(address("holder_gets_wiped")))
+            .comment("check balance before")
+            .expect(Expect::ok().out("500")))
+        .sc_call(ScCall::new("3-wipe", call(address("asset_protection_role"), "wipeFrozenAddress").arg(address("holder_gets_wiped")))
+            .comment("wipe")
+            .expect(Expect::ok()
+                .log(Log::event(contract(), "FrozenAddressWiped")
+                    .topic(address("holder_gets_wiped")))
+                .log(Log::event(contract(), "SupplyDecreased")
+                    .topic(address("holder_gets_wiped"))
+                    .data("500"))
+                .log(transfer_log(contract(), address("holder_gets_wiped"), zero_address(), "500"))))
+        .sc_call(ScCall::new("4", call(address("busd_owner"), "balanceOf").arg(address("holder_gets_wiped")))
+            .comment("check balance after")
+            .expect(Expect::ok().out("0")))
+        .sc_call(ScCall::new("5", Tx::call(address("busd_owner"), contract(), "totalSupply").gas_limit("0x100000"))
+            .comment("check total supply after")
+            .expect(Expect::ok().out("22,500")))
+}
+
+fn wipe_bad_caller() -> Scenario {
+    Scenario::new("wipe test")
+        .set_state(wipe_state())
+        .sc_call(ScCall::new("1-wipe", Tx::call(address("another_holder"), contract(), "wipeFrozenAddress")
+                .arg(address("holder_gets_wiped"))
+                .gas_limit("100,000"))
+            .comment("wipe, but by someone unauthorized")
+            .expect(Expect::error("4", "only asset protection role can wipe")))
+        .sc_call(ScCall::new("2-wipe", Tx::call(address("busd_owner"), contract(), "wipeFrozenAddress")
+                .arg(address("holder_gets_wiped"))
+                .gas_limit("100,000"))
+            .comment("owner also not allowed if not asset protection role")
+            .expect(Expect::error("4", "only asset protection role can wipe")))
+        .sc_call(check_balance_not_wiped())
+        .sc_call(check_total_supply_unchanged())
+}
+
+fn wipe_not_frozen() -> Scenario {
+    Scenario::new("wipe test")
+        .set_state(wipe_state())
+        .sc_call(ScCall::new("2", Tx::call(address("busd_owner"), contract(), "balanceOf")
+                .arg(address("holder_gets_wiped"))
+                .gas_limit("100,000"))
+            .comment("check balance before")
+            .expect(Expect::ok().out("500")))
+        .sc_call(ScCall::new("3-wipe", Tx::call(address("asset_protection_role"), contract(), "wipeFrozenAddress")
+                .arg(address("holder_gets_wiped"))
+                .gas_limit("100,000"))
+            .comment("wipe")
+            .expect(Expect::error("4", "address is not frozen")))
+        .sc_call(check_balance_not_wiped())
+        .sc_call(check_total_supply_unchanged())
+}
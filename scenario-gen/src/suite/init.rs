@@ -0,0 +1,175 @@
+use super::*;
+
+pub fn scenarios() -> Vec<(&'static str, Scenario)> {
+    vec![
+        ("init/create", create()),
+        ("init/create_metadata", create_metadata()),
+        ("init/create_roles", create_roles()),
+        ("init/create_pauser_only", create_pauser_only()),
+        ("init/create_bad_symbol", create_bad_metadata("``EU", "18", "invalid symbol length")),
+        ("init/create_bad_decimals", create_bad_metadata("``EURS", "19", "decimals out of range")),
+        ("init/create_holder_without_supply", create_holder_without_supply()),
+    ]
+}
+
+fn contract() -> String {
+    address("new_busd_contract")
+}
+
+fn call<S: Into<String>>(from: S, function: &str) -> Tx {
+    Tx::call(from, contract(), function).gas_limit("100,000,000")
+}
+
+fn create() -> Scenario {
+    Scenario::new("create")
+        .set_state(SetState::new()
+            .account(address("busd_owner"), Account::new().balance("100,000,000,000"))
+            .new_address(address("busd_owner"), "0", contract()))
+        .sc_deploy(ScCall::new("1", deploy_busd(address("busd_owner")).gas_limit("1,000,000,000"))
+            .comment("deploy contract")
+            .expect(Expect::ok().log(initialized_log(contract(), &address("busd_owner")))))
+        .check_state(CheckState::new()
+            .account(address("busd_owner"), Account::new().nonce("*").balance("*"))
+            .account(contract(), Account::contract()
+                .storage(key("owner", &[]), address("busd_owner"))
+                .storage(key("paused", &[]), "true")
+                .storage(key("supply_c", &[]), address("busd_owner"))
+                .storage(key("ap_role", &[]), "")
+                .storage(key("prop_owner", &[]), "")
+                .storage(key("pauser", &[]), "")
+                .busd_metadata()
+                .storage(key("schema_version", &[]), "2")))
+        .sc_call(ScCall::new("2", call(address("busd_owner"), "balanceOf").arg(address("busd_owner")))
+            .expect(Expect::ok().out("0")))
+        .sc_call(ScCall::new("name", call(address("busd_owner"), "name"))
+            .expect(Expect::ok().out("``Binance USD")))
+        .sc_call(ScCall::new("symbol", call(address("busd_owner"), "symbol"))
+            .expect(Expect::ok().out("``BUSD")))
+        .sc_call(ScCall::new("decimals", call(address("busd_owner"), "decimals"))
+            .expect(Expect::ok().out("18")))
+        .sc_call(ScCall::new("storage_version", call(address("busd_owner"), "getStorageVersion"))
+            .expect(Expect::ok().out("2")))
+}
+
+fn create_metadata() -> Scenario {
+    Scenario::new("create with other metadata")
+        .set_state(SetState::new()
+            .account(address("busd_owner"), Account::new().balance("100,000,000,000"))
+            .new_address(address("busd_owner"), "0", contract()))
+        .sc_deploy(ScCall::new("1", Tx::deploy(address("busd_owner"))
+                .arg("``Euro Stable")
+                .arg("``EURS")
+                .arg("6")
+                .gas_limit("1,000,000,000"))
+            .comment("deploy the same contract for another token")
+            .expect(Expect::ok().log(initialized_log(contract(), &address("busd_owner")))))
+        .sc_call(ScCall::new("name", call(address("busd_owner"), "name"))
+            .expect(Expect::ok().out("``Euro Stable")))
+        .sc_call(ScCall::new("symbol", call(address("busd_owner"), "symbol"))
+            .expect(Expect::ok().out("``EURS")))
+        .sc_call(ScCall::new("decimals", call(address("busd_owner"), "decimals"))
+            .expect(Expect::ok().out("6")))
+}
+
+fn create_bad_metadata(symbol: &str, decimals: &str, message: &str) -> Scenario {
+    Scenario::new("create with invalid metadata")
+        .set_state(SetState::new()
+            .account(address("busd_owner"), Account::new().balance("100,000,000,000"))
+            .new_address(address("busd_owner"), "0", contract()))
+        .sc_deploy(ScCall::new("1", Tx::deploy(address("busd_owner"))
+                .arg("``Euro Stable")
+                .arg(symbol)
+                .arg(decimals)
+                .gas_limit("1,000,000,000"))
+            .comment("symbols have 3 to 10 characters, there are at most 18 decimals")
+            .expect(Expect::error("4", message)))
+}
+
+fn create_pauser_only() -> Scenario {
+    Scenario::new("create with only a pauser")
+        .set_state(SetState::new()
+            .account(address("deployer"), Account::new().balance("100,000,000,000"))
+            .new_address(address("deployer"), "0", contract()))
+        .sc_deploy(ScCall::new("1", deploy_busd(address("deployer"))
+                .arg(zero_address())
+                .arg(zero_address())
+                .arg(zero_address())
+                .arg(address("pauser"))
+                .gas_limit("1,000,000,000"))
+            .comment("the zero address leaves the roles before the pauser to their defaults")
+            .expect(Expect::ok()
+                .log(Log::event(contract(), "Initialized")
+                    .topic(address("deployer"))
+                    .topic(address("deployer"))
+                    .topic(zero_address())
+                    .topic(address("pauser"))
+                    .data("true"))))
+        .check_state(CheckState::new()
+            .account(address("deployer"), Account::new().nonce("*").balance("*"))
+            .account(contract(), Account::contract()
+                .storage(key("owner", &[]), address("deployer"))
+                .storage(key("paused", &[]), "true")
+                .storage(key("supply_c", &[]), address("deployer"))
+                .storage(key("ap_role", &[]), "")
+                .storage(key("prop_owner", &[]), "")
+                .storage(key("pauser", &[]), some(&address("pauser")))
+                .busd_metadata()
+                .storage(key("schema_version", &[]), "2")))
+}
+
+fn create_holder_without_supply() -> Scenario {
+    Scenario::new("create with an initial holder but no initial supply")
+        .set_state(SetState::new()
+            .account(address("deployer"), Account::new().balance("100,000,000,000"))
+            .new_address(address("deployer"), "0", contract()))
+        .sc_deploy(ScCall::new("1", deploy_busd(address("deployer"))
+                .arg(address("busd_owner"))
+                .arg(address("supply_controller"))
+                .arg(address("asset_protection_role"))
+                .arg(address("pauser"))
+                .arg(address("holder_1"))
+                .gas_limit("1,000,000,000"))
+            .comment("the initial holder and the initial supply are given together, or not at all")
+            .expect(Expect::error("4", "initial holder and initial supply go together")))
+}
+
+fn create_roles() -> Scenario {
+    Scenario::new("create with roles")
+        .set_state(SetState::new()
+            .account(address("deployer"), Account::new().balance("100,000,000,000"))
+            .new_address(address("deployer"), "0", contract()))
+        .sc_deploy(ScCall::new("1", deploy_busd(address("deployer"))
+                .arg(address("busd_owner"))
+                .arg(address("supply_controller"))
+                .arg(address("asset_protection_role"))
+                .arg(address("pauser"))
+                .arg(address("holder_1"))
+                .arg("5000")
+                .gas_limit("1,000,000,000"))
+            .comment("assign every role and mint the initial supply, nothing is left to the deployer")
+            .expect(Expect::ok()
+                .log(Log::event(contract(), "Initialized")
+                    .topic(address("busd_owner"))
+                    .topic(address("supply_controller"))
+                    .topic(address("asset_protection_role"))
+                    .topic(address("pauser"))
+                    .data("true"))
+                .log(Log::event(contract(), "SupplyIncreased")
+                    .topic(address("holder_1"))
+                    .data("5000"))
+                .log(transfer_log(contract(), zero_address(), address("holder_1"), "5000"))))
+        .check_state(CheckState::new()
+            .account(address("deployer"), Account::new().nonce("*").balance("*"))
+            .account(contract(), Account::contract()
+                .storage(key("owner", &[]), address("busd_owner"))
+                .storage(key("paused", &[]), "true")
+                .storage(key("supply_c", &[]), address("supply_controller"))
+                .storage(key("ap_role", &[]), some(&address("asset_protection_role")))
+                .storage(key("prop_owner", &[]), "")
+                .storage(key("pauser", &[]), some(&address("pauser")))
+                .busd_metadata()
+                .storage(key("schema_version", &[]), "2")
+                .busd_balance(&address("holder_1"), "5000")
+                .busd_holders(&[&address("holder_1")])
+                .total_supply("5000")))
+}
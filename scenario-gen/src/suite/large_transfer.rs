@@ -0,0 +1,378 @@
+use super::*;
+
+pub fn scenarios() -> Vec<(&'static str, Scenario)> {
+    vec![
+        ("large_transfer/large_transfer_cancel", large_transfer_cancel()),
+        ("large_transfer/large_transfer_cancel_daily_limit", large_transfer_cancel_daily_limit()),
+        ("large_transfer/large_transfer_execute", large_transfer_execute()),
+        ("large_transfer/large_transfer_execute_restricted", large_transfer_execute_restricted()),
+        ("large_transfer/large_transfer_settings", large_transfer_settings()),
+    ]
+}
+
+fn contract() -> String {
+    address("busd_contract")
+}
+
+fn call<S: Into<String>>(from: S, function: &str) -> Tx {
+    Tx::call(from, contract(), function)
+}
+
+fn large_transfer_cancel() -> Scenario {
+    Scenario::new("large transfer cancelled by asset protection role")
+        .set_state(SetState::new()
+            .account(address("busd_owner"), Account::new().balance("100,000,000,000"))
+            .account(address("asset_protection_role"), Account::new().balance("100,000,000,000"))
+            .account(address("holder_1"), Account::new().balance("100,000,000,000"))
+            .account(address("holder_2"), Account::new().balance("100,000,000,000"))
+            .account(address("someone_else"), Account::new().balance("100,000,000,000"))
+            .account(contract(), Account::contract()
+                .storage(key("owner", &[]), address("busd_owner"))
+                .storage(key("paused", &[]), "false")
+                .storage(key("ap_role", &[]), some(&address("asset_protection_role")))
+                .storage(key("supply_c", &[]), address("busd_owner"))
+                .storage(key("lt_threshold", &[]), some("1000"))
+                .storage(key("lt_review", &[]), "3600")
+                .busd_balance(&address("holder_1"), "5000")
+                .busd_allowance(&address("holder_1"), &address("someone_else"), "3000"))
+            .block_timestamp("1,000,000"))
+        .sc_call(ScCall::new("1", call(address("someone_else"), "transferFrom")
+                .arg(address("holder_1"))
+                .arg(address("holder_2"))
+                .arg("2000"))
+            .comment("transferFrom gets queued too")
+            .expect(Expect::ok()
+                .log(Log::event(contract(), "LargeTransferQueued")
+                    .topic(address("holder_1"))
+                    .topic(address("holder_2"))
+                    .topic("0x0000000000000000000000000000000000000000000000000000000000000001")
+                    .data("2000"))))
+        .sc_call(ScCall::new("2", call(address("holder_1"), "transfer").arg(address("someone_else")).arg("2500"))
+            .expect(Expect::ok()
+                .log(Log::event(contract(), "LargeTransferQueued")
+                    .topic(address("holder_1"))
+                    .topic(address("someone_else"))
+                    .topic("0x0000000000000000000000000000000000000000000000000000000000000002")
+                    .data("2500"))))
+        .sc_call(ScCall::new("check_1", call(address("someone_else"), "getPendingTransferIds"))
+            .expect(Expect::ok()
+                .out("1")
+                .out("2")))
+        .sc_call(ScCall::new("3", call(address("busd_owner"), "cancelPendingTransfer").arg("1"))
+            .comment("only asset protection role")
+            .expect(Expect::error("0x04", "only asset protection role can cancel pending transfers")))
+        .sc_call(ScCall::new("4", call(address("asset_protection_role"), "cancelPendingTransfer").arg("1"))
+            .comment("cancel")
+            .expect(Expect::ok()
+                .log(Log::event(contract(), "LargeTransferCancelled")
+                    .topic(address("holder_1"))
+                    .topic(address("holder_2"))
+                    .topic("0x0000000000000000000000000000000000000000000000000000000000000001")
+                    .data("2000"))))
+        .sc_call(ScCall::new("5", call(address("asset_protection_role"), "cancelPendingTransfer").arg("1"))
+            .comment("already cancelled")
+            .expect(Expect::error("0x04", "pending transfer not found")))
+        .sc_call(ScCall::new("check_2", call(address("someone_else"), "getPendingTransferIds"))
+            .expect(Expect::ok().out("2")))
+        .sc_call(ScCall::new("check_3", call(address("someone_else"), "getPendingTransfer").arg("1"))
+            .expect(Expect::error("0x04", "pending transfer not found")))
+        .check_state(CheckState::new()
+            .account(address("busd_owner"), Account::new().nonce("*").balance("*"))
+            .account(address("asset_protection_role"), Account::new().nonce("*").balance("*"))
+            .account(address("holder_1"), Account::new().nonce("*").balance("*"))
+            .account(address("holder_2"), Account::new().nonce("*").balance("*"))
+            .account(address("someone_else"), Account::new().nonce("*").balance("*"))
+            .account(contract(), Account::contract()
+                .storage(key("owner", &[]), address("busd_owner"))
+                .storage(key("paused", &[]), "false")
+                .storage(key("ap_role", &[]), some(&address("asset_protection_role")))
+                .storage(key("supply_c", &[]), address("busd_owner"))
+                .storage(key("lt_threshold", &[]), some("1000"))
+                .storage(key("lt_review", &[]), "3600")
+                .busd_balance(&address("holder_1"), "2500")
+                .busd_allowance(&address("holder_1"), &address("someone_else"), "1000")
+                .storage(key("pt_last_id", &[]), "2")
+                .storage(key("pt_ids", &[]), "0x0000000000000002")
+                .storage(key("pt_total", &[]), "2500")
+                .storage(key("pt_sender", &["1"]), address("holder_1"))
+                .storage(key("pt_recipient", &["1"]), address("holder_2"))
+                .storage(key("pt_time", &["1"]), "1,000,000")
+                .storage(key("pt_sender", &["2"]), address("holder_1"))
+                .storage(key("pt_recipient", &["2"]), address("someone_else"))
+                .storage(key("pt_amount", &["2"]), "2500")
+                .storage(key("pt_time", &["2"]), "1,000,000")))
+}
+
+fn large_transfer_cancel_daily_limit() -> Scenario {
+    Scenario::new("cancelling a large transfer gives its amount back to the sender daily limit")
+        .set_state(SetState::new()
+            .account(address("busd_owner"), Account::new().balance("100,000,000,000"))
+            .account(address("asset_protection_role"), Account::new().balance("100,000,000,000"))
+            .account(address("holder_1"), Account::new().balance("100,000,000,000"))
+            .account(address("holder_2"), Account::new().balance("100,000,000,000"))
+            .account(contract(), Account::contract()
+                .storage(key("owner", &[]), address("busd_owner"))
+                .storage(key("paused", &[]), "false")
+                .storage(key("ap_role", &[]), some(&address("asset_protection_role")))
+                .storage(key("supply_c", &[]), address("busd_owner"))
+                .storage(key("lt_threshold", &[]), some("1000"))
+                .storage(key("lt_review", &[]), "3600")
+                .storage(key("vel_limit", &[&address("holder_1")]), some("3000"))
+                .busd_balance(&address("holder_1"), "5000"))
+            .block_timestamp("1,000,000"))
+        .sc_call(ScCall::new("1", call(address("holder_1"), "transfer").arg(address("holder_2")).arg("2000"))
+            .comment("queued, and counted towards the daily limit")
+            .expect(Expect::ok()
+                .log(Log::event(contract(), "LargeTransferQueued")
+                    .topic(address("holder_1"))
+                    .topic(address("holder_2"))
+                    .topic("0x0000000000000000000000000000000000000000000000000000000000000001")
+                    .data("2000"))))
+        .sc_call(ScCall::new("check_1", call(address("holder_2"), "getRemainingDailyCapacity").arg(address("holder_1")))
+            .expect(Expect::ok().out("1000")))
+        .sc_call(ScCall::new("2", call(address("asset_protection_role"), "cancelPendingTransfer").arg("1"))
+            .expect(Expect::ok()
+                .log(Log::event(contract(), "LargeTransferCancelled")
+                    .topic(address("holder_1"))
+                    .topic(address("holder_2"))
+                    .topic("0x0000000000000000000000000000000000000000000000000000000000000001")
+                    .data("2000"))))
+        .sc_call(ScCall::new("check_2", call(address("holder_2"), "getRemainingDailyCapacity").arg(address("holder_1")))
+            .expect(Expect::ok().out("3000")))
+        .check_state(CheckState::new()
+            .account(address("busd_owner"), Account::new().nonce("*").balance("*"))
+            .account(address("asset_protection_role"), Account::new().nonce("*").balance("*"))
+            .account(address("holder_1"), Account::new().nonce("*").balance("*"))
+            .account(address("holder_2"), Account::new().nonce("*").balance("*"))
+            .account(contract(), Account::contract()
+                .storage(key("owner", &[]), address("busd_owner"))
+                .storage(key("paused", &[]), "false")
+                .storage(key("ap_role", &[]), some(&address("asset_protection_role")))
+                .storage(key("supply_c", &[]), address("busd_owner"))
+                .storage(key("lt_threshold", &[]), some("1000"))
+                .storage(key("lt_review", &[]), "3600")
+                .storage(key("vel_limit", &[&address("holder_1")]), some("3000"))
+                .storage(key("vel_hour", &[&address("holder_1"), "2"]), "277")
+                .busd_balance(&address("holder_1"), "5000")
+                .storage(key("pt_last_id", &[]), "1")
+                .storage(key("pt_sender", &["1"]), address("holder_1"))
+                .storage(key("pt_recipient", &["1"]), address("holder_2"))
+                .storage(key("pt_time", &["1"]), "1,000,000")))
+}
+
+fn large_transfer_execute() -> Scenario {
+    Scenario::new("large transfer queued then executed")
+        .set_state(SetState::new()
+            .account(address("busd_owner"), Account::new().balance("100,000,000,000"))
+            .account(address("asset_protection_role"), Account::new().balance("100,000,000,000"))
+            .account(address("holder_1"), Account::new().balance("100,000,000,000"))
+            .account(address("holder_2"), Account::new().balance("100,000,000,000"))
+            .account(address("someone_else"), Account::new().balance("100,000,000,000"))
+            .account(contract(), Account::contract()
+                .storage(key("owner", &[]), address("busd_owner"))
+                .storage(key("paused", &[]), "false")
+                .storage(key("ap_role", &[]), some(&address("asset_protection_role")))
+                .storage(key("supply_c", &[]), address("busd_owner"))
+                .storage(key("lt_threshold", &[]), some("1000"))
+                .storage(key("lt_review", &[]), "3600")
+                .busd_balance(&address("holder_1"), "5000"))
+            .block_timestamp("1,000,000"))
+        .sc_call(ScCall::new("1", call(address("holder_1"), "transfer").arg(address("holder_2")).arg("1000"))
+            .comment("at the threshold, settled instantly")
+            .expect(Expect::ok()
+                .log(transfer_log(contract(), address("holder_1"), address("holder_2"), "1000"))))
+        .sc_call(ScCall::new("2", call(address("holder_1"), "transfer").arg(address("holder_2")).arg("1001"))
+            .comment("above the threshold, queued")
+            .expect(Expect::ok()
+                .log(Log::event(contract(), "LargeTransferQueued")
+                    .topic(address("holder_1"))
+                    .topic(address("holder_2"))
+                    .topic("0x0000000000000000000000000000000000000000000000000000000000000001")
+                    .data("1001"))))
+        .sc_call(ScCall::new("check_1", call(address("someone_else"), "getPendingTransferIds"))
+            .expect(Expect::ok().out("1")))
+        .sc_call(ScCall::new("check_2", call(address("someone_else"), "getPendingTransfer").arg("1"))
+            .expect(Expect::ok()
+                .out(address("holder_1"))
+                .out(address("holder_2"))
+                .out("1001")
+                .out("1,000,000")))
+        .sc_call(ScCall::new("check_3", call(address("someone_else"), "getPendingTransfersTotal"))
+            .expect(Expect::ok().out("1001")))
+        .sc_call(ScCall::new("check_4", call(address("someone_else"), "balanceOf").arg(address("holder_1")))
+            .comment("sender balance already debited")
+            .expect(Expect::ok().out("2999")))
+        .set_state(SetState::new()
+            .block_timestamp("1,003,599"))
+        .sc_call(ScCall::new("3", call(address("someone_else"), "executePendingTransfer").arg("1"))
+            .comment("too early")
+            .expect(Expect::error("0x04", "review period not over")))
+        .set_state(SetState::new()
+            .block_timestamp("1,003,600"))
+        .sc_call(ScCall::new("4", call(address("someone_else"), "executePendingTransfer").arg("1"))
+            .comment("anyone can execute")
+            .expect(Expect::ok()
+                .log(Log::event(contract(), "LargeTransferExecuted")
+                    .topic(address("holder_1"))
+                    .topic(address("holder_2"))
+                    .topic("0x0000000000000000000000000000000000000000000000000000000000000001")
+                    .data("1001"))
+                .log(transfer_log(contract(), address("holder_1"), address("holder_2"), "1001"))))
+        .sc_call(ScCall::new("5", call(address("someone_else"), "executePendingTransfer").arg("1"))
+            .comment("only once")
+            .expect(Expect::error("0x04", "pending transfer not found")))
+        .sc_call(ScCall::new("check_5", call(address("someone_else"), "getPendingTransferIds")))
+        .check_state(CheckState::new()
+            .account(address("busd_owner"), Account::new().nonce("*").balance("*"))
+            .account(address("asset_protection_role"), Account::new().nonce("*").balance("*"))
+            .account(address("holder_1"), Account::new().nonce("*").balance("*"))
+            .account(address("holder_2"), Account::new().nonce("*").balance("*"))
+            .account(address("someone_else"), Account::new().nonce("*").balance("*"))
+            .account(contract(), Account::contract()
+                .storage(key("owner", &[]), address("busd_owner"))
+                .storage(key("paused", &[]), "false")
+                .storage(key("ap_role", &[]), some(&address("asset_protection_role")))
+                .storage(key("supply_c", &[]), address("busd_owner"))
+                .storage(key("lt_threshold", &[]), some("1000"))
+                .storage(key("lt_review", &[]), "3600")
+                .busd_balance(&address("holder_1"), "2999")
+                .busd_balance(&address("holder_2"), "2001")
+                .busd_holders(&[&address("holder_2")])
+                .storage(key("pt_last_id", &[]), "1")
+                .storage(key("pt_sender", &["1"]), address("holder_1"))
+                .storage(key("pt_recipient", &["1"]), address("holder_2"))
+                .storage(key("pt_time", &["1"]), "1,000,000")))
+}
+
+fn large_transfer_execute_restricted() -> Scenario {
+    Scenario::new("pending transfers obey pause and freeze")
+        .set_state(SetState::new()
+            .account(address("busd_owner"), Account::new().balance("100,000,000,000"))
+            .account(address("asset_protection_role"), Account::new().balance("100,000,000,000"))
+            .account(address("holder_1"), Account::new().balance("100,000,000,000"))
+            .account(address("holder_2"), Account::new().balance("100,000,000,000"))
+            .account(address("someone_else"), Account::new().balance("100,000,000,000"))
+            .account(contract(), Account::contract()
+                .storage(key("owner", &[]), address("busd_owner"))
+                .storage(key("paused", &[]), "false")
+                .storage(key("ap_role", &[]), some(&address("asset_protection_role")))
+                .storage(key("supply_c", &[]), address("busd_owner"))
+                .storage(key("lt_threshold", &[]), some("1000"))
+                .storage(key("lt_review", &[]), "3600")
+                .busd_balance(&address("holder_1"), "5000")
+                .storage(key("pt_last_id", &[]), "1")
+                .storage(key("pt_ids", &[]), "0x0000000000000001")
+                .storage(key("pt_total", &[]), "2000")
+                .storage(key("pt_sender", &["1"]), address("holder_1"))
+                .storage(key("pt_recipient", &["1"]), address("holder_2"))
+                .storage(key("pt_amount", &["1"]), "2000")
+                .storage(key("pt_time", &["1"]), "0")
+                .storage(key("frozen", &[&address("holder_2")]), "true"))
+            .block_timestamp("1,000,000"))
+        .sc_call(ScCall::new("1", call(address("someone_else"), "executePendingTransfer").arg("1"))
+            .comment("recipient frozen")
+            .expect(Expect::error("0x04", "address frozen")))
+        .sc_call(ScCall::new("2", call(address("asset_protection_role"), "unfreeze").arg(address("holder_2")))
+            .expect(Expect::ok()
+                .log(Log::event(contract(), "AddressUnfrozen")
+                    .topic(address("holder_2")))))
+        .sc_call(ScCall::new("3", call(address("busd_owner"), "pause"))
+            .comment("pause")
+            .expect(Expect::ok()
+                .log(Log::event(contract(), "Pause")
+                    .topic(address("busd_owner")))))
+        .sc_call(ScCall::new("4", call(address("someone_else"), "executePendingTransfer").arg("1"))
+            .comment("paused")
+            .expect(Expect::error("0x04", "paused")))
+        .sc_call(ScCall::new("5", call(address("busd_owner"), "unpause"))
+            .comment("unpause")
+            .expect(Expect::ok()
+                .log(Log::event(contract(), "Unpause")
+                    .topic(address("busd_owner")))))
+        .sc_call(ScCall::new("6", call(address("someone_else"), "executePendingTransfer").arg("1"))
+            .comment("executes")
+            .expect(Expect::ok()
+                .log(Log::event(contract(), "LargeTransferExecuted")
+                    .topic(address("holder_1"))
+                    .topic(address("holder_2"))
+                    .topic("0x0000000000000000000000000000000000000000000000000000000000000001")
+                    .data("2000"))
+                .log(transfer_log(contract(), address("holder_1"), address("holder_2"), "2000"))))
+        .check_state(CheckState::new()
+            .account(address("busd_owner"), Account::new().nonce("*").balance("*"))
+            .account(address("asset_protection_role"), Account::new().nonce("*").balance("*"))
+            .account(address("holder_1"), Account::new().nonce("*").balance("*"))
+            .account(address("holder_2"), Account::new().nonce("*").balance("*"))
+            .account(address("someone_else"), Account::new().nonce("*").balance("*"))
+            .account(contract(), Account::contract()
+                .storage(key("owner", &[]), address("busd_owner"))
+                .storage(key("paused", &[]), "false")
+                .storage(key("ap_role", &[]), some(&address("asset_protection_role")))
+                .storage(key("supply_c", &[]), address("busd_owner"))
+                .storage(key("lt_threshold", &[]), some("1000"))
+                .storage(key("lt_review", &[]), "3600")
+                .busd_balance(&address("holder_1"), "5000")
+                .busd_balance(&address("holder_2"), "2000")
+                .busd_holders(&[&address("holder_2")])
+                .storage(key("pt_last_id", &[]), "1")
+                .storage(key("pt_sender", &["1"]), address("holder_1"))
+                .storage(key("pt_recipient", &["1"]), address("holder_2"))
+                .storage(key("pt_time", &["1"]), "0")))
+}
+
+fn large_transfer_settings() -> Scenario {
+    Scenario::new("large transfer settings")
+        .set_state(SetState::new()
+            .account(address("busd_owner"), Account::new().balance("100,000,000,000"))
+            .account(address("asset_protection_role"), Account::new().balance("100,000,000,000"))
+            .account(address("holder_1"), Account::new().balance("100,000,000,000"))
+            .account(address("holder_2"), Account::new().balance("100,000,000,000"))
+            .account(address("someone_else"), Account::new().balance("100,000,000,000"))
+            .account(contract(), Account::contract()
+                .storage(key("owner", &[]), address("busd_owner"))
+                .storage(key("paused", &[]), "false")
+                .storage(key("ap_role", &[]), some(&address("asset_protection_role")))
+                .storage(key("supply_c", &[]), address("busd_owner"))
+                .busd_balance(&address("holder_1"), "5000"))
+            .block_timestamp("1,000,000"))
+        .sc_call(ScCall::new("1", call(address("asset_protection_role"), "setLargeTransferThreshold").arg("1000"))
+            .comment("only owner")
+            .expect(Expect::error("0x04", "only owner can change large transfer settings")))
+        .sc_call(ScCall::new("2", call(address("asset_protection_role"), "setLargeTransferReviewPeriod").arg("3600"))
+            .comment("only owner")
+            .expect(Expect::error("0x04", "only owner can change large transfer settings")))
+        .sc_call(ScCall::new("3", call(address("busd_owner"), "removeLargeTransferThreshold"))
+            .comment("nothing to remove")
+            .expect(Expect::error("0x04", "no large transfer threshold")))
+        .sc_call(ScCall::new("4", call(address("busd_owner"), "setLargeTransferThreshold").arg("1000"))
+            .comment("set threshold")
+            .expect(Expect::ok()
+                .log(Log::event(contract(), "LargeTransferThresholdSet")
+                    .data("1000"))))
+        .sc_call(ScCall::new("5", call(address("busd_owner"), "setLargeTransferReviewPeriod").arg("3600"))
+            .comment("set review period")
+            .expect(Expect::ok()
+                .log(Log::event(contract(), "LargeTransferReviewPeriodSet")
+                    .data("3600"))))
+        .sc_call(ScCall::new("check_1", call(address("someone_else"), "getLargeTransferThreshold"))
+            .expect(Expect::ok().out("1000")))
+        .sc_call(ScCall::new("check_2", call(address("someone_else"), "getLargeTransferReviewPeriod"))
+            .expect(Expect::ok().out("3600")))
+        .sc_call(ScCall::new("6", call(address("busd_owner"), "removeLargeTransferThreshold"))
+            .comment("remove threshold")
+            .expect(Expect::ok().log(Log::event(contract(), "LargeTransferThresholdRemoved"))))
+        .sc_call(ScCall::new("check_3", call(address("someone_else"), "getLargeTransferThreshold")))
+        .check_state(CheckState::new()
+            .account(address("busd_owner"), Account::new().nonce("*").balance("*"))
+            .account(address("asset_protection_role"), Account::new().nonce("*").balance("*"))
+            .account(address("holder_1"), Account::new().nonce("*").balance("*"))
+            .account(address("holder_2"), Account::new().nonce("*").balance("*"))
+            .account(address("someone_else"), Account::new().nonce("*").balance("*"))
+            .account(contract(), Account::contract()
+                .storage(key("owner", &[]), address("busd_owner"))
+                .storage(key("paused", &[]), "false")
+                .storage(key("ap_role", &[]), some(&address("asset_protection_role")))
+                .storage(key("supply_c", &[]), address("busd_owner"))
+                .storage(key("lt_review", &[]), "3600")
+                .busd_balance(&address("holder_1"), "5000")))
+}
@@ -0,0 +1,201 @@
+use super::*;
+
+pub fn scenarios() -> Vec<(&'static str, Scenario)> {
+    vec![
+        ("migration/export_state", export_state()),
+        ("migration/import_state", import_state()),
+    ]
+}
+
+fn contract() -> String {
+    address("busd_contract")
+}
+
+fn new_contract() -> String {
+    address("new_busd_contract")
+}
+
+fn call<S: Into<String>>(from: S, function: &str) -> Tx {
+    Tx::call(from, contract(), function)
+}
+
+fn new_call<S: Into<String>>(from: S, function: &str) -> Tx {
+    Tx::call(from, new_contract(), function).gas_limit("100,000,000")
+}
+
+fn export_state() -> Scenario {
+    Scenario::new("export state")
+        .set_state(SetState::new()
+            .account(address("busd_owner"), Account::new().balance("100,000,000,000"))
+            .account(address("holder_1"), Account::new().balance("100,000,000,000"))
+            .account(address("holder_2"), Account::new().balance("100,000,000,000"))
+            .account(contract(), Account::contract()
+                .storage(key("owner", &[]), address("busd_owner"))
+                .storage(key("paused", &[]), "false")
+                .storage(key("supply_c", &[]), address("busd_owner"))
+                .busd_metadata()
+                .storage(key("schema_version", &[]), "2")
+                .busd_balance(&address("holder_1"), "4900")
+                .busd_balance(&address("holder_2"), "100")
+                .busd_allowance(&address("holder_1"), &address("spender"), "10")
+                .storage(key("frozen", &[&address("holder_2")]), "true")
+                .storage(key("frozen", &[&address("wiped_holder")]), "true")
+                .total_supply("5000")))
+        .sc_call(ScCall::new("1", call(address("busd_owner"), "exportState").arg("0").arg("10"))
+            .comment("balances set before the holder registry are not listed")
+            .expect(Expect::ok()))
+        .sc_call(ScCall::new("2", call(address("holder_1"), "registerHolders").arg(address("holder_1")))
+            .comment("only the owner can register holders")
+            .expect(Expect::error("4", "only owner can register holders")))
+        .sc_call(ScCall::new("3", call(address("busd_owner"), "registerHolders")
+                .arg(address("holder_1"))
+                .arg(address("holder_2"))
+                .arg(address("spender"))
+                .arg(address("wiped_holder")))
+            .comment("addresses neither holding a balance nor frozen are skipped")
+            .expect(Expect::ok()))
+        .sc_call(ScCall::new("4", call(address("busd_owner"), "registerSpenders")
+                .arg(address("holder_1"))
+                .arg(address("spender")))
+            .expect(Expect::ok()))
+        .sc_call(ScCall::new("5", call(address("holder_1"), "exportState").arg("0").arg("10"))
+            .comment("only the owner can export")
+            .expect(Expect::error("4", "only owner can export state")))
+        .sc_call(ScCall::new("6", call(address("busd_owner"), "exportState").arg("0").arg("1"))
+            .comment("one page: address, balance and frozen flag of each holder")
+            .expect(Expect::ok()
+                .out(address("holder_1"))
+                .out("4900")
+                .out("false")))
+        .sc_call(ScCall::new("7", call(address("busd_owner"), "exportState").arg("1").arg("10"))
+            .expect(Expect::ok()
+                .out(address("holder_2"))
+                .out("100")
+                .out("true")))
+        .sc_call(ScCall::new("8", call(address("busd_owner"), "exportAllowances").arg(address("holder_1")))
+            .expect(Expect::ok()
+                .out(address("spender"))
+                .out("10")))
+        .sc_call(ScCall::new("9", call(address("holder_2"), "holderCount"))
+            .comment("anyone can list the holders")
+            .expect(Expect::ok().out("2")))
+        .sc_call(ScCall::new("10", call(address("holder_2"), "getHolders").arg("1").arg("10"))
+            .expect(Expect::ok().out(address("holder_2"))))
+        .sc_call(ScCall::new("11", call(address("holder_2"), "frozenCount"))
+            .expect(Expect::ok().out("2")))
+        .sc_call(ScCall::new("12", call(address("busd_owner"), "exportFrozen").arg("0").arg("10"))
+            .comment("frozen addresses, a wiped one without a balance included")
+            .expect(Expect::ok()
+                .out(address("holder_2"))
+                .out(address("wiped_holder"))))
+        .check_state(CheckState::new()
+            .account(address("busd_owner"), Account::new().nonce("*").balance("*"))
+            .account(address("holder_1"), Account::new().nonce("*").balance("*"))
+            .account(address("holder_2"), Account::new().nonce("*").balance("*"))
+            .account(contract(), Account::contract()
+                .storage(key("owner", &[]), address("busd_owner"))
+                .storage(key("paused", &[]), "false")
+                .storage(key("supply_c", &[]), address("busd_owner"))
+                .busd_metadata()
+                .storage(key("schema_version", &[]), "2")
+                .busd_balance(&address("holder_1"), "4900")
+                .busd_balance(&address("holder_2"), "100")
+                .busd_allowance(&address("holder_1"), &address("spender"), "10")
+                .storage(key("frozen", &[&address("holder_2")]), "true")
+                .storage(key("frozen", &[&address("wiped_holder")]), "true")
+                .total_supply("5000")
+                .busd_holders(&[&address("holder_1"), &address("holder_2")])
+                .busd_frozen(&[&address("holder_2"), &address("wiped_holder")])
+                .busd_spenders(&address("holder_1"), &[&address("spender")])))
+}
+
+fn import_state() -> Scenario {
+    Scenario::new("import state into a fresh deployment")
+        .set_state(SetState::new()
+            .account(address("busd_owner"), Account::new().balance("100,000,000,000"))
+            .account(address("holder_1"), Account::new().balance("100,000,000,000"))
+            .new_address(address("busd_owner"), "0", new_contract()))
+        .sc_deploy(ScCall::new("1", deploy_busd(address("busd_owner")).gas_limit("1,000,000,000"))
+            .comment("the new deployment starts paused")
+            .expect(Expect::ok().log(initialized_log(new_contract(), &address("busd_owner")))))
+        .sc_call(ScCall::new("2", new_call(address("holder_1"), "beginImport").arg("5000"))
+            .comment("only the owner can import")
+            .expect(Expect::error("4", "only owner can import state")))
+        .sc_call(ScCall::new("3", new_call(address("busd_owner"), "importState")
+                .arg(address("holder_1"))
+                .arg("4900")
+                .arg("false"))
+            .expect(Expect::error("4", "no import in progress")))
+        .sc_call(ScCall::new("4", new_call(address("busd_owner"), "beginImport").arg("5000"))
+            .comment("declare the total supply of the exported contract")
+            .expect(Expect::ok().log(Log::event(new_contract(), "ImportStarted").data("5000"))))
+        .sc_call(ScCall::new("5", new_call(address("busd_owner"), "beginImport").arg("5000"))
+            .expect(Expect::error("4", "can only import into a fresh, paused contract")))
+        .sc_call(ScCall::new("6", new_call(address("busd_owner"), "importState")
+                .arg(address("holder_1"))
+                .arg("4900")
+                .arg("false"))
+            .expect(Expect::ok()
+                .log(Log::event(new_contract(), "SupplyIncreased")
+                    .topic(address("holder_1"))
+                    .data("4900"))
+                .log(transfer_log(new_contract(), zero_address(), address("holder_1"), "4900"))))
+        .sc_call(ScCall::new("7", new_call(address("busd_owner"), "importAllowances")
+                .arg(address("holder_1"))
+                .arg(address("spender"))
+                .arg("10"))
+            .expect(Expect::ok()
+                .log(approve_log(new_contract(), address("holder_1"), address("spender"), "10"))))
+        .sc_call(ScCall::new("8", new_call(address("busd_owner"), "unpause"))
+            .comment("the imported balances do not add up to the declared total supply yet")
+            .expect(Expect::error("4", "imported balances do not add up to declared total supply")))
+        .sc_call(ScCall::new("9", new_call(address("busd_owner"), "importState")
+                .arg(address("holder_2"))
+                .arg("101")
+                .arg("true"))
+            .expect(Expect::error("4", "imported balances exceed declared total supply")))
+        .sc_call(ScCall::new("10", new_call(address("busd_owner"), "importState")
+                .arg(address("holder_2"))
+                .arg("100")
+                .arg("true"))
+            .expect(Expect::ok()
+                .log(Log::event(new_contract(), "SupplyIncreased")
+                    .topic(address("holder_2"))
+                    .data("100"))
+                .log(transfer_log(new_contract(), zero_address(), address("holder_2"), "100"))
+                .log(Log::event(new_contract(), "AddressFrozen")
+                    .topic(address("holder_2")))))
+        .sc_call(ScCall::new("11", new_call(address("busd_owner"), "importFrozen")
+                .arg(address("holder_2"))
+                .arg(address("wiped_holder")))
+            .comment("frozen addresses already imported are skipped")
+            .expect(Expect::ok()
+                .log(Log::event(new_contract(), "AddressFrozen")
+                    .topic(address("wiped_holder")))))
+        .sc_call(ScCall::new("12", new_call(address("busd_owner"), "unpause"))
+            .comment("the import is complete")
+            .expect(Expect::ok()
+                .log(Log::event(new_contract(), "Unpause")
+                    .topic(address("busd_owner")))))
+        .check_state(CheckState::new()
+            .account(address("busd_owner"), Account::new().nonce("*").balance("*"))
+            .account(address("holder_1"), Account::new().nonce("*").balance("*"))
+            .account(new_contract(), Account::contract()
+                .storage(key("owner", &[]), address("busd_owner"))
+                .storage(key("paused", &[]), "false")
+                .storage(key("supply_c", &[]), address("busd_owner"))
+                .storage(key("ap_role", &[]), "")
+                .storage(key("prop_owner", &[]), "")
+                .storage(key("pauser", &[]), "")
+                .busd_metadata()
+                .storage(key("schema_version", &[]), "2")
+                .busd_balance(&address("holder_1"), "4900")
+                .busd_balance(&address("holder_2"), "100")
+                .busd_allowance(&address("holder_1"), &address("spender"), "10")
+                .storage(key("frozen", &[&address("holder_2")]), "true")
+                .storage(key("frozen", &[&address("wiped_holder")]), "true")
+                .total_supply("5000")
+                .busd_holders(&[&address("holder_1"), &address("holder_2")])
+                .busd_frozen(&[&address("holder_2"), &address("wiped_holder")])
+                .busd_spenders(&address("holder_1"), &[&address("spender")])))
+}
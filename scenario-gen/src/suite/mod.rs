@@ -0,0 +1,131 @@
+//! The BUSD scenario suite, one module per folder under `tests`.
+
+pub use crate::*;
+
+mod erc20;
+mod errors;
+mod esdt_migration;
+mod events;
+mod freeze;
+mod init;
+mod large_transfer;
+mod migration;
+mod ownable;
+mod pause;
+mod reclaim;
+mod restriction;
+mod snapshot;
+mod supply;
+mod travel_rule;
+mod upgrade;
+mod velocity;
+
+/// All scenarios, with their path relative to the `tests` folder, without the `.scen.json` extension.
+pub fn all() -> Vec<(&'static str, Scenario)> {
+    let mut scenarios = Vec::new();
+    scenarios.extend(erc20::scenarios());
+    scenarios.extend(errors::scenarios());
+    scenarios.extend(esdt_migration::scenarios());
+    scenarios.extend(events::scenarios());
+    scenarios.extend(freeze::scenarios());
+    scenarios.extend(init::scenarios());
+    scenarios.extend(large_transfer::scenarios());
+    scenarios.extend(migration::scenarios());
+    scenarios.extend(ownable::scenarios());
+    scenarios.extend(pause::scenarios());
+    scenarios.extend(reclaim::scenarios());
+    scenarios.extend(restriction::scenarios());
+    scenarios.extend(snapshot::scenarios());
+    scenarios.extend(supply::scenarios());
+    scenarios.extend(travel_rule::scenarios());
+    scenarios.extend(upgrade::scenarios());
+    scenarios.extend(velocity::scenarios());
+    scenarios
+}
+
+/// Shorthands for the most common BUSD storage entries.
+pub trait BusdStorage {
+    fn busd_balance(self, holder: &str, balance: &str) -> Self;
+    fn busd_allowance(self, owner: &str, spender: &str, allowance: &str) -> Self;
+    fn total_supply(self, total_supply: &str) -> Self;
+    fn busd_metadata(self) -> Self;
+    fn busd_holders(self, holders: &[&str]) -> Self;
+    fn busd_frozen(self, addresses: &[&str]) -> Self;
+    fn busd_spenders(self, owner: &str, spenders: &[&str]) -> Self;
+}
+
+impl BusdStorage for Account {
+    fn busd_balance(self, holder: &str, balance: &str) -> Self {
+        self.storage(key("balance", &[holder]), balance)
+    }
+
+    fn busd_allowance(self, owner: &str, spender: &str, allowance: &str) -> Self {
+        self.storage(key("allowance", &[owner, spender]), allowance)
+    }
+
+    fn total_supply(self, total_supply: &str) -> Self {
+        self.storage(key("total_supply", &[]), total_supply)
+    }
+
+    /// The token metadata set by `deploy_busd`.
+    fn busd_metadata(self) -> Self {
+        self.storage(key("name", &[]), "``Binance USD")
+            .storage(key("symbol", &[]), "``BUSD")
+            .storage(key("decimals", &[]), "18")
+    }
+
+    /// The holder registry, holders in the order they were registered.
+    fn busd_holders(self, holders: &[&str]) -> Self {
+        let mut account = self.storage(key("holders_len", &[]), holders.len().to_string());
+        for (i, holder) in holders.iter().enumerate() {
+            let index = (i + 1).to_string();
+            account = account
+                .storage(key("holder_at", &[&index]), some(holder))
+                .storage(key("holder_idx", &[holder]), index);
+        }
+        account
+    }
+
+    /// The frozen address registry, addresses in the order they were registered.
+    fn busd_frozen(self, addresses: &[&str]) -> Self {
+        let mut account = self.storage(key("frozen_len", &[]), addresses.len().to_string());
+        for (i, address) in addresses.iter().enumerate() {
+            let index = (i + 1).to_string();
+            account = account
+                .storage(key("frozen_at", &[&index]), some(address))
+                .storage(key("frozen_idx", &[address]), index);
+        }
+        account
+    }
+
+    /// The spenders listed for an owner, in the order they were approved.
+    fn busd_spenders(self, owner: &str, spenders: &[&str]) -> Self {
+        self.storage(key("spenders", &[owner]), spenders.join("|"))
+    }
+}
+
+/// Deploys the contract as BUSD: `Binance USD`, with 18 decimals.
+pub fn deploy_busd<F: Into<String>>(from: F) -> Tx {
+    Tx::deploy(from).arg("``Binance USD").arg("``BUSD").arg("18")
+}
+
+/// The `Initialized` event of a deployment from `owner`, which is also the supply controller, starting paused,
+/// without asset protection role or pauser.
+pub fn initialized_log<C: Into<String>>(contract: C, owner: &str) -> Log {
+    Log::event(contract, "Initialized")
+        .topic(owner)
+        .topic(owner)
+        .topic(zero_address())
+        .topic(zero_address())
+        .data("true")
+}
+
+/// The ERC20 `Transfer` event.
+pub fn transfer_log<C: Into<String>, F: Into<String>, T: Into<String>>(contract: C, from: F, to: T, amount: &str) -> Log {
+    Log::event(contract, "Transfer").topic(from).topic(to).data(amount)
+}
+
+/// The ERC20 `Approval` event.
+pub fn approve_log<C: Into<String>, O: Into<String>, S: Into<String>>(contract: C, owner: O, spender: S, amount: &str) -> Log {
+    Log::event(contract, "Approval").topic(owner).topic(spender).data(amount)
+}
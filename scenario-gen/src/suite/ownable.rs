@@ -0,0 +1,255 @@
+use super::*;
+
+pub fn scenarios() -> Vec<(&'static str, Scenario)> {
+    vec![
+        ("ownable/ownable_claim", ownable_claim()),
+        ("ownable/ownable_claim_bad_caller_1", ownable_claim_bad_caller_1()),
+        ("ownable/ownable_claim_bad_caller_2", ownable_claim_bad_caller_2()),
+        ("ownable/ownable_claim_no_one_proposed", ownable_claim_no_one_proposed()),
+        ("ownable/ownable_disregard_1", ownable_disregard_1()),
+        ("ownable/ownable_disregard_2", ownable_disregard_2()),
+        ("ownable/ownable_disregard_bad_caller", ownable_disregard_bad_caller()),
+        ("ownable/ownable_disregard_no_one", ownable_disregard_no_one()),
+        ("ownable/ownable_init", ownable_init()),
+        ("ownable/ownable_propose", ownable_propose()),
+        ("ownable/ownable_propose_bad_1", ownable_propose_bad_1()),
+        ("ownable/ownable_propose_bad_2", ownable_propose_bad_2()),
+        ("ownable/ownable_propose_bad_3", ownable_propose_bad_3()),
+        ("ownable/ownable_propose_change", ownable_propose_change()),
+        ("ownable/ownable_propose_twice", ownable_propose_twice()),
+    ]
+}
+
+fn contract() -> String {
+    address("new_busd_contract")
+}
+
+fn call<S: Into<String>>(from: S, function: &str) -> Tx {
+    Tx::call(from, contract(), function).gas_limit("100,000")
+}
+
+fn accounts_before_deploy() -> SetState {
+    SetState::new()
+        .account(address("busd_owner"), Account::new().balance("100,000,000,000"))
+        .account(address("proposed"), Account::new().balance("100,000,000,000"))
+        .account(address("someone_else"), Account::new().balance("100,000,000,000"))
+        .new_address(address("busd_owner"), "0", contract())
+}
+
+fn deploy() -> ScCall {
+    ScCall::new("1", deploy_busd(address("busd_owner")).gas_limit("100,000"))
+        .comment("deploy contract")
+        .expect(Expect::ok().log(initialized_log(contract(), &address("busd_owner"))))
+}
+
+fn propose_owner() -> ScCall {
+    ScCall::new("2", call(address("busd_owner"), "proposeOwner").arg(address("proposed")))
+        .comment("propose new owner")
+        .expect(Expect::ok()
+            .log(Log::event(contract(), "OwnershipTransferProposed")
+                .topic(address("busd_owner"))
+                .topic(address("proposed"))))
+}
+
+fn check_proposed_owner() -> ScCall {
+    ScCall::new("3", call(address("someone_else"), "getProposedOwner"))
+        .comment("get proposed owner")
+        .expect(Expect::ok().out(address("proposed")))
+}
+
+fn check_owner_unchanged() -> ScCall {
+    ScCall::new("2", call(address("someone_else"), "getContractOwner"))
+        .comment("get owner")
+        .expect(Expect::ok().out(address("busd_owner")))
+}
+
+fn check_no_proposed_owner() -> ScCall {
+    ScCall::new("3", call(address("someone_else"), "getProposedOwner"))
+        .comment("get proposed owner - no one")
+}
+
+fn ownable_claim() -> Scenario {
+    Scenario::new("ownable test")
+        .set_state(accounts_before_deploy())
+        .sc_deploy(deploy())
+        .sc_call(propose_owner())
+        .sc_call(check_proposed_owner())
+        .sc_call(ScCall::new("4", call(address("proposed"), "claimOwnership"))
+            .comment("proposed owner claims ownership")
+            .expect(Expect::ok()
+                .log(Log::event(contract(), "OwnershipTransferred")
+                    .topic(address("busd_owner"))
+                    .topic(address("proposed")))))
+        .sc_call(ScCall::new("2", call(address("someone_else"), "getContractOwner"))
+            .comment("get owner")
+            .expect(Expect::ok().out(address("proposed"))))
+}
+
+fn ownable_claim_bad_caller_1() -> Scenario {
+    Scenario::new("ownable test")
+        .set_state(accounts_before_deploy())
+        .sc_deploy(deploy())
+        .sc_call(propose_owner())
+        .sc_call(check_proposed_owner())
+        .sc_call(ScCall::new("4", call(address("someone_else"), "claimOwnership"))
+            .comment("someone else claims ownership - illegal")
+            .expect(Expect::error("4", "only proposed owner can claim ownership")))
+}
+
+fn ownable_claim_bad_caller_2() -> Scenario {
+    Scenario::new("ownable test")
+        .set_state(accounts_before_deploy())
+        .sc_deploy(deploy())
+        .sc_call(propose_owner())
+        .sc_call(check_proposed_owner())
+        .sc_call(ScCall::new("4", call(address("busd_owner"), "claimOwnership"))
+            .comment("someone else claims ownership - illegal, even if it is the old owner")
+            .expect(Expect::error("4", "only proposed owner can claim ownership")))
+}
+
+fn ownable_claim_no_one_proposed() -> Scenario {
+    Scenario::new("ownable test")
+        .set_state(accounts_before_deploy())
+        .sc_deploy(deploy())
+        .sc_call(ScCall::new("3", call(address("someone_else"), "getProposedOwner"))
+            .comment("get proposed owner"))
+        .sc_call(ScCall::new("4", call(address("busd_owner"), "claimOwnership"))
+            .comment("proposed owner claims ownership")
+            .expect(Expect::error("4", "no owner proposed")))
+        .sc_call(check_owner_unchanged())
+}
+
+fn ownable_disregard_1() -> Scenario {
+    Scenario::new("ownable test")
+        .set_state(accounts_before_deploy())
+        .sc_deploy(deploy())
+        .sc_call(propose_owner())
+        .sc_call(check_proposed_owner())
+        .sc_call(ScCall::new("4", call(address("busd_owner"), "disregardProposedOwner"))
+            .comment("disregard - by old owner")
+            .expect(Expect::ok()
+                .log(Log::event(contract(), "OwnershipTransferDisregarded")
+                    .topic(address("proposed")))))
+}
+
+fn ownable_disregard_2() -> Scenario {
+    Scenario::new("ownable test")
+        .set_state(accounts_before_deploy())
+        .sc_deploy(deploy())
+        .sc_call(propose_owner())
+        .sc_call(check_proposed_owner())
+        .sc_call(ScCall::new("4", call(address("proposed"), "disregardProposedOwner"))
+            .comment("disregard - by the proposed")
+            .expect(Expect::ok()
+                .log(Log::event(contract(), "OwnershipTransferDisregarded")
+                    .topic(address("proposed")))))
+}
+
+fn ownable_disregard_bad_caller() -> Scenario {
+    Scenario::new("ownable test")
+        .set_state(accounts_before_deploy())
+        .sc_deploy(deploy())
+        .sc_call(propose_owner())
+        .sc_call(check_proposed_owner())
+        .sc_call(ScCall::new("4", call(address("someone_else"), "disregardProposedOwner"))
+            .comment("disregard - by someone who isn't allowed")
+            .expect(Expect::error("4", "only proposedOwner or owner can disregard proposed owner")))
+}
+
+fn ownable_disregard_no_one() -> Scenario {
+    Scenario::new("ownable test")
+        .set_state(accounts_before_deploy())
+        .sc_deploy(deploy())
+        .sc_call(ScCall::new("4", call(address("busd_owner"), "disregardProposedOwner"))
+            .comment("disregard - but no-one is proposed")
+            .expect(Expect::error("4", "can only disregard a proposed owner that was previously set")))
+}
+
+fn ownable_init() -> Scenario {
+    Scenario::new("ownable test")
+        .set_state(SetState::new()
+            .account(address("busd_owner"), Account::new().balance("100,000,000,000"))
+            .account(address("someone_else"), Account::new().balance("100,000,000,000"))
+            .new_address(address("busd_owner"), "0", contract()))
+        .sc_deploy(deploy())
+        .sc_call(check_owner_unchanged())
+        .sc_call(check_no_proposed_owner())
+}
+
+fn ownable_propose() -> Scenario {
+    Scenario::new("ownable test")
+        .set_state(accounts_before_deploy())
+        .sc_deploy(deploy())
+        .sc_call(propose_owner())
+        .sc_call(check_proposed_owner())
+}
+
+fn ownable_propose_bad_1() -> Scenario {
+    Scenario::new("ownable test")
+        .set_state(accounts_before_deploy())
+        .sc_deploy(deploy())
+        .sc_call(ScCall::new("2", call(address("someone_else"), "proposeOwner").arg(address("proposed")))
+            .comment("propose new owner - but not by current owner")
+            .expect(Expect::error("4", "only owner can propose another owner")))
+        .sc_call(check_no_proposed_owner())
+}
+
+fn ownable_propose_bad_2() -> Scenario {
+    Scenario::new("ownable test")
+        .set_state(accounts_before_deploy())
+        .sc_deploy(deploy())
+        .sc_call(ScCall::new("2", call(address("busd_owner"), "proposeOwner").arg(address("busd_owner")))
+            .comment("owner proposes self")
+            .expect(Expect::error("4", "current owner cannot propose itself")))
+        .sc_call(check_no_proposed_owner())
+}
+
+fn ownable_propose_bad_3() -> Scenario {
+    Scenario::new("ownable test")
+        .set_state(accounts_before_deploy())
+        .sc_deploy(deploy())
+        .sc_call(ScCall::new("2", call(address("busd_owner"), "proposeOwner").arg(""))
+            .comment("owner proposes some invalid value - should be refused by the framework before starting method itself")
+            .expect(Expect::error("4", "argument decode error (proposed_owner): input too short")))
+        .sc_call(check_no_proposed_owner())
+}
+
+fn ownable_propose_change() -> Scenario {
+    Scenario::new("ownable test")
+        .set_state(SetState::new()
+            .account(address("busd_owner"), Account::new().balance("100,000,000,000"))
+            .account("``proposed_#1___________________s1", Account::new().balance("100,000,000,000"))
+            .account("``proposed_#2___________________s1", Account::new().balance("100,000,000,000"))
+            .account(address("someone_else"), Account::new().balance("100,000,000,000"))
+            .new_address(address("busd_owner"), "0", contract()))
+        .sc_deploy(deploy())
+        .sc_call(ScCall::new("2", call(address("busd_owner"), "proposeOwner").arg("``proposed_#1___________________s1"))
+            .comment("propose new owner")
+            .expect(Expect::ok()
+                .log(Log::event(contract(), "OwnershipTransferProposed")
+                    .topic(address("busd_owner"))
+                    .topic("``proposed_#1___________________s1"))))
+        .sc_call(ScCall::new("3", call(address("someone_else"), "getProposedOwner"))
+            .comment("get proposed owner")
+            .expect(Expect::ok().out("``proposed_#1___________________s1")))
+        .sc_call(ScCall::new("4", call(address("busd_owner"), "proposeOwner").arg("``proposed_#2___________________s1"))
+            .comment("propose new owner - again the same")
+            .expect(Expect::ok()
+                .log(Log::event(contract(), "OwnershipTransferProposed")
+                    .topic(address("busd_owner"))
+                    .topic("``proposed_#2___________________s1"))))
+        .sc_call(ScCall::new("3", call(address("someone_else"), "getProposedOwner"))
+            .comment("get proposed owner")
+            .expect(Expect::ok().out("``proposed_#2___________________s1")))
+}
+
+fn ownable_propose_twice() -> Scenario {
+    Scenario::new("ownable test")
+        .set_state(accounts_before_deploy())
+        .sc_deploy(deploy())
+        .sc_call(propose_owner())
+        .sc_call(check_proposed_owner())
+        .sc_call(ScCall::new("4", call(address("busd_owner"), "proposeOwner").arg(address("proposed")))
+            .comment("propose new owner - again the same")
+            .expect(Expect::error("4", "caller already is proposed owner")))
+}
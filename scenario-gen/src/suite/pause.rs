@@ -0,0 +1,207 @@
+use super::*;
+
+pub fn scenarios() -> Vec<(&'static str, Scenario)> {
+    vec![
+        ("pause/pause_check_false", pause_check_false()),
+        ("pause/pause_check_true", pause_check_true()),
+        ("pause/pause_storage", pause_storage()),
+        ("pause/transfer_vs_pause", transfer_vs_pause()),
+        ("pause/pauser", pauser()),
+    ]
+}
+
+fn contract() -> String {
+    address("busd_contract")
+}
+
+fn call<S: Into<String>>(from: S, function: &str) -> Tx {
+    Tx::call(from, contract(), function)
+}
+
+fn unpaused_state() -> SetState {
+    SetState::new()
+        .account(address("busd_owner"), Account::new().balance("100,000,000,000"))
+        .account(address("holder_1"), Account::new().balance("100,000,000,000"))
+        .account(address("holder_2"), Account::new().balance("100,000,000,000"))
+        .account(contract(), Account::contract()
+            .storage(key("owner", &[]), address("busd_owner"))
+            .storage(key("paused", &[]), "false")
+            .storage(key("supply_c", &[]), address("busd_owner"))
+            .busd_balance(&address("holder_1"), "500"))
+}
+
+fn pause_check_false() -> Scenario {
+    Scenario::new("pause test")
+        .set_state(SetState::new()
+            .account(address("busd_owner"), Account::new().balance("100,000,000,000"))
+            .account(address("holder_1"), Account::new().balance("100,000,000,000"))
+            .account(address("holder_2"), Account::new().balance("100,000,000,000"))
+            .account(contract(), Account::contract()
+                .storage(key("owner", &[]), address("busd_owner"))
+                .storage(key("paused", &[]), "true")
+                .storage(key("supply_c", &[]), address("busd_owner"))
+                .busd_balance(&address("holder_1"), "500")))
+        .sc_call(ScCall::new("1", Tx::call(address("holder_1"), contract(), "isPaused").gas_limit("100,000"))
+            .expect(Expect::ok().out("true")))
+}
+
+fn pause_check_true() -> Scenario {
+    Scenario::new("pause test")
+        .set_state(unpaused_state())
+        .sc_call(ScCall::new("1", Tx::call(address("holder_1"), contract(), "isPaused").gas_limit("100,000"))
+            .expect(Expect::ok().out("false")))
+}
+
+fn pause_storage() -> Scenario {
+    Scenario::new("pause test")
+        .set_state(SetState::new()
+            .account(address("busd_owner"), Account::new().balance("100,000,000,000"))
+            .new_address(address("busd_owner"), "0", address("new_busd_contract")))
+        .sc_deploy(ScCall::new("1", deploy_busd(address("busd_owner")).gas_limit("100,000"))
+            .comment("deploy contract")
+            .expect(Expect::ok().log(initialized_log(address("new_busd_contract"), &address("busd_owner")))))
+        .check_state(CheckState::new()
+            .account(address("busd_owner"), Account::new().nonce("1").balance("*"))
+            .account(address("new_busd_contract"), Account::contract()
+                .storage(key("owner", &[]), address("busd_owner"))
+                .storage(key("paused", &[]), "true")
+                .storage(key("supply_c", &[]), address("busd_owner"))
+                .storage(key("ap_role", &[]), "")
+                .storage(key("prop_owner", &[]), "")
+                .storage(key("pauser", &[]), "")
+                .busd_metadata()
+                .storage(key("schema_version", &[]), "2")))
+        .sc_call(ScCall::new("2", Tx::call(address("busd_owner"), address("new_busd_contract"), "pause").gas_limit("100,000"))
+            .comment("cannot pause, already paused")
+            .expect(Expect::error("4", "already paused")))
+        .sc_call(ScCall::new("3", Tx::call(address("busd_owner"), address("new_busd_contract"), "unpause").gas_limit("100,000"))
+            .comment("unpause")
+            .expect(Expect::ok()
+                .log(Log::event(address("new_busd_contract"), "Unpause")
+                    .topic(address("busd_owner")))))
+        .check_state(CheckState::new()
+            .account(address("busd_owner"), Account::new().nonce("*").balance("*"))
+            .account(address("new_busd_contract"), Account::contract()
+                .storage(key("owner", &[]), address("busd_owner"))
+                .storage(key("paused", &[]), "false")
+                .storage(key("supply_c", &[]), address("busd_owner"))
+                .storage(key("ap_role", &[]), "")
+                .storage(key("prop_owner", &[]), "")
+                .storage(key("pauser", &[]), "")
+                .busd_metadata()
+                .storage(key("schema_version", &[]), "2")))
+        .sc_call(ScCall::new("4", Tx::call(address("busd_owner"), address("new_busd_contract"), "unpause").gas_limit("100,000"))
+            .expect(Expect::error("4", "already unpaused")))
+        .sc_call(ScCall::new("5", Tx::call(address("busd_owner"), address("new_busd_contract"), "pause").gas_limit("100,000"))
+            .comment("pause")
+            .expect(Expect::ok()
+                .log(Log::event(address("new_busd_contract"), "Pause")
+                    .topic(address("busd_owner")))))
+        .check_state(CheckState::new()
+            .account(address("busd_owner"), Account::new().nonce("*").balance("*"))
+            .account(address("new_busd_contract"), Account::contract()
+                .storage(key("owner", &[]), address("busd_owner"))
+                .storage(key("paused", &[]), "true")
+                .storage(key("supply_c", &[]), address("busd_owner"))
+                .storage(key("ap_role", &[]), "")
+                .storage(key("prop_owner", &[]), "")
+                .storage(key("pauser", &[]), "")
+                .busd_metadata()
+                .storage(key("schema_version", &[]), "2")))
+}
+
+fn transfer_vs_pause() -> Scenario {
+    Scenario::new("pause test")
+        .set_state(unpaused_state())
+        .sc_call(ScCall::new("1", Tx::call(address("holder_1"), contract(), "transfer")
+                .arg(address("holder_2"))
+                .arg("10")
+                .gas_limit("100,000"))
+            .comment("unpaused, transfer ok")
+            .expect(Expect::ok()
+                .log(transfer_log(contract(), address("holder_1"), address("holder_2"), "10"))))
+        .sc_call(ScCall::new("2", call(address("holder_2"), "approve").arg(address("holder_1")).arg("10"))
+            .comment("unpaused, allowance ok")
+            .expect(Expect::ok()
+                .log(approve_log(contract(), address("holder_2"), address("holder_1"), "10"))))
+        .sc_call(ScCall::new("3", call(address("busd_owner"), "pause"))
+            .comment("pause")
+            .expect(Expect::ok()
+                .log(Log::event(contract(), "Pause")
+                    .topic(address("busd_owner")))))
+        .sc_call(ScCall::new("4", call(address("holder_1"), "transfer").arg(address("holder_2")).arg("10"))
+            .comment("paused, no transfer")
+            .expect(Expect::error("4", "paused")))
+        .sc_call(ScCall::new("5", call(address("holder_1"), "approve").arg(address("holder_2")).arg("10"))
+            .comment("paused, no allowance")
+            .expect(Expect::error("4", "paused")))
+        .sc_call(ScCall::new("6", call(address("holder_1"), "transferFrom")
+                .arg(address("holder_2"))
+                .arg(address("holder_1"))
+                .arg("10"))
+            .comment("paused, no transferFrom")
+            .expect(Expect::error("4", "paused")))
+        .sc_call(ScCall::new("7", call(address("busd_owner"), "unpause"))
+            .comment("pause")
+            .expect(Expect::ok()
+                .log(Log::event(contract(), "Unpause")
+                    .topic(address("busd_owner")))))
+        .sc_call(ScCall::new("8", call(address("holder_1"), "transfer").arg(address("holder_2")).arg("10"))
+            .comment("unpaused, transfer ok again")
+            .expect(Expect::ok()
+                .log(transfer_log(contract(), address("holder_1"), address("holder_2"), "10"))))
+        .sc_call(ScCall::new("9", call(address("holder_2"), "approve").arg(address("holder_1")).arg("10"))
+            .comment("unpaused, allowance ok again")
+            .expect(Expect::ok()
+                .log(approve_log(contract(), address("holder_2"), address("holder_1"), "10"))))
+        .sc_call(ScCall::new("10", call(address("holder_1"), "transferFrom")
+                .arg(address("holder_2"))
+                .arg(address("holder_1"))
+                .arg("10"))
+            .comment("unpaused, transferFrom ok now")
+            .expect(Expect::ok()
+                .log(transfer_log(contract(), address("holder_2"), address("holder_1"), "10"))))
+}
+
+fn pauser() -> Scenario {
+    Scenario::new("pauser")
+        .set_state(unpaused_state()
+            .account(address("pauser"), Account::new().balance("100,000,000,000")))
+        .sc_call(ScCall::new("1", call(address("holder_1"), "setPauser").arg(address("pauser")))
+            .comment("only the owner can set the pauser")
+            .expect(Expect::error("4", "only owner can change pauser")))
+        .sc_call(ScCall::new("2", call(address("holder_1"), "pause"))
+            .comment("until then, only the owner can pause")
+            .expect(Expect::error("4", "only pauser can pause")))
+        .sc_call(ScCall::new("3", call(address("busd_owner"), "setPauser").arg(address("pauser")))
+            .expect(Expect::ok()
+                .log(Log::event(contract(), "PauserSet")
+                    .topic(zero_address())
+                    .topic(address("pauser")))))
+        .sc_call(ScCall::new("4", call(address("holder_1"), "getPauser"))
+            .expect(Expect::ok().out(address("pauser"))))
+        .sc_call(ScCall::new("5", call(address("busd_owner"), "pause"))
+            .comment("once set, only the pauser can pause")
+            .expect(Expect::error("4", "only pauser can pause")))
+        .sc_call(ScCall::new("6", call(address("pauser"), "pause"))
+            .expect(Expect::ok()
+                .log(Log::event(contract(), "Pause")
+                    .topic(address("pauser")))))
+        .sc_call(ScCall::new("7", call(address("busd_owner"), "unpause"))
+            .expect(Expect::error("4", "only pauser can unpause")))
+        .sc_call(ScCall::new("8", call(address("pauser"), "unpause"))
+            .expect(Expect::ok()
+                .log(Log::event(contract(), "Unpause")
+                    .topic(address("pauser")))))
+        .check_state(CheckState::new()
+            .account(address("busd_owner"), Account::new().nonce("*").balance("*"))
+            .account(address("holder_1"), Account::new().nonce("*").balance("*"))
+            .account(address("holder_2"), Account::new().nonce("*").balance("*"))
+            .account(address("pauser"), Account::new().nonce("*").balance("*"))
+            .account(contract(), Account::contract()
+                .storage(key("owner", &[]), address("busd_owner"))
+                .storage(key("paused", &[]), "false")
+                .storage(key("supply_c", &[]), address("busd_owner"))
+                .storage(key("pauser", &[]), some(&address("pauser")))
+                .busd_balance(&address("holder_1"), "500")))
+}
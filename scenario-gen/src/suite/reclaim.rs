@@ -0,0 +1,97 @@
+use super::*;
+
+pub fn scenarios() -> Vec<(&'static str, Scenario)> {
+    vec![
+        ("reclaim/reclaim", reclaim()),
+        ("reclaim/reclaim_bad_caller", reclaim_bad_caller()),
+        ("reclaim/reclaim_zero", reclaim_zero()),
+    ]
+}
+
+fn contract() -> String {
+    address("busd")
+}
+
+fn call<S: Into<String>>(from: S, function: &str) -> Tx {
+    Tx::call(from, contract(), function).gas_limit("0x100000")
+}
+
+fn reclaim_state() -> SetState {
+    SetState::new()
+        .account(address("busd_owner"), Account::new().balance("100,000,000,000"))
+        .account(address("someone_else"), Account::new().balance("100,000,000,000"))
+        .account(contract(), Account::contract()
+            .total_supply("12345000")
+            .storage(key("owner", &[]), address("busd_owner"))
+            .storage(key("paused", &[]), "false")
+            .storage(key("supply_c", &[]), address("busd_owner"))
+            .busd_balance(&address("someone_else"), "12345000"))
+}
+
+fn transfer_to_contract() -> ScCall {
+    ScCall::new("1", call(address("someone_else"), "transfer").arg(contract()).arg("2340000"))
+        .expect(Expect::ok()
+            .log(transfer_log(contract(), address("someone_else"), contract(), "2340000")))
+}
+
+fn check_contract_balance() -> ScCall {
+    ScCall::new("2", call(address("someone_else"), "balanceOf").arg(contract()))
+        .comment("check the contract balance")
+        .expect(Expect::ok().out("2340000"))
+}
+
+fn check_contract_balance_reclaimed() -> ScCall {
+    ScCall::new("4", call(address("someone_else"), "balanceOf").arg(contract()))
+        .comment("check the contract balance")
+        .expect(Expect::ok().out("0"))
+}
+
+fn reclaim() -> Scenario {
+    Scenario::new("reclaim test")
+        .set_state(reclaim_state())
+        .sc_call(transfer_to_contract())
+        .sc_call(check_contract_balance())
+        .sc_call(ScCall::new("also_pause", Tx::call(address("busd_owner"), contract(), "pause").gas_limit("100,000"))
+            .comment("pausing shouldn't matter")
+            .expect(Expect::ok().any_logs()))
+        .sc_call(ScCall::new("3", call(address("busd_owner"), "reclaimBUSD"))
+            .comment("reclaim!")
+            .expect(Expect::ok()
+                .log(Log::event(contract(), "Reclaimed")
+                    .topic(address("busd_owner"))
+                    .data("2340000"))
+                .log(transfer_log(contract(), contract(), address("busd_owner"), "2340000"))))
+        .sc_call(check_contract_balance_reclaimed())
+        .sc_call(ScCall::new("5", call(address("busd_owner"), "balanceOf").arg(address("busd_owner")))
+            .comment("check owner's balance")
+            .expect(Expect::ok().out("2340000")))
+}
+
+fn reclaim_bad_caller() -> Scenario {
+    Scenario::new("reclaim test")
+        .set_state(reclaim_state())
+        .sc_call(transfer_to_contract())
+        .sc_call(check_contract_balance())
+        .sc_call(ScCall::new("3", call(address("someone_else"), "reclaimBUSD"))
+            .comment("reclaim!")
+            .expect(Expect::error("4", "only owner can reclaim")))
+}
+
+fn reclaim_zero() -> Scenario {
+    Scenario::new("reclaim test")
+        .set_state(reclaim_state())
+        .sc_call(ScCall::new("2", call(address("someone_else"), "balanceOf").arg(contract()))
+            .comment("check the contract balance")
+            .expect(Expect::ok().out("0")))
+        .sc_call(ScCall::new("3", call(address("busd_owner"), "reclaimBUSD"))
+            .comment("reclaim!")
+            .expect(Expect::ok()
+                .log(Log::event(contract(), "Reclaimed")
+                    .topic(address("busd_owner"))
+                    .data("0"))
+                .log(transfer_log(contract(), contract(), address("busd_owner"), "0"))))
+        .sc_call(check_contract_balance_reclaimed())
+        .sc_call(ScCall::new("5", call(address("busd_owner"), "balanceOf").arg(address("busd_owner")))
+            .comment("check owner's balance")
+            .expect(Expect::ok().out("0")))
+}
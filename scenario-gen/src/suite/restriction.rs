@@ -0,0 +1,176 @@
+use super::*;
+
+pub fn scenarios() -> Vec<(&'static str, Scenario)> {
+    vec![
+        ("restriction/detect_transfer_restriction", detect_transfer_restriction()),
+        ("restriction/detect_transfer_restriction_paused", detect_transfer_restriction_paused()),
+        ("restriction/message_for_transfer_restriction", message_for_transfer_restriction()),
+        ("restriction/transfer_vs_restriction", transfer_vs_restriction()),
+    ]
+}
+
+fn contract() -> String {
+    address("busd_contract")
+}
+
+fn call<S: Into<String>>(from: S, function: &str) -> Tx {
+    Tx::call(from, contract(), function)
+}
+
+fn restriction_state() -> SetState {
+    SetState::new()
+        .account(address("busd_owner"), Account::new().balance("100,000,000,000"))
+        .account(address("holder_1"), Account::new().balance("100,000,000,000"))
+        .account(address("holder_2"), Account::new().balance("100,000,000,000"))
+        .account(address("holder_frozen"), Account::new().balance("100,000,000,000"))
+        .account(address("spender"), Account::new().balance("100,000,000,000"))
+        .account(contract(), Account::contract()
+            .storage(key("owner", &[]), address("busd_owner"))
+            .storage(key("paused", &[]), "false")
+            .storage(key("supply_c", &[]), address("busd_owner"))
+            .storage(key("frozen", &[&address("holder_frozen")]), "true")
+            .busd_balance(&address("holder_1"), "500")
+            .busd_balance(&address("holder_frozen"), "500")
+            .busd_allowance(&address("holder_1"), &address("spender"), "100"))
+}
+
+fn detect_transfer_restriction() -> Scenario {
+    Scenario::new("detect transfer restriction")
+        .set_state(restriction_state())
+        .sc_call(ScCall::new("1", call(address("spender"), "detectTransferRestriction")
+                .arg(address("holder_1"))
+                .arg(address("holder_2"))
+                .arg("500"))
+            .comment("no restriction")
+            .expect(Expect::ok().out("0")))
+        .sc_call(ScCall::new("2", call(address("spender"), "detectTransferRestriction")
+                .arg(address("holder_frozen"))
+                .arg(address("holder_2"))
+                .arg("10"))
+            .comment("sender frozen")
+            .expect(Expect::ok().out("2")))
+        .sc_call(ScCall::new("3", call(address("spender"), "detectTransferRestriction")
+                .arg(address("holder_1"))
+                .arg(address("holder_frozen"))
+                .arg("10"))
+            .comment("recipient frozen")
+            .expect(Expect::ok().out("3")))
+        .sc_call(ScCall::new("4", call(address("spender"), "detectTransferRestriction")
+                .arg(address("holder_1"))
+                .arg(address("holder_2"))
+                .arg("501"))
+            .comment("insufficient funds")
+            .expect(Expect::ok().out("6")))
+        .sc_call(ScCall::new("5", call(address("spender"), "detectTransferRestriction")
+                .arg(address("holder_1"))
+                .arg(address("holder_2"))
+                .arg("101"))
+            .comment("allowance is irrelevant for detectTransferRestriction")
+            .expect(Expect::ok().out("0")))
+}
+
+fn detect_transfer_restriction_paused() -> Scenario {
+    Scenario::new("detect transfer restriction while paused")
+        .set_state(SetState::new()
+            .account(address("busd_owner"), Account::new().balance("100,000,000,000"))
+            .account(address("holder_1"), Account::new().balance("100,000,000,000"))
+            .account(address("holder_2"), Account::new().balance("100,000,000,000"))
+            .account(address("holder_frozen"), Account::new().balance("100,000,000,000"))
+            .account(address("spender"), Account::new().balance("100,000,000,000"))
+            .account(contract(), Account::contract()
+                .storage(key("owner", &[]), address("busd_owner"))
+                .storage(key("paused", &[]), "true")
+                .storage(key("supply_c", &[]), address("busd_owner"))
+                .storage(key("frozen", &[&address("holder_frozen")]), "true")
+                .busd_balance(&address("holder_1"), "500")
+                .busd_balance(&address("holder_frozen"), "500")
+                .busd_allowance(&address("holder_1"), &address("spender"), "100")))
+        .sc_call(ScCall::new("1", call(address("spender"), "detectTransferRestriction")
+                .arg(address("holder_frozen"))
+                .arg(address("holder_2"))
+                .arg("501"))
+            .comment("paused takes precedence over everything else")
+            .expect(Expect::ok().out("1")))
+        .sc_call(ScCall::new("2", call(address("holder_1"), "transfer").arg(address("holder_2")).arg("10"))
+            .comment("transfer fails with the same reason")
+            .expect(Expect::error("0x04", "paused")))
+}
+
+fn message_for_transfer_restriction() -> Scenario {
+    Scenario::new("message for transfer restriction")
+        .set_state(restriction_state())
+        .sc_call(ScCall::new("1", call(address("spender"), "messageForTransferRestriction").arg("0"))
+            .expect(Expect::ok().out("``no restriction")))
+        .sc_call(ScCall::new("2", call(address("spender"), "messageForTransferRestriction").arg("1"))
+            .expect(Expect::ok().out("``transfers are paused")))
+        .sc_call(ScCall::new("3", call(address("spender"), "messageForTransferRestriction").arg("2"))
+            .expect(Expect::ok().out("``sender address is frozen")))
+        .sc_call(ScCall::new("4", call(address("spender"), "messageForTransferRestriction").arg("3"))
+            .expect(Expect::ok().out("``recipient address is frozen")))
+        .sc_call(ScCall::new("5", call(address("spender"), "messageForTransferRestriction").arg("4"))
+            .expect(Expect::ok().out("``spender address is frozen")))
+        .sc_call(ScCall::new("6", call(address("spender"), "messageForTransferRestriction").arg("5"))
+            .expect(Expect::ok().out("``amount exceeds the spender allowance")))
+        .sc_call(ScCall::new("7", call(address("spender"), "messageForTransferRestriction").arg("6"))
+            .expect(Expect::ok().out("``amount exceeds the sender balance")))
+        .sc_call(ScCall::new("8", call(address("spender"), "messageForTransferRestriction").arg("7"))
+            .expect(Expect::ok().out("``amount exceeds the sender daily transfer limit")))
+        .sc_call(ScCall::new("9", call(address("spender"), "messageForTransferRestriction").arg("8"))
+            .expect(Expect::ok().out("``travel rule metadata required between VASPs")))
+        .sc_call(ScCall::new("10", call(address("spender"), "messageForTransferRestriction").arg("9"))
+            .expect(Expect::ok().out("``unknown restriction code")))
+}
+
+fn transfer_vs_restriction() -> Scenario {
+    Scenario::new("transfer and transferFrom agree with detectTransferRestriction")
+        .set_state(restriction_state())
+        .sc_call(ScCall::new("1", call(address("spender"), "detectTransferRestriction")
+                .arg(address("holder_1"))
+                .arg(address("holder_2"))
+                .arg("501"))
+            .comment("restricted by insufficient funds")
+            .expect(Expect::ok().out("6")))
+        .sc_call(ScCall::new("2", call(address("holder_1"), "transfer").arg(address("holder_2")).arg("501"))
+            .comment("transfer fails with the same reason")
+            .expect(Expect::error("0x04", "insufficient funds")))
+        .sc_call(ScCall::new("3", call(address("spender"), "transferFrom")
+                .arg(address("holder_1"))
+                .arg(address("holder_frozen"))
+                .arg("10"))
+            .comment("spender frozen is only reported by transferFrom")
+            .expect(Expect::error("0x04", "address frozen")))
+        .sc_call(ScCall::new("4", call(address("spender"), "transferFrom")
+                .arg(address("holder_1"))
+                .arg(address("holder_2"))
+                .arg("101"))
+            .comment("allowance exceeded")
+            .expect(Expect::error("0x04", "allowance exceeded")))
+        .sc_call(ScCall::new("5", call(address("spender"), "detectTransferRestriction")
+                .arg(address("holder_1"))
+                .arg(address("holder_2"))
+                .arg("100"))
+            .comment("unrestricted transfer")
+            .expect(Expect::ok().out("0")))
+        .sc_call(ScCall::new("6", call(address("spender"), "transferFrom")
+                .arg(address("holder_1"))
+                .arg(address("holder_2"))
+                .arg("100"))
+            .comment("transferFrom succeeds")
+            .expect(Expect::ok()
+                .log(transfer_log(contract(), address("holder_1"), address("holder_2"), "100"))))
+        .check_state(CheckState::new()
+            .account(address("busd_owner"), Account::new().nonce("*").balance("*"))
+            .account(address("holder_1"), Account::new().nonce("*").balance("*"))
+            .account(address("holder_2"), Account::new().nonce("*").balance("*"))
+            .account(address("holder_frozen"), Account::new().nonce("*").balance("*"))
+            .account(address("spender"), Account::new().nonce("*").balance("*"))
+            .account(contract(), Account::contract()
+                .storage(key("owner", &[]), address("busd_owner"))
+                .storage(key("paused", &[]), "false")
+                .storage(key("supply_c", &[]), address("busd_owner"))
+                .storage(key("frozen", &[&address("holder_frozen")]), "true")
+                .busd_balance(&address("holder_1"), "400")
+                .busd_balance(&address("holder_2"), "100")
+                .busd_holders(&[&address("holder_2")])
+                .busd_balance(&address("holder_frozen"), "500")))
+}
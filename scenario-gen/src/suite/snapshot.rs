@@ -0,0 +1,67 @@
+use super::*;
+
+pub fn scenarios() -> Vec<(&'static str, Scenario)> {
+    vec![
+        ("snapshot/snapshot", snapshot()),
+    ]
+}
+
+fn contract() -> String {
+    address("busd_contract")
+}
+
+fn call<S: Into<String>>(from: S, function: &str) -> Tx {
+    Tx::call(from, contract(), function)
+}
+
+fn snapshot() -> Scenario {
+    Scenario::new("balances and total supply at a snapshot")
+        .set_state(SetState::new()
+            .account(address("busd_owner"), Account::new().balance("100,000,000,000"))
+            .account(address("holder_1"), Account::new().balance("100,000,000,000"))
+            .account(address("holder_2"), Account::new().balance("100,000,000,000"))
+            .account(contract(), Account::contract()
+                .storage(key("owner", &[]), address("busd_owner"))
+                .storage(key("paused", &[]), "false")
+                .storage(key("supply_c", &[]), address("busd_owner"))
+                .busd_metadata()
+                .storage(key("schema_version", &[]), "2")
+                .busd_balance(&address("holder_1"), "5000")
+                .total_supply("5000")))
+        .sc_call(ScCall::new("1", call(address("holder_1"), "snapshot"))
+            .expect(Expect::error("4", "only supply controller can take snapshots")))
+        .sc_call(ScCall::new("2", call(address("busd_owner"), "snapshot"))
+            .expect(Expect::ok()
+                .out("1")
+                .log(Log::event(contract(), "Snapshot").data("1"))))
+        .sc_call(ScCall::new("3", call(address("holder_1"), "transfer").arg(address("holder_2")).arg("1000"))
+            .comment("the first change after the snapshot saves the balances it had")
+            .expect(Expect::ok().log(transfer_log(contract(), address("holder_1"), address("holder_2"), "1000"))))
+        .sc_call(ScCall::new("4", call(address("holder_2"), "balanceOfAt").arg(address("holder_1")).arg("1"))
+            .expect(Expect::ok().out("5000")))
+        .sc_call(ScCall::new("5", call(address("holder_2"), "balanceOfAt").arg(address("holder_2")).arg("1"))
+            .expect(Expect::ok().out("0")))
+        .sc_call(ScCall::new("6", call(address("holder_2"), "totalSupplyAt").arg("1"))
+            .comment("the total supply did not change since, so it is read as it is now")
+            .expect(Expect::ok().out("5000")))
+        .sc_call(ScCall::new("7", call(address("holder_2"), "totalSupplyAt").arg("2"))
+            .expect(Expect::error("4", "snapshot not found")))
+        .check_state(CheckState::new()
+            .account(address("busd_owner"), Account::new().nonce("*").balance("*"))
+            .account(address("holder_1"), Account::new().nonce("*").balance("*"))
+            .account(address("holder_2"), Account::new().nonce("*").balance("*"))
+            .account(contract(), Account::contract()
+                .storage(key("owner", &[]), address("busd_owner"))
+                .storage(key("paused", &[]), "false")
+                .storage(key("supply_c", &[]), address("busd_owner"))
+                .busd_metadata()
+                .storage(key("schema_version", &[]), "2")
+                .busd_balance(&address("holder_1"), "4000")
+                .busd_balance(&address("holder_2"), "1000")
+                .total_supply("5000")
+                .busd_holders(&[&address("holder_2")])
+                .storage(key("snap_id", &[]), "1")
+                .storage(key("snap_bal_ids", &[&address("holder_1")]), "0x0000000000000001")
+                .storage(key("snap_bal", &[&address("holder_1"), "1"]), "5000")
+                .storage(key("snap_bal_ids", &[&address("holder_2")]), "0x0000000000000001")))
+}
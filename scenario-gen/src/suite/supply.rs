@@ -0,0 +1,274 @@
+use super::*;
+
+pub fn scenarios() -> Vec<(&'static str, Scenario)> {
+    vec![
+        ("supply/supply_controller_change", supply_controller_change()),
+        ("supply/supply_controller_change_again", supply_controller_change_again()),
+        ("supply/supply_controller_change_bad_caller", supply_controller_change_bad_caller()),
+        ("supply/supply_decrease_bad_caller", supply_decrease_bad_caller()),
+        ("supply/supply_increase", supply_increase()),
+        ("supply/supply_increase_bad_caller", supply_increase_bad_caller()),
+        ("supply/supply_increase_decrease", supply_increase_decrease()),
+        ("supply/supply_increase_decrease_all", supply_increase_decrease_all()),
+        ("supply/supply_increase_decrease_too_much", supply_increase_decrease_too_much()),
+        ("supply/supply_increase_increase", supply_increase_increase()),
+        ("supply/supply_init", supply_init()),
+    ]
+}
+
+fn contract() -> String {
+    address("new_busd_contract")
+}
+
+fn call<S: Into<String>>(from: S, function: &str) -> Tx {
+    Tx::call(from, contract(), function).gas_limit("100,000")
+}
+
+fn accounts_before_deploy() -> SetState {
+    SetState::new()
+        .account(address("busd_owner"), Account::new().balance("100,000,000,000"))
+        .account(address("supply_controller"), Account::new().balance("100,000,000,000"))
+        .account(address("someone_else"), Account::new().balance("100,000,000,000"))
+        .new_address(address("busd_owner"), "0", contract())
+}
+
+fn deploy() -> ScCall {
+    ScCall::new("1", deploy_busd(address("busd_owner")).gas_limit("100,000"))
+        .comment("deploy contract")
+        .expect(Expect::ok().log(initialized_log(contract(), &address("busd_owner"))))
+}
+
+fn set_supply_controller() -> ScCall {
+    ScCall::new("2", call(address("busd_owner"), "setSupplyController").arg(address("supply_controller")))
+        .comment("set new supply controller")
+        .expect(Expect::ok()
+            .log(Log::event(contract(), "SupplyControllerSet")
+                .topic(address("busd_owner"))
+                .topic(address("supply_controller"))))
+}
+
+fn check_supply_controller() -> ScCall {
+    ScCall::new("3", call(address("someone_else"), "getSupplyController"))
+        .comment("get proposed owner")
+        .expect(Expect::ok().out(address("supply_controller")))
+}
+
+fn increase_supply_100123() -> ScCall {
+    ScCall::new("4", call(address("supply_controller"), "increaseSupply").arg("100123"))
+        .comment("increase supply")
+        .expect(Expect::ok()
+            .log(Log::event(contract(), "SupplyIncreased")
+                .topic(address("supply_controller"))
+                .data("100123"))
+            .log(transfer_log(contract(), zero_address(), address("supply_controller"), "100123")))
+}
+
+fn increase_supply_999999999() -> ScCall {
+    ScCall::new("4", call(address("supply_controller"), "increaseSupply").arg("999,999,999"))
+        .comment("increase supply")
+        .expect(Expect::ok()
+            .log(Log::event(contract(), "SupplyIncreased")
+                .topic(address("supply_controller"))
+                .data("999,999,999"))
+            .log(transfer_log(contract(), zero_address(), address("supply_controller"), "999,999,999")))
+}
+
+fn supply_controller_change() -> Scenario {
+    Scenario::new("supply test")
+        .set_state(accounts_before_deploy())
+        .sc_deploy(deploy())
+        .sc_call(ScCall::new("check_before", call(address("someone_else"), "getSupplyController"))
+            .comment("initial supply controller should be owner")
+            .expect(Expect::ok().out(address("busd_owner"))))
+        .sc_call(set_supply_controller())
+        .sc_call(ScCall::new("check_after", call(address("someone_else"), "getSupplyController"))
+            .comment("get new supply controller")
+            .expect(Expect::ok().out(address("supply_controller"))))
+}
+
+fn supply_controller_change_again() -> Scenario {
+    Scenario::new("supply test")
+        .set_state(accounts_before_deploy())
+        .sc_deploy(deploy())
+        .sc_call(set_supply_controller())
+        .sc_call(check_supply_controller())
+        .sc_call(ScCall::new("4", call(address("busd_owner"), "setSupplyController").arg(address("supply_controller")))
+            .comment("owner sets supply controller again")
+            .expect(Expect::ok()
+                .log(Log::event(contract(), "SupplyControllerSet")
+                    .topic(address("supply_controller"))
+                    .topic(address("supply_controller")))))
+        .sc_call(ScCall::new("4", call(address("supply_controller"), "setSupplyController").arg(address("supply_controller")))
+            .comment("supply controller sets supply controller again")
+            .expect(Expect::ok()
+                .log(Log::event(contract(), "SupplyControllerSet")
+                    .topic(address("supply_controller"))
+                    .topic(address("supply_controller")))))
+}
+
+fn supply_controller_change_bad_caller() -> Scenario {
+    Scenario::new("supply test")
+        .set_state(accounts_before_deploy())
+        .sc_deploy(deploy())
+        .sc_call(set_supply_controller())
+        .sc_call(check_supply_controller())
+        .sc_call(ScCall::new("4", call(address("someone_else"), "setSupplyController").arg(address("busd_owner")))
+            .comment("someone who is neither owner nor supply controller tries to change it again")
+            .expect(Expect::error("4", "only supply controller or owner can change supply controller")))
+}
+
+fn supply_decrease_bad_caller() -> Scenario {
+    Scenario::new("supply test")
+        .set_state(accounts_before_deploy())
+        .sc_deploy(deploy())
+        .sc_call(set_supply_controller())
+        .sc_call(check_supply_controller())
+        .sc_call(ScCall::new("4", call(address("someone_else"), "decreaseSupply").arg("100123"))
+            .comment("decrease supply - but caller is not the supply controller")
+            .expect(Expect::error("4", "only supply controller can decrease supply")))
+        .sc_call(ScCall::new("4", call(address("busd_owner"), "decreaseSupply").arg("100123"))
+            .comment("decrease supply - owner also not allowed")
+            .expect(Expect::error("4", "only supply controller can decrease supply")))
+}
+
+fn supply_increase() -> Scenario {
+    Scenario::new("supply test")
+        .set_state(accounts_before_deploy())
+        .sc_deploy(deploy())
+        .sc_call(set_supply_controller())
+        .sc_call(check_supply_controller())
+        .sc_call(increase_supply_100123())
+        .sc_call(ScCall::new("5", Tx::call(address("someone_else"), contract(), "balanceOf")
+                .arg(address("supply_controller"))
+                .gas_limit("0x100000"))
+            .comment("check that supplier got the money")
+            .expect(Expect::ok().out("100123")))
+        .sc_call(ScCall::new("5", Tx::call(address("someone_else"), contract(), "totalSupply").gas_limit("0x100000"))
+            .comment("check that total supply increased")
+            .expect(Expect::ok().out("100123")))
+}
+
+fn supply_increase_bad_caller() -> Scenario {
+    Scenario::new("supply test")
+        .set_state(accounts_before_deploy())
+        .sc_deploy(deploy())
+        .sc_call(set_supply_controller())
+        .sc_call(check_supply_controller())
+        .sc_call(ScCall::new("4", call(address("someone_else"), "increaseSupply").arg("100123"))
+            .comment("increase supply - but caller is not the supply controller")
+            .expect(Expect::error("4", "only supply controller can increase supply")))
+        .sc_call(ScCall::new("4", call(address("busd_owner"), "increaseSupply").arg("100123"))
+            .comment("increase supply - owner also not allowed")
+            .expect(Expect::error("4", "only supply controller can increase supply")))
+}
+
+fn supply_increase_decrease() -> Scenario {
+    Scenario::new("supply test")
+        .set_state(accounts_before_deploy())
+        .sc_deploy(deploy())
+        .sc_call(set_supply_controller())
+        .sc_call(check_supply_controller())
+        .sc_call(increase_supply_999999999())
+        .sc_call(ScCall::new("5", call(address("supply_controller"), "decreaseSupply").arg("123,456,789"))
+            .comment("decrease supply")
+            .expect(Expect::ok()
+                .log(Log::event(contract(), "SupplyDecreased")
+                    .topic(address("supply_controller"))
+                    .data("123,456,789"))
+                .log(transfer_log(contract(), address("supply_controller"), zero_address(), "123,456,789"))))
+        .sc_call(ScCall::new("5", Tx::call(address("someone_else"), contract(), "balanceOf")
+                .arg(address("supply_controller"))
+                .gas_limit("0x100000"))
+            .comment("check that supplier got the money")
+            .expect(Expect::ok().out("876,543,210")))
+        .sc_call(ScCall::new("5", Tx::call(address("someone_else"), contract(), "totalSupply").gas_limit("0x100000"))
+            .comment("check that total supply increased")
+            .expect(Expect::ok().out("876,543,210")))
+}
+
+fn supply_increase_decrease_all() -> Scenario {
+    Scenario::new("supply test")
+        .set_state(accounts_before_deploy())
+        .sc_deploy(deploy())
+        .sc_call(set_supply_controller())
+        .sc_call(check_supply_controller())
+        .sc_call(increase_supply_999999999())
+        .sc_call(ScCall::new("5", call(address("supply_controller"), "decreaseSupply").arg("999,999,999"))
+            .comment("decrease supply")
+            .expect(Expect::ok()
+                .log(Log::event(contract(), "SupplyDecreased")
+                    .topic(address("supply_controller"))
+                    .data("999,999,999"))
+                .log(transfer_log(contract(), address("supply_controller"), zero_address(), "999,999,999"))))
+        .sc_call(ScCall::new("5", Tx::call(address("someone_else"), contract(), "balanceOf")
+                .arg(address("supply_controller"))
+                .gas_limit("0x100000"))
+            .comment("check that supplier got the money")
+            .expect(Expect::ok().out("0")))
+        .sc_call(ScCall::new("5", Tx::call(address("someone_else"), contract(), "totalSupply").gas_limit("0x100000"))
+            .comment("check that total supply increased")
+            .expect(Expect::ok().out("0")))
+}
+
+fn supply_increase_decrease_too_much() -> Scenario {
+    Scenario::new("supply test")
+        .set_state(accounts_before_deploy())
+        .sc_deploy(deploy())
+        .sc_call(set_supply_controller())
+        .sc_call(check_supply_controller())
+        .sc_call(ScCall::new("4", call(address("supply_controller"), "increaseSupply").arg("999,999,998"))
+            .comment("increase supply")
+            .expect(Expect::ok()
+                .log(Log::event(contract(), "SupplyIncreased")
+                    .topic(address("supply_controller"))
+                    .data("999,999,998"))
+                .log(transfer_log(contract(), zero_address(), address("supply_controller"), "999,999,998"))))
+        .sc_call(ScCall::new("5", call(address("supply_controller"), "decreaseSupply").arg("999,999,999"))
+            .comment("decrease supply")
+            .expect(Expect::error("4", "not enough supply to decrease")))
+}
+
+fn supply_increase_increase() -> Scenario {
+    Scenario::new("supply test")
+        .set_state(accounts_before_deploy())
+        .sc_deploy(deploy())
+        .sc_call(set_supply_controller())
+        .sc_call(check_supply_controller())
+        .sc_call(increase_supply_100123())
+        .sc_call(ScCall::new("5", call(address("supply_controller"), "increaseSupply").arg("400321"))
+            .comment("increase supply again")
+            .expect(Expect::ok()
+                .log(Log::event(contract(), "SupplyIncreased")
+                    .topic(address("supply_controller"))
+                    .data("400321"))
+                .log(transfer_log(contract(), zero_address(), address("supply_controller"), "400321"))))
+        .sc_call(ScCall::new("5", Tx::call(address("someone_else"), contract(), "balanceOf")
+                .arg(address("supply_controller"))
+                .gas_limit("0x100000"))
+            .comment("check that supplier got the money")
+            .expect(Expect::ok().out("500444")))
+        .sc_call(ScCall::new("5", Tx::call(address("someone_else"), contract(), "totalSupply").gas_limit("0x100000"))
+            .comment("check that total supply increased")
+            .expect(Expect::ok().out("500444")))
+}
+
+fn supply_init() -> Scenario {
+    Scenario::new("supply test")
+        .set_state(SetState::new()
+            .account(address("busd_owner"), Account::new().balance("100,000,000,000"))
+            .account(address("someone_else"), Account::new().balance("100,000,000,000"))
+            .new_address(address("busd_owner"), "0", contract()))
+        .sc_deploy(deploy())
+        .sc_call(ScCall::new("2", call(address("someone_else"), "getSupplyController"))
+            .comment("owner should be suply controller")
+            .expect(Expect::ok().out(address("busd_owner"))))
+        .sc_call(ScCall::new("2", call(address("someone_else"), "totalSupply"))
+            .comment("total supply should be 0 after init")
+            .expect(Expect::ok().out("0")))
+        .sc_call(ScCall::new("3", call(address("someone_else"), "balanceOf").arg(address("busd_owner")))
+            .comment("owner balance should be 0 after init")
+            .expect(Expect::ok().out("0")))
+        .sc_call(ScCall::new("4", call(address("someone_else"), "balanceOf").arg(address("someone_else")))
+            .comment("other balance should be 0 after init")
+            .expect(Expect::ok().out("0")))
+}
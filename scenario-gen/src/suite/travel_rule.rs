@@ -0,0 +1,241 @@
+use super::*;
+
+pub fn scenarios() -> Vec<(&'static str, Scenario)> {
+    vec![
+        ("travel_rule/transfer_with_travel_rule", transfer_with_travel_rule()),
+        ("travel_rule/transfer_with_travel_rule_queued", transfer_with_travel_rule_queued()),
+        ("travel_rule/vasp_registry", vasp_registry()),
+    ]
+}
+
+fn contract() -> String {
+    address("busd_contract")
+}
+
+fn call<S: Into<String>>(from: S, function: &str) -> Tx {
+    Tx::call(from, contract(), function)
+}
+
+fn transfer_with_travel_rule() -> Scenario {
+    Scenario::new("travel rule enforcement")
+        .set_state(SetState::new()
+            .account(address("busd_owner"), Account::new().balance("100,000,000,000"))
+            .account(address("vasp_a"), Account::new().balance("100,000,000,000"))
+            .account(address("vasp_b"), Account::new().balance("100,000,000,000"))
+            .account(address("holder_1"), Account::new().balance("100,000,000,000"))
+            .account(address("someone_else"), Account::new().balance("100,000,000,000"))
+            .account(contract(), Account::contract()
+                .storage(key("owner", &[]), address("busd_owner"))
+                .storage(key("paused", &[]), "false")
+                .storage(key("supply_c", &[]), address("busd_owner"))
+                .busd_balance(&address("vasp_a"), "5000")
+                .storage(key("tr_threshold", &[]), some("1000"))
+                .storage(key("vasp_id", &[&address("vasp_a")]), some(&bytes32("vasp_a_identifier")))
+                .storage(key("vasp_id", &[&address("vasp_b")]), some(&bytes32("vasp_b_identifier")))))
+        .sc_call(ScCall::new("1", call(address("vasp_a"), "transfer").arg(address("vasp_b")).arg("1000"))
+            .comment("at the threshold no metadata needed")
+            .expect(Expect::ok()
+                .log(transfer_log(contract(), address("vasp_a"), address("vasp_b"), "1000"))))
+        .sc_call(ScCall::new("check_1", call(address("someone_else"), "detectTransferRestriction")
+                .arg(address("vasp_a"))
+                .arg(address("vasp_b"))
+                .arg("1001"))
+            .expect(Expect::ok().out("8")))
+        .sc_call(ScCall::new("2", call(address("vasp_a"), "transfer").arg(address("vasp_b")).arg("1001"))
+            .comment("above the threshold metadata is required")
+            .expect(Expect::error("0x04", "travel rule metadata required")))
+        .sc_call(ScCall::new("3", call(address("vasp_a"), "transfer").arg(address("holder_1")).arg("1001"))
+            .comment("non-VASP recipient needs no metadata")
+            .expect(Expect::ok()
+                .log(transfer_log(contract(), address("vasp_a"), address("holder_1"), "1001"))))
+        .sc_call(ScCall::new("4", call(address("vasp_a"), "transferWithTravelRule")
+                .arg(address("vasp_b"))
+                .arg("1001")
+                .arg(bytes32("payload_hash"))
+                .arg(bytes32("vasp_b_identifier")))
+            .comment("VASP id must match the sender")
+            .expect(Expect::error("0x04", "VASP id mismatch")))
+        .sc_call(ScCall::new("5", call(address("vasp_a"), "transferWithTravelRule")
+                .arg(address("vasp_b"))
+                .arg("1001")
+                .arg(bytes32("payload_hash"))
+                .arg(bytes32("vasp_a_identifier")))
+            .comment("transfer with metadata")
+            .expect(Expect::ok()
+                .log(Log::event(contract(), "TravelRule")
+                    .topic(address("vasp_a"))
+                    .topic(address("vasp_b"))
+                    .topic(bytes32("payload_hash"))
+                    .topic(bytes32("vasp_a_identifier"))
+                    .data("1001"))
+                .log(transfer_log(contract(), address("vasp_a"), address("vasp_b"), "1001"))))
+        .sc_call(ScCall::new("6", call(address("vasp_a"), "transferWithTravelRule")
+                .arg(address("vasp_b"))
+                .arg("5000")
+                .arg(bytes32("payload_hash"))
+                .arg(bytes32("vasp_a_identifier")))
+            .comment("other restrictions still apply")
+            .expect(Expect::error("0x04", "insufficient funds")))
+        .sc_call(ScCall::new("7", call(address("holder_1"), "transferWithTravelRule")
+                .arg(address("vasp_b"))
+                .arg("1001")
+                .arg(bytes32("payload_hash"))
+                .arg(bytes32("vasp_a_identifier")))
+            .comment("a sender that is not a VASP cannot claim a VASP id")
+            .expect(Expect::error("0x04", "VASP id mismatch")))
+        .check_state(CheckState::new()
+            .account(address("busd_owner"), Account::new().nonce("*").balance("*"))
+            .account(address("vasp_a"), Account::new().nonce("*").balance("*"))
+            .account(address("vasp_b"), Account::new().nonce("*").balance("*"))
+            .account(address("holder_1"), Account::new().nonce("*").balance("*"))
+            .account(address("someone_else"), Account::new().nonce("*").balance("*"))
+            .account(contract(), Account::contract()
+                .storage(key("owner", &[]), address("busd_owner"))
+                .storage(key("paused", &[]), "false")
+                .storage(key("supply_c", &[]), address("busd_owner"))
+                .busd_balance(&address("vasp_a"), "1998")
+                .storage(key("tr_threshold", &[]), some("1000"))
+                .storage(key("vasp_id", &[&address("vasp_a")]), some(&bytes32("vasp_a_identifier")))
+                .storage(key("vasp_id", &[&address("vasp_b")]), some(&bytes32("vasp_b_identifier")))
+                .busd_balance(&address("vasp_b"), "2001")
+                .busd_balance(&address("holder_1"), "1001")
+                .busd_holders(&[&address("vasp_b"), &address("holder_1")])))
+}
+
+fn transfer_with_travel_rule_queued() -> Scenario {
+    Scenario::new("travel rule metadata of a large transfer logged once it is executed")
+        .set_state(SetState::new()
+            .account(address("busd_owner"), Account::new().balance("100,000,000,000"))
+            .account(address("vasp_a"), Account::new().balance("100,000,000,000"))
+            .account(address("vasp_b"), Account::new().balance("100,000,000,000"))
+            .account(address("someone_else"), Account::new().balance("100,000,000,000"))
+            .account(contract(), Account::contract()
+                .storage(key("owner", &[]), address("busd_owner"))
+                .storage(key("paused", &[]), "false")
+                .storage(key("supply_c", &[]), address("busd_owner"))
+                .busd_balance(&address("vasp_a"), "5000")
+                .storage(key("tr_threshold", &[]), some("1000"))
+                .storage(key("vasp_id", &[&address("vasp_a")]), some(&bytes32("vasp_a_identifier")))
+                .storage(key("vasp_id", &[&address("vasp_b")]), some(&bytes32("vasp_b_identifier")))
+                .storage(key("lt_threshold", &[]), some("2000"))
+                .storage(key("lt_review", &[]), "3600"))
+            .block_timestamp("1,000,000"))
+        .sc_call(ScCall::new("1", call(address("vasp_a"), "transferWithTravelRule")
+                .arg(address("vasp_b"))
+                .arg("2001")
+                .arg(bytes32("payload_hash"))
+                .arg(bytes32("vasp_a_identifier")))
+            .comment("queued, the metadata is kept with the transfer")
+            .expect(Expect::ok()
+                .log(Log::event(contract(), "LargeTransferQueued")
+                    .topic(address("vasp_a"))
+                    .topic(address("vasp_b"))
+                    .topic("0x0000000000000000000000000000000000000000000000000000000000000001")
+                    .data("2001"))))
+        .set_state(SetState::new()
+            .block_timestamp("1,003,600"))
+        .sc_call(ScCall::new("2", call(address("someone_else"), "executePendingTransfer").arg("1"))
+            .comment("logged when the funds reach the recipient")
+            .expect(Expect::ok()
+                .log(Log::event(contract(), "LargeTransferExecuted")
+                    .topic(address("vasp_a"))
+                    .topic(address("vasp_b"))
+                    .topic("0x0000000000000000000000000000000000000000000000000000000000000001")
+                    .data("2001"))
+                .log(Log::event(contract(), "TravelRule")
+                    .topic(address("vasp_a"))
+                    .topic(address("vasp_b"))
+                    .topic(bytes32("payload_hash"))
+                    .topic(bytes32("vasp_a_identifier"))
+                    .data("2001"))
+                .log(transfer_log(contract(), address("vasp_a"), address("vasp_b"), "2001"))))
+        .check_state(CheckState::new()
+            .account(address("busd_owner"), Account::new().nonce("*").balance("*"))
+            .account(address("vasp_a"), Account::new().nonce("*").balance("*"))
+            .account(address("vasp_b"), Account::new().nonce("*").balance("*"))
+            .account(address("someone_else"), Account::new().nonce("*").balance("*"))
+            .account(contract(), Account::contract()
+                .storage(key("owner", &[]), address("busd_owner"))
+                .storage(key("paused", &[]), "false")
+                .storage(key("supply_c", &[]), address("busd_owner"))
+                .busd_balance(&address("vasp_a"), "2999")
+                .storage(key("tr_threshold", &[]), some("1000"))
+                .storage(key("vasp_id", &[&address("vasp_a")]), some(&bytes32("vasp_a_identifier")))
+                .storage(key("vasp_id", &[&address("vasp_b")]), some(&bytes32("vasp_b_identifier")))
+                .storage(key("lt_threshold", &[]), some("2000"))
+                .storage(key("lt_review", &[]), "3600")
+                .busd_balance(&address("vasp_b"), "2001")
+                .busd_holders(&[&address("vasp_b")])
+                .storage(key("pt_last_id", &[]), "1")
+                .storage(key("pt_sender", &["1"]), address("vasp_a"))
+                .storage(key("pt_recipient", &["1"]), address("vasp_b"))
+                .storage(key("pt_time", &["1"]), "1,000,000")
+                .storage(key("pt_payload", &["1"]), some(&bytes32("payload_hash")))
+                .storage(key("pt_vasp", &["1"]), bytes32("vasp_a_identifier"))))
+}
+
+fn vasp_registry() -> Scenario {
+    Scenario::new("VASP registry")
+        .set_state(SetState::new()
+            .account(address("busd_owner"), Account::new().balance("100,000,000,000"))
+            .account(address("vasp_a"), Account::new().balance("100,000,000,000"))
+            .account(address("vasp_b"), Account::new().balance("100,000,000,000"))
+            .account(address("holder_1"), Account::new().balance("100,000,000,000"))
+            .account(address("someone_else"), Account::new().balance("100,000,000,000"))
+            .account(contract(), Account::contract()
+                .storage(key("owner", &[]), address("busd_owner"))
+                .storage(key("paused", &[]), "false")
+                .storage(key("supply_c", &[]), address("busd_owner"))
+                .busd_balance(&address("vasp_a"), "5000")))
+        .sc_call(ScCall::new("1", call(address("someone_else"), "registerVasp")
+                .arg(address("vasp_a"))
+                .arg(bytes32("vasp_a_identifier")))
+            .comment("only owner")
+            .expect(Expect::error("0x04", "only owner can register VASPs")))
+        .sc_call(ScCall::new("2", call(address("busd_owner"), "registerVasp")
+                .arg(address("vasp_a"))
+                .arg(bytes32("vasp_a_identifier")))
+            .comment("register")
+            .expect(Expect::ok()
+                .log(Log::event(contract(), "VaspRegistered")
+                    .topic(address("vasp_a"))
+                    .topic(bytes32("vasp_a_identifier")))))
+        .sc_call(ScCall::new("check_1", call(address("someone_else"), "getVaspId").arg(address("vasp_a")))
+            .expect(Expect::ok().out(bytes32("vasp_a_identifier"))))
+        .sc_call(ScCall::new("check_2", call(address("someone_else"), "getVaspId").arg(address("vasp_b"))))
+        .sc_call(ScCall::new("3", call(address("someone_else"), "setTravelRuleThreshold").arg("1000"))
+            .comment("only owner")
+            .expect(Expect::error("0x04", "only owner can change travel rule threshold")))
+        .sc_call(ScCall::new("4", call(address("busd_owner"), "setTravelRuleThreshold").arg("1000"))
+            .comment("set threshold")
+            .expect(Expect::ok()
+                .log(Log::event(contract(), "TravelRuleThresholdSet")
+                    .data("1000"))))
+        .sc_call(ScCall::new("check_3", call(address("someone_else"), "getTravelRuleThreshold"))
+            .expect(Expect::ok().out("1000")))
+        .sc_call(ScCall::new("5", call(address("busd_owner"), "unregisterVasp").arg(address("vasp_a")))
+            .comment("unregister")
+            .expect(Expect::ok()
+                .log(Log::event(contract(), "VaspUnregistered")
+                    .topic(address("vasp_a")))))
+        .sc_call(ScCall::new("6", call(address("busd_owner"), "unregisterVasp").arg(address("vasp_a")))
+            .comment("already unregistered")
+            .expect(Expect::error("0x04", "address is not a registered VASP")))
+        .sc_call(ScCall::new("7", call(address("busd_owner"), "removeTravelRuleThreshold"))
+            .comment("remove threshold")
+            .expect(Expect::ok().log(Log::event(contract(), "TravelRuleThresholdRemoved"))))
+        .sc_call(ScCall::new("8", call(address("busd_owner"), "removeTravelRuleThreshold"))
+            .comment("already removed")
+            .expect(Expect::error("0x04", "no travel rule threshold")))
+        .check_state(CheckState::new()
+            .account(address("busd_owner"), Account::new().nonce("*").balance("*"))
+            .account(address("vasp_a"), Account::new().nonce("*").balance("*"))
+            .account(address("vasp_b"), Account::new().nonce("*").balance("*"))
+            .account(address("holder_1"), Account::new().nonce("*").balance("*"))
+            .account(address("someone_else"), Account::new().nonce("*").balance("*"))
+            .account(contract(), Account::contract()
+                .storage(key("owner", &[]), address("busd_owner"))
+                .storage(key("paused", &[]), "false")
+                .storage(key("supply_c", &[]), address("busd_owner"))
+                .busd_balance(&address("vasp_a"), "5000")))
+}
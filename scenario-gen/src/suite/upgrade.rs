@@ -0,0 +1,92 @@
+use super::*;
+
+pub fn scenarios() -> Vec<(&'static str, Scenario)> {
+    vec![
+        ("upgrade/upgrade_v0", upgrade_v0()),
+        ("upgrade/upgrade_newer_storage", upgrade_newer_storage()),
+    ]
+}
+
+fn contract() -> String {
+    address("busd_contract")
+}
+
+fn call<S: Into<String>>(from: S, function: &str) -> Tx {
+    Tx::call(from, contract(), function)
+}
+
+/// The storage of a contract deployed with v0.3.0: no token metadata and no storage version.
+fn v0_storage() -> Account {
+    Account::contract()
+        .storage(key("owner", &[]), address("busd_owner"))
+        .storage(key("paused", &[]), "false")
+        .storage(key("supply_c", &[]), address("busd_owner"))
+        .busd_balance(&address("holder_1"), "5000")
+        .total_supply("5000")
+}
+
+fn upgrade_v0() -> Scenario {
+    Scenario::new("upgrade from v0.3.0")
+        .set_state(SetState::new()
+            .account(address("busd_owner"), Account::new().balance("100,000,000,000"))
+            .account(address("holder_1"), Account::new().balance("100,000,000,000"))
+            .account(address("holder_2"), Account::new().balance("100,000,000,000"))
+            .account(contract(), v0_storage()))
+        .sc_call(ScCall::new("1", call(address("busd_owner"), "getStorageVersion"))
+            .comment("the code has been upgraded, the storage is still that of v0.3.0")
+            .expect(Expect::ok().out("0")))
+        .sc_call(ScCall::new("2", call(address("busd_owner"), "getLatestStorageVersion"))
+            .expect(Expect::ok().out("2")))
+        .sc_call(ScCall::new("3", call(address("holder_1"), "upgrade"))
+            .comment("only the owner can migrate the storage")
+            .expect(Expect::error("4", "only owner can upgrade")))
+        .sc_call(ScCall::new("4", call(address("busd_owner"), "upgrade"))
+            .comment("v0.3.0 had the token metadata hardcoded and numbered events, version 2 added the holder registry")
+            .expect(Expect::ok()
+                .log(Log::event(contract(), "LegacyEventsEnabled"))
+                .log(Log::event(contract(), "StorageMigrated").data("1"))
+                .log(Log::event(contract(), "StorageMigrated").data("2"))))
+        .sc_call(ScCall::new("5", call(address("busd_owner"), "getStorageVersion"))
+            .expect(Expect::ok().out("2")))
+        .sc_call(ScCall::new("6", call(address("busd_owner"), "name"))
+            .expect(Expect::ok().out("``Binance USD")))
+        .sc_call(ScCall::new("7", call(address("busd_owner"), "upgrade"))
+            .expect(Expect::error("4", "storage already up to date")))
+        .sc_call(ScCall::new("8", call(address("busd_owner"), "legacyEventsEnabled"))
+            .expect(Expect::ok().out("true")))
+        .sc_call(ScCall::new("9", call(address("holder_1"), "transfer").arg(address("holder_2")).arg("100"))
+            .comment("balances are kept, and the numbered events still emitted")
+            .expect(Expect::ok()
+                .log(transfer_log(contract(), address("holder_1"), address("holder_2"), "100"))
+                .log(Log::legacy_event(contract(), 0x01)
+                    .topic(address("holder_1"))
+                    .topic(address("holder_2"))
+                    .data("100"))))
+        .check_state(CheckState::new()
+            .account(address("busd_owner"), Account::new().nonce("*").balance("*"))
+            .account(address("holder_1"), Account::new().nonce("*").balance("*"))
+            .account(address("holder_2"), Account::new().nonce("*").balance("*"))
+            .account(contract(), Account::contract()
+                .storage(key("owner", &[]), address("busd_owner"))
+                .storage(key("paused", &[]), "false")
+                .storage(key("supply_c", &[]), address("busd_owner"))
+                .busd_metadata()
+                .storage(key("schema_version", &[]), "2")
+                .storage(key("legacy_events", &[]), "true")
+                .busd_balance(&address("holder_1"), "4900")
+                .busd_balance(&address("holder_2"), "100")
+                .busd_holders(&[&address("holder_2")])
+                .total_supply("5000")))
+}
+
+fn upgrade_newer_storage() -> Scenario {
+    Scenario::new("upgrade to older code")
+        .set_state(SetState::new()
+            .account(address("busd_owner"), Account::new().balance("100,000,000,000"))
+            .account(contract(), v0_storage()
+                .busd_metadata()
+                .storage(key("schema_version", &[]), "3")))
+        .sc_call(ScCall::new("1", call(address("busd_owner"), "upgrade"))
+            .comment("the storage was migrated by newer code, which cannot be undone")
+            .expect(Expect::error("4", "storage version newer than code")))
+}
@@ -0,0 +1,223 @@
+use super::*;
+
+pub fn scenarios() -> Vec<(&'static str, Scenario)> {
+    vec![
+        ("velocity/daily_limit_bad_caller", daily_limit_bad_caller()),
+        ("velocity/daily_limit_set", daily_limit_set()),
+        ("velocity/default_daily_limit", default_daily_limit()),
+        ("velocity/transfer_vs_daily_limit", transfer_vs_daily_limit()),
+    ]
+}
+
+fn contract() -> String {
+    address("busd_contract")
+}
+
+fn call<S: Into<String>>(from: S, function: &str) -> Tx {
+    Tx::call(from, contract(), function)
+}
+
+fn velocity_state() -> SetState {
+    SetState::new()
+        .account(address("busd_owner"), Account::new().balance("100,000,000,000"))
+        .account(address("asset_protection_role"), Account::new().balance("100,000,000,000"))
+        .account(address("holder_1"), Account::new().balance("100,000,000,000"))
+        .account(address("holder_2"), Account::new().balance("100,000,000,000"))
+        .account(address("holder_3"), Account::new().balance("100,000,000,000"))
+        .account(contract(), Account::contract()
+            .storage(key("owner", &[]), address("busd_owner"))
+            .storage(key("paused", &[]), "false")
+            .storage(key("ap_role", &[]), some(&address("asset_protection_role")))
+            .storage(key("supply_c", &[]), address("busd_owner"))
+            .busd_balance(&address("holder_1"), "1000")
+            .busd_balance(&address("holder_2"), "1000"))
+        .block_timestamp("1,000,000")
+}
+
+fn daily_limit_bad_caller() -> Scenario {
+    Scenario::new("only asset protection role can set daily limits")
+        .set_state(velocity_state())
+        .sc_call(ScCall::new("1", call(address("busd_owner"), "setDailyLimit").arg(address("holder_1")).arg("300"))
+            .expect(Expect::error("0x04", "only asset protection role can set daily limits")))
+        .sc_call(ScCall::new("2", call(address("holder_1"), "setDefaultDailyLimit").arg("300"))
+            .expect(Expect::error("0x04", "only asset protection role can set daily limits")))
+        .sc_call(ScCall::new("3", call(address("holder_1"), "removeDefaultDailyLimit"))
+            .expect(Expect::error("0x04", "only asset protection role can remove daily limits")))
+        .check_state(CheckState::new()
+            .account(address("busd_owner"), Account::new().nonce("*").balance("*"))
+            .account(address("asset_protection_role"), Account::new().nonce("*").balance("*"))
+            .account(address("holder_1"), Account::new().nonce("*").balance("*"))
+            .account(address("holder_2"), Account::new().nonce("*").balance("*"))
+            .account(address("holder_3"), Account::new().nonce("*").balance("*"))
+            .account(contract(), Account::contract()
+                .storage(key("owner", &[]), address("busd_owner"))
+                .storage(key("paused", &[]), "false")
+                .storage(key("ap_role", &[]), some(&address("asset_protection_role")))
+                .storage(key("supply_c", &[]), address("busd_owner"))
+                .busd_balance(&address("holder_1"), "1000")
+                .busd_balance(&address("holder_2"), "1000")))
+}
+
+fn daily_limit_set() -> Scenario {
+    Scenario::new("set daily limit")
+        .set_state(velocity_state())
+        .sc_call(ScCall::new("check_before", call(address("holder_3"), "getDailyLimit").arg(address("holder_1")))
+            .comment("no limit initially"))
+        .sc_call(ScCall::new("1", call(address("asset_protection_role"), "setDailyLimit").arg(address("holder_1")).arg("300"))
+            .comment("set limit")
+            .expect(Expect::ok()
+                .log(Log::event(contract(), "DailyLimitSet")
+                    .topic(address("holder_1"))
+                    .data("300"))))
+        .sc_call(ScCall::new("check_after", call(address("holder_3"), "getDailyLimit").arg(address("holder_1")))
+            .comment("limit is set")
+            .expect(Expect::ok().out("300")))
+        .sc_call(ScCall::new("check_remaining", call(address("holder_3"), "getRemainingDailyCapacity").arg(address("holder_1")))
+            .comment("full capacity available")
+            .expect(Expect::ok().out("300")))
+        .sc_call(ScCall::new("check_other", call(address("holder_3"), "getRemainingDailyCapacity").arg(address("holder_2")))
+            .comment("other addresses unaffected"))
+        .sc_call(ScCall::new("2", call(address("asset_protection_role"), "removeDailyLimit").arg(address("holder_1")))
+            .comment("remove limit")
+            .expect(Expect::ok()
+                .log(Log::event(contract(), "DailyLimitRemoved")
+                    .topic(address("holder_1")))))
+        .sc_call(ScCall::new("check_removed", call(address("holder_3"), "getDailyLimit").arg(address("holder_1")))
+            .comment("limit removed"))
+        .sc_call(ScCall::new("3", call(address("asset_protection_role"), "removeDailyLimit").arg(address("holder_1")))
+            .comment("nothing left to remove")
+            .expect(Expect::error("0x04", "address has no daily limit")))
+}
+
+fn default_daily_limit() -> Scenario {
+    Scenario::new("default daily limit")
+        .set_state(SetState::new()
+            .account(address("busd_owner"), Account::new().balance("100,000,000,000"))
+            .account(address("asset_protection_role"), Account::new().balance("100,000,000,000"))
+            .account(address("holder_1"), Account::new().balance("100,000,000,000"))
+            .account(address("holder_2"), Account::new().balance("100,000,000,000"))
+            .account(address("holder_3"), Account::new().balance("100,000,000,000"))
+            .account(contract(), Account::contract()
+                .storage(key("owner", &[]), address("busd_owner"))
+                .storage(key("paused", &[]), "false")
+                .storage(key("ap_role", &[]), some(&address("asset_protection_role")))
+                .storage(key("supply_c", &[]), address("busd_owner"))
+                .busd_balance(&address("holder_1"), "1000")
+                .busd_balance(&address("holder_2"), "1000")
+                .busd_allowance(&address("holder_1"), &address("holder_3"), "1000"))
+            .block_timestamp("1,000,000"))
+        .sc_call(ScCall::new("1", call(address("asset_protection_role"), "setDefaultDailyLimit").arg("100"))
+            .comment("set default limit")
+            .expect(Expect::ok()
+                .log(Log::event(contract(), "DefaultDailyLimitSet")
+                    .data("100"))))
+        .sc_call(ScCall::new("2", call(address("asset_protection_role"), "setDailyLimit").arg(address("holder_2")).arg("500"))
+            .comment("set specific limit")
+            .expect(Expect::ok()
+                .log(Log::event(contract(), "DailyLimitSet")
+                    .topic(address("holder_2"))
+                    .data("500"))))
+        .sc_call(ScCall::new("check_1", call(address("holder_3"), "getDailyLimit").arg(address("holder_1")))
+            .expect(Expect::ok().out("100")))
+        .sc_call(ScCall::new("check_2", call(address("holder_3"), "getDailyLimit").arg(address("holder_2")))
+            .expect(Expect::ok().out("500")))
+        .sc_call(ScCall::new("3", call(address("holder_3"), "transferFrom")
+                .arg(address("holder_1"))
+                .arg(address("holder_3"))
+                .arg("101"))
+            .comment("default applies to transferFrom too")
+            .expect(Expect::error("0x04", "daily transfer limit exceeded")))
+        .sc_call(ScCall::new("4", call(address("holder_2"), "transfer").arg(address("holder_1")).arg("500"))
+            .comment("specific limit overrides default")
+            .expect(Expect::ok()
+                .log(transfer_log(contract(), address("holder_2"), address("holder_1"), "500"))))
+        .sc_call(ScCall::new("5", call(address("asset_protection_role"), "removeDefaultDailyLimit"))
+            .comment("remove default limit")
+            .expect(Expect::ok().log(Log::event(contract(), "DefaultDailyLimitRemoved"))))
+        .sc_call(ScCall::new("check_3", call(address("holder_3"), "getDefaultDailyLimit")))
+        .sc_call(ScCall::new("6", call(address("holder_3"), "transferFrom")
+                .arg(address("holder_1"))
+                .arg(address("holder_3"))
+                .arg("1000"))
+            .comment("no longer limited")
+            .expect(Expect::ok()
+                .log(transfer_log(contract(), address("holder_1"), address("holder_3"), "1000"))))
+        .sc_call(ScCall::new("7", call(address("asset_protection_role"), "removeDefaultDailyLimit"))
+            .comment("nothing left to remove")
+            .expect(Expect::error("0x04", "no default daily limit")))
+}
+
+fn transfer_vs_daily_limit() -> Scenario {
+    Scenario::new("transfers against a daily limit")
+        .set_state(SetState::new()
+            .account(address("busd_owner"), Account::new().balance("100,000,000,000"))
+            .account(address("asset_protection_role"), Account::new().balance("100,000,000,000"))
+            .account(address("holder_1"), Account::new().balance("100,000,000,000"))
+            .account(address("holder_2"), Account::new().balance("100,000,000,000"))
+            .account(address("holder_3"), Account::new().balance("100,000,000,000"))
+            .account(contract(), Account::contract()
+                .storage(key("owner", &[]), address("busd_owner"))
+                .storage(key("paused", &[]), "false")
+                .storage(key("ap_role", &[]), some(&address("asset_protection_role")))
+                .storage(key("supply_c", &[]), address("busd_owner"))
+                .busd_balance(&address("holder_1"), "1000")
+                .busd_balance(&address("holder_2"), "1000")
+                .storage(key("vel_limit", &[&address("holder_1")]), some("300")))
+            .block_timestamp("1,000,000"))
+        .sc_call(ScCall::new("1", call(address("holder_1"), "transfer").arg(address("holder_2")).arg("200"))
+            .comment("counted in the bucket of hour 277, the one at 277 % 25")
+            .expect(Expect::ok()
+                .log(transfer_log(contract(), address("holder_1"), address("holder_2"), "200"))))
+        .sc_call(ScCall::new("check_1", call(address("holder_3"), "getRemainingDailyCapacity").arg(address("holder_1")))
+            .expect(Expect::ok().out("100")))
+        .sc_call(ScCall::new("check_2", call(address("holder_3"), "detectTransferRestriction")
+                .arg(address("holder_1"))
+                .arg(address("holder_2"))
+                .arg("101"))
+            .comment("restriction is reported")
+            .expect(Expect::ok().out("7")))
+        .sc_call(ScCall::new("2", call(address("holder_1"), "transfer").arg(address("holder_2")).arg("101"))
+            .comment("over the limit")
+            .expect(Expect::error("0x04", "daily transfer limit exceeded")))
+        .sc_call(ScCall::new("3", call(address("holder_1"), "transfer").arg(address("holder_2")).arg("100"))
+            .comment("exactly up to the limit")
+            .expect(Expect::ok()
+                .log(transfer_log(contract(), address("holder_1"), address("holder_2"), "100"))))
+        .sc_call(ScCall::new("4", call(address("holder_1"), "transfer").arg(address("holder_2")).arg("1"))
+            .comment("limit exhausted")
+            .expect(Expect::error("0x04", "daily transfer limit exceeded")))
+        .sc_call(ScCall::new("5", call(address("holder_2"), "transfer").arg(address("holder_1")).arg("500"))
+            .comment("receiving is not limited")
+            .expect(Expect::ok()
+                .log(transfer_log(contract(), address("holder_2"), address("holder_1"), "500"))))
+        .set_state(SetState::new()
+            .block_timestamp("1,087,199"))
+        .sc_call(ScCall::new("6", call(address("holder_1"), "transfer").arg(address("holder_2")).arg("1"))
+            .comment("hour 277 is not yet entirely more than 24 hours old")
+            .expect(Expect::error("0x04", "daily transfer limit exceeded")))
+        .set_state(SetState::new()
+            .block_timestamp("1,087,200"))
+        .sc_call(ScCall::new("check_3", call(address("holder_3"), "getDailySpent").arg(address("holder_1")))
+            .comment("hour 277 left the window")
+            .expect(Expect::ok().out("0")))
+        .sc_call(ScCall::new("7", call(address("holder_1"), "transfer").arg(address("holder_2")).arg("300"))
+            .comment("hour 302 reuses the bucket")
+            .expect(Expect::ok()
+                .log(transfer_log(contract(), address("holder_1"), address("holder_2"), "300"))))
+        .check_state(CheckState::new()
+            .account(address("busd_owner"), Account::new().nonce("*").balance("*"))
+            .account(address("asset_protection_role"), Account::new().nonce("*").balance("*"))
+            .account(address("holder_1"), Account::new().nonce("*").balance("*"))
+            .account(address("holder_2"), Account::new().nonce("*").balance("*"))
+            .account(address("holder_3"), Account::new().nonce("*").balance("*"))
+            .account(contract(), Account::contract()
+                .storage(key("owner", &[]), address("busd_owner"))
+                .storage(key("paused", &[]), "false")
+                .storage(key("ap_role", &[]), some(&address("asset_protection_role")))
+                .storage(key("supply_c", &[]), address("busd_owner"))
+                .busd_balance(&address("holder_1"), "700")
+                .busd_balance(&address("holder_2"), "1300")
+                .storage(key("vel_limit", &[&address("holder_1")]), some("300"))
+                .storage(key("vel_hour", &[&address("holder_1"), "2"]), "302")
+                .storage(key("vel_amount", &[&address("holder_1"), "2"]), "300")))
+}
@@ -0,0 +1,75 @@
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+
+use busd_scenario_gen::bench::*;
+
+#[test]
+fn test_case_names_unique() {
+    let cases = cases();
+    let names: BTreeSet<&str> = cases.iter().map(|case| case.name).collect();
+    assert_eq!(names.len(), cases.len());
+}
+
+#[test]
+fn test_case_scenario_gas_limit() {
+    let json = cases()[0].scenario(12345).to_json_string();
+    assert!(json.contains("\"gasLimit\": \"12345\""));
+    assert!(json.contains("\"status\": \"\""));
+}
+
+#[test]
+fn test_costs_round_trip() {
+    let mut costs = Costs::default();
+    assert_eq!(Costs::parse(&costs.to_json_string()), Ok(costs.clone()));
+
+    costs.wasm_size = Some(9000);
+    costs.gas.insert("transfer/fresh_recipient".to_string(), 12345);
+    assert_eq!(Costs::parse(&costs.to_json_string()), Ok(costs));
+
+    assert!(Costs::parse("{\"wasm_size\": \"big\"}").is_err());
+}
+
+#[test]
+fn test_regressions_past_tolerance() {
+    let mut baseline = Costs { wasm_size: Some(1000), ..Costs::default() };
+    baseline.gas.insert("pause".to_string(), 1000);
+    baseline.gas.insert("unpause".to_string(), 1000);
+
+    let mut measured = Costs { wasm_size: Some(1050), ..Costs::default() };
+    measured.gas.insert("pause".to_string(), 1051);
+    measured.gas.insert("unpause".to_string(), 500);
+    measured.gas.insert("freeze".to_string(), 5000);
+
+    let found = regressions(&baseline, &measured, 5);
+    assert_eq!(found, vec![Regression { what: "pause".to_string(), baseline: 1000, measured: 1051 }]);
+    assert_eq!(found[0].to_string(), "pause: 1000 -> 1051 (+5.1%)");
+
+    assert_eq!(regressions(&baseline, &measured, 10), vec![]);
+}
+
+#[test]
+fn test_missing_baseline_entries() {
+    let mut baseline = Costs::default();
+    baseline.gas.insert("pause".to_string(), 1000);
+
+    let mut measured = Costs { wasm_size: Some(1000), ..Costs::default() };
+    measured.gas.insert("pause".to_string(), 1000);
+    measured.gas.insert("freeze".to_string(), 5000);
+    assert_eq!(missing(&baseline, &measured), vec!["wasm size".to_string(), "freeze".to_string()]);
+
+    baseline.wasm_size = Some(1000);
+    baseline.gas.insert("freeze".to_string(), 5000);
+    assert_eq!(missing(&baseline, &measured), Vec::<String>::new());
+}
+
+/// The baseline only records costs of existing cases.
+#[test]
+fn test_committed_baseline_valid() {
+    let file = Path::new(env!("CARGO_MANIFEST_DIR")).join("gas-baseline.json");
+    let baseline = Costs::parse(&fs::read_to_string(file).unwrap()).unwrap();
+    let names: BTreeSet<&str> = cases().iter().map(|case| case.name).collect();
+    for name in baseline.gas.keys() {
+        assert!(names.contains(name.as_str()), "{} is not a benchmark case", name);
+    }
+}
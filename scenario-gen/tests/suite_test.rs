@@ -0,0 +1,25 @@
+use std::fs;
+use std::path::Path;
+
+use busd_scenario_gen::*;
+
+#[test]
+fn test_address_padding() {
+    assert_eq!(address("holder_1"), "``holder_1______________________s1");
+    assert_eq!(address_with_suffix("coin_holder_1", "sx"), "``coin_holder_1_________________sx");
+    assert_eq!(bytes32("payload_hash"), "``payload_hash____________________");
+    assert_eq!(event_identifier("Pause"), format!("0x5061757365{}", "0".repeat(54)));
+    assert_eq!(legacy_event_identifier(0x1d), format!("0x{}1d", "0".repeat(62)));
+}
+
+/// The committed scenarios must hold what the generator writes,
+/// run `cargo run` in this folder after changing the suite.
+#[test]
+fn test_committed_scenarios_up_to_date() {
+    let tests_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../tests");
+    for (path, scenario) in suite::all() {
+        let file = tests_dir.join(format!("{}.scen.json", path));
+        let committed = fs::read_to_string(&file).unwrap_or_default();
+        assert!(scenario.matches(&committed), "{} is out of date", file.display());
+    }
+}
@@ -0,0 +1,273 @@
+use elrond_wasm::ErrorMessage;
+
+/// All the errors the contract can fail with.
+///
+/// The numeric codes are stable: once released, a code keeps its meaning and is never reused.
+/// The message is what gets signalled on failure, so it is also stable.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum BUSDError {
+    // TRANSFER
+    Paused                                  = 1,
+    AddressFrozen                           = 2,
+    AllowanceExceeded                       = 3,
+    InsufficientFunds                       = 4,
+    DailyLimitExceeded                      = 5,
+    TravelRuleRequired                      = 6,
+
+    // OWNERSHIP
+    OnlyOwnerCanPropose                     = 10,
+    OwnerCannotProposeItself                = 11,
+    AlreadyProposedOwner                    = 12,
+    NoProposedOwnerToDisregard              = 13,
+    OnlyOwnerOrProposedOwnerCanDisregard    = 14,
+    NoOwnerProposed                         = 15,
+    OnlyProposedOwnerCanClaim               = 16,
+    OnlyOwnerCanReclaim                     = 17,
+
+    // PAUSE
+    AlreadyPaused                           = 20,
+    AlreadyUnpaused                         = 21,
+    OnlyPauserCanPause                      = 22,
+    OnlyPauserCanUnpause                    = 23,
+    OnlyOwnerCanSetPauser                   = 24,
+
+    // ASSET PROTECTION
+    OnlyOwnerOrAssetProtectionCanSetRole    = 30,
+    OnlyAssetProtectionCanFreeze            = 31,
+    AddressAlreadyFrozen                    = 32,
+    OnlyAssetProtectionCanUnfreeze          = 33,
+    AddressAlreadyUnfrozen                  = 34,
+    OnlyAssetProtectionCanWipe              = 35,
+    AddressNotFrozen                        = 36,
+
+    // SUPPLY CONTROL
+    OnlyOwnerOrSupplyControllerCanSet       = 40,
+    OnlySupplyControllerCanIncrease         = 41,
+    OnlySupplyControllerCanDecrease         = 42,
+    NotEnoughSupplyToDecrease               = 43,
+
+    // VELOCITY LIMITS
+    OnlyAssetProtectionCanSetDailyLimits    = 50,
+    OnlyAssetProtectionCanRemoveDailyLimits = 51,
+    NoAddressDailyLimit                     = 52,
+    NoDefaultDailyLimit                     = 53,
+
+    // LARGE TRANSFERS
+    OnlyOwnerCanChangeLargeTransfers        = 60,
+    NoLargeTransferThreshold                = 61,
+    PendingTransferNotFound                 = 62,
+    ReviewPeriodNotOver                     = 63,
+    OnlyAssetProtectionCanCancelTransfers   = 64,
+
+    // TRAVEL RULE
+    OnlyOwnerCanRegisterVasps               = 70,
+    AddressNotVasp                          = 71,
+    OnlyOwnerCanChangeTravelRule            = 72,
+    NoTravelRuleThreshold                   = 73,
+    VaspIdMismatch                          = 74,
+
+    // LEGACY EVENTS
+    OnlyOwnerCanChangeLegacyEvents          = 80,
+
+    // TOKEN METADATA
+    InvalidSymbolLength                     = 90,
+    InvalidDecimals                         = 91,
+    IncompleteInitialSupply                 = 92,
+
+    // UPGRADE
+    OnlyOwnerCanUpgrade                     = 100,
+    StorageUpToDate                         = 101,
+    StorageVersionTooNew                    = 102,
+
+    // STATE MIGRATION
+    OnlyOwnerCanRegisterHolders             = 110,
+    OnlyOwnerCanExport                      = 111,
+    OnlyOwnerCanImport                      = 112,
+    ImportRequiresFreshContract             = 113,
+    NoImportInProgress                      = 114,
+    ImportExceedsTotalSupply                = 115,
+    ImportIncomplete                        = 116,
+
+    // ESDT MIGRATION
+    OnlyOwnerCanChangeEsdtMigration         = 120,
+    EsdtMigrationNotEnabled                 = 121,
+    EsdtMigrationAlreadyEnabled             = 122,
+    InvalidEsdtToken                        = 123,
+    EsdtMigrationTooLarge                   = 124,
+
+    // SNAPSHOT
+    OnlySupplyControllerCanSnapshot         = 140,
+    SnapshotNotFound                        = 141,
+}
+
+use BUSDError::*;
+
+impl BUSDError {
+    /// Every error, in code order.
+    pub const ALL: &'static [BUSDError] = &[
+        Paused,
+        AddressFrozen,
+        AllowanceExceeded,
+        InsufficientFunds,
+        DailyLimitExceeded,
+        TravelRuleRequired,
+        OnlyOwnerCanPropose,
+        OwnerCannotProposeItself,
+        AlreadyProposedOwner,
+        NoProposedOwnerToDisregard,
+        OnlyOwnerOrProposedOwnerCanDisregard,
+        NoOwnerProposed,
+        OnlyProposedOwnerCanClaim,
+        OnlyOwnerCanReclaim,
+        AlreadyPaused,
+        AlreadyUnpaused,
+        OnlyPauserCanPause,
+        OnlyPauserCanUnpause,
+        OnlyOwnerCanSetPauser,
+        OnlyOwnerOrAssetProtectionCanSetRole,
+        OnlyAssetProtectionCanFreeze,
+        AddressAlreadyFrozen,
+        OnlyAssetProtectionCanUnfreeze,
+        AddressAlreadyUnfrozen,
+        OnlyAssetProtectionCanWipe,
+        AddressNotFrozen,
+        OnlyOwnerOrSupplyControllerCanSet,
+        OnlySupplyControllerCanIncrease,
+        OnlySupplyControllerCanDecrease,
+        NotEnoughSupplyToDecrease,
+        OnlyAssetProtectionCanSetDailyLimits,
+        OnlyAssetProtectionCanRemoveDailyLimits,
+        NoAddressDailyLimit,
+        NoDefaultDailyLimit,
+        OnlyOwnerCanChangeLargeTransfers,
+        NoLargeTransferThreshold,
+        PendingTransferNotFound,
+        ReviewPeriodNotOver,
+        OnlyAssetProtectionCanCancelTransfers,
+        OnlyOwnerCanRegisterVasps,
+        AddressNotVasp,
+        OnlyOwnerCanChangeTravelRule,
+        NoTravelRuleThreshold,
+        VaspIdMismatch,
+        OnlyOwnerCanChangeLegacyEvents,
+        InvalidSymbolLength,
+        InvalidDecimals,
+        IncompleteInitialSupply,
+        OnlyOwnerCanUpgrade,
+        StorageUpToDate,
+        StorageVersionTooNew,
+        OnlyOwnerCanRegisterHolders,
+        OnlyOwnerCanExport,
+        OnlyOwnerCanImport,
+        ImportRequiresFreshContract,
+        NoImportInProgress,
+        ImportExceedsTotalSupply,
+        ImportIncomplete,
+        OnlyOwnerCanChangeEsdtMigration,
+        EsdtMigrationNotEnabled,
+        EsdtMigrationAlreadyEnabled,
+        InvalidEsdtToken,
+        EsdtMigrationTooLarge,
+        OnlySupplyControllerCanSnapshot,
+        SnapshotNotFound,
+    ];
+
+    #[inline]
+    pub fn code(self) -> u32 {
+        self as u32
+    }
+
+    pub fn from_code(code: u32) -> Option<BUSDError> {
+        BUSDError::ALL.iter().find(|error| error.code() == code).copied()
+    }
+
+    pub fn message(self) -> &'static str {
+        match self {
+            Paused => "paused",
+            AddressFrozen => "address frozen",
+            AllowanceExceeded => "allowance exceeded",
+            InsufficientFunds => "insufficient funds",
+            DailyLimitExceeded => "daily transfer limit exceeded",
+            TravelRuleRequired => "travel rule metadata required",
+
+            OnlyOwnerCanPropose => "only owner can propose another owner",
+            OwnerCannotProposeItself => "current owner cannot propose itself",
+            AlreadyProposedOwner => "caller already is proposed owner",
+            NoProposedOwnerToDisregard => "can only disregard a proposed owner that was previously set",
+            OnlyOwnerOrProposedOwnerCanDisregard => "only proposedOwner or owner can disregard proposed owner",
+            NoOwnerProposed => "no owner proposed",
+            OnlyProposedOwnerCanClaim => "only proposed owner can claim ownership",
+            OnlyOwnerCanReclaim => "only owner can reclaim",
+
+            AlreadyPaused => "already paused",
+            AlreadyUnpaused => "already unpaused",
+            OnlyPauserCanPause => "only pauser can pause",
+            OnlyPauserCanUnpause => "only pauser can unpause",
+            OnlyOwnerCanSetPauser => "only owner can change pauser",
+
+            OnlyOwnerOrAssetProtectionCanSetRole => "only asset protection role or owner can change asset protection role",
+            OnlyAssetProtectionCanFreeze => "only asset protection role can freeze",
+            AddressAlreadyFrozen => "address already frozen",
+            OnlyAssetProtectionCanUnfreeze => "only asset protection role can unfreeze",
+            AddressAlreadyUnfrozen => "address already unfrozen",
+            OnlyAssetProtectionCanWipe => "only asset protection role can wipe",
+            AddressNotFrozen => "address is not frozen",
+
+            OnlyOwnerOrSupplyControllerCanSet => "only supply controller or owner can change supply controller",
+            OnlySupplyControllerCanIncrease => "only supply controller can increase supply",
+            OnlySupplyControllerCanDecrease => "only supply controller can decrease supply",
+            NotEnoughSupplyToDecrease => "not enough supply to decrease",
+
+            OnlyAssetProtectionCanSetDailyLimits => "only asset protection role can set daily limits",
+            OnlyAssetProtectionCanRemoveDailyLimits => "only asset protection role can remove daily limits",
+            NoAddressDailyLimit => "address has no daily limit",
+            NoDefaultDailyLimit => "no default daily limit",
+
+            OnlyOwnerCanChangeLargeTransfers => "only owner can change large transfer settings",
+            NoLargeTransferThreshold => "no large transfer threshold",
+            PendingTransferNotFound => "pending transfer not found",
+            ReviewPeriodNotOver => "review period not over",
+            OnlyAssetProtectionCanCancelTransfers => "only asset protection role can cancel pending transfers",
+
+            OnlyOwnerCanRegisterVasps => "only owner can register VASPs",
+            AddressNotVasp => "address is not a registered VASP",
+            OnlyOwnerCanChangeTravelRule => "only owner can change travel rule threshold",
+            NoTravelRuleThreshold => "no travel rule threshold",
+            VaspIdMismatch => "VASP id mismatch",
+
+            OnlyOwnerCanChangeLegacyEvents => "only owner can change legacy events",
+
+            InvalidSymbolLength => "invalid symbol length",
+            InvalidDecimals => "decimals out of range",
+            IncompleteInitialSupply => "initial holder and initial supply go together",
+
+            OnlyOwnerCanUpgrade => "only owner can upgrade",
+            StorageUpToDate => "storage already up to date",
+            StorageVersionTooNew => "storage version newer than code",
+
+            OnlyOwnerCanRegisterHolders => "only owner can register holders",
+            OnlyOwnerCanExport => "only owner can export state",
+            OnlyOwnerCanImport => "only owner can import state",
+            ImportRequiresFreshContract => "can only import into a fresh, paused contract",
+            NoImportInProgress => "no import in progress",
+            ImportExceedsTotalSupply => "imported balances exceed declared total supply",
+            ImportIncomplete => "imported balances do not add up to declared total supply",
+
+            OnlyOwnerCanChangeEsdtMigration => "only owner can change ESDT migration",
+            EsdtMigrationNotEnabled => "ESDT migration not enabled",
+            EsdtMigrationAlreadyEnabled => "ESDT migration already enabled",
+            InvalidEsdtToken => "invalid ESDT token identifier",
+            EsdtMigrationTooLarge => "ESDT migration above large transfer threshold",
+
+            OnlySupplyControllerCanSnapshot => "only supply controller can take snapshots",
+            SnapshotNotFound => "snapshot not found",
+        }
+    }
+}
+
+impl ErrorMessage for BUSDError {
+    #[inline]
+    fn with_message_slice<R, F: FnOnce(&[u8]) -> R>(&self, f: F) -> R {
+        f(self.message().as_bytes())
+    }
+}
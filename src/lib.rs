@@ -3,51 +3,171 @@
 #![no_main]
 #![allow(non_snake_case)]
 #![allow(unused_attributes)]
+// storage setters and the ESDT transfer arguments take `&Vec<_>`: the macros only handle sized values
+#![allow(clippy::ptr_arg)]
+// the optional constructor arguments, which the contract macro does not let through an attribute on `init`
+#![allow(clippy::too_many_arguments)]
 
 imports!();
 
-const NAME:     &[u8]    = b"Binance USD";
-const SYMBOL:   &[u8]    = b"BUSD";
-const DECIMALS: usize    = 18;
+mod errors;
+pub use errors::BUSDError;
+
+// TOKEN METADATA
+
+const MIN_SYMBOL_LENGTH: usize = 3;
+const MAX_SYMBOL_LENGTH: usize = 10;
+const MAX_DECIMALS:      u32   = 18;
+
+// TRANSFER RESTRICTION CODES (ERC-1404)
+
+const RESTRICTION_NONE:                 u8 = 0;
+const RESTRICTION_PAUSED:               u8 = 1;
+const RESTRICTION_SENDER_FROZEN:        u8 = 2;
+const RESTRICTION_RECIPIENT_FROZEN:     u8 = 3;
+const RESTRICTION_SPENDER_FROZEN:       u8 = 4;
+const RESTRICTION_ALLOWANCE_EXCEEDED:   u8 = 5;
+const RESTRICTION_INSUFFICIENT_FUNDS:   u8 = 6;
+const RESTRICTION_DAILY_LIMIT_EXCEEDED: u8 = 7;
+const RESTRICTION_TRAVEL_RULE_REQUIRED: u8 = 8;
+
+// VELOCITY LIMITS
+
+const DAILY_WINDOW_SECONDS: u64 = 24 * 60 * 60;
+const DAILY_BUCKET_SECONDS: u64 = 60 * 60;
+// a bucket counts until all of it is more than 24 hours old, so the window spans one bucket more than a day
+const DAILY_BUCKETS:        u64 = DAILY_WINDOW_SECONDS / DAILY_BUCKET_SECONDS + 1;
+
+// STORAGE VERSION
+
+// Version 0 is the layout of v0.3.0, deployed before the version was stored.
+const STORAGE_VERSION: u32 = 2;
+
+// The token metadata v0.3.0 had hardcoded, stored by the migration to version 1.
+const V0_NAME:     &[u8] = b"Binance USD";
+const V0_SYMBOL:   &[u8] = b"BUSD";
+const V0_DECIMALS: u32 = 18;
+
+/// The built-in function moving ESDT tokens, called on the recipient with the token identifier and the value.
+/// `ESDTTransfer` itself is not a contract endpoint: the protocol executes it, then calls back the sender.
+#[elrond_wasm_derive::callable(EsdtTransferProxy)]
+pub trait EsdtTransfer {
+    #[callback(esdt_transfer_callback)]
+    fn ESDTTransfer(&self, token_identifier: &Vec<u8>, value: &BigUint,
+        #[callback_arg] cb_holder: &Address,
+        #[callback_arg] cb_value: &BigUint,
+        #[callback_arg] cb_timestamp: u64);
+}
 
 #[elrond_wasm_derive::contract(BUSDCoinImpl)]
 pub trait BUSDCoin {
 
-    // STATIC INFO
+    // TOKEN METADATA
 
     #[view]
-    fn name(&self) -> &'static [u8] {
-        NAME
-    }
+    #[storage_get("name")]
+    fn name(&self) -> Vec<u8>;
+
+    #[storage_set("name")]
+    fn set_name(&self, name: &[u8]);
 
     #[view]
-    fn symbol(&self) -> &'static [u8] {
-        SYMBOL
-    }
+    #[storage_get("symbol")]
+    fn symbol(&self) -> Vec<u8>;
+
+    #[storage_set("symbol")]
+    fn set_symbol(&self, symbol: &[u8]);
 
     #[view]
-    fn decimals(&self) -> usize {
-        DECIMALS
-    }
+    #[storage_get("decimals")]
+    fn decimals(&self) -> u32;
+
+    #[storage_set("decimals")]
+    fn set_decimals(&self, decimals: u32);
 
     // CONSTRUCTOR
 
     /// constructor function
     /// is called immediately after the contract is created
+    /// with the token metadata, e.g. `Binance USD`, `BUSD` and 18:
+    /// the symbol has 3 to 10 characters, and there are at most 18 decimals
+    /// 
+    /// The optional arguments that follow assign the roles, so that the deploy key holds none of them.
+    /// The zero address leaves a role to its default, so that a later one can still be given:
+    /// 
+    /// * `owner` Defaults to the deploy caller.
+    /// * `supply_controller` Defaults to the owner.
+    /// * `asset_protection_role` Not set by default.
+    /// * `pauser` Not set by default, see `getPauser`.
+    /// * `initial_holder`, `initial_supply` The initial supply is minted to the initial holder, both or neither are given.
+    /// 
     #[init]
-    fn init(&self) {
-        // owner will be deploy caller
-        let owner = self.get_caller();
+    fn init(&self,
+        name: Vec<u8>,
+        symbol: Vec<u8>,
+        decimals: u32,
+        #[var_args] owner: OptionalArg<Address>,
+        #[var_args] supply_controller: OptionalArg<Address>,
+        #[var_args] asset_protection_role: OptionalArg<Address>,
+        #[var_args] pauser: OptionalArg<Address>,
+        #[var_args] initial_holder: OptionalArg<Address>,
+        #[var_args] initial_supply: OptionalArg<BigUint>) -> Result<(), BUSDError> {
+
+        // upgrading the code runs the constructor again:
+        // an initialized contract is left as it is, for the owner to migrate with `upgrade`
+        if !self.get_contract_owner_raw().is_empty() {
+            return Ok(());
+        }
+
+        if symbol.len() < MIN_SYMBOL_LENGTH || symbol.len() > MAX_SYMBOL_LENGTH {
+            return Err(BUSDError::InvalidSymbolLength);
+        }
+        if decimals > MAX_DECIMALS {
+            return Err(BUSDError::InvalidDecimals);
+        }
+        let initial_holder = initial_holder.into_option();
+        let initial_supply = initial_supply.into_option();
+        if initial_holder.is_some() != initial_supply.is_some() {
+            return Err(BUSDError::IncompleteInitialSupply);
+        }
+        self.set_name(&name);
+        self.set_symbol(&symbol);
+        self.set_decimals(decimals);
+        self.set_storage_version(STORAGE_VERSION);
+
+        // owner will be deploy caller, unless given
+        let owner = self.given_role(owner).unwrap_or_else(|| self.get_caller());
         self.set_contract_owner(&owner);
         
-        // owner is also the initial supply controller
-        self.set_supply_controller(&owner);
+        // owner is also the initial supply controller, unless given
+        let supply_controller = self.given_role(supply_controller).unwrap_or_else(|| owner.clone());
+        self.set_supply_controller(&supply_controller);
         
-        self.set_asset_protection_role(None);
+        let asset_protection_role = self.given_role(asset_protection_role);
+        self.set_asset_protection_role(asset_protection_role.as_ref());
+        let pauser = self.given_role(pauser);
+        self.set_pauser(pauser.as_ref());
         self.set_proposed_owner(None);
     
         // the contract starts paused
         self.set_paused(true);
+
+        self.initialized_event(
+            &owner,
+            &supply_controller,
+            &asset_protection_role.unwrap_or_else(Address::zero),
+            &pauser.unwrap_or_else(Address::zero),
+            true);
+
+        if let (Some(holder), Some(amount)) = (initial_holder, initial_supply) {
+            self.perform_mint(&holder, &amount);
+        }
+        Ok(())
+    }
+
+    /// A role argument of the constructor, `None` if left out or given as the zero address.
+    fn given_role(&self, role: OptionalArg<Address>) -> Option<Address> {
+        role.into_option().filter(|address| *address != Address::zero())
     }
 
     // ERC20 LOGIC
@@ -57,27 +177,51 @@ pub trait BUSDCoin {
     #[storage_get_mut("total_supply")]
     fn get_mut_total_supply(&self) -> mut_storage!(BigUint);
 
-    fn perform_transfer(&self, sender: Address, recipient: Address, amount: BigUint) -> Result<(), &str> {        
+    /// The travel rule metadata, if attached, is logged once the transfer settles.
+    fn perform_transfer(&self, sender: Address, recipient: Address, amount: BigUint,
+        travel_rule: Option<(&H256, &H256)>) -> Result<(), BUSDError> {
+
         // check if enough funds & decrease sender balance
         {
             let mut sender_balance = self.get_mut_balance(&sender);
-            if &amount > &*sender_balance {
-                return Err("insufficient funds");
+            if amount > *sender_balance {
+                return Err(BUSDError::InsufficientFunds);
             }
             
+            self.snapshot_balance(&sender);
             *sender_balance -= &amount; // saved automatically at the end of scope
+            self.holder_debited(&sender, &sender_balance);
+        }
+
+        // count amount towards the sender daily limit, if any
+        self.record_daily_spending(&sender, &amount);
+
+        // large transfers are held until the review period passes
+        if self.is_large_transfer(&amount) {
+            self.queue_pending_transfer(&sender, &recipient, &amount, travel_rule);
+            return Ok(());
+        }
+
+        if let Some((payload_hash, vasp_id)) = travel_rule {
+            self.travel_rule_event(&sender, &recipient, payload_hash, vasp_id, &amount);
         }
+        self.settle_transfer(&sender, &recipient, &amount);
 
+        Ok(())
+    }
+
+    /// Credits the recipient with funds already taken from the sender.
+    fn settle_transfer(&self, sender: &Address, recipient: &Address, amount: &BigUint) {
         // increase recipient balance
+        self.snapshot_balance(recipient);
         {
-            let mut recipient_balance = self.get_mut_balance(&recipient);
-            *recipient_balance += &amount; // saved automatically at the end of scope
+            let mut recipient_balance = self.get_mut_balance(recipient);
+            *recipient_balance += amount; // saved automatically at the end of scope
+            self.holder_credited(recipient, amount, &recipient_balance);
         }
     
         // log operation
-        self.transfer_event(&sender, &recipient, &amount);
-
-        Ok(())
+        self.log_transfer(sender, recipient, amount);
     }
 
     /// Transfer token to a specified address from sender.
@@ -87,19 +231,13 @@ pub trait BUSDCoin {
     /// * `to` The address to transfer to.
     /// 
     #[endpoint]
-    fn transfer(&self, to: Address, amount: BigUint) -> Result<(), &str> {
-        if self.is_paused() {
-            return Err("paused");
-        }
-        
+    fn transfer(&self, to: Address, amount: BigUint) -> Result<(), BUSDError> {
         // sender is the caller
         let sender = self.get_caller();
 
-        if self.is_frozen(&sender) || self.is_frozen(&to) {
-            return Err("address frozen");
-        }
+        self.check_transfer_restriction(None, &sender, &to, &amount, false)?;
 
-        self.perform_transfer(sender, to, amount)
+        self.perform_transfer(sender, to, amount, None)
     }
 
     /// Gets the balance of the specified address.
@@ -129,31 +267,20 @@ pub trait BUSDCoin {
     /// * `amount` the amount of tokens to be transferred.
     /// 
     #[endpoint(transferFrom)]
-    fn transfer_from(&self, sender: Address, recipient: Address, amount: BigUint) -> Result<(), &str> {
-        if self.is_paused() {
-            return Err("paused");
-        }
-        
+    fn transfer_from(&self, sender: Address, recipient: Address, amount: BigUint) -> Result<(), BUSDError> {
         // get caller
         let caller = self.get_caller();
 
-        if self.is_frozen(&caller) || self.is_frozen(&sender) || self.is_frozen(&recipient) {
-            return Err("address frozen");
-        }
+        self.check_transfer_restriction(Some(&caller), &sender, &recipient, &amount, false)?;
 
         // load allowance
         let mut allowance = self.get_mut_allowance(&sender, &caller);
 
-        // amount should not exceed allowance
-        if &amount > &*allowance {
-            return Err("allowance exceeded");
-        }
-
         // update allowance
         *allowance -= &amount; // saved automatically at the end of scope
 
         // transfer
-        self.perform_transfer(sender, recipient, amount)
+        self.perform_transfer(sender, recipient, amount, None)
     }
 
     /// Approve the given address to spend the specified amount of tokens on behalf of the sender.
@@ -165,23 +292,24 @@ pub trait BUSDCoin {
     /// * `amount` The amount of tokens to be spent.
     /// 
     #[endpoint]
-    fn approve(&self, spender: Address, amount: BigUint) -> Result<(), &str> {
+    fn approve(&self, spender: Address, amount: BigUint) -> Result<(), BUSDError> {
         if self.is_paused() {
-            return Err("paused");
+            return Err(BUSDError::Paused);
         }
 
         // sender is the caller
         let caller = self.get_caller();
 
         if self.is_frozen(&caller) || self.is_frozen(&spender) {
-            return Err("address frozen");
+            return Err(BUSDError::AddressFrozen);
         }
 
         // store allowance
         self.set_allowance(&caller, &spender, &amount);
+        self.update_spender(&caller, &spender, &amount);
       
         // log operation
-        self.approve_event(&caller, &spender, &amount);
+        self.log_approval(&caller, &spender, &amount);
         Ok(())
     }
 
@@ -196,9 +324,151 @@ pub trait BUSDCoin {
     #[storage_get_mut("allowance")]
     fn get_mut_allowance(&self, owner: &Address, spender: &Address) -> mut_storage!(BigUint);
 
+    #[storage_get("allowance")]
+    fn get_allowance(&self, owner: &Address, spender: &Address) -> BigUint;
+
     #[storage_set("allowance")]
     fn set_allowance(&self, owner: &Address, spender: &Address, allowance: &BigUint);
 
+    // TRANSFER RESTRICTION FUNCTIONALITY
+
+    /// Evaluates all the rules that can block a transfer, in the order in which they are enforced.
+    /// When a spender is given, the transfer is evaluated as a `transferFrom` performed by that spender.
+    /// Both `transfer` and `transferFrom` go through here, so the views below never diverge from them.
+    fn detect_transfer_restriction_for(&self, 
+        spender: Option<&Address>,
+        sender: &Address,
+        recipient: &Address,
+        amount: &BigUint,
+        travel_rule_attached: bool) -> u8 {
+
+        if self.is_paused() {
+            return RESTRICTION_PAUSED;
+        }
+        if let Some(spender) = spender {
+            if self.is_frozen(spender) {
+                return RESTRICTION_SPENDER_FROZEN;
+            }
+        }
+        if self.is_frozen(sender) {
+            return RESTRICTION_SENDER_FROZEN;
+        }
+        if self.is_frozen(recipient) {
+            return RESTRICTION_RECIPIENT_FROZEN;
+        }
+        if let Some(spender) = spender {
+            if amount > &self.get_allowance(sender, spender) {
+                return RESTRICTION_ALLOWANCE_EXCEEDED;
+            }
+        }
+        if amount > &self.balance_of(sender) {
+            return RESTRICTION_INSUFFICIENT_FUNDS;
+        }
+        if let Some(remaining) = self.remaining_daily_capacity(sender) {
+            if amount > &remaining {
+                return RESTRICTION_DAILY_LIMIT_EXCEEDED;
+            }
+        }
+        if !travel_rule_attached && self.travel_rule_required(sender, recipient, amount) {
+            return RESTRICTION_TRAVEL_RULE_REQUIRED;
+        }
+        RESTRICTION_NONE
+    }
+
+    fn check_transfer_restriction(&self, 
+        spender: Option<&Address>,
+        sender: &Address,
+        recipient: &Address,
+        amount: &BigUint,
+        travel_rule_attached: bool) -> Result<(), BUSDError> {
+
+        let restriction = self.detect_transfer_restriction_for(spender, sender, recipient, amount, travel_rule_attached);
+        match self.restriction_error(restriction) {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+
+    fn restriction_error(&self, restriction_code: u8) -> Option<BUSDError> {
+        match restriction_code {
+            RESTRICTION_PAUSED => Some(BUSDError::Paused),
+            RESTRICTION_SENDER_FROZEN |
+            RESTRICTION_RECIPIENT_FROZEN |
+            RESTRICTION_SPENDER_FROZEN => Some(BUSDError::AddressFrozen),
+            RESTRICTION_ALLOWANCE_EXCEEDED => Some(BUSDError::AllowanceExceeded),
+            RESTRICTION_INSUFFICIENT_FUNDS => Some(BUSDError::InsufficientFunds),
+            RESTRICTION_DAILY_LIMIT_EXCEEDED => Some(BUSDError::DailyLimitExceeded),
+            RESTRICTION_TRAVEL_RULE_REQUIRED => Some(BUSDError::TravelRuleRequired),
+            _ => None,
+        }
+    }
+
+    /// Yields the code of the restriction that would currently block a transfer, or 0 if it would succeed.
+    /// 
+    /// Arguments:
+    /// 
+    /// * `from` The address sending the tokens.
+    /// * `to` The address receiving the tokens.
+    /// * `amount` The amount of tokens to be transferred.
+    /// 
+    #[view(detectTransferRestriction)]
+    fn detect_transfer_restriction(&self, from: &Address, to: &Address, amount: &BigUint) -> u8 {
+        self.detect_transfer_restriction_for(None, from, to, amount, false)
+    }
+
+    /// Yields a human-readable explanation of a transfer restriction code.
+    /// Unlike the error signalled by the transfer itself, it tells which party is frozen.
+    /// 
+    /// Arguments:
+    /// 
+    /// * `restriction_code` A code, as returned by `detectTransferRestriction`.
+    /// 
+    #[view(messageForTransferRestriction)]
+    fn message_for_transfer_restriction(&self, restriction_code: u8) -> &'static [u8] {
+        match restriction_code {
+            RESTRICTION_NONE => b"no restriction",
+            RESTRICTION_PAUSED => b"transfers are paused",
+            RESTRICTION_SENDER_FROZEN => b"sender address is frozen",
+            RESTRICTION_RECIPIENT_FROZEN => b"recipient address is frozen",
+            RESTRICTION_SPENDER_FROZEN => b"spender address is frozen",
+            RESTRICTION_ALLOWANCE_EXCEEDED => b"amount exceeds the spender allowance",
+            RESTRICTION_INSUFFICIENT_FUNDS => b"amount exceeds the sender balance",
+            RESTRICTION_DAILY_LIMIT_EXCEEDED => b"amount exceeds the sender daily transfer limit",
+            RESTRICTION_TRAVEL_RULE_REQUIRED => b"travel rule metadata required between VASPs",
+            _ => b"unknown restriction code",
+        }
+    }
+
+    // ERROR CODES
+
+    /// Yields the message signalled by the contract for an error code.
+    /// 
+    /// Arguments:
+    /// 
+    /// * `error_code` A code, as listed in `BUSDError`.
+    /// 
+    #[view(getErrorMessage)]
+    fn get_error_message(&self, error_code: u32) -> &'static [u8] {
+        match BUSDError::from_code(error_code) {
+            Some(error) => error.message().as_bytes(),
+            None => b"unknown error code",
+        }
+    }
+
+    /// Yields the error code corresponding to a message signalled by the contract, or 0 if there is none.
+    /// 
+    /// Arguments:
+    /// 
+    /// * `message` The error message.
+    /// 
+    #[view(getErrorCode)]
+    fn get_error_code(&self, message: Vec<u8>) -> u32 {
+        BUSDError::ALL.iter()
+            .find(|error| error.message().as_bytes() == message.as_slice())
+            .map(|error| error.code())
+            .unwrap_or(0)
+    }
+
     // OWNER FUNCTIONALITY
 
     /// Yields the current contract owner.
@@ -228,40 +498,40 @@ pub trait BUSDCoin {
     /// * `proposed_owner` The address to transfer ownership to.
     /// 
     #[endpoint(proposeOwner)]
-    fn propose_owner(&self, proposed_owner: Address) -> Result<(), &str> {
+    fn propose_owner(&self, proposed_owner: Address) -> Result<(), BUSDError> {
         let caller = self.get_caller();
         if caller != self.get_contract_owner() {
-            return Err("only owner can propose another owner");
+            return Err(BUSDError::OnlyOwnerCanPropose);
         }
         if caller == proposed_owner {
-            return Err("current owner cannot propose itself");
+            return Err(BUSDError::OwnerCannotProposeItself);
         }
         if let Some(previous_proposed_owner) = self.get_proposed_owner() {
             if proposed_owner == previous_proposed_owner {
-                return Err("caller already is proposed owner"); 
+                return Err(BUSDError::AlreadyProposedOwner); 
             }
         }
 
         self.set_proposed_owner(Some(&proposed_owner));
 
         // event
-        self.ownership_transfer_proposed_event(&caller, &proposed_owner, ());
+        self.log_ownership_transfer_proposed(&caller, &proposed_owner);
         Ok(())
     }
 
     /// Allows the current owner or proposed owner to cancel transferring control of the contract to the proposed owner.
     #[endpoint(disregardProposedOwner)]
-    fn disregard_proposed_owner() -> Result<(), &str> {
+    fn disregard_proposed_owner() -> Result<(), BUSDError> {
         match self.get_proposed_owner() {
-            None => Err("can only disregard a proposed owner that was previously set"),
+            None => Err(BUSDError::NoProposedOwnerToDisregard),
             Some(proposed_owner) => {
                 let caller = self.get_caller();
                 if caller != self.get_contract_owner() && caller != proposed_owner {
-                    return Err("only proposedOwner or owner can disregard proposed owner"); 
+                    return Err(BUSDError::OnlyOwnerOrProposedOwnerCanDisregard); 
                 }
                 self.set_proposed_owner(None);
 
-                self.ownership_transfer_disregarded_event(&proposed_owner, ());
+                self.log_ownership_transfer_disregarded(&proposed_owner);
                 Ok(())
             }
         }
@@ -269,13 +539,13 @@ pub trait BUSDCoin {
 
     /// Allows the proposed owner to complete transferring control of the contract to herself..
     #[endpoint(claimOwnership)]
-    fn claim_ownership() -> Result<(), &str> {
+    fn claim_ownership() -> Result<(), BUSDError> {
         match self.get_proposed_owner() {
-            None => Err("no owner proposed"),
+            None => Err(BUSDError::NoOwnerProposed),
             Some(proposed_owner) => {
                 let caller = self.get_caller();
                 if caller != proposed_owner {
-                    return Err("only proposed owner can claim ownership")
+                    return Err(BUSDError::OnlyProposedOwnerCanClaim)
                 }
                 
                 let old_owner = self.get_contract_owner();
@@ -285,7 +555,7 @@ pub trait BUSDCoin {
                 // clear proposed owner
                 self.set_proposed_owner(None);
 
-                self.ownership_transferred_event(&old_owner, &proposed_owner, ());
+                self.log_ownership_transferred(&old_owner, &proposed_owner);
                 Ok(())  
             }
         }
@@ -295,25 +565,30 @@ pub trait BUSDCoin {
     /// This sends all the BUSD tokens that the address of the contract itself is holding to the owner.
     /// Note: this is not affected by freeze constraints.
     #[endpoint(reclaimBUSD)]
-    fn reclaim_busd() -> Result<(), &str> {
+    fn reclaim_busd() -> Result<(), BUSDError> {
         let caller = self.get_caller();
         if caller != self.get_contract_owner() {
-            return Err("only owner can reclaim"); 
+            return Err(BUSDError::OnlyOwnerCanReclaim); 
         }
 
         // load contract own balance
         let contract_address = self.get_sc_address();
+        self.snapshot_balance(&contract_address);
+        self.snapshot_balance(&caller);
         let mut contract_balance = self.get_mut_balance(&contract_address);
 
         // increment owner balance
         let mut owner_balance = self.get_mut_balance(&caller);
         *owner_balance += &*contract_balance; // saved automatically at the end of scope
+        self.holder_credited(&caller, &contract_balance, &owner_balance);
     
         // log operation
-        self.transfer_event(&contract_address, &caller, &contract_balance);
+        self.reclaimed_event(&caller, &contract_balance);
+        self.log_transfer(&contract_address, &caller, &contract_balance);
 
         // clear contract own balance
         (*contract_balance) = BigUint::zero();
+        self.holder_debited(&contract_address, &contract_balance);
 
         Ok(())
     }
@@ -327,27 +602,72 @@ pub trait BUSDCoin {
     #[storage_set("paused")]
     fn set_paused(&self, paused: bool);
 
-    /// Called by the owner to pause, triggers stopped state
+    #[storage_get("pauser")]
+    fn get_pauser(&self) -> Option<Address>;
+
+    #[storage_set("pauser")]
+    fn set_pauser(&self, pauser: Option<&Address>);
+
+    /// Yields the pauser, if any. Only the pauser can pause and unpause, or the owner while there is none.
+    #[view(getPauser)]
+    fn get_pauser_public(&self) -> OptionalResult<Address> {
+        self.get_pauser().into()
+    }
+
+    fn caller_can_pause(&self) -> bool {
+        match self.get_pauser() {
+            Some(pauser) => self.get_caller() == pauser,
+            None => self.get_caller() == self.get_contract_owner(),
+        }
+    }
+
+    /// Called by the owner to set a new pauser.
+    #[endpoint(setPauser)]
+    fn set_pauser_endpoint(&self, new_pauser: &Address) -> Result<(), BUSDError> {
+        if self.get_caller() != self.get_contract_owner() {
+            return Err(BUSDError::OnlyOwnerCanSetPauser)
+        }
+        let old_pauser = self.get_pauser().unwrap_or_else(Address::zero);
+        self.set_pauser(Some(new_pauser));
+
+        self.pauser_set_event(&old_pauser, new_pauser, ());
+        Ok(())
+    }
+
+    /// Called by the pauser, or by the owner while no pauser is set, to pause, triggers stopped state
     #[endpoint]
-    fn pause(&self) -> Result<(), &str> {
+    fn pause(&self) -> Result<(), BUSDError> {
+        if !self.caller_can_pause() {
+            return Err(BUSDError::OnlyPauserCanPause)
+        }
         if self.is_paused() {
-            return Err("already paused")
+            return Err(BUSDError::AlreadyPaused)
         }
         self.set_paused(true);
 
-        self.pause_event(());
+        self.log_pause(&self.get_caller());
         Ok(())
     }
 
-    /// Called by the owner to unpause, returns to normal state
+    /// Called by the pauser, or by the owner while no pauser is set, to unpause, returns to normal state
     #[endpoint]
-    fn unpause(&self) -> Result<(), &str> {
+    fn unpause(&self) -> Result<(), BUSDError> {
+        if !self.caller_can_pause() {
+            return Err(BUSDError::OnlyPauserCanUnpause)
+        }
         if !self.is_paused() {
-            return Err("already unpaused")
+            return Err(BUSDError::AlreadyUnpaused)
+        }
+        // a state import is complete once the imported balances add up to the declared total supply
+        if let Some(import_total_supply) = self.get_import_total_supply() {
+            if *self.get_mut_total_supply() != import_total_supply {
+                return Err(BUSDError::ImportIncomplete)
+            }
+            self.set_import_total_supply(None);
         }
         self.set_paused(false);
 
-        self.unpause_event(());
+        self.log_unpause(&self.get_caller());
         Ok(())
     }
 
@@ -381,26 +701,25 @@ pub trait BUSDCoin {
     /// * `new_asset_prot_role` The new address allowed to freeze/unfreeze addresses and seize their tokens.
     /// 
     #[endpoint(setAssetProtectionRole)]
-    fn set_asset_protection_role_endpoint(&self, new_asset_prot_role: &Address) -> Result<(), &str> {
+    fn set_asset_protection_role_endpoint(&self, new_asset_prot_role: &Address) -> Result<(), BUSDError> {
         let caller = self.get_caller();
         if caller != self.get_contract_owner() && 
            !self.caller_is_asset_protection_role() {
-            return Err("only asset protection role or owner can change asset protection role")
+            return Err(BUSDError::OnlyOwnerOrAssetProtectionCanSetRole)
         }
 
         // needed for logging
         let old_asset_protection_role = self
             .get_asset_protection_role()
-            .unwrap_or_else(|| Address::zero());
+            .unwrap_or_else(Address::zero);
 
         // change asset protection role
         self.set_asset_protection_role(Some(new_asset_prot_role));
 
         // log event
-        self.asset_protection_role_set_event(
+        self.log_asset_protection_role_set(
             &old_asset_protection_role,
-            new_asset_prot_role,
-            ()
+            new_asset_prot_role
         );
 
         Ok(())
@@ -413,16 +732,17 @@ pub trait BUSDCoin {
     /// * `address` The address to freeze.
     /// 
     #[endpoint]
-    fn freeze(&self, address: &Address) -> Result<(), &str> {
+    fn freeze(&self, address: &Address) -> Result<(), BUSDError> {
         if !self.caller_is_asset_protection_role() {
-            return Err("only asset protection role can freeze");
+            return Err(BUSDError::OnlyAssetProtectionCanFreeze);
         }
-        if self.is_frozen(&address) {
-            return Err("address already frozen");
+        if self.is_frozen(address) {
+            return Err(BUSDError::AddressAlreadyFrozen);
         }
-        self.set_frozen(&address, true);
+        self.set_frozen(address, true);
+        self.update_frozen_registry(address, true);
 
-        self.address_frozen_event(&address, ());
+        self.log_address_frozen(address);
         Ok(())
     }
 
@@ -433,16 +753,17 @@ pub trait BUSDCoin {
     /// * `address` The address to unfreeze.
     /// 
     #[endpoint]
-    fn unfreeze(&self, address: &Address) -> Result<(), &str> {
+    fn unfreeze(&self, address: &Address) -> Result<(), BUSDError> {
         if !self.caller_is_asset_protection_role() {
-            return Err("only asset protection role can unfreeze");
+            return Err(BUSDError::OnlyAssetProtectionCanUnfreeze);
         }
-        if !self.is_frozen(&address) {
-            return Err("address already unfrozen");
+        if !self.is_frozen(address) {
+            return Err(BUSDError::AddressAlreadyUnfrozen);
         }
-        self.set_frozen(&address, false);
+        self.set_frozen(address, false);
+        self.update_frozen_registry(address, false);
 
-        self.address_unfrozen_event(&address, ());
+        self.log_address_unfrozen(address);
         Ok(())
     }
 
@@ -454,28 +775,31 @@ pub trait BUSDCoin {
     /// * `address` The address to wipe.
     /// 
     #[endpoint(wipeFrozenAddress)]
-    fn wipe_frozen_address(&self, address: &Address) -> Result<(), &str> {
+    fn wipe_frozen_address(&self, address: &Address) -> Result<(), BUSDError> {
         if !self.caller_is_asset_protection_role() {
-            return Err("only asset protection role can wipe");
+            return Err(BUSDError::OnlyAssetProtectionCanWipe);
         }
-        if !self.is_frozen(&address) {
-            return Err("address is not frozen");
+        if !self.is_frozen(address) {
+            return Err(BUSDError::AddressNotFrozen);
         }
 
         // erase balance
-        let mut balance_to_wipe = self.get_mut_balance(&address);
+        self.snapshot_balance(address);
+        self.snapshot_total_supply();
+        let mut balance_to_wipe = self.get_mut_balance(address);
 
         // decrease total supply
         let mut total_supply = self.get_mut_total_supply();
         *total_supply -= &*balance_to_wipe;
 
         // log operation
-        self.frozen_address_wiped_event(&address, ());
-        self.supply_decreased_event(&address, &*balance_to_wipe);
-        self.transfer_event(&address,  &[0u8; 32].into(), &*balance_to_wipe);
+        self.log_frozen_address_wiped(address);
+        self.log_supply_decreased(address, &*balance_to_wipe);
+        self.log_transfer(address,  &[0u8; 32].into(), &*balance_to_wipe);
 
         // erase balance
         *balance_to_wipe = BigUint::zero(); // saved automatically at the end of scope
+        self.holder_debited(address, &balance_to_wipe);
 
         Ok(())
     }
@@ -493,186 +817,1805 @@ pub trait BUSDCoin {
     #[storage_set("frozen")]
     fn set_frozen(&self, address: &Address, frozen: bool);
 
-    // SUPPLY CONTROL FUNCTIONALITY
+    // VELOCITY LIMIT FUNCTIONALITY
 
-    /// Yields the currently proposed new owner, if any.
-    #[view(getSupplyController)]
-    #[storage_get("supply_c")]
-    fn get_supply_controller(&self) -> Address;
+    #[storage_get("vel_default")]
+    fn get_default_daily_limit(&self) -> Option<BigUint>;
 
-    #[storage_set("supply_c")]
-    fn set_supply_controller(&self, address: &Address);
+    #[storage_set("vel_default")]
+    fn set_default_daily_limit(&self, limit: Option<&BigUint>);
 
-    fn caller_is_supply_controller(&self) -> bool {
-        return self.get_caller() == self.get_supply_controller()
+    #[storage_get("vel_limit")]
+    fn get_address_daily_limit(&self, address: &Address) -> Option<BigUint>;
+
+    #[storage_set("vel_limit")]
+    fn set_address_daily_limit(&self, address: &Address, limit: Option<&BigUint>);
+
+    /// The hour, counted from the epoch, of the transfers summed up in a bucket.
+    #[storage_get("vel_hour")]
+    fn get_daily_bucket_hour(&self, address: &Address, bucket: u64) -> u64;
+
+    #[storage_set("vel_hour")]
+    fn set_daily_bucket_hour(&self, address: &Address, bucket: u64, hour: u64);
+
+    #[storage_get("vel_amount")]
+    fn get_daily_bucket_amount(&self, address: &Address, bucket: u64) -> BigUint;
+
+    #[storage_set("vel_amount")]
+    fn set_daily_bucket_amount(&self, address: &Address, bucket: u64, amount: &BigUint);
+
+    /// The limit that applies to an address: its own limit if set, otherwise the default one.
+    fn get_daily_limit(&self, address: &Address) -> Option<BigUint> {
+        self.get_address_daily_limit(address)
+            .or_else(|| self.get_default_daily_limit())
     }
 
-    /// Sets a new supply controller address.
+    /// Amount sent by the address in the rolling window.
+    /// Transfers are summed up per hour, in one of `DAILY_BUCKETS` buckets reused in turn,
+    /// and an hour counts until it is entirely more than 24 hours old:
+    /// an amount stops counting between 24 and 25 hours after it was sent, never sooner.
+    fn get_daily_spent(&self, address: &Address) -> BigUint {
+        let now = self.get_block_timestamp();
+        let current_hour = now / DAILY_BUCKET_SECONDS;
+        let mut spent = BigUint::zero();
+        for bucket in 0..DAILY_BUCKETS {
+            if self.get_daily_bucket_hour(address, bucket) + DAILY_BUCKETS > current_hour {
+                spent += &self.get_daily_bucket_amount(address, bucket);
+            }
+        }
+        spent
+    }
+
+    /// Only addresses that have a limit applied are tracked.
+    fn record_daily_spending(&self, address: &Address, amount: &BigUint) {
+        if self.get_daily_limit(address).is_none() {
+            return;
+        }
+
+        let current_hour = self.get_block_timestamp() / DAILY_BUCKET_SECONDS;
+        let bucket = current_hour % DAILY_BUCKETS;
+        let mut bucket_amount = if self.get_daily_bucket_hour(address, bucket) == current_hour {
+            self.get_daily_bucket_amount(address, bucket)
+        } else {
+            // the bucket held an hour that left the window
+            self.set_daily_bucket_hour(address, bucket, current_hour);
+            BigUint::zero()
+        };
+        bucket_amount += amount;
+        self.set_daily_bucket_amount(address, bucket, &bucket_amount);
+    }
+
+    /// Gives back an amount recorded at `timestamp`, from the bucket of that hour.
+    /// Nothing is given back once the bucket was reused for a later hour, since the amount no longer counts by then.
+    fn refund_daily_spending(&self, address: &Address, amount: &BigUint, timestamp: u64) {
+        let hour = timestamp / DAILY_BUCKET_SECONDS;
+        let bucket = hour % DAILY_BUCKETS;
+        if self.get_daily_bucket_hour(address, bucket) != hour {
+            return;
+        }
+
+        let bucket_amount = self.get_daily_bucket_amount(address, bucket);
+        if &bucket_amount > amount {
+            self.set_daily_bucket_amount(address, bucket, &(&bucket_amount - amount));
+        } else {
+            self.set_daily_bucket_amount(address, bucket, &BigUint::zero());
+        }
+    }
+
+    fn remaining_daily_capacity(&self, address: &Address) -> Option<BigUint> {
+        self.get_daily_limit(address).map(|limit| {
+            let spent = self.get_daily_spent(address);
+            if spent >= limit {
+                BigUint::zero()
+            } else {
+                &limit - &spent
+            }
+        })
+    }
+
+    /// Yields the daily outgoing transfer limit that applies to an address, if any.
     /// 
     /// Arguments:
     /// 
-    /// * `new_supply_controller` The address allowed to burn/mint tokens to control supply.
+    /// * `address` The address to check.
     /// 
-    #[endpoint(setSupplyController)]
-    fn set_supply_controller_endpoint(&self, new_supply_controller: &Address) -> Result<(), &str> {
-        let caller = self.get_caller();
-        if caller != self.get_contract_owner() && 
-           !self.caller_is_supply_controller() {
-            return Err("only supply controller or owner can change supply controller")
-        }
-
-        // needed for logging
-        let old_supply_controller = self.get_supply_controller();
+    #[view(getDailyLimit)]
+    fn get_daily_limit_public(&self, address: &Address) -> OptionalResult<BigUint> {
+        self.get_daily_limit(address).into()
+    }
 
-        // change supply controller
-        self.set_supply_controller(&new_supply_controller);
+    /// Yields the default daily outgoing transfer limit, if any.
+    #[view(getDefaultDailyLimit)]
+    fn get_default_daily_limit_public(&self) -> OptionalResult<BigUint> {
+        self.get_default_daily_limit().into()
+    }
 
-        // log event
-        self.supply_controller_set_event(
-            &old_supply_controller,
-            new_supply_controller,
-            ()
-        );
+    /// Yields the amount an address sent in the last 24 hours, counted by the hour:
+    /// an amount stops counting between 24 and 25 hours after it was sent.
+    /// 
+    /// Arguments:
+    /// 
+    /// * `address` The address to check.
+    /// 
+    #[view(getDailySpent)]
+    fn get_daily_spent_public(&self, address: &Address) -> BigUint {
+        self.get_daily_spent(address)
+    }
 
-        Ok(())
+    /// Yields how much more an address can send before its transfers of the last 24 hours reach its limit.
+    /// Nothing is returned if the address is not limited.
+    /// 
+    /// Arguments:
+    /// 
+    /// * `address` The address to check.
+    /// 
+    #[view(getRemainingDailyCapacity)]
+    fn get_remaining_daily_capacity(&self, address: &Address) -> OptionalResult<BigUint> {
+        self.remaining_daily_capacity(address).into()
     }
 
-    /// Increases the total supply by minting the specified number of tokens to the supply controller account.
+    /// Sets the maximum amount an address can send in any 24 hours.
+    /// Overrides the default limit for that address.
     /// 
     /// Arguments:
     /// 
-    /// * `amount` The number of tokens to add.
+    /// * `address` The address to limit.
+    /// * `limit` The maximum amount per 24 hours.
     /// 
-    #[endpoint(increaseSupply)]
-    fn increase_supply(&self, amount: BigUint) -> Result<(), &str> {
-        if !self.caller_is_supply_controller() {
-            return Err("only supply controller can increase supply");
+    #[endpoint(setDailyLimit)]
+    fn set_daily_limit(&self, address: &Address, limit: BigUint) -> Result<(), BUSDError> {
+        if !self.caller_is_asset_protection_role() {
+            return Err(BUSDError::OnlyAssetProtectionCanSetDailyLimits);
         }
-        let supply_controller = self.get_caller();
-
-        // increase supply controller balance
-        let mut supply_contr_balance = self.get_mut_balance(&supply_controller);
-        *supply_contr_balance += &amount; // saved automatically at the end of scope
+        self.set_address_daily_limit(address, Some(&limit));
 
-        // increase total supply
-        let mut total_supply = self.get_mut_total_supply();
-        *total_supply += &amount; // saved automatically at the end of scope
+        self.daily_limit_set_event(address, &limit);
+        Ok(())
+    }
 
-        // log operation
-        self.supply_increased_event(&supply_controller, &amount);
-        self.transfer_event(&[0u8; 32].into(), &supply_controller, &amount);
+    /// Removes the limit specific to an address, which falls back to the default limit.
+    /// 
+    /// Arguments:
+    /// 
+    /// * `address` The address whose limit is removed.
+    /// 
+    #[endpoint(removeDailyLimit)]
+    fn remove_daily_limit(&self, address: &Address) -> Result<(), BUSDError> {
+        if !self.caller_is_asset_protection_role() {
+            return Err(BUSDError::OnlyAssetProtectionCanRemoveDailyLimits);
+        }
+        if self.get_address_daily_limit(address).is_none() {
+            return Err(BUSDError::NoAddressDailyLimit);
+        }
+        self.set_address_daily_limit(address, None);
 
+        self.daily_limit_removed_event(address, ());
         Ok(())
     }
 
-    /// Decreases the total supply by burning the specified number of tokens from the supply controller account.
+    /// Sets the daily limit that applies to all addresses without a specific one.
     /// 
     /// Arguments:
     /// 
-    /// * `amount` The number of tokens to remove.
+    /// * `limit` The maximum amount per 24 hours.
     /// 
-    #[endpoint(decreaseSupply)]
-    fn decrease_supply(&self, amount: BigUint) -> Result<(), &str> {
-        if !self.caller_is_supply_controller() {
-            return Err("only supply controller can decrease supply");
+    #[endpoint(setDefaultDailyLimit)]
+    fn set_default_daily_limit_endpoint(&self, limit: BigUint) -> Result<(), BUSDError> {
+        if !self.caller_is_asset_protection_role() {
+            return Err(BUSDError::OnlyAssetProtectionCanSetDailyLimits);
         }
-        let supply_controller = self.get_caller();
+        self.set_default_daily_limit(Some(&limit));
 
-        // get supply controller balance
-        let mut supply_contr_balance = self.balance_of(&supply_controller);
+        self.default_daily_limit_set_event(&limit);
+        Ok(())
+    }
 
-        // check
-        if amount > supply_contr_balance {
-            return Err("not enough supply to decrease")
+    /// Removes the default daily limit.
+    #[endpoint(removeDefaultDailyLimit)]
+    fn remove_default_daily_limit(&self) -> Result<(), BUSDError> {
+        if !self.caller_is_asset_protection_role() {
+            return Err(BUSDError::OnlyAssetProtectionCanRemoveDailyLimits);
+        }
+        if self.get_default_daily_limit().is_none() {
+            return Err(BUSDError::NoDefaultDailyLimit);
         }
+        self.set_default_daily_limit(None);
 
-        // decrease supply controller balance
-        supply_contr_balance -= &amount;
-        self.set_balance(&supply_controller, &supply_contr_balance);
+        self.default_daily_limit_removed_event(());
+        Ok(())
+    }
 
-        // decrease total supply
-        let mut total_supply = self.get_mut_total_supply();
-        *total_supply -= &amount; // saved automatically at the end of scope
+    // LARGE TRANSFER FUNCTIONALITY
 
-        // log operation
-        self.supply_decreased_event(&supply_controller, &amount);
-        self.transfer_event(&supply_controller, &[0u8; 32].into(), &amount);
+    #[storage_get("lt_threshold")]
+    fn get_large_transfer_threshold(&self) -> Option<BigUint>;
 
-        Ok(())
+    #[storage_set("lt_threshold")]
+    fn set_large_transfer_threshold(&self, threshold: Option<&BigUint>);
+
+    /// Yields the amount above which transfers are held for review, if any.
+    #[view(getLargeTransferThreshold)]
+    fn get_large_transfer_threshold_public(&self) -> OptionalResult<BigUint> {
+        self.get_large_transfer_threshold().into()
     }
 
-    // ERC20 BASIC EVENTS
-    
-    #[event("0x0000000000000000000000000000000000000000000000000000000000000001")]
-    fn transfer_event(&self,
-        sender: &Address,
-        recipient: &Address,
-        amount: &BigUint);
+    /// Yields the number of seconds a large transfer is held before it can be executed.
+    #[view(getLargeTransferReviewPeriod)]
+    #[storage_get("lt_review")]
+    fn get_large_transfer_review_period(&self) -> u64;
 
-    // ERC20 EVENTS
+    #[storage_set("lt_review")]
+    fn set_large_transfer_review_period(&self, review_period: u64);
 
-    #[event("0x0000000000000000000000000000000000000000000000000000000000000002")]
-    fn approve_event(&self,
-        sender: &Address,
-        recipient: &Address,
-        amount: &BigUint);
+    #[storage_get("pt_last_id")]
+    fn get_last_pending_transfer_id(&self) -> u64;
 
-    // OWNABLE EVENTS
+    #[storage_set("pt_last_id")]
+    fn set_last_pending_transfer_id(&self, id: u64);
 
-    #[event("0x0000000000000000000000000000000000000000000000000000000000000003")]
-    fn ownership_transfer_proposed_event(&self, 
-        current_owner: &Address,
-        proposed_owner: &Address,
-        _data: ());
+    /// Yields the ids of all the transfers awaiting execution.
+    #[view(getPendingTransferIds)]
+    fn get_pending_transfer_ids_public(&self) -> MultiResultVec<u64> {
+        self.get_pending_transfer_ids().into()
+    }
 
-    #[event("0x0000000000000000000000000000000000000000000000000000000000000004")]
-    fn ownership_transfer_disregarded_event(&self, 
-        old_proposed_owner: &Address,
-        _data: ());
+    #[storage_get("pt_ids")]
+    fn get_pending_transfer_ids(&self) -> Vec<u64>;
 
-    #[event("0x0000000000000000000000000000000000000000000000000000000000000005")]
-    fn ownership_transferred_event(&self, 
-        old_owner: &Address,
-        new_owner: &Address,
-        _data: ());
-    
-    // PAUSABLE EVENTS
+    #[storage_set("pt_ids")]
+    fn set_pending_transfer_ids(&self, ids: &Vec<u64>);
 
-    #[event("0x0000000000000000000000000000000000000000000000000000000000000006")]
-    fn pause_event(&self, _data: ());
+    /// Yields the sum of all transfers awaiting execution.
+    /// These funds are no longer in the sender balance, but still count towards the total supply.
+    #[view(getPendingTransfersTotal)]
+    #[storage_get_mut("pt_total")]
+    fn get_mut_pending_transfers_total(&self) -> mut_storage!(BigUint);
 
-    #[event("0x0000000000000000000000000000000000000000000000000000000000000007")]
-    fn unpause_event(&self, _data: ());
+    #[storage_get("pt_sender")]
+    fn get_pending_transfer_sender(&self, id: u64) -> Address;
 
-    // ASSET PROTECTION EVENTS
+    #[storage_set("pt_sender")]
+    fn set_pending_transfer_sender(&self, id: u64, sender: &Address);
 
-    #[event("0x0000000000000000000000000000000000000000000000000000000000000008")]
-    fn address_frozen_event(&self, address: &Address, _data: ());
+    #[storage_get("pt_recipient")]
+    fn get_pending_transfer_recipient(&self, id: u64) -> Address;
 
-    #[event("0x0000000000000000000000000000000000000000000000000000000000000009")]
-    fn address_unfrozen_event(&self, address: &Address, _data: ());
+    #[storage_set("pt_recipient")]
+    fn set_pending_transfer_recipient(&self, id: u64, recipient: &Address);
 
-    #[event("0x000000000000000000000000000000000000000000000000000000000000000a")]
-    fn frozen_address_wiped_event(&self, address: &Address, _data: ());
+    #[storage_get("pt_amount")]
+    fn get_pending_transfer_amount(&self, id: u64) -> BigUint;
 
-    #[event("0x000000000000000000000000000000000000000000000000000000000000000b")]
-    fn asset_protection_role_set_event(&self, 
-        old_asset_protection_role: &Address,
-        new_asset_protection_role: &Address,
-        _data: ());
+    #[storage_set("pt_amount")]
+    fn set_pending_transfer_amount(&self, id: u64, amount: &BigUint);
 
-    // SUPPLY CONTROL EVENTS
+    #[storage_get("pt_time")]
+    fn get_pending_transfer_timestamp(&self, id: u64) -> u64;
 
-    #[event("0x000000000000000000000000000000000000000000000000000000000000000c")]
+    #[storage_set("pt_time")]
+    fn set_pending_transfer_timestamp(&self, id: u64, timestamp: u64);
+
+    /// The travel rule payload hash attached to the transfer, if any.
+    #[storage_get("pt_payload")]
+    fn get_pending_transfer_payload_hash(&self, id: u64) -> Option<H256>;
+
+    #[storage_set("pt_payload")]
+    fn set_pending_transfer_payload_hash(&self, id: u64, payload_hash: Option<&H256>);
+
+    #[storage_get("pt_vasp")]
+    fn get_pending_transfer_vasp_id(&self, id: u64) -> H256;
+
+    #[storage_set("pt_vasp")]
+    fn set_pending_transfer_vasp_id(&self, id: u64, vasp_id: &H256);
+
+    fn is_large_transfer(&self, amount: &BigUint) -> bool {
+        match self.get_large_transfer_threshold() {
+            Some(threshold) => amount > &threshold,
+            None => false,
+        }
+    }
+
+    /// Pending transfers always hold a non-zero amount, since only amounts above the threshold get queued.
+    fn pending_transfer_exists(&self, id: u64) -> bool {
+        self.get_pending_transfer_amount(id) > BigUint::zero()
+    }
+
+    fn queue_pending_transfer(&self, sender: &Address, recipient: &Address, amount: &BigUint,
+        travel_rule: Option<(&H256, &H256)>) {
+        let id = self.get_last_pending_transfer_id() + 1;
+        self.set_last_pending_transfer_id(id);
+
+        self.set_pending_transfer_sender(id, sender);
+        self.set_pending_transfer_recipient(id, recipient);
+        self.set_pending_transfer_amount(id, amount);
+        self.set_pending_transfer_timestamp(id, self.get_block_timestamp());
+        if let Some((payload_hash, vasp_id)) = travel_rule {
+            self.set_pending_transfer_payload_hash(id, Some(payload_hash));
+            self.set_pending_transfer_vasp_id(id, vasp_id);
+        }
+
+        let mut ids = self.get_pending_transfer_ids();
+        ids.push(id);
+        self.set_pending_transfer_ids(&ids);
+
+        let mut pending_total = self.get_mut_pending_transfers_total();
+        *pending_total += amount; // saved automatically at the end of scope
+
+        self.large_transfer_queued_event(sender, recipient, &BigUint::from(id), amount);
+    }
+
+    /// Only the amount is erased, the other fields are kept as a record of the transfer.
+    fn clear_pending_transfer(&self, id: u64) {
+        let amount = self.get_pending_transfer_amount(id);
+        let mut pending_total = self.get_mut_pending_transfers_total();
+        *pending_total -= &amount; // saved automatically at the end of scope
+
+        self.set_pending_transfer_amount(id, &BigUint::zero());
+
+        let mut ids = self.get_pending_transfer_ids();
+        ids.retain(|pending_id| *pending_id != id);
+        self.set_pending_transfer_ids(&ids);
+    }
+
+    /// Yields the sender, recipient, amount and queue timestamp of a pending transfer.
+    /// 
+    /// Arguments:
+    /// 
+    /// * `id` The pending transfer id.
+    /// 
+    #[view(getPendingTransfer)]
+    fn get_pending_transfer(&self, id: u64) -> Result<MultiResult4<Address, Address, BigUint, u64>, BUSDError> {
+        if !self.pending_transfer_exists(id) {
+            return Err(BUSDError::PendingTransferNotFound);
+        }
+        Ok((
+            self.get_pending_transfer_sender(id),
+            self.get_pending_transfer_recipient(id),
+            self.get_pending_transfer_amount(id),
+            self.get_pending_transfer_timestamp(id),
+        ).into())
+    }
+
+    /// Sets the amount above which transfers are queued instead of being settled immediately.
+    /// 
+    /// Arguments:
+    /// 
+    /// * `threshold` The largest amount that still gets transferred instantly.
+    /// 
+    #[endpoint(setLargeTransferThreshold)]
+    fn set_large_transfer_threshold_endpoint(&self, threshold: BigUint) -> Result<(), BUSDError> {
+        if self.get_caller() != self.get_contract_owner() {
+            return Err(BUSDError::OnlyOwnerCanChangeLargeTransfers);
+        }
+        self.set_large_transfer_threshold(Some(&threshold));
+
+        self.large_transfer_threshold_set_event(&threshold);
+        Ok(())
+    }
+
+    /// Disables queueing of large transfers. Already queued transfers remain pending.
+    #[endpoint(removeLargeTransferThreshold)]
+    fn remove_large_transfer_threshold(&self) -> Result<(), BUSDError> {
+        if self.get_caller() != self.get_contract_owner() {
+            return Err(BUSDError::OnlyOwnerCanChangeLargeTransfers);
+        }
+        if self.get_large_transfer_threshold().is_none() {
+            return Err(BUSDError::NoLargeTransferThreshold);
+        }
+        self.set_large_transfer_threshold(None);
+
+        self.large_transfer_threshold_removed_event(());
+        Ok(())
+    }
+
+    /// Sets how long large transfers are held, giving the asset protection role time to cancel them.
+    /// 
+    /// Arguments:
+    /// 
+    /// * `review_period` The review period, in seconds.
+    /// 
+    #[endpoint(setLargeTransferReviewPeriod)]
+    fn set_large_transfer_review_period_endpoint(&self, review_period: u64) -> Result<(), BUSDError> {
+        if self.get_caller() != self.get_contract_owner() {
+            return Err(BUSDError::OnlyOwnerCanChangeLargeTransfers);
+        }
+        self.set_large_transfer_review_period(review_period);
+
+        self.large_transfer_review_period_set_event(review_period);
+        Ok(())
+    }
+
+    /// Settles a queued transfer once its review period has passed.
+    /// Anyone can execute it, but the same pause and freeze rules as for regular transfers apply.
+    /// 
+    /// Arguments:
+    /// 
+    /// * `id` The pending transfer id.
+    /// 
+    #[endpoint(executePendingTransfer)]
+    fn execute_pending_transfer(&self, id: u64) -> Result<(), BUSDError> {
+        if !self.pending_transfer_exists(id) {
+            return Err(BUSDError::PendingTransferNotFound);
+        }
+        if self.is_paused() {
+            return Err(BUSDError::Paused);
+        }
+
+        let sender = self.get_pending_transfer_sender(id);
+        let recipient = self.get_pending_transfer_recipient(id);
+        if self.is_frozen(&sender) || self.is_frozen(&recipient) {
+            return Err(BUSDError::AddressFrozen);
+        }
+
+        let review_end = self.get_pending_transfer_timestamp(id) + self.get_large_transfer_review_period();
+        if self.get_block_timestamp() < review_end {
+            return Err(BUSDError::ReviewPeriodNotOver);
+        }
+
+        let amount = self.get_pending_transfer_amount(id);
+        self.clear_pending_transfer(id);
+
+        self.large_transfer_executed_event(&sender, &recipient, &BigUint::from(id), &amount);
+        if let Some(payload_hash) = self.get_pending_transfer_payload_hash(id) {
+            self.travel_rule_event(&sender, &recipient, &payload_hash, &self.get_pending_transfer_vasp_id(id), &amount);
+        }
+        self.settle_transfer(&sender, &recipient, &amount);
+
+        Ok(())
+    }
+
+    /// Cancels a queued transfer, returning the funds to the sender.
+    /// The amount no longer counts towards the sender daily limit either.
+    /// 
+    /// Arguments:
+    /// 
+    /// * `id` The pending transfer id.
+    /// 
+    #[endpoint(cancelPendingTransfer)]
+    fn cancel_pending_transfer(&self, id: u64) -> Result<(), BUSDError> {
+        if !self.caller_is_asset_protection_role() {
+            return Err(BUSDError::OnlyAssetProtectionCanCancelTransfers);
+        }
+        if !self.pending_transfer_exists(id) {
+            return Err(BUSDError::PendingTransferNotFound);
+        }
+
+        let sender = self.get_pending_transfer_sender(id);
+        let recipient = self.get_pending_transfer_recipient(id);
+        let amount = self.get_pending_transfer_amount(id);
+        self.refund_daily_spending(&sender, &amount, self.get_pending_transfer_timestamp(id));
+        self.clear_pending_transfer(id);
+
+        // return funds to sender
+        self.snapshot_balance(&sender);
+        let mut sender_balance = self.get_mut_balance(&sender);
+        *sender_balance += &amount; // saved automatically at the end of scope
+        self.holder_credited(&sender, &amount, &sender_balance);
+
+        self.large_transfer_cancelled_event(&sender, &recipient, &BigUint::from(id), &amount);
+
+        Ok(())
+    }
+
+    // TRAVEL RULE FUNCTIONALITY
+
+    #[storage_get("vasp_id")]
+    fn get_vasp_id(&self, address: &Address) -> Option<H256>;
+
+    #[storage_set("vasp_id")]
+    fn set_vasp_id(&self, address: &Address, vasp_id: Option<&H256>);
+
+    /// Yields the identifier under which an address is registered as a VASP, if any.
+    /// 
+    /// Arguments:
+    /// 
+    /// * `address` The address to check.
+    /// 
+    #[view(getVaspId)]
+    fn get_vasp_id_public(&self, address: &Address) -> OptionalResult<H256> {
+        self.get_vasp_id(address).into()
+    }
+
+    #[storage_get("tr_threshold")]
+    fn get_travel_rule_threshold(&self) -> Option<BigUint>;
+
+    #[storage_set("tr_threshold")]
+    fn set_travel_rule_threshold(&self, threshold: Option<&BigUint>);
+
+    /// Yields the amount above which transfers between VASPs must carry travel rule metadata, if any.
+    #[view(getTravelRuleThreshold)]
+    fn get_travel_rule_threshold_public(&self) -> OptionalResult<BigUint> {
+        self.get_travel_rule_threshold().into()
+    }
+
+    fn travel_rule_required(&self, sender: &Address, recipient: &Address, amount: &BigUint) -> bool {
+        match self.get_travel_rule_threshold() {
+            Some(threshold) => amount > &threshold &&
+                self.get_vasp_id(sender).is_some() &&
+                self.get_vasp_id(recipient).is_some(),
+            None => false,
+        }
+    }
+
+    /// Registers an address as belonging to a VASP.
+    /// 
+    /// Arguments:
+    /// 
+    /// * `address` The VASP address.
+    /// * `vasp_id` The identifier of the VASP.
+    /// 
+    #[endpoint(registerVasp)]
+    fn register_vasp(&self, address: &Address, vasp_id: &H256) -> Result<(), BUSDError> {
+        if self.get_caller() != self.get_contract_owner() {
+            return Err(BUSDError::OnlyOwnerCanRegisterVasps);
+        }
+        self.set_vasp_id(address, Some(vasp_id));
+
+        self.vasp_registered_event(address, vasp_id, ());
+        Ok(())
+    }
+
+    /// Removes an address from the VASP registry.
+    /// 
+    /// Arguments:
+    /// 
+    /// * `address` The VASP address.
+    /// 
+    #[endpoint(unregisterVasp)]
+    fn unregister_vasp(&self, address: &Address) -> Result<(), BUSDError> {
+        if self.get_caller() != self.get_contract_owner() {
+            return Err(BUSDError::OnlyOwnerCanRegisterVasps);
+        }
+        if self.get_vasp_id(address).is_none() {
+            return Err(BUSDError::AddressNotVasp);
+        }
+        self.set_vasp_id(address, None);
+
+        self.vasp_unregistered_event(address, ());
+        Ok(())
+    }
+
+    /// Sets the amount above which transfers between registered VASPs must carry travel rule metadata.
+    /// 
+    /// Arguments:
+    /// 
+    /// * `threshold` The largest amount that can be transferred between VASPs without metadata.
+    /// 
+    #[endpoint(setTravelRuleThreshold)]
+    fn set_travel_rule_threshold_endpoint(&self, threshold: BigUint) -> Result<(), BUSDError> {
+        if self.get_caller() != self.get_contract_owner() {
+            return Err(BUSDError::OnlyOwnerCanChangeTravelRule);
+        }
+        self.set_travel_rule_threshold(Some(&threshold));
+
+        self.travel_rule_threshold_set_event(&threshold);
+        Ok(())
+    }
+
+    /// Stops requiring travel rule metadata on transfers between VASPs.
+    #[endpoint(removeTravelRuleThreshold)]
+    fn remove_travel_rule_threshold(&self) -> Result<(), BUSDError> {
+        if self.get_caller() != self.get_contract_owner() {
+            return Err(BUSDError::OnlyOwnerCanChangeTravelRule);
+        }
+        if self.get_travel_rule_threshold().is_none() {
+            return Err(BUSDError::NoTravelRuleThreshold);
+        }
+        self.set_travel_rule_threshold(None);
+
+        self.travel_rule_threshold_removed_event(());
+        Ok(())
+    }
+
+    /// Transfer token to a specified address from sender, attaching travel rule metadata.
+    /// The metadata itself is exchanged off-chain, only its hash is logged, when the transfer settles.
+    /// 
+    /// Arguments:
+    /// 
+    /// * `to` The address to transfer to.
+    /// * `amount` The amount of tokens to be transferred.
+    /// * `payload_hash` Hash of the encrypted originator/beneficiary payload.
+    /// * `vasp_id` Identifier of the originating VASP, must match the sender registration, or be zero if the sender is not a VASP.
+    /// 
+    #[endpoint(transferWithTravelRule)]
+    fn transfer_with_travel_rule(&self, to: Address, amount: BigUint, payload_hash: H256, vasp_id: H256) -> Result<(), BUSDError> {
+        // sender is the caller
+        let sender = self.get_caller();
+
+        // only a registered VASP can claim to originate a transfer, others give a zero id
+        let sender_vasp_id = self.get_vasp_id(&sender).unwrap_or_else(H256::zero);
+        if sender_vasp_id != vasp_id {
+            return Err(BUSDError::VaspIdMismatch);
+        }
+
+        self.check_transfer_restriction(None, &sender, &to, &amount, true)?;
+
+        self.perform_transfer(sender, to, amount, Some((&payload_hash, &vasp_id)))
+    }
+
+    // SUPPLY CONTROL FUNCTIONALITY
+
+    /// Yields the currently proposed new owner, if any.
+    #[view(getSupplyController)]
+    #[storage_get("supply_c")]
+    fn get_supply_controller(&self) -> Address;
+
+    #[storage_set("supply_c")]
+    fn set_supply_controller(&self, address: &Address);
+
+    fn caller_is_supply_controller(&self) -> bool {
+        return self.get_caller() == self.get_supply_controller()
+    }
+
+    /// Sets a new supply controller address.
+    /// 
+    /// Arguments:
+    /// 
+    /// * `new_supply_controller` The address allowed to burn/mint tokens to control supply.
+    /// 
+    #[endpoint(setSupplyController)]
+    fn set_supply_controller_endpoint(&self, new_supply_controller: &Address) -> Result<(), BUSDError> {
+        let caller = self.get_caller();
+        if caller != self.get_contract_owner() && 
+           !self.caller_is_supply_controller() {
+            return Err(BUSDError::OnlyOwnerOrSupplyControllerCanSet)
+        }
+
+        // needed for logging
+        let old_supply_controller = self.get_supply_controller();
+
+        // change supply controller
+        self.set_supply_controller(new_supply_controller);
+
+        // log event
+        self.log_supply_controller_set(
+            &old_supply_controller,
+            new_supply_controller
+        );
+
+        Ok(())
+    }
+
+    /// Increases the total supply by minting the specified number of tokens to the supply controller account.
+    /// 
+    /// Arguments:
+    /// 
+    /// * `amount` The number of tokens to add.
+    /// 
+    #[endpoint(increaseSupply)]
+    fn increase_supply(&self, amount: BigUint) -> Result<(), BUSDError> {
+        if !self.caller_is_supply_controller() {
+            return Err(BUSDError::OnlySupplyControllerCanIncrease);
+        }
+        let supply_controller = self.get_caller();
+        self.perform_mint(&supply_controller, &amount);
+
+        Ok(())
+    }
+
+    fn perform_mint(&self, to: &Address, amount: &BigUint) {
+        self.snapshot_balance(to);
+        self.snapshot_total_supply();
+
+        // increase recipient balance
+        let mut balance = self.get_mut_balance(to);
+        *balance += amount; // saved automatically at the end of scope
+        self.holder_credited(to, amount, &balance);
+
+        // increase total supply
+        let mut total_supply = self.get_mut_total_supply();
+        *total_supply += amount; // saved automatically at the end of scope
+
+        // log operation
+        self.log_supply_increased(to, amount);
+        self.log_transfer(&[0u8; 32].into(), to, amount);
+    }
+
+    /// Decreases the total supply by burning the specified number of tokens from the supply controller account.
+    /// 
+    /// Arguments:
+    /// 
+    /// * `amount` The number of tokens to remove.
+    /// 
+    #[endpoint(decreaseSupply)]
+    fn decrease_supply(&self, amount: BigUint) -> Result<(), BUSDError> {
+        if !self.caller_is_supply_controller() {
+            return Err(BUSDError::OnlySupplyControllerCanDecrease);
+        }
+        let supply_controller = self.get_caller();
+
+        // get supply controller balance
+        let mut supply_contr_balance = self.balance_of(&supply_controller);
+
+        // check
+        if amount > supply_contr_balance {
+            return Err(BUSDError::NotEnoughSupplyToDecrease)
+        }
+
+        // decrease supply controller balance
+        self.snapshot_balance(&supply_controller);
+        self.snapshot_total_supply();
+        supply_contr_balance -= &amount;
+        self.set_balance(&supply_controller, &supply_contr_balance);
+        self.holder_debited(&supply_controller, &supply_contr_balance);
+
+        // decrease total supply
+        let mut total_supply = self.get_mut_total_supply();
+        *total_supply -= &amount; // saved automatically at the end of scope
+
+        // log operation
+        self.log_supply_decreased(&supply_controller, &amount);
+        self.log_transfer(&supply_controller, &[0u8; 32].into(), &amount);
+
+        Ok(())
+    }
+
+    // LEGACY EVENTS FUNCTIONALITY
+
+    /// Whether the legacy, numbered events are emitted along with the named ones.
+    #[view(legacyEventsEnabled)]
+    #[storage_get("legacy_events")]
+    fn legacy_events_enabled(&self) -> bool;
+
+    #[storage_set("legacy_events")]
+    fn set_legacy_events_enabled(&self, enabled: bool);
+
+    /// Called by the owner to also emit every event with its legacy numeric identifier,
+    /// for indexers that have not migrated to the named events yet.
+    #[endpoint(enableLegacyEvents)]
+    fn enable_legacy_events(&self) -> Result<(), BUSDError> {
+        if self.get_caller() != self.get_contract_owner() {
+            return Err(BUSDError::OnlyOwnerCanChangeLegacyEvents);
+        }
+        self.set_legacy_events_enabled(true);
+
+        self.legacy_events_enabled_event(());
+        Ok(())
+    }
+
+    /// Called by the owner to stop emitting the legacy events.
+    #[endpoint(disableLegacyEvents)]
+    fn disable_legacy_events(&self) -> Result<(), BUSDError> {
+        if self.get_caller() != self.get_contract_owner() {
+            return Err(BUSDError::OnlyOwnerCanChangeLegacyEvents);
+        }
+        self.set_legacy_events_enabled(false);
+
+        self.legacy_events_disabled_event(());
+        Ok(())
+    }
+
+    // UPGRADE FUNCTIONALITY
+
+    /// Version of the storage layout, 0 for a contract deployed before the version was stored.
+    #[view(getStorageVersion)]
+    #[storage_get("schema_version")]
+    fn get_storage_version(&self) -> u32;
+
+    #[storage_set("schema_version")]
+    fn set_storage_version(&self, version: u32);
+
+    /// Version of the storage layout of this code, which `upgrade` migrates to.
+    #[view(getLatestStorageVersion)]
+    fn get_latest_storage_version(&self) -> u32 {
+        STORAGE_VERSION
+    }
+
+    /// Empty until the constructor has run.
+    #[storage_get("owner")]
+    fn get_contract_owner_raw(&self) -> Vec<u8>;
+
+    /// Called by the owner after upgrading the code, to migrate the storage to its layout.
+    /// Migrates one version at a time, logging `StorageMigrated` after each step.
+    #[endpoint]
+    fn upgrade(&self) -> Result<(), BUSDError> {
+        if self.get_caller() != self.get_contract_owner() {
+            return Err(BUSDError::OnlyOwnerCanUpgrade);
+        }
+        let version = self.get_storage_version();
+        if version > STORAGE_VERSION {
+            return Err(BUSDError::StorageVersionTooNew);
+        }
+        if version == STORAGE_VERSION {
+            return Err(BUSDError::StorageUpToDate);
+        }
+
+        for next_version in version + 1..=STORAGE_VERSION {
+            self.migrate_storage(next_version);
+            self.set_storage_version(next_version);
+            self.storage_migrated_event(next_version);
+        }
+        Ok(())
+    }
+
+    /// Migrates the storage from the previous version to `version`.
+    fn migrate_storage(&self, version: u32) {
+        // the token metadata became constructor arguments,
+        // and the events got names: indexers of a v0.3.0 deployment still expect the numbered ones
+        if version == 1 {
+            self.set_name(V0_NAME);
+            self.set_symbol(V0_SYMBOL);
+            self.set_decimals(V0_DECIMALS);
+            self.set_legacy_events_enabled(true);
+            self.legacy_events_enabled_event(());
+        }
+        // version 2 added the holder and frozen address registries and the spenders of each owner, which start empty:
+        // the owner fills them in with `registerHolders` and `registerSpenders`
+    }
+
+    // STATE MIGRATION FUNCTIONALITY
+
+    // Every address with a non-zero balance is registered as a holder, at an index from 1,
+    // every frozen address is registered too, in a registry of its own since a wiped address has no balance left,
+    // and the spenders each owner approved are listed, so the state can be exported.
+
+    /// The number of addresses with a non-zero balance.
+    #[view(holderCount)]
+    #[storage_get("holders_len")]
+    fn get_holders_len(&self) -> u64;
+
+    #[storage_set("holders_len")]
+    fn set_holders_len(&self, len: u64);
+
+    #[storage_get("holder_at")]
+    fn get_holder_at(&self, index: u64) -> Option<Address>;
+
+    #[storage_set("holder_at")]
+    fn set_holder_at(&self, index: u64, holder: Option<&Address>);
+
+    /// 0 for an address that is not registered.
+    #[storage_get("holder_idx")]
+    fn get_holder_index(&self, address: &Address) -> u64;
+
+    #[storage_set("holder_idx")]
+    fn set_holder_index(&self, address: &Address, index: u64);
+
+    /// Yields up to `count` holders, starting from the holder at position `from`, counted from 0.
+    /// The order is not stable: when a holder empties its balance, the last holder takes its place.
+    /// 
+    /// Arguments:
+    /// 
+    /// * `from` The position of the first holder to yield.
+    /// * `count` The maximum number of holders to yield.
+    /// 
+    #[view(getHolders)]
+    fn get_holders(&self, from: u64, count: u64) -> MultiResultVec<Address> {
+        let end = core::cmp::min(from.saturating_add(count), self.get_holders_len());
+        let holders: Vec<Address> = (from..end)
+            .map(|position| self.get_holder_at(position + 1).unwrap_or_else(Address::zero))
+            .collect();
+        holders.into()
+    }
+
+    /// The number of frozen addresses.
+    #[view(frozenCount)]
+    #[storage_get("frozen_len")]
+    fn get_frozen_len(&self) -> u64;
+
+    #[storage_set("frozen_len")]
+    fn set_frozen_len(&self, len: u64);
+
+    #[storage_get("frozen_at")]
+    fn get_frozen_at(&self, index: u64) -> Option<Address>;
+
+    #[storage_set("frozen_at")]
+    fn set_frozen_at(&self, index: u64, address: Option<&Address>);
+
+    /// 0 for an address that is not registered.
+    #[storage_get("frozen_idx")]
+    fn get_frozen_index(&self, address: &Address) -> u64;
+
+    #[storage_set("frozen_idx")]
+    fn set_frozen_index(&self, address: &Address, index: u64);
+
+    #[storage_get("spenders")]
+    fn get_spenders(&self, owner: &Address) -> Vec<Address>;
+
+    #[storage_set("spenders")]
+    fn set_spenders(&self, owner: &Address, spenders: &[Address]);
+
+    /// Registers the address while its balance is not zero.
+    fn update_holder(&self, address: &Address, balance: &BigUint) {
+        let index = self.get_holder_index(address);
+        let is_holder = balance > &BigUint::zero();
+        if is_holder && index == 0 {
+            let len = self.get_holders_len() + 1;
+            self.set_holders_len(len);
+            self.set_holder_at(len, Some(address));
+            self.set_holder_index(address, len);
+        } else if !is_holder && index != 0 {
+            // the last holder takes the place of the removed one
+            let len = self.get_holders_len();
+            if index != len {
+                let last = self.get_holder_at(len).unwrap_or_else(Address::zero);
+                self.set_holder_at(index, Some(&last));
+                self.set_holder_index(&last, index);
+            }
+            self.set_holder_at(len, None);
+            self.set_holder_index(address, 0);
+            self.set_holders_len(len - 1);
+        }
+    }
+
+    /// Registers the address while it is frozen, the same way as `update_holder`.
+    fn update_frozen_registry(&self, address: &Address, frozen: bool) {
+        let index = self.get_frozen_index(address);
+        if frozen && index == 0 {
+            let len = self.get_frozen_len() + 1;
+            self.set_frozen_len(len);
+            self.set_frozen_at(len, Some(address));
+            self.set_frozen_index(address, len);
+        } else if !frozen && index != 0 {
+            let len = self.get_frozen_len();
+            if index != len {
+                let last = self.get_frozen_at(len).unwrap_or_else(Address::zero);
+                self.set_frozen_at(index, Some(&last));
+                self.set_frozen_index(&last, index);
+            }
+            self.set_frozen_at(len, None);
+            self.set_frozen_index(address, 0);
+            self.set_frozen_len(len - 1);
+        }
+    }
+
+    /// Registers the address when a credit of `amount` took its balance up from zero.
+    /// Balances that do not cross zero leave the registry alone, so most transfers never touch it.
+    fn holder_credited(&self, address: &Address, amount: &BigUint, balance: &BigUint) {
+        if balance == amount {
+            self.update_holder(address, balance);
+        }
+    }
+
+    /// Unregisters the address when a debit emptied its balance.
+    fn holder_debited(&self, address: &Address, balance: &BigUint) {
+        if balance == &BigUint::zero() {
+            self.update_holder(address, balance);
+        }
+    }
+
+    /// Lists the spender while its allowance is not zero.
+    fn update_spender(&self, owner: &Address, spender: &Address, allowance: &BigUint) {
+        let mut spenders = self.get_spenders(owner);
+        let listed = spenders.contains(spender);
+        if allowance > &BigUint::zero() && !listed {
+            spenders.push(spender.clone());
+            self.set_spenders(owner, &spenders);
+        } else if allowance == &BigUint::zero() && listed {
+            spenders.retain(|listed_spender| listed_spender != spender);
+            self.set_spenders(owner, &spenders);
+        }
+    }
+
+    /// Called by the owner to register the holders and frozen addresses of a contract deployed before the registries,
+    /// e.g. as found by indexing its transfers and freezes. Addresses neither holding a balance nor frozen are skipped.
+    #[endpoint(registerHolders)]
+    fn register_holders(&self, #[var_args] addresses: VarArgs<Address>) -> Result<(), BUSDError> {
+        if self.get_caller() != self.get_contract_owner() {
+            return Err(BUSDError::OnlyOwnerCanRegisterHolders);
+        }
+        for address in addresses.iter() {
+            self.update_holder(address, &self.balance_of(address));
+            self.update_frozen_registry(address, self.is_frozen(address));
+        }
+        Ok(())
+    }
+
+    /// Called by the owner to list the spenders an owner approved before the spenders were listed.
+    /// Spenders without an allowance are skipped.
+    #[endpoint(registerSpenders)]
+    fn register_spenders(&self, owner: Address, #[var_args] spenders: VarArgs<Address>) -> Result<(), BUSDError> {
+        if self.get_caller() != self.get_contract_owner() {
+            return Err(BUSDError::OnlyOwnerCanRegisterHolders);
+        }
+        for spender in spenders.iter() {
+            self.update_spender(&owner, spender, &self.get_allowance(&owner, spender));
+        }
+        Ok(())
+    }
+
+    /// Yields up to `count` holders, starting from the holder at position `from`, counted from 0,
+    /// each followed by its balance and whether it is frozen, for the owner to export the state
+    /// to a new deployment with `importState`. Frozen addresses without a balance are left to `exportFrozen`,
+    /// and pending large transfers are not exported.
+    /// 
+    /// The owner check only applies when called in a transaction: a query sets no verified caller,
+    /// and the balances and frozen flags are public storage anyway, so this is no confidentiality control.
+    #[view(exportState)]
+    fn export_state(&self, from: u64, count: u64) -> Result<MultiResultVec<MultiResult3<Address, BigUint, bool>>, BUSDError> {
+        if self.get_caller() != self.get_contract_owner() {
+            return Err(BUSDError::OnlyOwnerCanExport);
+        }
+        let end = core::cmp::min(from.saturating_add(count), self.get_holders_len());
+        let holders: Vec<MultiResult3<Address, BigUint, bool>> = (from..end)
+            .map(|position| {
+                let holder = self.get_holder_at(position + 1).unwrap_or_else(Address::zero);
+                let balance = self.balance_of(&holder);
+                let frozen = self.is_frozen(&holder);
+                (holder, balance, frozen).into()
+            })
+            .collect();
+        Ok(holders.into())
+    }
+
+    /// Yields up to `count` frozen addresses, starting from the one at position `from`, counted from 0,
+    /// for `importFrozen`. Unlike `exportState`, it includes frozen addresses without a balance, e.g. wiped ones.
+    /// Like `exportState`, only owner-checked when called in a transaction.
+    #[view(exportFrozen)]
+    fn export_frozen(&self, from: u64, count: u64) -> Result<MultiResultVec<Address>, BUSDError> {
+        if self.get_caller() != self.get_contract_owner() {
+            return Err(BUSDError::OnlyOwnerCanExport);
+        }
+        let end = core::cmp::min(from.saturating_add(count), self.get_frozen_len());
+        let frozen: Vec<Address> = (from..end)
+            .map(|position| self.get_frozen_at(position + 1).unwrap_or_else(Address::zero))
+            .collect();
+        Ok(frozen.into())
+    }
+
+    /// Yields the spenders an owner approved, each followed by its allowance, for `importAllowances`.
+    /// A spender that used up its allowance with `transferFrom` is still listed, with a zero allowance.
+    /// Like `exportState`, only owner-checked when called in a transaction.
+    #[view(exportAllowances)]
+    fn export_allowances(&self, owner: &Address) -> Result<MultiResultVec<MultiResult2<Address, BigUint>>, BUSDError> {
+        if self.get_caller() != self.get_contract_owner() {
+            return Err(BUSDError::OnlyOwnerCanExport);
+        }
+        let allowances: Vec<MultiResult2<Address, BigUint>> = self.get_spenders(owner).into_iter()
+            .map(|spender| {
+                let allowance = self.get_allowance(owner, &spender);
+                (spender, allowance).into()
+            })
+            .collect();
+        Ok(allowances.into())
+    }
+
+    #[storage_get("import_total")]
+    fn get_import_total_supply(&self) -> Option<BigUint>;
+
+    #[storage_set("import_total")]
+    fn set_import_total_supply(&self, total_supply: Option<&BigUint>);
+
+    /// Yields the total supply declared for the import in progress, if any.
+    #[view(getImportTotalSupply)]
+    fn get_import_total_supply_public(&self) -> OptionalResult<BigUint> {
+        self.get_import_total_supply().into()
+    }
+
+    /// Called by the owner of a fresh, paused deployment, before importing the state exported from another one.
+    /// The contract cannot be unpaused until the imported balances add up to the declared total supply.
+    /// 
+    /// Arguments:
+    /// 
+    /// * `total_supply` The total supply of the exported contract.
+    /// 
+    #[endpoint(beginImport)]
+    fn begin_import(&self, total_supply: BigUint) -> Result<(), BUSDError> {
+        if self.get_caller() != self.get_contract_owner() {
+            return Err(BUSDError::OnlyOwnerCanImport);
+        }
+        if !self.is_paused()
+            || *self.get_mut_total_supply() > BigUint::zero()
+            || self.get_import_total_supply().is_some() {
+            return Err(BUSDError::ImportRequiresFreshContract);
+        }
+        self.set_import_total_supply(Some(&total_supply));
+
+        self.import_started_event(&total_supply);
+        Ok(())
+    }
+
+    /// Called by the owner during an import with holders as exported by `exportState`:
+    /// each holder address followed by its balance and whether it is frozen.
+    /// Balances are minted to the holders.
+    #[endpoint(importState)]
+    fn import_state(&self, #[var_args] holders: VarArgs<MultiArg3<Address, BigUint, bool>>) -> Result<(), BUSDError> {
+        if self.get_caller() != self.get_contract_owner() {
+            return Err(BUSDError::OnlyOwnerCanImport);
+        }
+        let import_total_supply = self.get_import_total_supply().ok_or(BUSDError::NoImportInProgress)?;
+
+        for holder in holders.into_vec() {
+            let (address, balance, frozen) = holder.into_tuple();
+            if balance > BigUint::zero() {
+                self.perform_mint(&address, &balance);
+            }
+            if frozen {
+                self.import_frozen_address(&address);
+            }
+        }
+        if *self.get_mut_total_supply() > import_total_supply {
+            return Err(BUSDError::ImportExceedsTotalSupply);
+        }
+        Ok(())
+    }
+
+    /// Called by the owner during an import with the frozen addresses exported by `exportFrozen`.
+    #[endpoint(importFrozen)]
+    fn import_frozen(&self, #[var_args] addresses: VarArgs<Address>) -> Result<(), BUSDError> {
+        if self.get_caller() != self.get_contract_owner() {
+            return Err(BUSDError::OnlyOwnerCanImport);
+        }
+        if self.get_import_total_supply().is_none() {
+            return Err(BUSDError::NoImportInProgress);
+        }
+
+        for address in addresses.iter() {
+            self.import_frozen_address(address);
+        }
+        Ok(())
+    }
+
+    fn import_frozen_address(&self, address: &Address) {
+        if !self.is_frozen(address) {
+            self.set_frozen(address, true);
+            self.update_frozen_registry(address, true);
+            self.log_address_frozen(address);
+        }
+    }
+
+    /// Called by the owner during an import with allowances: each owner address followed by a spender
+    /// and its allowance, as exported by `exportAllowances`.
+    #[endpoint(importAllowances)]
+    fn import_allowances(&self, #[var_args] allowances: VarArgs<MultiArg3<Address, Address, BigUint>>) -> Result<(), BUSDError> {
+        if self.get_caller() != self.get_contract_owner() {
+            return Err(BUSDError::OnlyOwnerCanImport);
+        }
+        if self.get_import_total_supply().is_none() {
+            return Err(BUSDError::NoImportInProgress);
+        }
+
+        for allowance in allowances.into_vec() {
+            let (owner, spender, amount) = allowance.into_tuple();
+            self.set_allowance(&owner, &spender, &amount);
+            self.update_spender(&owner, &spender, &amount);
+            self.log_approval(&owner, &spender, &amount);
+        }
+        Ok(())
+    }
+
+    // ESDT MIGRATION FUNCTIONALITY
+
+    // Holders can move their balance to a native ESDT token: the balance is burned here
+    // and the same value of ESDT is sent from the reserve the owner funded the contract with.
+    // The migration is one way: elrond-wasm 0.5 cannot read the ESDT sent along with a call, nor issue a token,
+    // so the reverse path and a token issued by the contract itself are left out.
+
+    /// The identifier of the ESDT token balances migrate to, empty while migration is not enabled.
+    #[view(getEsdtToken)]
+    #[storage_get("esdt_token")]
+    fn get_esdt_token(&self) -> Vec<u8>;
+
+    #[storage_set("esdt_token")]
+    fn set_esdt_token(&self, token_identifier: &[u8]);
+
+    /// Total value burned by `migrateToEsdt`, ESDT transfers still in flight included.
+    #[view(getEsdtMigratedSupply)]
+    #[storage_get("esdt_migrated")]
+    fn get_esdt_migrated_supply(&self) -> BigUint;
+
+    #[storage_get_mut("esdt_migrated")]
+    fn get_mut_esdt_migrated_supply(&self) -> mut_storage!(BigUint);
+
+    /// Called by the owner to let holders migrate to the ESDT token, once the contract holds enough of it.
+    /// 
+    /// Arguments:
+    /// 
+    /// * `token_identifier` The ESDT token identifier, e.g. `BUSD-a1b2c3`.
+    /// 
+    #[endpoint(enableEsdtMigration)]
+    fn enable_esdt_migration(&self, token_identifier: Vec<u8>) -> Result<(), BUSDError> {
+        if self.get_caller() != self.get_contract_owner() {
+            return Err(BUSDError::OnlyOwnerCanChangeEsdtMigration);
+        }
+        if !self.get_esdt_token().is_empty() {
+            return Err(BUSDError::EsdtMigrationAlreadyEnabled);
+        }
+        if token_identifier.is_empty() {
+            return Err(BUSDError::InvalidEsdtToken);
+        }
+        self.set_esdt_token(&token_identifier);
+
+        self.esdt_migration_enabled_event(token_identifier.as_slice());
+        Ok(())
+    }
+
+    /// Called by the owner to stop new migrations. Transfers already in flight still complete or revert.
+    #[endpoint(disableEsdtMigration)]
+    fn disable_esdt_migration(&self) -> Result<(), BUSDError> {
+        if self.get_caller() != self.get_contract_owner() {
+            return Err(BUSDError::OnlyOwnerCanChangeEsdtMigration);
+        }
+        if self.get_esdt_token().is_empty() {
+            return Err(BUSDError::EsdtMigrationNotEnabled);
+        }
+        self.set_esdt_token(&[]);
+
+        self.esdt_migration_disabled_event(());
+        Ok(())
+    }
+
+    /// Burns `value` from the caller balance and sends the caller the same value of the ESDT token.
+    /// Should the ESDT transfer fail, e.g. because the reserve ran out, the callback gives the balance back.
+    /// The same restrictions as for a transfer to the caller apply, and the value counts towards its daily limit.
+    /// Since the ESDT is sent at once, a value above the large transfer threshold cannot be migrated in one go.
+    /// 
+    /// Arguments:
+    /// 
+    /// * `value` The number of tokens to migrate.
+    /// 
+    #[endpoint(migrateToEsdt)]
+    fn migrate_to_esdt(&self, value: BigUint) -> Result<(), BUSDError> {
+        let token_identifier = self.get_esdt_token();
+        if token_identifier.is_empty() {
+            return Err(BUSDError::EsdtMigrationNotEnabled);
+        }
+        let holder = self.get_caller();
+        self.check_transfer_restriction(None, &holder, &holder, &value, false)?;
+        if self.is_large_transfer(&value) {
+            return Err(BUSDError::EsdtMigrationTooLarge);
+        }
+
+        // burn the balance
+        {
+            let mut balance = self.get_mut_balance(&holder);
+            if value > *balance {
+                return Err(BUSDError::InsufficientFunds);
+            }
+            self.snapshot_balance(&holder);
+            *balance -= &value; // saved automatically at the end of scope
+            self.holder_debited(&holder, &balance);
+        }
+        {
+            self.snapshot_total_supply();
+            let mut total_supply = self.get_mut_total_supply();
+            *total_supply -= &value;
+            let mut migrated_supply = self.get_mut_esdt_migrated_supply();
+            *migrated_supply += &value;
+        }
+
+        // count value towards the holder daily limit, if any
+        self.record_daily_spending(&holder, &value);
+
+        // log operation
+        self.migrated_to_esdt_event(&holder, &value);
+        self.log_transfer(&holder, &[0u8; 32].into(), &value);
+
+        // the async call ends the execution, so everything above is saved by now
+        let esdt = contract_proxy!(self, &holder, EsdtTransfer);
+        esdt.ESDTTransfer(&token_identifier, &value, &holder, &value, self.get_block_timestamp());
+        Ok(())
+    }
+
+    /// Gives the balance, and the daily limit usage, back to the holder if the ESDT transfer of `migrateToEsdt` failed.
+    #[callback]
+    fn esdt_transfer_callback(&self, result: AsyncCallResult<()>,
+        #[callback_arg] holder: Address,
+        #[callback_arg] value: BigUint,
+        #[callback_arg] timestamp: u64) {
+
+        if let AsyncCallResult::Err(_) = result {
+            self.refund_daily_spending(&holder, &value, timestamp);
+            self.snapshot_balance(&holder);
+            self.snapshot_total_supply();
+            {
+                let mut balance = self.get_mut_balance(&holder);
+                *balance += &value; // saved automatically at the end of scope
+                self.holder_credited(&holder, &value, &balance);
+            }
+            let mut total_supply = self.get_mut_total_supply();
+            *total_supply += &value;
+            let mut migrated_supply = self.get_mut_esdt_migrated_supply();
+            *migrated_supply -= &value;
+
+            // log operation
+            self.esdt_migration_reverted_event(&holder, &value);
+            self.log_transfer(&[0u8; 32].into(), &holder, &value);
+        }
+    }
+
+    // SNAPSHOT FUNCTIONALITY
+
+    // Taking a snapshot only bumps its id. The first change to a balance, or to the total supply, after a snapshot
+    // saves the value it had under the id of the latest snapshot, so values that never changed since are read as they are now.
+
+    /// The id of the latest snapshot, 0 before the first one.
+    #[view(getCurrentSnapshotId)]
+    #[storage_get("snap_id")]
+    fn get_current_snapshot_id(&self) -> u64;
+
+    #[storage_set("snap_id")]
+    fn set_current_snapshot_id(&self, id: u64);
+
+    /// The snapshots the balance was saved for, in increasing order.
+    #[storage_get("snap_bal_ids")]
+    fn get_balance_snapshot_ids(&self, address: &Address) -> Vec<u64>;
+
+    #[storage_set("snap_bal_ids")]
+    fn set_balance_snapshot_ids(&self, address: &Address, ids: &Vec<u64>);
+
+    #[storage_get("snap_bal")]
+    fn get_balance_snapshot(&self, address: &Address, id: u64) -> BigUint;
+
+    #[storage_set("snap_bal")]
+    fn set_balance_snapshot(&self, address: &Address, id: u64, balance: &BigUint);
+
+    /// The snapshots the total supply was saved for, in increasing order.
+    #[storage_get("snap_supply_ids")]
+    fn get_total_supply_snapshot_ids(&self) -> Vec<u64>;
+
+    #[storage_set("snap_supply_ids")]
+    fn set_total_supply_snapshot_ids(&self, ids: &Vec<u64>);
+
+    #[storage_get("snap_supply")]
+    fn get_total_supply_snapshot(&self, id: u64) -> BigUint;
+
+    #[storage_set("snap_supply")]
+    fn set_total_supply_snapshot(&self, id: u64, total_supply: &BigUint);
+
+    /// Called by the supply controller to take a snapshot of the balances and the total supply, e.g. at the end of a month.
+    /// Yields the id of the snapshot, to query with `balanceOfAt` and `totalSupplyAt`.
+    #[endpoint]
+    fn snapshot(&self) -> Result<u64, BUSDError> {
+        if !self.caller_is_supply_controller() {
+            return Err(BUSDError::OnlySupplyControllerCanSnapshot);
+        }
+        let id = self.get_current_snapshot_id() + 1;
+        self.set_current_snapshot_id(id);
+
+        self.snapshot_event(id);
+        Ok(id)
+    }
+
+    /// Gets the balance of the address when the snapshot was taken.
+    /// 
+    /// Arguments:
+    /// 
+    /// * `address` The address to query the balance of.
+    /// * `snapshot_id` The id yielded by `snapshot`.
+    /// 
+    #[view(balanceOfAt)]
+    fn balance_of_at(&self, address: &Address, snapshot_id: u64) -> Result<BigUint, BUSDError> {
+        self.check_snapshot_id(snapshot_id)?;
+        match self.saved_snapshot_id(&self.get_balance_snapshot_ids(address), snapshot_id) {
+            Some(id) => Ok(self.get_balance_snapshot(address, id)),
+            None => Ok(self.balance_of(address)),
+        }
+    }
+
+    /// Gets the total supply when the snapshot was taken.
+    /// 
+    /// Arguments:
+    /// 
+    /// * `snapshot_id` The id yielded by `snapshot`.
+    /// 
+    #[view(totalSupplyAt)]
+    fn total_supply_at(&self, snapshot_id: u64) -> Result<BigUint, BUSDError> {
+        self.check_snapshot_id(snapshot_id)?;
+        match self.saved_snapshot_id(&self.get_total_supply_snapshot_ids(), snapshot_id) {
+            Some(id) => Ok(self.get_total_supply_snapshot(id)),
+            None => Ok(self.get_mut_total_supply().clone()),
+        }
+    }
+
+    fn check_snapshot_id(&self, snapshot_id: u64) -> Result<(), BUSDError> {
+        if snapshot_id == 0 || snapshot_id > self.get_current_snapshot_id() {
+            return Err(BUSDError::SnapshotNotFound);
+        }
+        Ok(())
+    }
+
+    /// The first of `ids` from `snapshot_id` on: the value saved then is the value at `snapshot_id`.
+    fn saved_snapshot_id(&self, ids: &[u64], snapshot_id: u64) -> Option<u64> {
+        let position = ids.binary_search(&snapshot_id).unwrap_or_else(|position| position);
+        ids.get(position).cloned()
+    }
+
+    /// Saves the balance for the latest snapshot, unless already saved. Called before the balance changes.
+    fn snapshot_balance(&self, address: &Address) {
+        let current_id = self.get_current_snapshot_id();
+        let mut ids = self.get_balance_snapshot_ids(address);
+        if current_id == 0 || ids.last() == Some(&current_id) {
+            return;
+        }
+        ids.push(current_id);
+        self.set_balance_snapshot_ids(address, &ids);
+        self.set_balance_snapshot(address, current_id, &self.balance_of(address));
+    }
+
+    /// Saves the total supply for the latest snapshot, unless already saved. Called before the total supply changes.
+    fn snapshot_total_supply(&self) {
+        let current_id = self.get_current_snapshot_id();
+        let mut ids = self.get_total_supply_snapshot_ids();
+        if current_id == 0 || ids.last() == Some(&current_id) {
+            return;
+        }
+        ids.push(current_id);
+        self.set_total_supply_snapshot_ids(&ids);
+        let total_supply = self.get_mut_total_supply().clone();
+        self.set_total_supply_snapshot(current_id, &total_supply);
+    }
+
+    // EVENT LOGGING
+
+    // The events v0.3.0 emitted are logged through these, which emit the named event,
+    // followed by the legacy one if enabled. Events added since are emitted directly.
+
+    fn log_transfer(&self, sender: &Address, recipient: &Address, amount: &BigUint) {
+        self.transfer_event(sender, recipient, amount);
+        if self.legacy_events_enabled() {
+            self.legacy_transfer_event(sender, recipient, amount);
+        }
+    }
+
+    fn log_approval(&self, sender: &Address, spender: &Address, amount: &BigUint) {
+        self.approval_event(sender, spender, amount);
+        if self.legacy_events_enabled() {
+            self.legacy_approve_event(sender, spender, amount);
+        }
+    }
+
+    fn log_ownership_transfer_proposed(&self, current_owner: &Address, proposed_owner: &Address) {
+        self.ownership_transfer_proposed_event(current_owner, proposed_owner, ());
+        if self.legacy_events_enabled() {
+            self.legacy_ownership_transfer_proposed_event(current_owner, proposed_owner, ());
+        }
+    }
+
+    fn log_ownership_transfer_disregarded(&self, old_proposed_owner: &Address) {
+        self.ownership_transfer_disregarded_event(old_proposed_owner, ());
+        if self.legacy_events_enabled() {
+            self.legacy_ownership_transfer_disregarded_event(old_proposed_owner, ());
+        }
+    }
+
+    fn log_ownership_transferred(&self, old_owner: &Address, new_owner: &Address) {
+        self.ownership_transferred_event(old_owner, new_owner, ());
+        if self.legacy_events_enabled() {
+            self.legacy_ownership_transferred_event(old_owner, new_owner, ());
+        }
+    }
+
+    fn log_pause(&self, caller: &Address) {
+        self.pause_event(caller, ());
+        if self.legacy_events_enabled() {
+            self.legacy_pause_event(());
+        }
+    }
+
+    fn log_unpause(&self, caller: &Address) {
+        self.unpause_event(caller, ());
+        if self.legacy_events_enabled() {
+            self.legacy_unpause_event(());
+        }
+    }
+
+    fn log_address_frozen(&self, address: &Address) {
+        self.address_frozen_event(address, ());
+        if self.legacy_events_enabled() {
+            self.legacy_address_frozen_event(address, ());
+        }
+    }
+
+    fn log_address_unfrozen(&self, address: &Address) {
+        self.address_unfrozen_event(address, ());
+        if self.legacy_events_enabled() {
+            self.legacy_address_unfrozen_event(address, ());
+        }
+    }
+
+    fn log_frozen_address_wiped(&self, address: &Address) {
+        self.frozen_address_wiped_event(address, ());
+        if self.legacy_events_enabled() {
+            self.legacy_frozen_address_wiped_event(address, ());
+        }
+    }
+
+    fn log_asset_protection_role_set(&self, old_role: &Address, new_role: &Address) {
+        self.asset_protection_role_set_event(old_role, new_role, ());
+        if self.legacy_events_enabled() {
+            self.legacy_asset_protection_role_set_event(old_role, new_role, ());
+        }
+    }
+
+    fn log_supply_increased(&self, to: &Address, amount: &BigUint) {
+        self.supply_increased_event(to, amount);
+        if self.legacy_events_enabled() {
+            self.legacy_supply_increased_event(to, amount);
+        }
+    }
+
+    fn log_supply_decreased(&self, from: &Address, amount: &BigUint) {
+        self.supply_decreased_event(from, amount);
+        if self.legacy_events_enabled() {
+            self.legacy_supply_decreased_event(from, amount);
+        }
+    }
+
+    fn log_supply_controller_set(&self, old_supply_controller: &Address, new_supply_controller: &Address) {
+        self.supply_controller_set_event(old_supply_controller, new_supply_controller, ());
+        if self.legacy_events_enabled() {
+            self.legacy_supply_controller_set_event(old_supply_controller, new_supply_controller, ());
+        }
+    }
+
+    // EVENTS
+
+    // Each event is identified by its name, as ASCII padded with zeros to 32 bytes.
+    // The addresses involved, hashes and the large transfer ids are indexed topics;
+    // amounts, and other values such as the snapshot id or the storage version reached, are the event data.
+    // Events with nothing to put in the data field have empty data.
+
+    // INIT EVENTS
+
+    // Roles that are not set are logged as the zero address.
+    #[event("0x496e697469616c697a6564000000000000000000000000000000000000000000")]
+    fn initialized_event(&self,
+        owner: &Address,
+        supply_controller: &Address,
+        asset_protection_role: &Address,
+        pauser: &Address,
+        paused: bool);
+
+    // ERC20 EVENTS
+
+    #[event("0x5472616e73666572000000000000000000000000000000000000000000000000")]
+    fn transfer_event(&self,
+        sender: &Address,
+        recipient: &Address,
+        amount: &BigUint);
+
+    #[event("0x417070726f76616c000000000000000000000000000000000000000000000000")]
+    fn approval_event(&self,
+        sender: &Address,
+        spender: &Address,
+        amount: &BigUint);
+
+    // OWNABLE EVENTS
+
+    #[event("0x4f776e6572736869705472616e7366657250726f706f73656400000000000000")]
+    fn ownership_transfer_proposed_event(&self,
+        current_owner: &Address,
+        proposed_owner: &Address,
+        _data: ());
+
+    #[event("0x4f776e6572736869705472616e73666572446973726567617264656400000000")]
+    fn ownership_transfer_disregarded_event(&self,
+        old_proposed_owner: &Address,
+        _data: ());
+
+    #[event("0x4f776e6572736869705472616e73666572726564000000000000000000000000")]
+    fn ownership_transferred_event(&self,
+        old_owner: &Address,
+        new_owner: &Address,
+        _data: ());
+
+    #[event("0x5265636c61696d65640000000000000000000000000000000000000000000000")]
+    fn reclaimed_event(&self, owner: &Address, amount: &BigUint);
+
+    // PAUSABLE EVENTS
+
+    #[event("0x5061757365000000000000000000000000000000000000000000000000000000")]
+    fn pause_event(&self, caller: &Address, _data: ());
+
+    #[event("0x556e706175736500000000000000000000000000000000000000000000000000")]
+    fn unpause_event(&self, caller: &Address, _data: ());
+
+    #[event("0x5061757365725365740000000000000000000000000000000000000000000000")]
+    fn pauser_set_event(&self,
+        old_pauser: &Address,
+        new_pauser: &Address,
+        _data: ());
+
+    // ASSET PROTECTION EVENTS
+
+    #[event("0x4164647265737346726f7a656e00000000000000000000000000000000000000")]
+    fn address_frozen_event(&self, address: &Address, _data: ());
+
+    #[event("0x41646472657373556e66726f7a656e0000000000000000000000000000000000")]
+    fn address_unfrozen_event(&self, address: &Address, _data: ());
+
+    #[event("0x46726f7a656e4164647265737357697065640000000000000000000000000000")]
+    fn frozen_address_wiped_event(&self, address: &Address, _data: ());
+
+    #[event("0x417373657450726f74656374696f6e526f6c6553657400000000000000000000")]
+    fn asset_protection_role_set_event(&self,
+        old_asset_protection_role: &Address,
+        new_asset_protection_role: &Address,
+        _data: ());
+
+    // SUPPLY CONTROL EVENTS
+
+    #[event("0x537570706c79496e637265617365640000000000000000000000000000000000")]
     fn supply_increased_event(&self, to: &Address, amount: &BigUint);
 
-    #[event("0x000000000000000000000000000000000000000000000000000000000000000d")]
+    #[event("0x537570706c794465637265617365640000000000000000000000000000000000")]
     fn supply_decreased_event(&self, from: &Address, amount: &BigUint);
 
-    #[event("0x000000000000000000000000000000000000000000000000000000000000000e")]
-    fn supply_controller_set_event(&self, 
+    #[event("0x537570706c79436f6e74726f6c6c657253657400000000000000000000000000")]
+    fn supply_controller_set_event(&self,
         old_supply_controller: &Address,
         new_supply_controller: &Address,
         _data: ());
+
+    // VELOCITY LIMIT EVENTS
+
+    #[event("0x4461696c794c696d697453657400000000000000000000000000000000000000")]
+    fn daily_limit_set_event(&self, address: &Address, limit: &BigUint);
+
+    #[event("0x4461696c794c696d697452656d6f766564000000000000000000000000000000")]
+    fn daily_limit_removed_event(&self, address: &Address, _data: ());
+
+    #[event("0x44656661756c744461696c794c696d6974536574000000000000000000000000")]
+    fn default_daily_limit_set_event(&self, limit: &BigUint);
+
+    #[event("0x44656661756c744461696c794c696d697452656d6f7665640000000000000000")]
+    fn default_daily_limit_removed_event(&self, _data: ());
+
+    // LARGE TRANSFER EVENTS
+
+    #[event("0x4c617267655472616e7366657251756575656400000000000000000000000000")]
+    fn large_transfer_queued_event(&self,
+        sender: &Address,
+        recipient: &Address,
+        transfer_id: &BigUint,
+        amount: &BigUint);
+
+    #[event("0x4c617267655472616e7366657245786563757465640000000000000000000000")]
+    fn large_transfer_executed_event(&self,
+        sender: &Address,
+        recipient: &Address,
+        transfer_id: &BigUint,
+        amount: &BigUint);
+
+    #[event("0x4c617267655472616e7366657243616e63656c6c656400000000000000000000")]
+    fn large_transfer_cancelled_event(&self,
+        sender: &Address,
+        recipient: &Address,
+        transfer_id: &BigUint,
+        amount: &BigUint);
+
+    #[event("0x4c617267655472616e736665725468726573686f6c6453657400000000000000")]
+    fn large_transfer_threshold_set_event(&self, threshold: &BigUint);
+
+    #[event("0x4c617267655472616e736665725468726573686f6c6452656d6f766564000000")]
+    fn large_transfer_threshold_removed_event(&self, _data: ());
+
+    #[event("0x4c617267655472616e73666572526576696577506572696f6453657400000000")]
+    fn large_transfer_review_period_set_event(&self, review_period: u64);
+
+    // TRAVEL RULE EVENTS
+
+    #[event("0x54726176656c52756c6500000000000000000000000000000000000000000000")]
+    fn travel_rule_event(&self,
+        sender: &Address,
+        recipient: &Address,
+        payload_hash: &H256,
+        vasp_id: &H256,
+        amount: &BigUint);
+
+    #[event("0x5661737052656769737465726564000000000000000000000000000000000000")]
+    fn vasp_registered_event(&self, address: &Address, vasp_id: &H256, _data: ());
+
+    #[event("0x56617370556e7265676973746572656400000000000000000000000000000000")]
+    fn vasp_unregistered_event(&self, address: &Address, _data: ());
+
+    #[event("0x54726176656c52756c655468726573686f6c6453657400000000000000000000")]
+    fn travel_rule_threshold_set_event(&self, threshold: &BigUint);
+
+    #[event("0x54726176656c52756c655468726573686f6c6452656d6f766564000000000000")]
+    fn travel_rule_threshold_removed_event(&self, _data: ());
+
+    // UPGRADE EVENTS
+
+    #[event("0x53746f726167654d696772617465640000000000000000000000000000000000")]
+    fn storage_migrated_event(&self, version: u32);
+
+    // STATE MIGRATION EVENTS
+
+    #[event("0x496d706f72745374617274656400000000000000000000000000000000000000")]
+    fn import_started_event(&self, total_supply: &BigUint);
+
+    // ESDT MIGRATION EVENTS
+
+    #[event("0x457364744d6967726174696f6e456e61626c6564000000000000000000000000")]
+    fn esdt_migration_enabled_event(&self, token_identifier: &[u8]);
+
+    #[event("0x457364744d6967726174696f6e44697361626c65640000000000000000000000")]
+    fn esdt_migration_disabled_event(&self, _data: ());
+
+    #[event("0x4d69677261746564546f45736474000000000000000000000000000000000000")]
+    fn migrated_to_esdt_event(&self, holder: &Address, value: &BigUint);
+
+    #[event("0x457364744d6967726174696f6e52657665727465640000000000000000000000")]
+    fn esdt_migration_reverted_event(&self, holder: &Address, value: &BigUint);
+
+    // SNAPSHOT EVENTS
+
+    #[event("0x536e617073686f74000000000000000000000000000000000000000000000000")]
+    fn snapshot_event(&self, id: u64);
+
+    // LEGACY EVENTS SWITCH
+
+    #[event("0x4c65676163794576656e7473456e61626c656400000000000000000000000000")]
+    fn legacy_events_enabled_event(&self, _data: ());
+
+    #[event("0x4c65676163794576656e747344697361626c6564000000000000000000000000")]
+    fn legacy_events_disabled_event(&self, _data: ());
+
+    // LEGACY EVENTS
+
+    // The events as v0.3.0 emitted them before they were named, with numeric identifiers from 0x01 to 0x0e.
+    // They are emitted after their named counterparts while `legacyEventsEnabled` is set.
+
+    // LEGACY ERC20 EVENTS
+
+    #[event("0x0000000000000000000000000000000000000000000000000000000000000001")]
+    fn legacy_transfer_event(&self,
+        sender: &Address,
+        recipient: &Address,
+        amount: &BigUint);
+
+    #[event("0x0000000000000000000000000000000000000000000000000000000000000002")]
+    fn legacy_approve_event(&self,
+        sender: &Address,
+        recipient: &Address,
+        amount: &BigUint);
+
+    // LEGACY OWNABLE EVENTS
+
+    #[event("0x0000000000000000000000000000000000000000000000000000000000000003")]
+    fn legacy_ownership_transfer_proposed_event(&self, 
+        current_owner: &Address,
+        proposed_owner: &Address,
+        _data: ());
+
+    #[event("0x0000000000000000000000000000000000000000000000000000000000000004")]
+    fn legacy_ownership_transfer_disregarded_event(&self, 
+        old_proposed_owner: &Address,
+        _data: ());
+
+    #[event("0x0000000000000000000000000000000000000000000000000000000000000005")]
+    fn legacy_ownership_transferred_event(&self, 
+        old_owner: &Address,
+        new_owner: &Address,
+        _data: ());
     
+    // LEGACY PAUSABLE EVENTS
+
+    #[event("0x0000000000000000000000000000000000000000000000000000000000000006")]
+    fn legacy_pause_event(&self, _data: ());
+
+    #[event("0x0000000000000000000000000000000000000000000000000000000000000007")]
+    fn legacy_unpause_event(&self, _data: ());
+
+    // LEGACY ASSET PROTECTION EVENTS
+
+    #[event("0x0000000000000000000000000000000000000000000000000000000000000008")]
+    fn legacy_address_frozen_event(&self, address: &Address, _data: ());
+
+    #[event("0x0000000000000000000000000000000000000000000000000000000000000009")]
+    fn legacy_address_unfrozen_event(&self, address: &Address, _data: ());
+
+    #[event("0x000000000000000000000000000000000000000000000000000000000000000a")]
+    fn legacy_frozen_address_wiped_event(&self, address: &Address, _data: ());
+
+    #[event("0x000000000000000000000000000000000000000000000000000000000000000b")]
+    fn legacy_asset_protection_role_set_event(&self, 
+        old_asset_protection_role: &Address,
+        new_asset_protection_role: &Address,
+        _data: ());
+
+    // LEGACY SUPPLY CONTROL EVENTS
+
+    #[event("0x000000000000000000000000000000000000000000000000000000000000000c")]
+    fn legacy_supply_increased_event(&self, to: &Address, amount: &BigUint);
+
+    #[event("0x000000000000000000000000000000000000000000000000000000000000000d")]
+    fn legacy_supply_decreased_event(&self, from: &Address, amount: &BigUint);
+
+    #[event("0x000000000000000000000000000000000000000000000000000000000000000e")]
+    fn legacy_supply_controller_set_event(&self, 
+        old_supply_controller: &Address,
+        new_supply_controller: &Address,
+        _data: ());
 }
@@ -0,0 +1,130 @@
+use std::collections::BTreeMap;
+
+use num_bigint::BigUint;
+use num_traits::Num;
+use serde_json::{Map, Value};
+
+/// Raw key/value storage of the contract.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct StorageDump {
+    pub entries: BTreeMap<Vec<u8>, Vec<u8>>,
+}
+
+impl StorageDump {
+    /// Detects the format: a Mandos scenario has `steps`, anything else is taken as the proxy response.
+    pub fn parse(json: &str) -> Result<StorageDump, String> {
+        let value: Value = serde_json::from_str(json).map_err(|e| format!("invalid JSON: {}", e))?;
+        if value.get("steps").is_some() {
+            StorageDump::from_mandos(&value, None)
+        } else {
+            StorageDump::from_api(&value)
+        }
+    }
+
+    /// The response of `/address/<contract>/keys`, hex keys to hex values under `data.pairs`.
+    /// The `pairs` object on its own is accepted too.
+    pub fn from_api(value: &Value) -> Result<StorageDump, String> {
+        let pairs = value.pointer("/data/pairs")
+            .or_else(|| value.get("pairs"))
+            .unwrap_or(value)
+            .as_object()
+            .ok_or("expected an object of hex keys to hex values")?;
+        let mut entries = BTreeMap::new();
+        for (key, value) in pairs.iter() {
+            let value = value.as_str().ok_or_else(|| format!("value of {} is not a string", key))?;
+            let key_bytes = hex::decode(key).map_err(|e| format!("invalid key {}: {}", key, e))?;
+            let value_bytes = hex::decode(value).map_err(|e| format!("invalid value of {}: {}", key, e))?;
+            insert(&mut entries, key_bytes, value_bytes);
+        }
+        Ok(StorageDump { entries })
+    }
+
+    /// The storage of a contract account in a Mandos scenario, as of the last step that sets or checks it.
+    /// The account is given in Mandos notation, e.g. ``` ``busd_contract____s1 ```,
+    /// by default it is the account that has non-empty `code`.
+    pub fn from_mandos(scenario: &Value, account: Option<&str>) -> Result<StorageDump, String> {
+        let account = match account {
+            Some(account) => Some(mandos_value(account)?),
+            None => None,
+        };
+        let steps = scenario["steps"].as_array().ok_or("steps is not an array")?;
+        let mut storage = None;
+        for step in steps {
+            let accounts = match step.get("accounts").and_then(Value::as_object) {
+                Some(accounts) => accounts,
+                None => continue,
+            };
+            for (name, state) in accounts.iter() {
+                let is_contract = match &account {
+                    Some(account) => &mandos_value(name)? == account,
+                    None => state.get("code").and_then(Value::as_str).is_some_and(|code| !code.is_empty()),
+                };
+                // checkState can leave the storage unchecked with "*"
+                if let (true, Some(found)) = (is_contract, state.get("storage").and_then(Value::as_object)) {
+                    storage = Some(found);
+                }
+            }
+        }
+        let storage: &Map<String, Value> = storage.ok_or("no storage found for the contract account")?;
+
+        let mut entries = BTreeMap::new();
+        for (key, value) in storage.iter() {
+            let value = value.as_str().ok_or_else(|| format!("value of {} is not a string", key))?;
+            insert(&mut entries, mandos_value(key)?, mandos_value(value)?);
+        }
+        Ok(StorageDump { entries })
+    }
+}
+
+/// Storing an empty value erases the key, so empty values are not part of the storage.
+fn insert(entries: &mut BTreeMap<Vec<u8>, Vec<u8>>, key: Vec<u8>, value: Vec<u8>) {
+    if !value.is_empty() {
+        entries.insert(key, value);
+    }
+}
+
+/// Bytes of a Mandos value, in the notations used by the BUSD scenarios:
+/// ``` ``text ``` or `''text` strings, `0x` hex, decimal numbers with optional `,` separators,
+/// `true` and `false`, `u64:`/`u32:`/`u8:` fixed-width numbers, and `|` concatenation.
+pub fn mandos_value(value: &str) -> Result<Vec<u8>, String> {
+    if value.contains('|') {
+        let mut bytes = Vec::new();
+        for part in value.split('|') {
+            bytes.extend(mandos_value(part)?);
+        }
+        return Ok(bytes);
+    }
+    if value.starts_with("``") || value.starts_with("''") {
+        return Ok(value.as_bytes()[2..].to_vec());
+    }
+    if let Some(text) = value.strip_prefix("str:") {
+        return Ok(text.as_bytes().to_vec());
+    }
+    for (prefix, width) in [("u64:", 8), ("u32:", 4), ("u8:", 1)].iter() {
+        if let Some(number) = value.strip_prefix(prefix) {
+            let bytes = mandos_number(number)?;
+            if bytes.len() > *width {
+                return Err(format!("{} does not fit in {} bytes", value, width));
+            }
+            let mut fixed = vec![0u8; width - bytes.len()];
+            fixed.extend(bytes);
+            return Ok(fixed);
+        }
+    }
+    match value {
+        "" | "false" => Ok(Vec::new()),
+        "true" => Ok(vec![1]),
+        _ => match value.strip_prefix("0x") {
+            Some(hex_value) => hex::decode(hex_value).map_err(|e| format!("invalid hex {}: {}", value, e)),
+            None => mandos_number(value),
+        },
+    }
+}
+
+/// Big-endian bytes without leading zeros, so 0 is empty.
+fn mandos_number(number: &str) -> Result<Vec<u8>, String> {
+    let digits = number.replace(',', "");
+    let parsed = BigUint::from_str_radix(&digits, 10).map_err(|_| format!("invalid Mandos value {}", number))?;
+    let bytes = parsed.to_bytes_be();
+    Ok(if bytes == [0] { Vec::new() } else { bytes })
+}
@@ -0,0 +1,62 @@
+//! Decodes raw BUSD contract storage into typed state.
+//!
+//! A storage dump is either the JSON returned by the proxy for `/address/<contract>/keys`,
+//! or the contract account of a Mandos scenario. See `dump` for the formats and `state` for the decoding.
+//!
+//! ```
+//! use busd_storage_inspector::*;
+//!
+//! // "paused" set to true
+//! let dump = StorageDump::parse(r#"{"data": {"pairs": {"706175736564": "01"}}}"#).unwrap();
+//! let state = BusdState::decode(&dump);
+//! assert!(state.paused);
+//! ```
+
+pub mod dump;
+pub mod state;
+
+pub use dump::StorageDump;
+pub use state::{BusdState, DailyBucket, PendingTransfer, Undecoded};
+
+use std::fmt;
+use std::str::FromStr;
+
+use bech32::{FromBase32, ToBase32};
+
+/// Human-readable part of Elrond bech32 addresses.
+const ADDRESS_HRP: &str = "erd";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Address(pub [u8; 32]);
+
+impl Address {
+    pub fn from_slice(bytes: &[u8]) -> Option<Address> {
+        if bytes.len() != 32 {
+            return None;
+        }
+        let mut address = [0u8; 32];
+        address.copy_from_slice(bytes);
+        Some(Address(address))
+    }
+}
+
+/// Bech32, the form used by explorers and wallets.
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", bech32::encode(ADDRESS_HRP, self.0.to_base32()).unwrap())
+    }
+}
+
+/// Bech32, or 64 hex digits.
+impl FromStr for Address {
+    type Err = String;
+
+    fn from_str(address: &str) -> Result<Address, String> {
+        let bytes = match bech32::decode(address) {
+            Ok((hrp, data)) if hrp == ADDRESS_HRP => Vec::<u8>::from_base32(&data).map_err(|e| e.to_string())?,
+            Ok((hrp, _)) => return Err(format!("{}: expected the {} prefix, got {}", address, ADDRESS_HRP, hrp)),
+            Err(_) => hex::decode(address).map_err(|_| format!("{}: not a bech32 or hex address", address))?,
+        };
+        Address::from_slice(&bytes).ok_or_else(|| format!("{}: expected 32 bytes, got {}", address, bytes.len()))
+    }
+}
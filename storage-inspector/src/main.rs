@@ -0,0 +1,64 @@
+//! Prints the BUSD state decoded from a storage dump, and what is inconsistent about it.
+//!
+//! Usage: `busd-inspect [--account ACCOUNT] FILE`
+//!
+//! `FILE` is either the JSON returned by `/address/<contract>/keys` or a Mandos scenario.
+//! For scenarios, `--account` picks the contract account in Mandos notation, by default the one with code.
+//! The command fails if the state has inconsistencies.
+
+use std::fs;
+use std::process;
+
+use busd_storage_inspector::{BusdState, StorageDump};
+
+const USAGE: &str = "Usage: busd-inspect [--account ACCOUNT] FILE";
+
+fn main() {
+    let mut account = None;
+    let mut file = None;
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--account" => account = args.next(),
+            _ if file.is_none() && !arg.starts_with("--") => file = Some(arg),
+            other => {
+                eprintln!("unknown argument {}\n\n{}", other, USAGE);
+                process::exit(1);
+            }
+        }
+    }
+    let file = match file {
+        Some(file) => file,
+        None => {
+            eprintln!("{}", USAGE);
+            process::exit(1);
+        }
+    };
+
+    let dump = fs::read_to_string(&file)
+        .map_err(|e| format!("cannot read {}: {}", file, e))
+        .and_then(|json| match &account {
+            Some(account) => serde_json::from_str(&json)
+                .map_err(|e| format!("invalid JSON: {}", e))
+                .and_then(|scenario| StorageDump::from_mandos(&scenario, Some(account))),
+            None => StorageDump::parse(&json),
+        });
+    let dump = match dump {
+        Ok(dump) => dump,
+        Err(error) => {
+            eprintln!("error: {}", error);
+            process::exit(1);
+        }
+    };
+
+    let state = BusdState::decode(&dump);
+    print!("{}", state);
+    let inconsistencies = state.inconsistencies();
+    if !inconsistencies.is_empty() {
+        println!("\ninconsistencies:");
+        for inconsistency in inconsistencies.iter() {
+            println!("  {}", inconsistency);
+        }
+        process::exit(1);
+    }
+}
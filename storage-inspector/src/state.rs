@@ -0,0 +1,547 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::convert::TryInto;
+use std::fmt;
+
+use num_bigint::BigUint;
+use num_traits::Zero;
+
+use crate::{Address, StorageDump};
+
+/// Velocity limit spending of an address in one hour, see `vel_hour` and `vel_amount`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DailyBucket {
+    /// Hours since the epoch.
+    pub hour: u64,
+    pub amount: BigUint,
+}
+
+/// A queued large transfer. Once executed or cancelled only the amount is erased,
+/// so a zero amount means the transfer is no longer pending.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PendingTransfer {
+    pub sender: Option<Address>,
+    pub recipient: Option<Address>,
+    pub amount: BigUint,
+    pub timestamp: u64,
+    /// The travel rule payload hash attached, logged once the transfer is executed.
+    pub payload_hash: Option<[u8; 32]>,
+    pub vasp_id: [u8; 32],
+}
+
+/// A storage entry that is not part of the BUSD storage layout, or whose value does not decode.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Undecoded {
+    pub key: Vec<u8>,
+    pub value: Vec<u8>,
+    pub reason: String,
+}
+
+/// The contract state, as written by `src/lib.rs`. Absent keys read as zero, false or `None`, same as in the contract.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BusdState {
+    pub name: Vec<u8>,
+    pub symbol: Vec<u8>,
+    pub decimals: u64,
+    /// 0 for a contract deployed before the storage version was stored.
+    pub storage_version: u64,
+
+    pub total_supply: BigUint,
+    pub balances: BTreeMap<Address, BigUint>,
+    pub allowances: BTreeMap<(Address, Address), BigUint>,
+
+    pub owner: Option<Address>,
+    pub proposed_owner: Option<Address>,
+    pub paused: bool,
+    pub pauser: Option<Address>,
+    pub asset_protection_role: Option<Address>,
+    pub frozen: BTreeSet<Address>,
+    pub supply_controller: Option<Address>,
+
+    pub default_daily_limit: Option<BigUint>,
+    pub daily_limits: BTreeMap<Address, BigUint>,
+    /// By address and bucket, from 0 to 24.
+    pub daily_buckets: BTreeMap<(Address, u64), DailyBucket>,
+
+    pub large_transfer_threshold: Option<BigUint>,
+    pub large_transfer_review_period: u64,
+    pub last_pending_transfer_id: u64,
+    pub pending_transfer_ids: Vec<u64>,
+    pub pending_transfers_total: BigUint,
+    pub pending_transfers: BTreeMap<u64, PendingTransfer>,
+
+    pub travel_rule_threshold: Option<BigUint>,
+    pub vasp_ids: BTreeMap<Address, [u8; 32]>,
+
+    pub legacy_events: bool,
+
+    /// Registered holders by position, from 1, see `holder_at`.
+    pub holders: BTreeMap<u64, Address>,
+    pub holders_len: u64,
+    pub holder_indexes: BTreeMap<Address, u64>,
+    /// Registered frozen addresses by position, from 1, see `frozen_at`.
+    pub frozen_registry: BTreeMap<u64, Address>,
+    pub frozen_len: u64,
+    pub frozen_indexes: BTreeMap<Address, u64>,
+    pub spenders: BTreeMap<Address, Vec<Address>>,
+    /// The total supply declared by `beginImport`, until the import completes.
+    pub import_total_supply: Option<BigUint>,
+
+    /// Empty while the ESDT migration is not enabled.
+    pub esdt_token: Vec<u8>,
+    pub esdt_migrated_supply: BigUint,
+
+    /// 0 before the first snapshot.
+    pub current_snapshot_id: u64,
+    /// The snapshots each balance was saved for, see `snap_bal_ids`.
+    pub balance_snapshot_ids: BTreeMap<Address, Vec<u64>>,
+    pub balance_snapshots: BTreeMap<(Address, u64), BigUint>,
+    pub total_supply_snapshot_ids: Vec<u64>,
+    pub total_supply_snapshots: BTreeMap<u64, BigUint>,
+
+    pub undecoded: Vec<Undecoded>,
+}
+
+/// Storage key arguments, encoded the way the contract appends them to the key.
+#[derive(Clone, Copy)]
+enum KeyArg {
+    Address,
+    Id,
+}
+
+impl KeyArg {
+    fn len(self) -> usize {
+        match self {
+            KeyArg::Address => 32,
+            KeyArg::Id => 8,
+        }
+    }
+}
+
+/// Every storage key of the contract, with the arguments that follow the name.
+const KEYS: &[(&str, &[KeyArg])] = &[
+    ("name", &[]),
+    ("symbol", &[]),
+    ("decimals", &[]),
+    ("schema_version", &[]),
+    ("total_supply", &[]),
+    ("balance", &[KeyArg::Address]),
+    ("allowance", &[KeyArg::Address, KeyArg::Address]),
+    ("owner", &[]),
+    ("prop_owner", &[]),
+    ("paused", &[]),
+    ("pauser", &[]),
+    ("ap_role", &[]),
+    ("frozen", &[KeyArg::Address]),
+    ("vel_default", &[]),
+    ("vel_limit", &[KeyArg::Address]),
+    ("vel_hour", &[KeyArg::Address, KeyArg::Id]),
+    ("vel_amount", &[KeyArg::Address, KeyArg::Id]),
+    ("lt_threshold", &[]),
+    ("lt_review", &[]),
+    ("pt_last_id", &[]),
+    ("pt_ids", &[]),
+    ("pt_total", &[]),
+    ("pt_sender", &[KeyArg::Id]),
+    ("pt_recipient", &[KeyArg::Id]),
+    ("pt_amount", &[KeyArg::Id]),
+    ("pt_time", &[KeyArg::Id]),
+    ("pt_payload", &[KeyArg::Id]),
+    ("pt_vasp", &[KeyArg::Id]),
+    ("vasp_id", &[KeyArg::Address]),
+    ("tr_threshold", &[]),
+    ("supply_c", &[]),
+    ("legacy_events", &[]),
+    ("holders_len", &[]),
+    ("holder_at", &[KeyArg::Id]),
+    ("holder_idx", &[KeyArg::Address]),
+    ("frozen_len", &[]),
+    ("frozen_at", &[KeyArg::Id]),
+    ("frozen_idx", &[KeyArg::Address]),
+    ("spenders", &[KeyArg::Address]),
+    ("import_total", &[]),
+    ("esdt_token", &[]),
+    ("esdt_migrated", &[]),
+    ("snap_id", &[]),
+    ("snap_bal_ids", &[KeyArg::Address]),
+    ("snap_bal", &[KeyArg::Address, KeyArg::Id]),
+    ("snap_supply_ids", &[]),
+    ("snap_supply", &[KeyArg::Id]),
+];
+
+/// Decoded key arguments.
+struct Key<'a> {
+    name: &'static str,
+    args: Vec<&'a [u8]>,
+}
+
+impl<'a> Key<'a> {
+    fn parse(key: &'a [u8]) -> Option<Key<'a>> {
+        KEYS.iter().find_map(|(name, args)| {
+            let rest = key.strip_prefix(name.as_bytes())?;
+            if rest.len() != args.iter().map(|arg| arg.len()).sum::<usize>() {
+                return None;
+            }
+            let mut split = Vec::new();
+            let mut offset = 0;
+            for arg in args.iter() {
+                split.push(&rest[offset..offset + arg.len()]);
+                offset += arg.len();
+            }
+            Some(Key { name, args: split })
+        })
+    }
+
+    fn address(&self, index: usize) -> Address {
+        Address::from_slice(self.args[index]).unwrap()
+    }
+
+    fn id(&self, index: usize) -> u64 {
+        u64::from_be_bytes(self.args[index].try_into().unwrap())
+    }
+}
+
+fn decode_big_uint(value: &[u8]) -> Result<BigUint, String> {
+    Ok(BigUint::from_bytes_be(value))
+}
+
+fn decode_u64(value: &[u8]) -> Result<u64, String> {
+    if value.len() > 8 {
+        return Err(format!("{} bytes is too long for a u64", value.len()));
+    }
+    Ok(value.iter().fold(0u64, |number, byte| number << 8 | *byte as u64))
+}
+
+fn decode_bool(value: &[u8]) -> Result<bool, String> {
+    match value {
+        [1] => Ok(true),
+        _ => Err("expected 01 for true".to_string()),
+    }
+}
+
+fn decode_address(value: &[u8]) -> Result<Address, String> {
+    Address::from_slice(value).ok_or_else(|| format!("expected a 32-byte address, got {} bytes", value.len()))
+}
+
+/// `Some` is a leading 01 followed by the nested encoding of the value, `None` is never stored.
+fn decode_some(value: &[u8]) -> Result<&[u8], String> {
+    value.strip_prefix(&[1]).ok_or_else(|| "expected 01 for Some".to_string())
+}
+
+fn decode_h256(value: &[u8], what: &str) -> Result<[u8; 32], String> {
+    value.try_into().map_err(|_| format!("expected a 32-byte {}, got {} bytes", what, value.len()))
+}
+
+/// Nested `BigUint`: a 4-byte length followed by the big-endian bytes.
+fn decode_some_big_uint(value: &[u8]) -> Result<BigUint, String> {
+    let nested = decode_some(value)?;
+    if nested.len() < 4 || u32::from_be_bytes(nested[..4].try_into().unwrap()) as usize != nested.len() - 4 {
+        return Err("expected a length-prefixed BigUint after 01".to_string());
+    }
+    decode_big_uint(&nested[4..])
+}
+
+fn decode_ids(value: &[u8]) -> Result<Vec<u64>, String> {
+    if !value.len().is_multiple_of(8) {
+        return Err(format!("{} bytes is not a list of u64", value.len()));
+    }
+    Ok(value.chunks(8).map(|chunk| u64::from_be_bytes(chunk.try_into().unwrap())).collect())
+}
+
+fn decode_addresses(value: &[u8]) -> Result<Vec<Address>, String> {
+    if !value.len().is_multiple_of(32) {
+        return Err(format!("{} bytes is not a list of addresses", value.len()));
+    }
+    value.chunks(32).map(decode_address).collect()
+}
+
+impl BusdState {
+    /// Decodes every entry of the dump, the ones that do not decode end up in `undecoded`.
+    pub fn decode(dump: &StorageDump) -> BusdState {
+        let mut state = BusdState::default();
+        for (key, value) in dump.entries.iter() {
+            if let Err(reason) = state.decode_entry(key, value) {
+                state.undecoded.push(Undecoded { key: key.clone(), value: value.clone(), reason });
+            }
+        }
+        state
+    }
+
+    fn decode_entry(&mut self, key: &[u8], value: &[u8]) -> Result<(), String> {
+        let key = Key::parse(key).ok_or("unknown key")?;
+        match key.name {
+            "name" => self.name = value.to_vec(),
+            "symbol" => self.symbol = value.to_vec(),
+            "decimals" => self.decimals = decode_u64(value)?,
+            "schema_version" => self.storage_version = decode_u64(value)?,
+            "total_supply" => self.total_supply = decode_big_uint(value)?,
+            "balance" => {
+                self.balances.insert(key.address(0), decode_big_uint(value)?);
+            },
+            "allowance" => {
+                self.allowances.insert((key.address(0), key.address(1)), decode_big_uint(value)?);
+            },
+            "owner" => self.owner = Some(decode_address(value)?),
+            "prop_owner" => self.proposed_owner = Some(decode_address(decode_some(value)?)?),
+            "paused" => self.paused = decode_bool(value)?,
+            "pauser" => self.pauser = Some(decode_address(decode_some(value)?)?),
+            "ap_role" => self.asset_protection_role = Some(decode_address(decode_some(value)?)?),
+            "frozen" => {
+                decode_bool(value)?;
+                self.frozen.insert(key.address(0));
+            },
+            "vel_default" => self.default_daily_limit = Some(decode_some_big_uint(value)?),
+            "vel_limit" => {
+                self.daily_limits.insert(key.address(0), decode_some_big_uint(value)?);
+            },
+            "vel_hour" => self.daily_buckets.entry((key.address(0), key.id(1))).or_default().hour = decode_u64(value)?,
+            "vel_amount" => self.daily_buckets.entry((key.address(0), key.id(1))).or_default().amount = decode_big_uint(value)?,
+            "lt_threshold" => self.large_transfer_threshold = Some(decode_some_big_uint(value)?),
+            "lt_review" => self.large_transfer_review_period = decode_u64(value)?,
+            "pt_last_id" => self.last_pending_transfer_id = decode_u64(value)?,
+            "pt_ids" => self.pending_transfer_ids = decode_ids(value)?,
+            "pt_total" => self.pending_transfers_total = decode_big_uint(value)?,
+            "pt_sender" => self.pending_transfers.entry(key.id(0)).or_default().sender = Some(decode_address(value)?),
+            "pt_recipient" => self.pending_transfers.entry(key.id(0)).or_default().recipient = Some(decode_address(value)?),
+            "pt_amount" => self.pending_transfers.entry(key.id(0)).or_default().amount = decode_big_uint(value)?,
+            "pt_time" => self.pending_transfers.entry(key.id(0)).or_default().timestamp = decode_u64(value)?,
+            "pt_payload" => self.pending_transfers.entry(key.id(0)).or_default().payload_hash = Some(decode_h256(decode_some(value)?, "payload hash")?),
+            "pt_vasp" => self.pending_transfers.entry(key.id(0)).or_default().vasp_id = decode_h256(value, "VASP id")?,
+            "vasp_id" => {
+                self.vasp_ids.insert(key.address(0), decode_h256(decode_some(value)?, "VASP id")?);
+            },
+            "tr_threshold" => self.travel_rule_threshold = Some(decode_some_big_uint(value)?),
+            "supply_c" => self.supply_controller = Some(decode_address(value)?),
+            "legacy_events" => self.legacy_events = decode_bool(value)?,
+            "holders_len" => self.holders_len = decode_u64(value)?,
+            "holder_at" => {
+                self.holders.insert(key.id(0), decode_address(decode_some(value)?)?);
+            },
+            "holder_idx" => {
+                self.holder_indexes.insert(key.address(0), decode_u64(value)?);
+            },
+            "frozen_len" => self.frozen_len = decode_u64(value)?,
+            "frozen_at" => {
+                self.frozen_registry.insert(key.id(0), decode_address(decode_some(value)?)?);
+            },
+            "frozen_idx" => {
+                self.frozen_indexes.insert(key.address(0), decode_u64(value)?);
+            },
+            "spenders" => {
+                self.spenders.insert(key.address(0), decode_addresses(value)?);
+            },
+            "import_total" => self.import_total_supply = Some(decode_some_big_uint(value)?),
+            "esdt_token" => self.esdt_token = value.to_vec(),
+            "esdt_migrated" => self.esdt_migrated_supply = decode_big_uint(value)?,
+            "snap_id" => self.current_snapshot_id = decode_u64(value)?,
+            "snap_bal_ids" => {
+                self.balance_snapshot_ids.insert(key.address(0), decode_ids(value)?);
+            },
+            "snap_bal" => {
+                self.balance_snapshots.insert((key.address(0), key.id(1)), decode_big_uint(value)?);
+            },
+            "snap_supply_ids" => self.total_supply_snapshot_ids = decode_ids(value)?,
+            "snap_supply" => {
+                self.total_supply_snapshots.insert(key.id(0), decode_big_uint(value)?);
+            },
+            other => unreachable!("key {} listed but not decoded", other),
+        }
+        Ok(())
+    }
+
+    /// Ids of the transfers still waiting for execution or cancellation.
+    pub fn pending_transfer_amounts(&self) -> BTreeMap<u64, &BigUint> {
+        self.pending_transfers.iter()
+            .filter(|(_, transfer)| !transfer.amount.is_zero())
+            .map(|(id, transfer)| (*id, &transfer.amount))
+            .collect()
+    }
+
+    /// States the contract can never reach by itself, e.g. after a bad migration or a manual storage edit.
+    pub fn inconsistencies(&self) -> Vec<String> {
+        let mut found = Vec::new();
+
+        // queued transfers are taken out of the sender balance until executed or cancelled
+        let balances: BigUint = self.balances.values().sum();
+        if &balances + &self.pending_transfers_total != self.total_supply {
+            found.push(format!(
+                "balances ({}) and pending transfers ({}) do not add up to the total supply ({})",
+                balances, self.pending_transfers_total, self.total_supply));
+        }
+
+        let pending = self.pending_transfer_amounts();
+        let pending_sum: BigUint = pending.values().cloned().sum();
+        if pending_sum != self.pending_transfers_total {
+            found.push(format!(
+                "pending transfer amounts ({}) do not add up to the pending total ({})",
+                pending_sum, self.pending_transfers_total));
+        }
+        let listed: BTreeSet<u64> = self.pending_transfer_ids.iter().cloned().collect();
+        if listed.len() != self.pending_transfer_ids.len() {
+            found.push("pending transfer ids listed more than once".to_string());
+        }
+        for id in listed.iter().filter(|id| !pending.contains_key(id)) {
+            found.push(format!("pending transfer {} is listed but has no amount", id));
+        }
+        for id in pending.keys().filter(|id| !listed.contains(id)) {
+            found.push(format!("pending transfer {} has an amount but is not listed", id));
+        }
+        for (id, transfer) in self.pending_transfers.iter() {
+            if *id == 0 || *id > self.last_pending_transfer_id {
+                found.push(format!("pending transfer {} is above the last id {}", id, self.last_pending_transfer_id));
+            }
+            if !transfer.amount.is_zero() && (transfer.sender.is_none() || transfer.recipient.is_none()) {
+                found.push(format!("pending transfer {} has no sender or recipient", id));
+            }
+        }
+
+        // the registry lists holders from 1 to its length, each pointing back to its position
+        if self.holders.keys().cloned().ne(1..=self.holders_len) {
+            found.push(format!("registered holders are not numbered 1 to {}", self.holders_len));
+        }
+        for (index, holder) in self.holders.iter() {
+            if self.holder_indexes.get(holder) != Some(index) {
+                found.push(format!("holder {} at {} has another index", holder, index));
+            }
+            if self.balances.get(holder).is_none_or(|balance| balance.is_zero()) {
+                found.push(format!("holder {} is registered but has no balance", holder));
+            }
+        }
+        for (holder, index) in self.holder_indexes.iter() {
+            if self.holders.get(index) != Some(holder) {
+                found.push(format!("holder {} has index {} but is not registered there", holder, index));
+            }
+        }
+
+        // and so does the frozen address registry
+        if self.frozen_registry.keys().cloned().ne(1..=self.frozen_len) {
+            found.push(format!("registered frozen addresses are not numbered 1 to {}", self.frozen_len));
+        }
+        for (index, address) in self.frozen_registry.iter() {
+            if self.frozen_indexes.get(address) != Some(index) {
+                found.push(format!("frozen address {} at {} has another index", address, index));
+            }
+            if !self.frozen.contains(address) {
+                found.push(format!("address {} is registered as frozen but is not", address));
+            }
+        }
+        for (address, index) in self.frozen_indexes.iter() {
+            if self.frozen_registry.get(index) != Some(address) {
+                found.push(format!("frozen address {} has index {} but is not registered there", address, index));
+            }
+        }
+
+        // values are only saved for snapshots already taken
+        let saved_ids = self.balance_snapshot_ids.values().flatten().chain(self.total_supply_snapshot_ids.iter());
+        if let Some(id) = saved_ids.filter(|id| **id == 0 || **id > self.current_snapshot_id).max() {
+            found.push(format!("values saved for snapshot {}, above the latest snapshot {}", id, self.current_snapshot_id));
+        }
+
+        match self.owner {
+            None => found.push("no owner".to_string()),
+            Some(owner) if self.proposed_owner == Some(owner) => found.push("the owner is also the proposed owner".to_string()),
+            _ => {},
+        }
+        if self.supply_controller.is_none() {
+            found.push("no supply controller".to_string());
+        }
+        found
+    }
+}
+
+fn option<T: fmt::Display>(value: &Option<T>) -> String {
+    match value {
+        Some(value) => value.to_string(),
+        None => "-".to_string(),
+    }
+}
+
+/// A readable report, one section per contract feature.
+impl fmt::Display for BusdState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "token: {} ({}), {} decimals",
+            String::from_utf8_lossy(&self.name), String::from_utf8_lossy(&self.symbol), self.decimals)?;
+        writeln!(f, "storage version: {}", self.storage_version)?;
+        writeln!(f, "total supply: {}", self.total_supply)?;
+        writeln!(f, "paused: {}", self.paused)?;
+        writeln!(f, "pauser: {}", option(&self.pauser))?;
+        writeln!(f, "owner: {}", option(&self.owner))?;
+        writeln!(f, "proposed owner: {}", option(&self.proposed_owner))?;
+        writeln!(f, "asset protection role: {}", option(&self.asset_protection_role))?;
+        writeln!(f, "supply controller: {}", option(&self.supply_controller))?;
+        writeln!(f, "import total supply: {}", option(&self.import_total_supply))?;
+        if self.esdt_token.is_empty() {
+            writeln!(f, "ESDT migration: disabled")?;
+        } else {
+            writeln!(f, "ESDT migration: to {}", String::from_utf8_lossy(&self.esdt_token))?;
+        }
+        writeln!(f, "migrated to ESDT: {}", self.esdt_migrated_supply)?;
+        writeln!(f, "latest snapshot: {}", self.current_snapshot_id)?;
+
+        writeln!(f, "\nbalances:")?;
+        for (address, balance) in self.balances.iter() {
+            let frozen = if self.frozen.contains(address) { " (frozen)" } else { "" };
+            writeln!(f, "  {} {}{}", address, balance, frozen)?;
+        }
+        writeln!(f, "\nallowances:")?;
+        for ((owner, spender), allowance) in self.allowances.iter() {
+            writeln!(f, "  {} -> {} {}", owner, spender, allowance)?;
+        }
+        writeln!(f, "\nspenders:")?;
+        for (owner, spenders) in self.spenders.iter() {
+            let spenders: Vec<String> = spenders.iter().map(Address::to_string).collect();
+            writeln!(f, "  {} -> {}", owner, spenders.join(", "))?;
+        }
+        writeln!(f, "\nholders ({}):", self.holders_len)?;
+        for (index, holder) in self.holders.iter() {
+            writeln!(f, "  {} {}", index, holder)?;
+        }
+        writeln!(f, "\nfrozen:")?;
+        for address in self.frozen.iter() {
+            writeln!(f, "  {}", address)?;
+        }
+        writeln!(f, "\nregistered frozen addresses ({}):", self.frozen_len)?;
+        for (index, address) in self.frozen_registry.iter() {
+            writeln!(f, "  {} {}", index, address)?;
+        }
+
+        writeln!(f, "\ndefault daily limit: {}", option(&self.default_daily_limit))?;
+        writeln!(f, "daily limits:")?;
+        for (address, limit) in self.daily_limits.iter() {
+            writeln!(f, "  {} {}", address, limit)?;
+        }
+        writeln!(f, "daily buckets:")?;
+        for ((address, bucket), spending) in self.daily_buckets.iter() {
+            writeln!(f, "  {} bucket {} spent {} in hour {}", address, bucket, spending.amount, spending.hour)?;
+        }
+
+        writeln!(f, "\nlarge transfer threshold: {}", option(&self.large_transfer_threshold))?;
+        writeln!(f, "large transfer review period: {}", self.large_transfer_review_period)?;
+        writeln!(f, "last pending transfer id: {}", self.last_pending_transfer_id)?;
+        writeln!(f, "pending transfers total: {}", self.pending_transfers_total)?;
+        writeln!(f, "pending transfers:")?;
+        for (id, transfer) in self.pending_transfers.iter().filter(|(_, transfer)| !transfer.amount.is_zero()) {
+            write!(f, "  {}: {} -> {} {} queued at {}",
+                id, option(&transfer.sender), option(&transfer.recipient), transfer.amount, transfer.timestamp)?;
+            if let Some(payload_hash) = &transfer.payload_hash {
+                write!(f, " travel rule {} from {}", hex::encode(payload_hash), hex::encode(transfer.vasp_id))?;
+            }
+            writeln!(f)?;
+        }
+
+        writeln!(f, "\ntravel rule threshold: {}", option(&self.travel_rule_threshold))?;
+        writeln!(f, "VASPs:")?;
+        for (address, vasp_id) in self.vasp_ids.iter() {
+            writeln!(f, "  {} {}", address, hex::encode(vasp_id))?;
+        }
+
+        writeln!(f, "\nlegacy events: {}", self.legacy_events)?;
+
+        if !self.undecoded.is_empty() {
+            writeln!(f, "\nundecoded:")?;
+            for entry in self.undecoded.iter() {
+                writeln!(f, "  {} = {}: {}", hex::encode(&entry.key), hex::encode(&entry.value), entry.reason)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,298 @@
+use num_bigint::BigUint;
+use serde_json::json;
+
+use busd_storage_inspector::*;
+use busd_storage_inspector::dump::mandos_value;
+
+fn address(name: &str) -> Address {
+    Address::from_slice(&mandos_value(&format!("``{}", name)).unwrap()).unwrap()
+}
+
+/// Hex storage key, `args` are appended to the name.
+fn key(name: &str, args: &[&[u8]]) -> String {
+    let mut bytes = name.as_bytes().to_vec();
+    for arg in args.iter() {
+        bytes.extend_from_slice(arg);
+    }
+    hex::encode(bytes)
+}
+
+fn big(value: u64) -> BigUint {
+    BigUint::from(value)
+}
+
+fn decode(pairs: serde_json::Value) -> BusdState {
+    let dump = StorageDump::parse(&json!({ "data": { "pairs": pairs }, "code": "successful" }).to_string()).unwrap();
+    BusdState::decode(&dump)
+}
+
+const OWNER: &str = "busd_owner____________________s1";
+const HOLDER: &str = "holder________________________s1";
+const SPENDER: &str = "spender_______________________s1";
+
+#[test]
+fn test_decode_api_dump() {
+    let owner = address(OWNER);
+    let holder = address(HOLDER);
+    let spender = address(SPENDER);
+    let mut pairs = serde_json::Map::new();
+    let mut pair = |key: String, value: String| {
+        pairs.insert(key, json!(value));
+    };
+    pair(key("name", &[]), hex::encode("Binance USD"));
+    pair(key("symbol", &[]), hex::encode("BUSD"));
+    pair(key("decimals", &[]), "12".to_string());
+    pair(key("schema_version", &[]), "01".to_string());
+    pair(key("total_supply", &[]), "03e8".to_string());
+    pair(key("owner", &[]), hex::encode(owner.0));
+    pair(key("supply_c", &[]), hex::encode(owner.0));
+    pair(key("prop_owner", &[]), format!("01{}", hex::encode(spender.0)));
+    pair(key("ap_role", &[]), format!("01{}", hex::encode(owner.0)));
+    pair(key("paused", &[]), "01".to_string());
+    pair(key("pauser", &[]), format!("01{}", hex::encode(spender.0)));
+    pair(key("balance", &[&owner.0]), "0384".to_string());
+    pair(key("balance", &[&holder.0]), "64".to_string());
+    pair(key("allowance", &[&holder.0, &spender.0]), "0a".to_string());
+    pair(key("frozen", &[&holder.0]), "01".to_string());
+    pair(key("vel_default", &[]), "010000000203e8".to_string());
+    pair(key("vel_hour", &[&holder.0, &2u64.to_be_bytes()]), "01b2".to_string());
+    pair(key("vel_amount", &[&holder.0, &2u64.to_be_bytes()]), "1e".to_string());
+    pair(key("vasp_id", &[&holder.0]), format!("01{}", "ab".repeat(32)));
+    pair(key("legacy_events", &[]), "01".to_string());
+    pair(key("esdt_token", &[]), hex::encode("BUSD-a1b2c3"));
+    pair(key("esdt_migrated", &[]), "32".to_string());
+    pair(key("unrelated", &[]), "01".to_string());
+
+    let state = decode(serde_json::Value::Object(pairs));
+    assert_eq!(state.name, b"Binance USD".to_vec());
+    assert_eq!(state.symbol, b"BUSD".to_vec());
+    assert_eq!(state.decimals, 18);
+    assert_eq!(state.storage_version, 1);
+    assert_eq!(state.total_supply, big(1000));
+    assert_eq!(state.owner, Some(owner));
+    assert_eq!(state.supply_controller, Some(owner));
+    assert_eq!(state.proposed_owner, Some(spender));
+    assert_eq!(state.asset_protection_role, Some(owner));
+    assert!(state.paused);
+    assert_eq!(state.pauser, Some(spender));
+    assert_eq!(state.balances.get(&owner), Some(&big(900)));
+    assert_eq!(state.balances.get(&holder), Some(&big(100)));
+    assert_eq!(state.allowances.get(&(holder, spender)), Some(&big(10)));
+    assert!(state.frozen.contains(&holder));
+    assert_eq!(state.default_daily_limit, Some(big(1000)));
+    assert_eq!(state.daily_buckets.get(&(holder, 2)), Some(&DailyBucket { hour: 434, amount: big(30) }));
+    assert_eq!(state.vasp_ids.get(&holder), Some(&[0xab; 32]));
+    assert!(state.legacy_events);
+    assert_eq!(state.esdt_token, b"BUSD-a1b2c3".to_vec());
+    assert_eq!(state.esdt_migrated_supply, big(50));
+
+    assert_eq!(state.undecoded.len(), 1);
+    assert_eq!(state.undecoded[0].key, b"unrelated".to_vec());
+    assert_eq!(state.undecoded[0].reason, "unknown key");
+    assert_eq!(state.inconsistencies(), Vec::<String>::new());
+}
+
+#[test]
+fn test_pending_transfers() {
+    let owner = address(OWNER);
+    let holder = address(HOLDER);
+    let id = |id: u64| id.to_be_bytes();
+    let state = decode(json!({
+        key("total_supply", &[]): "64",
+        key("owner", &[]): hex::encode(owner.0),
+        key("supply_c", &[]): hex::encode(owner.0),
+        key("balance", &[&owner.0]): "1e",
+        key("lt_threshold", &[]): "010000000114",
+        key("lt_review", &[]): "015180",
+        key("pt_last_id", &[]): "02",
+        key("pt_ids", &[]): hex::encode(id(2)),
+        key("pt_total", &[]): "46",
+        // transfer 1 was executed, only its amount is erased
+        key("pt_sender", &[&id(1)]): hex::encode(owner.0),
+        key("pt_recipient", &[&id(1)]): hex::encode(holder.0),
+        key("pt_time", &[&id(1)]): "0a",
+        key("pt_sender", &[&id(2)]): hex::encode(owner.0),
+        key("pt_recipient", &[&id(2)]): hex::encode(holder.0),
+        key("pt_amount", &[&id(2)]): "46",
+        key("pt_time", &[&id(2)]): "14",
+        key("pt_payload", &[&id(2)]): format!("01{}", hex::encode([7u8; 32])),
+        key("pt_vasp", &[&id(2)]): hex::encode([8u8; 32]),
+    }));
+    assert_eq!(state.large_transfer_threshold, Some(big(20)));
+    assert_eq!(state.large_transfer_review_period, 86400);
+    assert_eq!(state.last_pending_transfer_id, 2);
+    assert_eq!(state.pending_transfer_ids, vec![2]);
+    assert_eq!(state.pending_transfers[&2], PendingTransfer {
+        sender: Some(owner),
+        recipient: Some(holder),
+        amount: big(70),
+        timestamp: 20,
+        payload_hash: Some([7u8; 32]),
+        vasp_id: [8u8; 32],
+    });
+    assert_eq!(state.pending_transfer_amounts().keys().cloned().collect::<Vec<u64>>(), vec![2]);
+    assert!(state.undecoded.is_empty());
+    assert_eq!(state.inconsistencies(), Vec::<String>::new());
+}
+
+#[test]
+fn test_inconsistencies() {
+    let owner = address(OWNER);
+    let id = |id: u64| id.to_be_bytes();
+    let state = decode(json!({
+        key("total_supply", &[]): "64",
+        key("owner", &[]): hex::encode(owner.0),
+        key("prop_owner", &[]): format!("01{}", hex::encode(owner.0)),
+        key("balance", &[&owner.0]): "50",
+        key("pt_last_id", &[]): "01",
+        key("pt_ids", &[]): hex::encode([id(1), id(1)].concat()),
+        key("pt_amount", &[&id(3)]): "05",
+    }));
+    assert_eq!(state.inconsistencies(), vec![
+        "balances (80) and pending transfers (0) do not add up to the total supply (100)".to_string(),
+        "pending transfer amounts (5) do not add up to the pending total (0)".to_string(),
+        "pending transfer ids listed more than once".to_string(),
+        "pending transfer 1 is listed but has no amount".to_string(),
+        "pending transfer 3 has an amount but is not listed".to_string(),
+        "pending transfer 3 is above the last id 1".to_string(),
+        "pending transfer 3 has no sender or recipient".to_string(),
+        "the owner is also the proposed owner".to_string(),
+        "no supply controller".to_string(),
+    ]);
+}
+
+#[test]
+fn test_snapshots() {
+    let owner = address(OWNER);
+    let holder = address(HOLDER);
+    let id = |id: u64| id.to_be_bytes();
+    let state = decode(json!({
+        key("total_supply", &[]): "64",
+        key("owner", &[]): hex::encode(owner.0),
+        key("supply_c", &[]): hex::encode(owner.0),
+        key("balance", &[&owner.0]): "64",
+        key("snap_id", &[]): "02",
+        key("snap_bal_ids", &[&holder.0]): hex::encode([id(1), id(3)].concat()),
+        key("snap_bal", &[&holder.0, &id(1)]): "0a",
+        key("snap_supply_ids", &[]): hex::encode(id(2)),
+        key("snap_supply", &[&id(2)]): "6e",
+    }));
+    assert!(state.undecoded.is_empty());
+    assert_eq!(state.current_snapshot_id, 2);
+    assert_eq!(state.balance_snapshot_ids.get(&holder), Some(&vec![1, 3]));
+    assert_eq!(state.balance_snapshots.get(&(holder, 1)), Some(&big(10)));
+    assert_eq!(state.total_supply_snapshot_ids, vec![2]);
+    assert_eq!(state.total_supply_snapshots.get(&2), Some(&big(110)));
+    assert_eq!(state.inconsistencies(), vec![
+        "values saved for snapshot 3, above the latest snapshot 2".to_string(),
+    ]);
+}
+
+#[test]
+fn test_holder_registry() {
+    let owner = address(OWNER);
+    let holder = address(HOLDER);
+    let spender = address(SPENDER);
+    let id = |id: u64| id.to_be_bytes();
+    let state = decode(json!({
+        key("total_supply", &[]): "64",
+        key("owner", &[]): hex::encode(owner.0),
+        key("supply_c", &[]): hex::encode(owner.0),
+        key("balance", &[&owner.0]): "64",
+        key("holders_len", &[]): "02",
+        key("holder_at", &[&id(1)]): format!("01{}", hex::encode(owner.0)),
+        key("holder_idx", &[&owner.0]): "01",
+        key("holder_at", &[&id(2)]): format!("01{}", hex::encode(holder.0)),
+        key("holder_idx", &[&holder.0]): "03",
+        key("spenders", &[&owner.0]): hex::encode([holder.0, spender.0].concat()),
+        key("import_total", &[]): "010000000164",
+        key("frozen", &[&spender.0]): "01",
+        key("frozen_len", &[]): "02",
+        key("frozen_at", &[&id(1)]): format!("01{}", hex::encode(spender.0)),
+        key("frozen_idx", &[&spender.0]): "01",
+        key("frozen_at", &[&id(2)]): format!("01{}", hex::encode(holder.0)),
+        key("frozen_idx", &[&holder.0]): "02",
+    }));
+    assert_eq!(state.holders_len, 2);
+    assert_eq!(state.holders.values().cloned().collect::<Vec<Address>>(), vec![owner, holder]);
+    assert_eq!(state.spenders.get(&owner), Some(&vec![holder, spender]));
+    assert_eq!(state.import_total_supply, Some(big(100)));
+    assert_eq!(state.frozen_len, 2);
+    assert_eq!(state.frozen_registry.values().cloned().collect::<Vec<Address>>(), vec![spender, holder]);
+    assert!(state.undecoded.is_empty());
+    assert_eq!(state.inconsistencies(), vec![
+        format!("holder {} at 2 has another index", holder),
+        format!("holder {} is registered but has no balance", holder),
+        format!("holder {} has index 3 but is not registered there", holder),
+        format!("address {} is registered as frozen but is not", holder),
+    ]);
+}
+
+#[test]
+fn test_undecodable_values() {
+    let owner = address(OWNER);
+    let state = decode(json!({
+        key("owner", &[]): "0102",
+        key("paused", &[]): "02",
+        key("vel_default", &[]): "0103e8",
+        key("balance", &[b"short"]): "01",
+    }));
+    let reasons: Vec<(Vec<u8>, String)> = state.undecoded.iter().map(|entry| (entry.key.clone(), entry.reason.clone())).collect();
+    assert_eq!(reasons, vec![
+        (b"balanceshort".to_vec(), "unknown key".to_string()),
+        (b"owner".to_vec(), "expected a 32-byte address, got 2 bytes".to_string()),
+        (b"paused".to_vec(), "expected 01 for true".to_string()),
+        (b"vel_default".to_vec(), "expected a length-prefixed BigUint after 01".to_string()),
+    ]);
+    assert_eq!(state.owner, None);
+    assert!(!state.balances.contains_key(&owner));
+}
+
+#[test]
+fn test_mandos_scenario() {
+    let scenario = include_str!("../../tests/freeze/transfer_vs_freeze.scen.json");
+    let state = BusdState::decode(&StorageDump::parse(scenario).unwrap());
+    let holder = address("holder_gets_frozen____________s1");
+    assert_eq!(state.owner, Some(address("busd_owner____________________s1")));
+    assert_eq!(state.asset_protection_role, Some(address("asset_protection_role_________s1")));
+    assert!(!state.paused);
+    assert_eq!(state.proposed_owner, None);
+    assert_eq!(state.balances.get(&holder), Some(&big(500)));
+    assert_eq!(state.allowances.get(&(holder, address("another_holder________________s1"))), Some(&big(1000)));
+    assert!(state.undecoded.is_empty());
+
+    let scenario: serde_json::Value = serde_json::from_str(include_str!("../../tests/erc20/approve_Caller-Positive.scen.json")).unwrap();
+    let account_1 = address("account_1_____________________s1");
+    let state = BusdState::decode(&StorageDump::from_mandos(&scenario, Some("``busd__________________________s1")).unwrap());
+    // the last checkState
+    assert_eq!(state.allowances.get(&(account_1, account_1)), Some(&big(0x25)));
+    assert_eq!(state.total_supply, big(10000));
+
+    assert!(StorageDump::from_mandos(&scenario, Some("``nobody")).is_err());
+}
+
+#[test]
+fn test_mandos_values() {
+    assert_eq!(mandos_value("``abc"), Ok(b"abc".to_vec()));
+    assert_eq!(mandos_value("''abc"), Ok(b"abc".to_vec()));
+    assert_eq!(mandos_value("str:abc"), Ok(b"abc".to_vec()));
+    assert_eq!(mandos_value("0x0102"), Ok(vec![1, 2]));
+    assert_eq!(mandos_value("1,000"), Ok(vec![3, 0xe8]));
+    assert_eq!(mandos_value("0"), Ok(vec![]));
+    assert_eq!(mandos_value("false"), Ok(vec![]));
+    assert_eq!(mandos_value("true"), Ok(vec![1]));
+    assert_eq!(mandos_value("u64:5"), Ok(vec![0, 0, 0, 0, 0, 0, 0, 5]));
+    assert_eq!(mandos_value("u8:1|``ab"), Ok(vec![1, b'a', b'b']));
+    assert!(mandos_value("u8:256").is_err());
+    assert!(mandos_value("0xzz").is_err());
+    assert!(mandos_value("abc").is_err());
+}
+
+#[test]
+fn test_address_display() {
+    let zero = "erd1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq6gq4hu";
+    assert_eq!(Address([0; 32]).to_string(), zero);
+    assert_eq!(zero.parse(), Ok(Address([0; 32])));
+    assert_eq!("00".repeat(32).parse(), Ok(Address([0; 32])));
+    assert!("erd1qqqq".parse::<Address>().is_err());
+}
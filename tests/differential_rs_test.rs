@@ -0,0 +1,419 @@
+//! Differential tests against the Paxos reference model.
+//!
+//! The same call sequences are replayed against the model and the contract,
+//! and after every call the outcomes (success or failure) and the full states are compared.
+//! Revert reasons are not compared, only whether the call went through.
+//!
+//! Known divergences are reported but tolerated: the model is resynced from the contract state and the replay goes on.
+//! Any other divergence fails the test, with a report of every step where the two disagreed.
+
+mod mock;
+mod model;
+use mock::*;
+use model::*;
+
+use std::fmt;
+
+use elrond_wasm::Address;
+use num_traits::ToPrimitive;
+use proptest::prelude::*;
+use proptest::sample::select;
+use sc_busd_rs::*;
+
+/// Every address that can appear in a call. The contract owns `busd_contract`, so that reclaim has something to do.
+const NAMES: &[&str] = &["holder_1", "holder_2", "holder_3", "busd_owner", "asset_protection_role", "busd_contract", ZERO];
+
+/// Addresses that can send transactions, i.e. all but the zero address.
+const CALLERS: &[&str] = &["holder_1", "holder_2", "holder_3", "busd_owner", "asset_protection_role", "busd_contract"];
+
+const ZERO_RECIPIENT: &str = "transfers to the zero address are not rejected";
+const ZERO_PROPOSED_OWNER: &str = "the zero address can be proposed as owner";
+const REPEATED_PROPOSAL: &str = "proposing the already proposed owner again is rejected";
+const ZERO_SUPPLY_CONTROLLER: &str = "the zero address can be made supply controller";
+
+#[derive(Debug, Clone)]
+enum Call {
+    Transfer { caller: &'static str, to: &'static str, amount: u64 },
+    TransferFrom { caller: &'static str, from: &'static str, to: &'static str, amount: u64 },
+    Approve { caller: &'static str, spender: &'static str, amount: u64 },
+    ProposeOwner { caller: &'static str, proposed: &'static str },
+    DisregardProposedOwner { caller: &'static str },
+    ClaimOwnership { caller: &'static str },
+    Reclaim { caller: &'static str },
+    Pause { caller: &'static str },
+    Unpause { caller: &'static str },
+    SetAssetProtectionRole { caller: &'static str, role: &'static str },
+    Freeze { caller: &'static str, address: &'static str },
+    Unfreeze { caller: &'static str, address: &'static str },
+    Wipe { caller: &'static str, address: &'static str },
+    SetSupplyController { caller: &'static str, controller: &'static str },
+    IncreaseSupply { caller: &'static str, amount: u64 },
+    DecreaseSupply { caller: &'static str, amount: u64 },
+}
+
+/// Divergences that are already known, by the state of the model right before the call.
+/// Only consulted once a divergence was observed, so the conditions can be broad.
+fn known_divergence(call: &Call, before: &PaxosState) -> Option<&'static str> {
+    match *call {
+        Call::Transfer { to, .. } | Call::TransferFrom { to, .. } if to == ZERO =>
+            Some(ZERO_RECIPIENT),
+        Call::ProposeOwner { proposed, .. } if proposed == ZERO =>
+            Some(ZERO_PROPOSED_OWNER),
+        // once proposed, the zero address can also be disregarded, which the model sees as nothing to disregard
+        Call::DisregardProposedOwner { .. } if before.proposed_owner == ZERO =>
+            Some(ZERO_PROPOSED_OWNER),
+        Call::ProposeOwner { proposed, .. } if proposed == before.proposed_owner =>
+            Some(REPEATED_PROPOSAL),
+        Call::SetSupplyController { controller, .. } if controller == ZERO =>
+            Some(ZERO_SUPPLY_CONTROLLER),
+        _ => None,
+    }
+}
+
+fn to_address(name: &str) -> Address {
+    if name == ZERO {
+        Address::zero()
+    } else {
+        address(name)
+    }
+}
+
+fn to_name(address: &Address) -> &'static str {
+    NAMES.iter()
+        .find(|name| to_address(name) == *address)
+        .expect("unknown address in contract state")
+}
+
+fn to_u64(value: &MockBigUint) -> u64 {
+    value.value().to_u64().expect("amount out of range")
+}
+
+/// Reads the contract state into the same shape as the model state.
+/// An unset role and a role set to the zero address both read as `ZERO`, same as in Solidity.
+fn contract_state(test: &BUSDTest) -> PaxosState {
+    let c = &test.contract;
+    let mut state = PaxosModel::deploy("busd_contract", ZERO).state;
+    for &name in NAMES {
+        let balance = to_u64(&test.balance(name));
+        if balance > 0 {
+            state.balances.insert(name, balance);
+        }
+        if c.is_frozen(&to_address(name)) {
+            state.frozen.insert(name);
+        }
+        for &spender in NAMES {
+            let allowance = to_u64(&c.get_allowance(&to_address(name), &to_address(spender)));
+            if allowance > 0 {
+                state.allowances.insert((name, spender), allowance);
+            }
+        }
+    }
+    state.paused = c.is_paused();
+    state.total_supply = to_u64(&c.get_mut_total_supply());
+    state.owner = to_name(&c.get_contract_owner());
+    state.proposed_owner = c.get_proposed_owner().map_or(ZERO, |address| to_name(&address));
+    state.asset_protection_role = c.get_asset_protection_role().map_or(ZERO, |address| to_name(&address));
+    state.supply_controller = to_name(&c.get_supply_controller());
+    state
+}
+
+fn differences(model: &PaxosState, contract: &PaxosState) -> Vec<String> {
+    let mut differences = Vec::new();
+    let mut compare = |what: String, model: String, contract: String| {
+        if model != contract {
+            differences.push(format!("{}: model {}, contract {}", what, model, contract));
+        }
+    };
+    for &name in NAMES {
+        compare(format!("balance of {}", name),
+            model.balance(name).to_string(), contract.balance(name).to_string());
+        compare(format!("frozen {}", name),
+            model.is_frozen(name).to_string(), contract.is_frozen(name).to_string());
+        for &spender in NAMES {
+            compare(format!("allowance of {} to {}", name, spender),
+                model.allowance(name, spender).to_string(), contract.allowance(name, spender).to_string());
+        }
+    }
+    compare("paused".into(), model.paused.to_string(), contract.paused.to_string());
+    compare("total supply".into(), model.total_supply.to_string(), contract.total_supply.to_string());
+    compare("owner".into(), model.owner.into(), contract.owner.into());
+    compare("proposed owner".into(), model.proposed_owner.into(), contract.proposed_owner.into());
+    compare("asset protection role".into(), model.asset_protection_role.into(), contract.asset_protection_role.into());
+    compare("supply controller".into(), model.supply_controller.into(), contract.supply_controller.into());
+    differences
+}
+
+/// A step where the model and the contract disagreed.
+#[derive(Debug)]
+struct Divergence {
+    step: usize,
+    call: Call,
+    model: Result<(), Revert>,
+    contract: Result<(), BUSDError>,
+    differences: Vec<String>,
+    known: Option<&'static str>,
+}
+
+impl fmt::Display for Divergence {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "step {}: {:?}", self.step, self.call)?;
+        match self.model {
+            Ok(()) => writeln!(f, "    model: ok")?,
+            Err(reason) => writeln!(f, "    model: reverted, {}", reason)?,
+        }
+        match self.contract {
+            Ok(()) => writeln!(f, "    contract: ok")?,
+            Err(error) => writeln!(f, "    contract: failed, {}", error.message())?,
+        }
+        for difference in self.differences.iter() {
+            writeln!(f, "    {}", difference)?;
+        }
+        match self.known {
+            Some(known) => writeln!(f, "    known divergence: {}", known),
+            None => writeln!(f, "    unknown divergence"),
+        }
+    }
+}
+
+/// Replays calls against both the model and the contract, deployed by `busd_owner`.
+struct Differential {
+    model: PaxosModel,
+    test: BUSDTest,
+    steps: usize,
+    divergences: Vec<Divergence>,
+}
+
+impl Differential {
+    fn deploy() -> Self {
+        Differential {
+            model: PaxosModel::deploy("busd_contract", "busd_owner"),
+            test: BUSDTest::deploy(),
+            steps: 0,
+            divergences: Vec::new(),
+        }
+    }
+
+    fn replay(&mut self, calls: &[Call]) {
+        for call in calls {
+            self.step(call);
+        }
+    }
+
+    fn step(&mut self, call: &Call) {
+        let before = self.model.state.clone();
+        let model = self.call_model(call);
+        let contract = self.call_contract(call);
+        let state = contract_state(&self.test);
+        let differences = differences(&self.model.state, &state);
+
+        if model.is_ok() != contract.is_ok() || !differences.is_empty() {
+            self.divergences.push(Divergence {
+                step: self.steps,
+                call: call.clone(),
+                model,
+                contract,
+                differences,
+                known: known_divergence(call, &before),
+            });
+            // carry on from where the contract is, so that one divergence does not hide the next ones
+            self.model.state = state;
+        }
+        self.steps += 1;
+    }
+
+    fn call_model(&mut self, call: &Call) -> Result<(), Revert> {
+        let m = &mut self.model;
+        match *call {
+            Call::Transfer { caller, to, amount } => m.transfer(caller, to, amount),
+            Call::TransferFrom { caller, from, to, amount } => m.transfer_from(caller, from, to, amount),
+            Call::Approve { caller, spender, amount } => m.approve(caller, spender, amount),
+            Call::ProposeOwner { caller, proposed } => m.propose_owner(caller, proposed),
+            Call::DisregardProposedOwner { caller } => m.disregard_proposed_owner(caller),
+            Call::ClaimOwnership { caller } => m.claim_ownership(caller),
+            Call::Reclaim { caller } => m.reclaim_busd(caller),
+            Call::Pause { caller } => m.pause(caller),
+            Call::Unpause { caller } => m.unpause(caller),
+            Call::SetAssetProtectionRole { caller, role } => m.set_asset_protection_role(caller, role),
+            Call::Freeze { caller, address } => m.freeze(caller, address),
+            Call::Unfreeze { caller, address } => m.unfreeze(caller, address),
+            Call::Wipe { caller, address } => m.wipe_frozen_address(caller, address),
+            Call::SetSupplyController { caller, controller } => m.set_supply_controller(caller, controller),
+            Call::IncreaseSupply { caller, amount } => m.increase_supply(caller, amount),
+            Call::DecreaseSupply { caller, amount } => m.decrease_supply(caller, amount),
+        }
+    }
+
+    fn call_contract(&self, call: &Call) -> Result<(), BUSDError> {
+        let test = &self.test;
+        match *call {
+            Call::Transfer { caller, to, amount } =>
+                test.execute(caller, |c| c.transfer(to_address(to), big(amount))),
+            Call::TransferFrom { caller, from, to, amount } =>
+                test.execute(caller, |c| c.transfer_from(to_address(from), to_address(to), big(amount))),
+            Call::Approve { caller, spender, amount } =>
+                test.execute(caller, |c| c.approve(to_address(spender), big(amount))),
+            Call::ProposeOwner { caller, proposed } =>
+                test.execute(caller, |c| c.propose_owner(to_address(proposed))),
+            Call::DisregardProposedOwner { caller } =>
+                test.execute(caller, |c| c.disregard_proposed_owner()),
+            Call::ClaimOwnership { caller } =>
+                test.execute(caller, |c| c.claim_ownership()),
+            Call::Reclaim { caller } =>
+                test.execute(caller, |c| c.reclaim_busd()),
+            Call::Pause { caller } =>
+                test.execute(caller, |c| c.pause()),
+            Call::Unpause { caller } =>
+                test.execute(caller, |c| c.unpause()),
+            Call::SetAssetProtectionRole { caller, role } =>
+                test.execute(caller, |c| c.set_asset_protection_role_endpoint(&to_address(role))),
+            Call::Freeze { caller, address } =>
+                test.execute(caller, |c| c.freeze(&to_address(address))),
+            Call::Unfreeze { caller, address } =>
+                test.execute(caller, |c| c.unfreeze(&to_address(address))),
+            Call::Wipe { caller, address } =>
+                test.execute(caller, |c| c.wipe_frozen_address(&to_address(address))),
+            Call::SetSupplyController { caller, controller } =>
+                test.execute(caller, |c| c.set_supply_controller_endpoint(&to_address(controller))),
+            Call::IncreaseSupply { caller, amount } =>
+                test.execute(caller, |c| c.increase_supply(big(amount))),
+            Call::DecreaseSupply { caller, amount } =>
+                test.execute(caller, |c| c.decrease_supply(big(amount))),
+        }
+    }
+
+    fn unknown_divergences(&self) -> Vec<&Divergence> {
+        self.divergences.iter().filter(|divergence| divergence.known.is_none()).collect()
+    }
+
+    fn report(&self) -> String {
+        self.divergences.iter().map(|divergence| divergence.to_string()).collect()
+    }
+}
+
+/// Unpauses, sets up the asset protection role and hands out 1000 tokens to each holder.
+fn setup() -> Differential {
+    let mut differential = Differential::deploy();
+    differential.replay(&[
+        Call::Unpause { caller: "busd_owner" },
+        Call::SetAssetProtectionRole { caller: "busd_owner", role: "asset_protection_role" },
+        Call::IncreaseSupply { caller: "busd_owner", amount: 3000 },
+        Call::Transfer { caller: "busd_owner", to: "holder_1", amount: 1000 },
+        Call::Transfer { caller: "busd_owner", to: "holder_2", amount: 1000 },
+        Call::Transfer { caller: "busd_owner", to: "holder_3", amount: 1000 },
+    ]);
+    assert!(differential.divergences.is_empty(), "{}", differential.report());
+    differential
+}
+
+/// With no pauser set, only the owner can pause and unpause, as in Paxos.
+#[test]
+fn test_pause_matches_model() {
+    let mut differential = setup();
+    differential.replay(&[
+        Call::Pause { caller: "holder_1" },
+        Call::Transfer { caller: "holder_1", to: "holder_2", amount: 10 },
+        Call::Pause { caller: "busd_owner" },
+        Call::Transfer { caller: "holder_1", to: "holder_2", amount: 10 },
+        Call::Unpause { caller: "holder_2" },
+        Call::Unpause { caller: "busd_owner" },
+    ]);
+    assert!(differential.divergences.is_empty(), "{}", differential.report());
+}
+
+#[test]
+fn test_reports_known_divergences() {
+    let cases: Vec<(Vec<Call>, &str)> = vec![
+        (vec![Call::Transfer { caller: "holder_1", to: ZERO, amount: 10 }], ZERO_RECIPIENT),
+        (vec![
+            Call::Approve { caller: "holder_1", spender: "holder_2", amount: 10 },
+            Call::TransferFrom { caller: "holder_2", from: "holder_1", to: ZERO, amount: 10 },
+        ], ZERO_RECIPIENT),
+        (vec![Call::ProposeOwner { caller: "busd_owner", proposed: ZERO }], ZERO_PROPOSED_OWNER),
+        (vec![
+            Call::ProposeOwner { caller: "busd_owner", proposed: "holder_1" },
+            Call::ProposeOwner { caller: "busd_owner", proposed: "holder_1" },
+        ], REPEATED_PROPOSAL),
+        (vec![Call::SetSupplyController { caller: "busd_owner", controller: ZERO }], ZERO_SUPPLY_CONTROLLER),
+    ];
+    for (calls, known) in cases {
+        let mut differential = setup();
+        differential.replay(&calls);
+        assert_eq!(differential.divergences.len(), 1, "{}", differential.report());
+        assert_eq!(differential.divergences[0].known, Some(known), "{}", differential.report());
+    }
+}
+
+#[test]
+fn test_ownership_transfer_matches_model() {
+    let mut differential = setup();
+    differential.replay(&[
+        Call::ProposeOwner { caller: "holder_1", proposed: "holder_1" },
+        Call::ProposeOwner { caller: "busd_owner", proposed: "busd_owner" },
+        Call::ProposeOwner { caller: "busd_owner", proposed: "holder_1" },
+        Call::ClaimOwnership { caller: "holder_2" },
+        Call::DisregardProposedOwner { caller: "holder_2" },
+        Call::DisregardProposedOwner { caller: "holder_1" },
+        Call::DisregardProposedOwner { caller: "busd_owner" },
+        Call::ProposeOwner { caller: "busd_owner", proposed: "holder_2" },
+        Call::ClaimOwnership { caller: "holder_2" },
+        Call::Reclaim { caller: "busd_owner" },
+        Call::Reclaim { caller: "holder_2" },
+        Call::Pause { caller: "holder_2" },
+    ]);
+    assert!(differential.divergences.is_empty(), "{}", differential.report());
+}
+
+fn caller() -> impl Strategy<Value = &'static str> {
+    select(CALLERS)
+}
+
+fn target() -> impl Strategy<Value = &'static str> {
+    select(NAMES)
+}
+
+fn amount() -> impl Strategy<Value = u64> {
+    prop_oneof![0..=100u64, 0..=2000u64]
+}
+
+fn call() -> impl Strategy<Value = Call> {
+    prop_oneof![
+        6 => (caller(), target(), amount())
+            .prop_map(|(caller, to, amount)| Call::Transfer { caller, to, amount }),
+        4 => (caller(), target(), target(), amount())
+            .prop_map(|(caller, from, to, amount)| Call::TransferFrom { caller, from, to, amount }),
+        4 => (caller(), target(), amount())
+            .prop_map(|(caller, spender, amount)| Call::Approve { caller, spender, amount }),
+        1 => (caller(), target())
+            .prop_map(|(caller, proposed)| Call::ProposeOwner { caller, proposed }),
+        1 => caller().prop_map(|caller| Call::DisregardProposedOwner { caller }),
+        1 => caller().prop_map(|caller| Call::ClaimOwnership { caller }),
+        1 => caller().prop_map(|caller| Call::Reclaim { caller }),
+        1 => caller().prop_map(|caller| Call::Pause { caller }),
+        1 => caller().prop_map(|caller| Call::Unpause { caller }),
+        1 => (caller(), target())
+            .prop_map(|(caller, role)| Call::SetAssetProtectionRole { caller, role }),
+        2 => (caller(), target())
+            .prop_map(|(caller, address)| Call::Freeze { caller, address }),
+        1 => (caller(), target())
+            .prop_map(|(caller, address)| Call::Unfreeze { caller, address }),
+        1 => (caller(), target())
+            .prop_map(|(caller, address)| Call::Wipe { caller, address }),
+        1 => (caller(), target())
+            .prop_map(|(caller, controller)| Call::SetSupplyController { caller, controller }),
+        3 => (caller(), amount())
+            .prop_map(|(caller, amount)| Call::IncreaseSupply { caller, amount }),
+        2 => (caller(), amount())
+            .prop_map(|(caller, amount)| Call::DecreaseSupply { caller, amount }),
+    ]
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(300))]
+
+    #[test]
+    fn test_contract_matches_paxos_model(calls in prop::collection::vec(call(), 1..50)) {
+        let mut differential = setup();
+        differential.replay(&calls);
+        prop_assert!(differential.unknown_divergences().is_empty(), "{}", differential.report());
+    }
+}
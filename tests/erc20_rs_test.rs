@@ -0,0 +1,121 @@
+mod mock;
+use mock::*;
+
+use sc_busd_rs::*;
+
+#[test]
+fn test_static_info() {
+    let test = BUSDTest::deploy();
+    assert_eq!(test.contract.name(), b"Binance USD");
+    assert_eq!(test.contract.symbol(), b"BUSD");
+    assert_eq!(test.contract.decimals(), 18);
+}
+
+#[test]
+fn test_metadata() {
+    let test = BUSDTest::new();
+    assert_eq!(test.init(b"Euro", b"EU", 18), Err(BUSDError::InvalidSymbolLength));
+    assert_eq!(test.init(b"Euro", b"EUROSTABLE1", 18), Err(BUSDError::InvalidSymbolLength));
+    assert_eq!(test.init(b"Euro", b"EURS", 19), Err(BUSDError::InvalidDecimals));
+
+    test.init(b"Euro Stable", b"EURS", 6).unwrap();
+    assert_eq!(test.contract.name(), b"Euro Stable");
+    assert_eq!(test.contract.symbol(), b"EURS");
+    assert_eq!(test.contract.decimals(), 6);
+}
+
+#[test]
+fn test_transfer() {
+    let test = BUSDTest::deploy_unpaused();
+    test.mint("holder_1", 1000);
+
+    test.call_as("holder_1").transfer(address("holder_2"), big(300)).unwrap();
+
+    assert_eq!(test.balance("holder_1"), big(700));
+    assert_eq!(test.balance("holder_2"), big(300));
+    assert_eq!(test.contract.get_mut_total_supply().clone(), big(1000));
+
+    let logs = test.logs();
+    assert_eq!(logs.len(), 1);
+    assert_eq!(logs[0].event_name(), "Transfer");
+    assert_eq!(logs[0].topic_address(0), address("holder_1"));
+    assert_eq!(logs[0].topic_address(1), address("holder_2"));
+    assert_eq!(logs[0].data_big_uint(), big(300));
+}
+
+#[test]
+fn test_transfer_to_self() {
+    let test = BUSDTest::deploy_unpaused();
+    test.mint("holder_1", 1000);
+
+    test.call_as("holder_1").transfer(address("holder_1"), big(1000)).unwrap();
+    assert_eq!(test.balance("holder_1"), big(1000));
+}
+
+#[test]
+fn test_transfer_insufficient_funds() {
+    let test = BUSDTest::deploy_unpaused();
+    test.mint("holder_1", 1000);
+
+    let result = test.call_as("holder_1").transfer(address("holder_2"), big(1001));
+    assert_eq!(result, Err(BUSDError::InsufficientFunds));
+    assert_eq!(test.balance("holder_1"), big(1000));
+    assert!(test.logs().is_empty());
+}
+
+#[test]
+fn test_approve() {
+    let test = BUSDTest::deploy_unpaused();
+
+    test.call_as("holder_1").approve(address("spender"), big(500)).unwrap();
+    assert_eq!(test.contract.get_allowance(&address("holder_1"), &address("spender")), big(500));
+    assert_eq!(test.event_names(), vec!["Approval"]);
+
+    // overwrites the previous allowance
+    test.call_as("holder_1").approve(address("spender"), big(200)).unwrap();
+    assert_eq!(test.contract.get_allowance(&address("holder_1"), &address("spender")), big(200));
+}
+
+#[test]
+fn test_transfer_from() {
+    let test = BUSDTest::deploy_unpaused();
+    test.mint("holder_1", 1000);
+    test.call_as("holder_1").approve(address("spender"), big(500)).unwrap();
+    test.clear_logs();
+
+    test.call_as("spender").transfer_from(address("holder_1"), address("holder_2"), big(200)).unwrap();
+
+    assert_eq!(test.balance("holder_1"), big(800));
+    assert_eq!(test.balance("holder_2"), big(200));
+    assert_eq!(test.contract.get_allowance(&address("holder_1"), &address("spender")), big(300));
+    assert_eq!(test.event_names(), vec!["Transfer"]);
+}
+
+#[test]
+fn test_transfer_from_allowance_exceeded() {
+    let test = BUSDTest::deploy_unpaused();
+    test.mint("holder_1", 1000);
+    test.call_as("holder_1").approve(address("spender"), big(500)).unwrap();
+
+    // allowance is checked before balance
+    let result = test.call_as("spender").transfer_from(address("holder_1"), address("holder_2"), big(1001));
+    assert_eq!(result, Err(BUSDError::AllowanceExceeded));
+
+    test.call_as("holder_1").approve(address("spender"), big(2000)).unwrap();
+    let result = test.call_as("spender").transfer_from(address("holder_1"), address("holder_2"), big(1001));
+    assert_eq!(result, Err(BUSDError::InsufficientFunds));
+    assert_eq!(test.contract.get_allowance(&address("holder_1"), &address("spender")), big(2000));
+}
+
+#[test]
+fn test_paused_blocks_erc20_operations() {
+    let test = BUSDTest::deploy_unpaused();
+    test.mint("holder_1", 1000);
+    test.call_as("busd_owner").pause().unwrap();
+
+    assert_eq!(test.call_as("holder_1").transfer(address("holder_2"), big(1)), Err(BUSDError::Paused));
+    assert_eq!(test.call_as("holder_1").approve(address("spender"), big(1)), Err(BUSDError::Paused));
+    assert_eq!(
+        test.call_as("spender").transfer_from(address("holder_1"), address("holder_2"), big(1)),
+        Err(BUSDError::Paused));
+}
@@ -0,0 +1,119 @@
+mod mock;
+use mock::*;
+
+use elrond_wasm::AsyncCallResult;
+use sc_busd_rs::*;
+
+const TOKEN: &[u8] = b"BUSD-a1b2c3";
+
+fn enabled() -> BUSDTest {
+    let test = BUSDTest::deploy_unpaused();
+    test.call_as("busd_owner").enable_esdt_migration(TOKEN.to_vec()).unwrap();
+    test.mint("holder_1", 5000);
+    test.set_block_timestamp(1_000_000);
+    test
+}
+
+#[test]
+fn test_enable_esdt_migration() {
+    let test = BUSDTest::deploy_unpaused();
+    assert_eq!(test.call_as("holder_1").migrate_to_esdt(big(1)), Err(BUSDError::EsdtMigrationNotEnabled));
+    assert_eq!(
+        test.call_as("holder_1").enable_esdt_migration(TOKEN.to_vec()),
+        Err(BUSDError::OnlyOwnerCanChangeEsdtMigration));
+    assert_eq!(test.call_as("busd_owner").enable_esdt_migration(Vec::new()), Err(BUSDError::InvalidEsdtToken));
+
+    test.call_as("busd_owner").enable_esdt_migration(TOKEN.to_vec()).unwrap();
+    assert_eq!(test.contract.get_esdt_token(), TOKEN.to_vec());
+    assert_eq!(test.logs()[0].data, TOKEN.to_vec());
+    assert_eq!(
+        test.call_as("busd_owner").enable_esdt_migration(b"OTHER-000000".to_vec()),
+        Err(BUSDError::EsdtMigrationAlreadyEnabled));
+
+    assert_eq!(test.call_as("holder_1").disable_esdt_migration(), Err(BUSDError::OnlyOwnerCanChangeEsdtMigration));
+    test.call_as("busd_owner").disable_esdt_migration().unwrap();
+    assert!(test.contract.get_esdt_token().is_empty());
+    assert_eq!(test.event_names(), vec!["EsdtMigrationEnabled", "EsdtMigrationDisabled"]);
+    assert_eq!(test.call_as("busd_owner").disable_esdt_migration(), Err(BUSDError::EsdtMigrationNotEnabled));
+}
+
+#[test]
+fn test_migrate_to_esdt() {
+    let test = enabled();
+    test.call_as("holder_1").migrate_to_esdt(big(1000)).unwrap();
+    assert_eq!(test.balance("holder_1"), big(4000));
+    assert_eq!(test.contract.get_mut_total_supply().clone(), big(4000));
+    assert_eq!(test.contract.get_esdt_migrated_supply(), big(1000));
+    assert_eq!(test.event_names(), vec!["MigratedToEsdt", "Transfer"]);
+
+    // the ESDT is sent to the holder, who the built-in function is called on
+    let (call, callback) = test.take_async_call();
+    assert_eq!(call.to, address("holder_1"));
+    assert_eq!(call.amount, big(0));
+    assert_eq!(
+        String::from_utf8(call.data).unwrap(),
+        format!("ESDTTransfer@{}@03e8", hex(TOKEN)));
+    assert_eq!(callback, format!("esdt_transfer_callback@{}@03e8@0f4240", hex(address("holder_1").as_bytes())));
+
+    // nothing to undo once the transfer went through
+    test.clear_logs();
+    test.contract.esdt_transfer_callback(AsyncCallResult::Ok(()), address("holder_1"), big(1000), 1_000_000);
+    assert_eq!(test.balance("holder_1"), big(4000));
+    assert_eq!(test.contract.get_esdt_migrated_supply(), big(1000));
+    assert!(test.logs().is_empty());
+}
+
+#[test]
+fn test_failed_esdt_transfer_reverts() {
+    let test = enabled();
+    test.call_as("holder_1").migrate_to_esdt(big(5000)).unwrap();
+    test.take_async_call();
+    test.clear_logs();
+
+    test.contract.esdt_transfer_callback(failed_transfer(), address("holder_1"), big(5000), 1_000_000);
+    assert_eq!(test.balance("holder_1"), big(5000));
+    assert_eq!(test.contract.get_mut_total_supply().clone(), big(5000));
+    assert_eq!(test.contract.get_esdt_migrated_supply(), big(0));
+    assert_eq!(test.event_names(), vec!["EsdtMigrationReverted", "Transfer"]);
+}
+
+#[test]
+fn test_migrate_to_esdt_restrictions() {
+    let test = enabled();
+    assert_eq!(test.call_as("holder_1").migrate_to_esdt(big(5001)), Err(BUSDError::InsufficientFunds));
+
+    test.call_as("asset_protection_role").freeze(&address("holder_1")).unwrap();
+    assert_eq!(test.call_as("holder_1").migrate_to_esdt(big(1)), Err(BUSDError::AddressFrozen));
+    test.call_as("asset_protection_role").unfreeze(&address("holder_1")).unwrap();
+
+    test.call_as("busd_owner").pause().unwrap();
+    assert_eq!(test.call_as("holder_1").migrate_to_esdt(big(1)), Err(BUSDError::Paused));
+    test.call_as("busd_owner").unpause().unwrap();
+
+    // a migration cannot be queued for review
+    test.call_as("busd_owner").set_large_transfer_threshold_endpoint(big(1000)).unwrap();
+    assert_eq!(test.call_as("holder_1").migrate_to_esdt(big(1001)), Err(BUSDError::EsdtMigrationTooLarge));
+
+    // a VASP has no counterparty to send the travel rule metadata to
+    test.call_as("busd_owner").register_vasp(&address("holder_1"), &address("vasp_id")).unwrap();
+    test.call_as("busd_owner").set_travel_rule_threshold_endpoint(big(100)).unwrap();
+    assert_eq!(test.call_as("holder_1").migrate_to_esdt(big(101)), Err(BUSDError::TravelRuleRequired));
+    assert!(test.api.state.borrow().async_calls.is_empty());
+}
+
+#[test]
+fn test_migrate_to_esdt_daily_limit() {
+    let test = enabled();
+    test.call_as("asset_protection_role").set_daily_limit(&address("holder_1"), big(3000)).unwrap();
+    test.call_as("holder_1").transfer(address("holder_2"), big(1000)).unwrap();
+    assert_eq!(test.call_as("holder_1").migrate_to_esdt(big(2001)), Err(BUSDError::DailyLimitExceeded));
+
+    test.call_as("holder_1").migrate_to_esdt(big(2000)).unwrap();
+    test.take_async_call();
+    assert_eq!(test.contract.remaining_daily_capacity(&address("holder_1")), Some(big(0)));
+
+    // a failed ESDT transfer gives the usage back too
+    test.set_block_timestamp(1_000_000 + 6);
+    test.contract.esdt_transfer_callback(failed_transfer(), address("holder_1"), big(2000), 1_000_000);
+    assert_eq!(test.contract.remaining_daily_capacity(&address("holder_1")), Some(big(2000)));
+}
@@ -0,0 +1,85 @@
+mod mock;
+use mock::*;
+
+use sc_busd_rs::*;
+
+fn topic(value: u64) -> [u8; 32] {
+    let mut topic = [0u8; 32];
+    topic[24..].copy_from_slice(&value.to_be_bytes());
+    topic
+}
+
+#[test]
+fn test_named_events() {
+    let test = BUSDTest::deploy_unpaused();
+    test.mint("holder_1", 5000);
+    test.call_as("busd_owner").set_large_transfer_threshold_endpoint(big(1000)).unwrap();
+    test.clear_logs();
+
+    test.call_as("holder_1").transfer(address("holder_2"), big(100)).unwrap();
+    test.call_as("holder_1").transfer(address("holder_2"), big(2000)).unwrap();
+    let logs = test.logs();
+    assert_eq!(test.event_names(), vec!["Transfer", "LargeTransferQueued"]);
+    assert_eq!(&logs[0].identifier[..8], b"Transfer");
+
+    // addresses and ids are topics, amounts are data
+    assert_eq!(logs[0].topics.len(), 2);
+    assert_eq!(logs[0].topic_address(0), address("holder_1"));
+    assert_eq!(logs[0].topic_address(1), address("holder_2"));
+    assert_eq!(logs[0].data_big_uint(), big(100));
+    assert_eq!(logs[1].topics.len(), 3);
+    assert_eq!(logs[1].topic_address(0), address("holder_1"));
+    assert_eq!(logs[1].topic_address(1), address("holder_2"));
+    assert_eq!(logs[1].topics[2], topic(1));
+    assert_eq!(logs[1].data_big_uint(), big(2000));
+}
+
+#[test]
+fn test_init_event() {
+    let test = BUSDTest::deploy_with_logs();
+    let logs = test.logs();
+    assert_eq!(test.event_names(), vec!["Initialized"]);
+    assert_eq!(logs[0].topic_address(0), address("busd_owner"));
+    assert_eq!(logs[0].topic_address(1), address("busd_owner"));
+    // the asset protection role and the pauser are not set
+    assert_eq!(logs[0].topics[2], [0u8; 32]);
+    assert_eq!(logs[0].topics[3], [0u8; 32]);
+    assert_eq!(logs[0].data, vec![1]);
+}
+
+#[test]
+fn test_legacy_events() {
+    let test = BUSDTest::deploy_unpaused();
+    test.mint("holder_1", 5000);
+    assert!(!test.contract.legacy_events_enabled());
+
+    assert_eq!(test.call_as("holder_1").enable_legacy_events(), Err(BUSDError::OnlyOwnerCanChangeLegacyEvents));
+    test.call_as("busd_owner").enable_legacy_events().unwrap();
+    assert!(test.contract.legacy_events_enabled());
+    assert_eq!(test.event_names(), vec!["LegacyEventsEnabled"]);
+    test.clear_logs();
+
+    // every named event is followed by its legacy counterpart
+    test.call_as("holder_1").transfer(address("holder_2"), big(100)).unwrap();
+    test.call_as("holder_1").approve(address("holder_2"), big(10)).unwrap();
+    let logs = test.logs();
+    assert_eq!(logs.len(), 4);
+    assert_eq!(logs[0].event_name(), "Transfer");
+    assert_eq!(logs[2].event_name(), "Approval");
+    assert_eq!(test.legacy_event_ids(), vec![0x01, 0x02]);
+    assert_eq!(logs[1].topics, logs[0].topics);
+    assert_eq!(logs[1].data, logs[0].data);
+
+    // events added since v0.3.0 have no legacy counterpart
+    test.call_as("busd_owner").set_large_transfer_threshold_endpoint(big(1000)).unwrap();
+    test.clear_logs();
+    test.call_as("holder_1").transfer(address("holder_2"), big(2000)).unwrap();
+    assert_eq!(test.event_names(), vec!["LargeTransferQueued"]);
+    test.clear_logs();
+
+    assert_eq!(test.call_as("holder_1").disable_legacy_events(), Err(BUSDError::OnlyOwnerCanChangeLegacyEvents));
+    test.call_as("busd_owner").disable_legacy_events().unwrap();
+    assert!(!test.contract.legacy_events_enabled());
+    test.call_as("holder_1").transfer(address("holder_2"), big(100)).unwrap();
+    assert_eq!(test.event_names(), vec!["LegacyEventsDisabled", "Transfer"]);
+}
@@ -0,0 +1,77 @@
+mod mock;
+use mock::*;
+
+use sc_busd_rs::*;
+
+#[test]
+fn test_set_asset_protection_role() {
+    let test = BUSDTest::deploy();
+    assert_eq!(
+        test.call_as("someone_else").set_asset_protection_role_endpoint(&address("asset_protection_role")),
+        Err(BUSDError::OnlyOwnerOrAssetProtectionCanSetRole));
+
+    test.call_as("busd_owner").set_asset_protection_role_endpoint(&address("asset_protection_role")).unwrap();
+    assert_eq!(test.contract.get_asset_protection_role(), Some(address("asset_protection_role")));
+    assert_eq!(test.event_names(), vec!["AssetProtectionRoleSet"]);
+
+    // the asset protection role can pass on the role
+    test.call_as("asset_protection_role").set_asset_protection_role_endpoint(&address("new_role")).unwrap();
+    assert_eq!(test.contract.get_asset_protection_role(), Some(address("new_role")));
+}
+
+#[test]
+fn test_freeze_unfreeze() {
+    let test = BUSDTest::deploy_unpaused();
+    test.mint("holder_1", 1000);
+
+    assert_eq!(test.call_as("busd_owner").freeze(&address("holder_1")), Err(BUSDError::OnlyAssetProtectionCanFreeze));
+
+    test.call_as("asset_protection_role").freeze(&address("holder_1")).unwrap();
+    assert!(test.contract.is_frozen(&address("holder_1")));
+    assert_eq!(test.call_as("asset_protection_role").freeze(&address("holder_1")), Err(BUSDError::AddressAlreadyFrozen));
+
+    assert_eq!(test.call_as("holder_1").transfer(address("holder_2"), big(1)), Err(BUSDError::AddressFrozen));
+    assert_eq!(test.call_as("holder_2").transfer(address("holder_1"), big(0)), Err(BUSDError::AddressFrozen));
+    assert_eq!(test.call_as("holder_1").approve(address("holder_2"), big(1)), Err(BUSDError::AddressFrozen));
+
+    assert_eq!(test.call_as("busd_owner").unfreeze(&address("holder_1")), Err(BUSDError::OnlyAssetProtectionCanUnfreeze));
+    test.call_as("asset_protection_role").unfreeze(&address("holder_1")).unwrap();
+    assert_eq!(test.call_as("asset_protection_role").unfreeze(&address("holder_1")), Err(BUSDError::AddressAlreadyUnfrozen));
+
+    test.call_as("holder_1").transfer(address("holder_2"), big(1)).unwrap();
+    assert_eq!(test.event_names(), vec!["AddressFrozen", "AddressUnfrozen", "Transfer"]);
+}
+
+#[test]
+fn test_transfer_from_frozen_spender() {
+    let test = BUSDTest::deploy_unpaused();
+    test.mint("holder_1", 1000);
+    test.call_as("holder_1").approve(address("spender"), big(500)).unwrap();
+    test.call_as("asset_protection_role").freeze(&address("spender")).unwrap();
+
+    assert_eq!(
+        test.call_as("spender").transfer_from(address("holder_1"), address("holder_2"), big(1)),
+        Err(BUSDError::AddressFrozen));
+}
+
+#[test]
+fn test_wipe() {
+    let test = BUSDTest::deploy_unpaused();
+    test.mint("holder_1", 1000);
+    test.mint("holder_2", 500);
+
+    assert_eq!(
+        test.call_as("asset_protection_role").wipe_frozen_address(&address("holder_1")),
+        Err(BUSDError::AddressNotFrozen));
+
+    test.call_as("asset_protection_role").freeze(&address("holder_1")).unwrap();
+    assert_eq!(
+        test.call_as("busd_owner").wipe_frozen_address(&address("holder_1")),
+        Err(BUSDError::OnlyAssetProtectionCanWipe));
+    test.clear_logs();
+
+    test.call_as("asset_protection_role").wipe_frozen_address(&address("holder_1")).unwrap();
+    assert_eq!(test.balance("holder_1"), big(0));
+    assert_eq!(test.contract.get_mut_total_supply().clone(), big(500));
+    assert_eq!(test.event_names(), vec!["FrozenAddressWiped", "SupplyDecreased", "Transfer"]);
+}
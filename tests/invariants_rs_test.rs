@@ -0,0 +1,238 @@
+//! Property-based tests of the supply accounting.
+//!
+//! Random sequences of calls are run against the contract and the invariants are checked after every step.
+//! When a sequence breaks an invariant, proptest shrinks it down to a minimal reproducer.
+
+mod mock;
+use mock::*;
+
+use proptest::prelude::*;
+use proptest::sample::select;
+use sc_busd_rs::*;
+
+/// Accounts used in the generated calls.
+/// `busd_owner` is also the supply controller, `busd_contract` is the contract itself, so that reclaim has something to do.
+const ACCOUNTS: &[&str] = &["holder_1", "holder_2", "holder_3", "busd_owner", "busd_contract"];
+
+/// The owner and supply controller never get frozen:
+/// minting, burning and reclaiming are not affected by freeze constraints, by design.
+const FREEZABLE: &[&str] = &["holder_1", "holder_2", "holder_3", "busd_contract"];
+
+const SUPPLY_CONTROLLER: &str = "busd_owner";
+const ASSET_PROTECTION: &str = "asset_protection_role";
+
+#[derive(Debug, Clone)]
+enum Op {
+    Transfer { from: &'static str, to: &'static str, amount: u64 },
+    TransferFrom { spender: &'static str, from: &'static str, to: &'static str, amount: u64 },
+    Approve { owner: &'static str, spender: &'static str, amount: u64 },
+    Mint { amount: u64 },
+    Burn { amount: u64 },
+    Freeze { address: &'static str },
+    Unfreeze { address: &'static str },
+    Wipe { address: &'static str },
+    Reclaim,
+    Pause,
+    Unpause,
+    Snapshot,
+}
+
+impl Op {
+    /// Operations that any token holder can perform, which must all be blocked while paused.
+    fn is_user_operation(&self) -> bool {
+        matches!(self, Op::Transfer { .. } | Op::TransferFrom { .. } | Op::Approve { .. })
+    }
+}
+
+fn account() -> impl Strategy<Value = &'static str> {
+    select(ACCOUNTS)
+}
+
+fn freezable() -> impl Strategy<Value = &'static str> {
+    select(FREEZABLE)
+}
+
+fn amount() -> impl Strategy<Value = u64> {
+    prop_oneof![0..=100u64, 0..=2000u64]
+}
+
+fn op() -> impl Strategy<Value = Op> {
+    prop_oneof![
+        6 => (account(), account(), amount())
+            .prop_map(|(from, to, amount)| Op::Transfer { from, to, amount }),
+        4 => (account(), account(), account(), amount())
+            .prop_map(|(spender, from, to, amount)| Op::TransferFrom { spender, from, to, amount }),
+        4 => (account(), account(), amount())
+            .prop_map(|(owner, spender, amount)| Op::Approve { owner, spender, amount }),
+        3 => amount().prop_map(|amount| Op::Mint { amount }),
+        2 => amount().prop_map(|amount| Op::Burn { amount }),
+        2 => freezable().prop_map(|address| Op::Freeze { address }),
+        1 => freezable().prop_map(|address| Op::Unfreeze { address }),
+        1 => freezable().prop_map(|address| Op::Wipe { address }),
+        1 => Just(Op::Reclaim),
+        1 => Just(Op::Pause),
+        1 => Just(Op::Unpause),
+        1 => Just(Op::Snapshot),
+    ]
+}
+
+fn execute(test: &BUSDTest, op: &Op) -> Result<(), BUSDError> {
+    match *op {
+        Op::Transfer { from, to, amount } =>
+            test.execute(from, |c| c.transfer(address(to), big(amount))),
+        Op::TransferFrom { spender, from, to, amount } =>
+            test.execute(spender, |c| c.transfer_from(address(from), address(to), big(amount))),
+        Op::Approve { owner, spender, amount } =>
+            test.execute(owner, |c| c.approve(address(spender), big(amount))),
+        Op::Mint { amount } =>
+            test.execute(SUPPLY_CONTROLLER, |c| c.increase_supply(big(amount))),
+        Op::Burn { amount } =>
+            test.execute(SUPPLY_CONTROLLER, |c| c.decrease_supply(big(amount))),
+        Op::Freeze { address: target } =>
+            test.execute(ASSET_PROTECTION, |c| c.freeze(&address(target))),
+        Op::Unfreeze { address: target } =>
+            test.execute(ASSET_PROTECTION, |c| c.unfreeze(&address(target))),
+        Op::Wipe { address: target } =>
+            test.execute(ASSET_PROTECTION, |c| c.wipe_frozen_address(&address(target))),
+        Op::Reclaim =>
+            test.execute("busd_owner", |c| c.reclaim_busd()),
+        Op::Pause =>
+            test.execute("busd_owner", |c| c.pause()),
+        Op::Unpause =>
+            test.execute("busd_owner", |c| c.unpause()),
+        Op::Snapshot =>
+            test.execute(SUPPLY_CONTROLLER, |c| c.snapshot().map(|_| ())),
+    }
+}
+
+/// Everything the invariants look at, captured before and after each step.
+#[derive(Debug, Clone, PartialEq)]
+struct Snapshot {
+    balances: Vec<MockBigUint>,
+    allowances: Vec<MockBigUint>,
+    frozen: Vec<bool>,
+    holders: Vec<bool>,
+    paused: bool,
+    total_supply: MockBigUint,
+}
+
+impl Snapshot {
+    fn take(test: &BUSDTest) -> Self {
+        let c = &test.contract;
+        let mut allowances = Vec::new();
+        for owner in ACCOUNTS {
+            for spender in ACCOUNTS {
+                allowances.push(c.get_allowance(&address(owner), &address(spender)));
+            }
+        }
+        Snapshot {
+            balances: ACCOUNTS.iter().map(|name| test.balance(name)).collect(),
+            allowances,
+            frozen: ACCOUNTS.iter().map(|name| c.is_frozen(&address(name))).collect(),
+            holders: ACCOUNTS.iter().map(|name| c.get_holder_index(&address(name)) != 0).collect(),
+            paused: c.is_paused(),
+            total_supply: c.get_mut_total_supply().clone(),
+        }
+    }
+
+    fn allowance(&self, owner: &str, spender: &str) -> &MockBigUint {
+        &self.allowances[index(owner) * ACCOUNTS.len() + index(spender)]
+    }
+}
+
+fn index(name: &str) -> usize {
+    ACCOUNTS.iter().position(|account| *account == name).unwrap()
+}
+
+fn check_invariants(op: &Op, result: &Result<(), BUSDError>, before: &Snapshot, after: &Snapshot) -> Result<(), TestCaseError> {
+    // sum of balances equals total supply
+    let sum = after.balances.iter().fold(big(0), |sum, balance| &sum + balance);
+    prop_assert_eq!(&sum, &after.total_supply, "balances do not add up to the total supply");
+
+    // the holder registry lists exactly the accounts with a balance
+    for (i, name) in ACCOUNTS.iter().enumerate() {
+        prop_assert_eq!(after.holders[i], after.balances[i] != big(0), "holder registry out of sync for {}", name);
+    }
+
+    // a failed call leaves no trace
+    if result.is_err() {
+        prop_assert_eq!(before, after, "failed call changed the state");
+    }
+
+    // frozen accounts only change balance when wiped, or when the contract balance is reclaimed
+    for (i, name) in ACCOUNTS.iter().enumerate() {
+        if !before.frozen[i] || before.balances[i] == after.balances[i] {
+            continue;
+        }
+        let allowed = match *op {
+            Op::Wipe { address } => address == *name,
+            Op::Reclaim => *name == "busd_contract",
+            _ => false,
+        };
+        prop_assert!(allowed, "balance of frozen account {} changed", name);
+    }
+
+    // allowances only ever decrease by the amount spent through them
+    if let (Op::TransferFrom { spender, from, amount, .. }, Ok(())) = (op, result) {
+        let expected = before.allowance(from, spender) - &big(*amount);
+        prop_assert_eq!(after.allowance(from, spender), &expected, "allowance not decreased by the amount spent");
+    }
+    for (i, allowance) in after.allowances.iter().enumerate() {
+        let changed_by_op = match *op {
+            Op::Approve { owner, spender, .. } => i == index(owner) * ACCOUNTS.len() + index(spender),
+            Op::TransferFrom { spender, from, .. } => i == index(from) * ACCOUNTS.len() + index(spender),
+            _ => false,
+        };
+        if !changed_by_op {
+            prop_assert_eq!(allowance, &before.allowances[i], "unrelated allowance changed");
+        }
+    }
+
+    // paused state blocks every user operation
+    if before.paused && op.is_user_operation() {
+        prop_assert_eq!(result, &Err(BUSDError::Paused), "user operation not blocked while paused");
+    }
+
+    Ok(())
+}
+
+/// Snapshots taken earlier in the sequence keep reading the balances and total supply of when they were taken.
+fn check_snapshots(test: &BUSDTest, snapshots: &[Snapshot]) -> Result<(), TestCaseError> {
+    let c = &test.contract;
+    for (i, snapshot) in snapshots.iter().enumerate() {
+        let id = i as u64 + 1;
+        for (name, balance) in ACCOUNTS.iter().zip(snapshot.balances.iter()) {
+            prop_assert_eq!(&c.balance_of_at(&address(name), id).unwrap(), balance, "balance of {} at snapshot {} changed", name, id);
+        }
+        prop_assert_eq!(&c.total_supply_at(id).unwrap(), &snapshot.total_supply, "total supply at snapshot {} changed", id);
+    }
+    Ok(())
+}
+
+fn setup() -> BUSDTest {
+    let test = BUSDTest::deploy_unpaused();
+    for name in &["holder_1", "holder_2", "holder_3"] {
+        test.mint(name, 1000);
+    }
+    test
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(500))]
+
+    #[test]
+    fn test_supply_invariants(ops in prop::collection::vec(op(), 1..50)) {
+        let test = setup();
+        let mut snapshots = Vec::new();
+        for op in ops.iter() {
+            let before = Snapshot::take(&test);
+            let result = execute(&test, op);
+            let after = Snapshot::take(&test);
+            check_invariants(op, &result, &before, &after)?;
+            if let (Op::Snapshot, Ok(())) = (op, &result) {
+                snapshots.push(after);
+            }
+            check_snapshots(&test, &snapshots)?;
+        }
+    }
+}
@@ -0,0 +1,112 @@
+mod mock;
+use mock::*;
+
+use sc_busd_rs::*;
+
+fn setup() -> BUSDTest {
+    let test = BUSDTest::deploy_unpaused();
+    test.mint("holder_1", 5000);
+    test.set_block_timestamp(1_000_000);
+    test.call_as("busd_owner").set_large_transfer_threshold_endpoint(big(1000)).unwrap();
+    test.call_as("busd_owner").set_large_transfer_review_period_endpoint(3600).unwrap();
+    test.clear_logs();
+    test
+}
+
+#[test]
+fn test_large_transfer_execute() {
+    let test = setup();
+
+    test.call_as("holder_1").transfer(address("holder_2"), big(1000)).unwrap();
+    assert_eq!(test.balance("holder_2"), big(1000));
+
+    test.call_as("holder_1").transfer(address("holder_2"), big(1001)).unwrap();
+    assert_eq!(test.balance("holder_1"), big(2999));
+    assert_eq!(test.balance("holder_2"), big(1000));
+    assert_eq!(test.contract.get_pending_transfer_ids(), vec![1u64]);
+    assert_eq!(test.contract.get_mut_pending_transfers_total().clone(), big(1001));
+    assert_eq!(test.event_names(), vec!["Transfer", "LargeTransferQueued"]);
+    test.clear_logs();
+
+    test.set_block_timestamp(1_000_000 + 3599);
+    assert_eq!(test.call_as("someone_else").execute_pending_transfer(1), Err(BUSDError::ReviewPeriodNotOver));
+
+    test.set_block_timestamp(1_000_000 + 3600);
+    test.call_as("someone_else").execute_pending_transfer(1).unwrap();
+    assert_eq!(test.balance("holder_2"), big(2001));
+    assert!(test.contract.get_pending_transfer_ids().is_empty());
+    assert_eq!(test.contract.get_mut_pending_transfers_total().clone(), big(0));
+    assert_eq!(test.event_names(), vec!["LargeTransferExecuted", "Transfer"]);
+
+    assert_eq!(test.call_as("someone_else").execute_pending_transfer(1), Err(BUSDError::PendingTransferNotFound));
+}
+
+#[test]
+fn test_large_transfer_cancel() {
+    let test = setup();
+    test.call_as("holder_1").transfer(address("holder_2"), big(2000)).unwrap();
+
+    assert_eq!(
+        test.call_as("busd_owner").cancel_pending_transfer(1),
+        Err(BUSDError::OnlyAssetProtectionCanCancelTransfers));
+
+    test.call_as("asset_protection_role").cancel_pending_transfer(1).unwrap();
+    assert_eq!(test.balance("holder_1"), big(5000));
+    assert_eq!(test.balance("holder_2"), big(0));
+    assert!(!test.contract.pending_transfer_exists(1));
+}
+
+#[test]
+fn test_large_transfer_cancel_refunds_daily_limit() {
+    let test = setup();
+    test.call_as("asset_protection_role").set_daily_limit(&address("holder_1"), big(3000)).unwrap();
+    test.call_as("holder_1").transfer(address("holder_2"), big(500)).unwrap();
+    test.call_as("holder_1").transfer(address("holder_2"), big(2000)).unwrap();
+    assert_eq!(test.contract.get_daily_spent(&address("holder_1")), big(2500));
+
+    test.set_block_timestamp(1_000_000 + 60);
+    test.call_as("asset_protection_role").cancel_pending_transfer(1).unwrap();
+    assert_eq!(test.contract.get_daily_spent(&address("holder_1")), big(500));
+    assert_eq!(test.contract.remaining_daily_capacity(&address("holder_1")), Some(big(2500)));
+
+    // once the hour of the queued transfer left the window, there is nothing left to give back
+    test.call_as("holder_1").transfer(address("holder_2"), big(2000)).unwrap();
+    test.set_block_timestamp(1_000_000 + 25 * 3600);
+    test.call_as("holder_1").transfer(address("holder_2"), big(100)).unwrap();
+    test.call_as("asset_protection_role").cancel_pending_transfer(2).unwrap();
+    assert_eq!(test.contract.get_daily_spent(&address("holder_1")), big(100));
+}
+
+#[test]
+fn test_large_transfer_execute_restricted() {
+    let test = setup();
+    test.call_as("holder_1").transfer(address("holder_2"), big(2000)).unwrap();
+    test.set_block_timestamp(2_000_000);
+
+    test.call_as("asset_protection_role").freeze(&address("holder_2")).unwrap();
+    assert_eq!(test.call_as("someone_else").execute_pending_transfer(1), Err(BUSDError::AddressFrozen));
+    test.call_as("asset_protection_role").unfreeze(&address("holder_2")).unwrap();
+
+    test.call_as("busd_owner").pause().unwrap();
+    assert_eq!(test.call_as("someone_else").execute_pending_transfer(1), Err(BUSDError::Paused));
+    test.call_as("busd_owner").unpause().unwrap();
+
+    test.call_as("someone_else").execute_pending_transfer(1).unwrap();
+    assert_eq!(test.balance("holder_2"), big(2000));
+}
+
+#[test]
+fn test_large_transfer_settings() {
+    let test = setup();
+    assert_eq!(
+        test.call_as("asset_protection_role").set_large_transfer_threshold_endpoint(big(1)),
+        Err(BUSDError::OnlyOwnerCanChangeLargeTransfers));
+
+    test.call_as("busd_owner").remove_large_transfer_threshold().unwrap();
+    assert_eq!(
+        test.call_as("busd_owner").remove_large_transfer_threshold(),
+        Err(BUSDError::NoLargeTransferThreshold));
+
+    test.call_as("holder_1").transfer(address("holder_2"), big(5000)).unwrap();
+    assert_eq!(test.balance("holder_2"), big(5000));
+}
@@ -0,0 +1,193 @@
+mod mock;
+use mock::*;
+
+use elrond_wasm::{Address, MultiArg3, VarArgs};
+use sc_busd_rs::*;
+
+fn export(test: &BUSDTest, from: u64, count: u64) -> Vec<(Address, MockBigUint, bool)> {
+    test.call_as("busd_owner").export_state(from, count).unwrap().0.into_iter()
+        .map(|holder| holder.0)
+        .collect()
+}
+
+fn holders(test: &BUSDTest) -> Vec<Address> {
+    test.contract.get_holders(0, 100).0
+}
+
+fn frozen(test: &BUSDTest) -> Vec<Address> {
+    test.call_as("busd_owner").export_frozen(0, 100).unwrap().0
+}
+
+fn holder_entry(name: &str, balance: u64, frozen: bool) -> MultiArg3<Address, MockBigUint, bool> {
+    MultiArg3((address(name), big(balance), frozen))
+}
+
+#[test]
+fn test_holder_registry() {
+    let test = BUSDTest::deploy_unpaused();
+    assert!(holders(&test).is_empty());
+
+    test.mint("holder_1", 5000);
+    test.call_as("holder_1").transfer(address("holder_2"), big(100)).unwrap();
+    test.call_as("holder_1").transfer(address("someone_else"), big(100)).unwrap();
+    assert_eq!(holders(&test), vec![address("holder_1"), address("holder_2"), address("someone_else")]);
+
+    // an emptied balance is unregistered, the last holder takes its place
+    test.call_as("holder_1").transfer(address("holder_2"), big(4800)).unwrap();
+    assert_eq!(holders(&test), vec![address("someone_else"), address("holder_2")]);
+
+    test.call_as("asset_protection_role").freeze(&address("holder_2")).unwrap();
+    test.call_as("asset_protection_role").wipe_frozen_address(&address("holder_2")).unwrap();
+    assert_eq!(holders(&test), vec![address("someone_else")]);
+}
+
+#[test]
+fn test_frozen_registry() {
+    let test = BUSDTest::deploy_unpaused();
+    test.mint("holder_1", 5000);
+    for name in &["holder_1", "holder_2", "holder_3"] {
+        test.call_as("asset_protection_role").freeze(&address(name)).unwrap();
+    }
+    assert_eq!(test.contract.get_frozen_len(), 3);
+
+    // a wiped address stays registered while it is frozen
+    test.call_as("asset_protection_role").wipe_frozen_address(&address("holder_1")).unwrap();
+    assert!(holders(&test).is_empty());
+    assert_eq!(frozen(&test), vec![address("holder_1"), address("holder_2"), address("holder_3")]);
+
+    // an unfrozen address is unregistered, the last one takes its place
+    test.call_as("asset_protection_role").unfreeze(&address("holder_2")).unwrap();
+    assert_eq!(frozen(&test), vec![address("holder_1"), address("holder_3")]);
+}
+
+#[test]
+fn test_holder_views() {
+    let test = BUSDTest::deploy_unpaused();
+    assert_eq!(test.contract.get_holders_len(), 0);
+    assert!(test.contract.get_holders(0, 10).0.is_empty());
+
+    for name in &["holder_1", "holder_2", "holder_3"] {
+        test.mint(name, 1000);
+    }
+    assert_eq!(test.contract.get_holders_len(), 3);
+    assert_eq!(test.contract.get_holders(0, 2).0, vec![address("holder_1"), address("holder_2")]);
+    assert_eq!(test.contract.get_holders(2, 2).0, vec![address("holder_3")]);
+    assert!(test.contract.get_holders(3, 2).0.is_empty());
+    assert_eq!(test.contract.get_holders(1, u64::MAX).0, vec![address("holder_2"), address("holder_3")]);
+
+    // burning the whole balance of the supply controller unregisters it
+    test.mint("busd_owner", 10);
+    assert_eq!(test.contract.get_holders_len(), 4);
+    test.call_as("busd_owner").decrease_supply(big(10)).unwrap();
+    assert_eq!(test.contract.get_holders_len(), 3);
+}
+
+#[test]
+fn test_export_state() {
+    let test = BUSDTest::deploy_unpaused();
+    test.mint("holder_1", 5000);
+    test.call_as("holder_1").transfer(address("holder_2"), big(100)).unwrap();
+    test.call_as("holder_1").approve(address("spender"), big(10)).unwrap();
+    test.call_as("asset_protection_role").freeze(&address("holder_2")).unwrap();
+
+    assert_eq!(test.call_as("holder_1").export_state(0, 10).err(), Some(BUSDError::OnlyOwnerCanExport));
+    assert_eq!(export(&test, 0, 1), vec![(address("holder_1"), big(4900), false)]);
+    assert_eq!(export(&test, 1, 10), vec![(address("holder_2"), big(100), true)]);
+    assert!(export(&test, 2, 10).is_empty());
+
+    // a wiped address is only left in the frozen addresses
+    test.call_as("asset_protection_role").freeze(&address("someone_else")).unwrap();
+    assert_eq!(test.call_as("holder_1").export_frozen(0, 10).err(), Some(BUSDError::OnlyOwnerCanExport));
+    assert_eq!(test.call_as("busd_owner").export_frozen(1, 10).unwrap().0, vec![address("someone_else")]);
+    assert_eq!(frozen(&test), vec![address("holder_2"), address("someone_else")]);
+
+    let allowances: Vec<(Address, MockBigUint)> = test.call_as("busd_owner").export_allowances(&address("holder_1")).unwrap().0
+        .into_iter()
+        .map(|allowance| allowance.0)
+        .collect();
+    assert_eq!(allowances, vec![(address("spender"), big(10))]);
+
+    // a spender is no longer listed once its allowance is reset
+    test.call_as("holder_1").approve(address("spender"), big(0)).unwrap();
+    assert!(test.call_as("busd_owner").export_allowances(&address("holder_1")).unwrap().0.is_empty());
+}
+
+#[test]
+fn test_register_holders() {
+    let test = BUSDTest::deploy_unpaused();
+    test.mint("holder_1", 5000);
+    test.call_as("holder_1").approve(address("spender"), big(10)).unwrap();
+    test.call_as("asset_protection_role").freeze(&address("holder_3")).unwrap();
+    // a contract deployed before the registries
+    test.api.state.borrow_mut().storage
+        .retain(|key, _| !key.starts_with(b"holder") && !key.starts_with(b"frozen_") && !key.starts_with(b"spenders"));
+    assert!(holders(&test).is_empty());
+    assert!(frozen(&test).is_empty());
+
+    let addresses = || VarArgs(vec![address("holder_1"), address("holder_2"), address("holder_3")]);
+    assert_eq!(test.call_as("holder_1").register_holders(addresses()), Err(BUSDError::OnlyOwnerCanRegisterHolders));
+    test.call_as("busd_owner").register_holders(addresses()).unwrap();
+    // registering again changes nothing, and addresses neither holding a balance nor frozen are skipped
+    test.call_as("busd_owner").register_holders(addresses()).unwrap();
+    assert_eq!(holders(&test), vec![address("holder_1")]);
+    assert_eq!(frozen(&test), vec![address("holder_3")]);
+
+    test.call_as("busd_owner").register_spenders(address("holder_1"), VarArgs(vec![address("spender"), address("holder_2")])).unwrap();
+    assert_eq!(test.call_as("busd_owner").export_allowances(&address("holder_1")).unwrap().0.len(), 1);
+}
+
+#[test]
+fn test_import_state() {
+    let test = BUSDTest::deploy();
+    assert_eq!(test.call_as("holder_1").begin_import(big(5000)), Err(BUSDError::OnlyOwnerCanImport));
+    assert_eq!(
+        test.call_as("busd_owner").import_state(VarArgs(vec![])),
+        Err(BUSDError::NoImportInProgress));
+    test.call_as("busd_owner").begin_import(big(5000)).unwrap();
+    assert_eq!(test.call_as("busd_owner").begin_import(big(5000)), Err(BUSDError::ImportRequiresFreshContract));
+
+    test.call_as("busd_owner").import_state(VarArgs(vec![holder_entry("holder_1", 4900, false)])).unwrap();
+    test.call_as("busd_owner").import_allowances(VarArgs(vec![
+        MultiArg3((address("holder_1"), address("spender"), big(10))),
+    ])).unwrap();
+    assert_eq!(test.contract.get_allowance(&address("holder_1"), &address("spender")), big(10));
+
+    // unpausing waits for the imported balances to add up to the declared total supply
+    assert_eq!(test.call_as("busd_owner").unpause(), Err(BUSDError::ImportIncomplete));
+    assert_eq!(
+        test.execute("busd_owner", |c| c.import_state(VarArgs(vec![holder_entry("holder_2", 101, true)]))),
+        Err(BUSDError::ImportExceedsTotalSupply));
+    test.clear_logs();
+    test.call_as("busd_owner").import_state(VarArgs(vec![holder_entry("holder_2", 100, true)])).unwrap();
+    assert_eq!(test.event_names(), vec!["SupplyIncreased", "Transfer", "AddressFrozen"]);
+    assert!(test.contract.is_frozen(&address("holder_2")));
+
+    // frozen addresses without a balance come from `exportFrozen`, those already imported are skipped
+    test.clear_logs();
+    assert_eq!(
+        test.call_as("holder_1").import_frozen(VarArgs(vec![address("holder_3")])),
+        Err(BUSDError::OnlyOwnerCanImport));
+    test.call_as("busd_owner").import_frozen(VarArgs(vec![address("holder_2"), address("holder_3")])).unwrap();
+    assert_eq!(test.event_names(), vec!["AddressFrozen"]);
+    assert_eq!(frozen(&test), vec![address("holder_2"), address("holder_3")]);
+
+    test.call_as("busd_owner").unpause().unwrap();
+    assert_eq!(test.balance("holder_1"), big(4900));
+    assert_eq!(test.contract.get_mut_total_supply().clone(), big(5000));
+    assert_eq!(
+        test.call_as("busd_owner").import_state(VarArgs(vec![holder_entry("holder_1", 1, false)])),
+        Err(BUSDError::NoImportInProgress));
+    assert_eq!(
+        test.call_as("busd_owner").import_frozen(VarArgs(vec![address("holder_1")])),
+        Err(BUSDError::NoImportInProgress));
+}
+
+#[test]
+fn test_import_requires_fresh_contract() {
+    let test = BUSDTest::deploy_unpaused();
+    assert_eq!(test.call_as("busd_owner").begin_import(big(5000)), Err(BUSDError::ImportRequiresFreshContract));
+
+    test.mint("holder_1", 5000);
+    test.call_as("busd_owner").pause().unwrap();
+    assert_eq!(test.call_as("busd_owner").begin_import(big(5000)), Err(BUSDError::ImportRequiresFreshContract));
+}
@@ -0,0 +1,146 @@
+use core::cmp::Ordering;
+use core::ops::{Add, Sub, Mul, Div, Rem, Neg};
+use core::ops::{AddAssign, SubAssign, MulAssign, DivAssign, RemAssign};
+
+use elrond_wasm::{BigIntApi, Sign};
+use elrond_wasm::esd_light::*;
+use num_bigint::BigInt;
+use num_traits::{Signed, Zero};
+
+use super::MockBigUint;
+
+/// BigInt implementation for the mock, backed by `num_bigint`.
+/// The contract does not use signed integers, but the api requires the type.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct MockBigInt(pub BigInt);
+
+impl From<MockBigUint> for MockBigInt {
+    fn from(item: MockBigUint) -> Self {
+        MockBigInt(BigInt::from(item.value().clone()))
+    }
+}
+
+impl From<i64> for MockBigInt {
+    fn from(item: i64) -> Self {
+        MockBigInt(BigInt::from(item))
+    }
+}
+
+impl From<i32> for MockBigInt {
+    fn from(item: i32) -> Self {
+        MockBigInt(BigInt::from(item))
+    }
+}
+
+macro_rules! binary_operator {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident) => {
+        impl $trait<MockBigInt> for MockBigInt {
+            type Output = MockBigInt;
+
+            fn $method(self, other: MockBigInt) -> MockBigInt {
+                MockBigInt((self.0).$method(other.0))
+            }
+        }
+
+        impl<'a, 'b> $trait<&'b MockBigInt> for &'a MockBigInt {
+            type Output = MockBigInt;
+
+            fn $method(self, other: &MockBigInt) -> MockBigInt {
+                MockBigInt((&self.0).$method(&other.0))
+            }
+        }
+
+        impl $assign_trait<MockBigInt> for MockBigInt {
+            fn $assign_method(&mut self, other: MockBigInt) {
+                *self = (&*self).$method(&other);
+            }
+        }
+
+        impl<'b> $assign_trait<&'b MockBigInt> for MockBigInt {
+            fn $assign_method(&mut self, other: &MockBigInt) {
+                *self = (&*self).$method(other);
+            }
+        }
+    }
+}
+
+binary_operator!{Add, add, AddAssign, add_assign}
+binary_operator!{Sub, sub, SubAssign, sub_assign}
+binary_operator!{Mul, mul, MulAssign, mul_assign}
+binary_operator!{Div, div, DivAssign, div_assign}
+binary_operator!{Rem, rem, RemAssign, rem_assign}
+
+impl Neg for MockBigInt {
+    type Output = MockBigInt;
+
+    fn neg(self) -> MockBigInt {
+        MockBigInt(-self.0)
+    }
+}
+
+impl PartialEq<i64> for MockBigInt {
+    fn eq(&self, other: &i64) -> bool {
+        self.0 == BigInt::from(*other)
+    }
+}
+
+impl PartialOrd<i64> for MockBigInt {
+    fn partial_cmp(&self, other: &i64) -> Option<Ordering> {
+        self.0.partial_cmp(&BigInt::from(*other))
+    }
+}
+
+impl Encode for MockBigInt {
+    const TYPE_INFO: TypeInfo = TypeInfo::BigInt;
+
+    fn using_top_encoded<F: FnOnce(&[u8])>(&self, f: F) {
+        let bytes = self.to_signed_bytes_be();
+        f(&bytes);
+    }
+
+    fn dep_encode_to<O: Output>(&self, dest: &mut O) {
+        let bytes = self.to_signed_bytes_be();
+        bytes.as_slice().dep_encode_to(dest);
+    }
+}
+
+impl Decode for MockBigInt {
+    const TYPE_INFO: TypeInfo = TypeInfo::BigInt;
+
+    fn top_decode<I: Input>(input: &mut I) -> Result<Self, DecodeError> {
+        let bytes = input.flush()?;
+        Ok(MockBigInt::from_signed_bytes_be(bytes))
+    }
+
+    fn dep_decode<I: Input>(input: &mut I) -> Result<Self, DecodeError> {
+        let size = usize::dep_decode(input)?;
+        let bytes = input.read_slice(size)?;
+        Ok(MockBigInt::from_signed_bytes_be(bytes))
+    }
+}
+
+impl BigIntApi<MockBigUint> for MockBigInt {
+    fn abs_uint(&self) -> MockBigUint {
+        MockBigUint::new(self.0.abs().to_biguint().unwrap())
+    }
+
+    fn sign(&self) -> Sign {
+        match self.0.sign() {
+            num_bigint::Sign::Minus => Sign::Minus,
+            num_bigint::Sign::NoSign => Sign::NoSign,
+            num_bigint::Sign::Plus => Sign::Plus,
+        }
+    }
+
+    fn to_signed_bytes_be(&self) -> Vec<u8> {
+        if self.0.is_zero() {
+            Vec::new()
+        } else {
+            self.0.to_signed_bytes_be()
+        }
+    }
+
+    fn from_signed_bytes_be(bytes: &[u8]) -> Self {
+        MockBigInt(BigInt::from_signed_bytes_be(bytes))
+    }
+}
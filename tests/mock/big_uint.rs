@@ -0,0 +1,239 @@
+use core::cmp::Ordering;
+use core::mem::ManuallyDrop;
+use core::ops::{Add, Sub, Mul, Div, Rem};
+use core::ops::{AddAssign, SubAssign, MulAssign, DivAssign, RemAssign};
+use core::ops::{BitAnd, BitOr, BitXor, Shr, Shl};
+use core::ops::{BitAndAssign, BitOrAssign, BitXorAssign, ShrAssign, ShlAssign};
+
+use elrond_wasm::BigUintApi;
+use elrond_wasm::esd_light::*;
+use num_bigint::BigUint;
+use num_traits::Zero;
+
+/// BigUint implementation for the mock, backed by `num_bigint`.
+///
+/// The framework loads BigUint storage with a `transmute_copy` and drops the original,
+/// which is harmless for Arwen handles but would free the same buffer twice here.
+/// The value is never dropped, so tests leak a little memory instead.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MockBigUint(ManuallyDrop<BigUint>);
+
+impl MockBigUint {
+    pub fn new(value: BigUint) -> Self {
+        MockBigUint(ManuallyDrop::new(value))
+    }
+
+    pub fn value(&self) -> &BigUint {
+        &self.0
+    }
+}
+
+impl From<u64> for MockBigUint {
+    fn from(item: u64) -> Self {
+        MockBigUint::new(BigUint::from(item))
+    }
+}
+
+impl From<u32> for MockBigUint {
+    fn from(item: u32) -> Self {
+        MockBigUint::new(BigUint::from(item))
+    }
+}
+
+impl From<usize> for MockBigUint {
+    fn from(item: usize) -> Self {
+        MockBigUint::new(BigUint::from(item))
+    }
+}
+
+impl From<BigUint> for MockBigUint {
+    fn from(item: BigUint) -> Self {
+        MockBigUint::new(item)
+    }
+}
+
+macro_rules! binary_operator {
+    ($trait:ident, $method:ident) => {
+        impl $trait<MockBigUint> for MockBigUint {
+            type Output = MockBigUint;
+
+            fn $method(self, other: MockBigUint) -> MockBigUint {
+                MockBigUint::new((&*self.0).$method(&*other.0))
+            }
+        }
+
+        impl<'a, 'b> $trait<&'b MockBigUint> for &'a MockBigUint {
+            type Output = MockBigUint;
+
+            fn $method(self, other: &MockBigUint) -> MockBigUint {
+                MockBigUint::new((&*self.0).$method(&*other.0))
+            }
+        }
+    }
+}
+
+binary_operator!{Add, add}
+binary_operator!{Mul, mul}
+binary_operator!{Div, div}
+binary_operator!{Rem, rem}
+binary_operator!{BitAnd, bitand}
+binary_operator!{BitOr, bitor}
+binary_operator!{BitXor, bitxor}
+
+// subtraction signals an error on underflow, same as the Arwen implementation
+impl Sub<MockBigUint> for MockBigUint {
+    type Output = MockBigUint;
+
+    fn sub(self, other: MockBigUint) -> MockBigUint {
+        &self - &other
+    }
+}
+
+impl Sub<&MockBigUint> for &MockBigUint {
+    type Output = MockBigUint;
+
+    fn sub(self, other: &MockBigUint) -> MockBigUint {
+        if self.0 < other.0 {
+            panic!("cannot subtract because result would be negative");
+        }
+        MockBigUint::new(&*self.0 - &*other.0)
+    }
+}
+
+macro_rules! binary_assign_operator {
+    ($trait:ident, $method:ident, $op_trait:ident, $op_method:ident) => {
+        impl $trait<MockBigUint> for MockBigUint {
+            fn $method(&mut self, other: MockBigUint) {
+                *self = (&*self).$op_method(&other);
+            }
+        }
+
+        impl<'b> $trait<&'b MockBigUint> for MockBigUint {
+            fn $method(&mut self, other: &MockBigUint) {
+                *self = (&*self).$op_method(other);
+            }
+        }
+    }
+}
+
+binary_assign_operator!{AddAssign, add_assign, Add, add}
+binary_assign_operator!{SubAssign, sub_assign, Sub, sub}
+binary_assign_operator!{MulAssign, mul_assign, Mul, mul}
+binary_assign_operator!{DivAssign, div_assign, Div, div}
+binary_assign_operator!{RemAssign, rem_assign, Rem, rem}
+binary_assign_operator!{BitAndAssign, bitand_assign, BitAnd, bitand}
+binary_assign_operator!{BitOrAssign, bitor_assign, BitOr, bitor}
+binary_assign_operator!{BitXorAssign, bitxor_assign, BitXor, bitxor}
+
+macro_rules! shift_operator {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident) => {
+        impl $trait<usize> for MockBigUint {
+            type Output = MockBigUint;
+
+            fn $method(self, rhs: usize) -> MockBigUint {
+                MockBigUint::new((&*self.0).$method(rhs))
+            }
+        }
+
+        impl<'a> $trait<usize> for &'a MockBigUint {
+            type Output = MockBigUint;
+
+            fn $method(self, rhs: usize) -> MockBigUint {
+                MockBigUint::new((&*self.0).$method(rhs))
+            }
+        }
+
+        impl $assign_trait<usize> for MockBigUint {
+            fn $assign_method(&mut self, rhs: usize) {
+                *self = (&*self).$method(rhs);
+            }
+        }
+    }
+}
+
+shift_operator!{Shr, shr, ShrAssign, shr_assign}
+shift_operator!{Shl, shl, ShlAssign, shl_assign}
+
+impl PartialEq<u64> for MockBigUint {
+    fn eq(&self, other: &u64) -> bool {
+        *self.0 == BigUint::from(*other)
+    }
+}
+
+impl PartialOrd<u64> for MockBigUint {
+    fn partial_cmp(&self, other: &u64) -> Option<Ordering> {
+        (*self.0).partial_cmp(&BigUint::from(*other))
+    }
+}
+
+impl Encode for MockBigUint {
+    const TYPE_INFO: TypeInfo = TypeInfo::BigUint;
+
+    fn using_top_encoded<F: FnOnce(&[u8])>(&self, f: F) {
+        let bytes = self.to_bytes_be();
+        f(&bytes);
+    }
+
+    fn dep_encode_to<O: Output>(&self, dest: &mut O) {
+        let bytes = self.to_bytes_be();
+        bytes.as_slice().dep_encode_to(dest);
+    }
+}
+
+impl Decode for MockBigUint {
+    const TYPE_INFO: TypeInfo = TypeInfo::BigUint;
+
+    fn top_decode<I: Input>(input: &mut I) -> Result<Self, DecodeError> {
+        let bytes = input.flush()?;
+        Ok(MockBigUint::from_bytes_be(bytes))
+    }
+
+    fn dep_decode<I: Input>(input: &mut I) -> Result<Self, DecodeError> {
+        let size = usize::dep_decode(input)?;
+        let bytes = input.read_slice(size)?;
+        Ok(MockBigUint::from_bytes_be(bytes))
+    }
+}
+
+impl BigUintApi for MockBigUint {
+    fn byte_length(&self) -> i32 {
+        self.to_bytes_be().len() as i32
+    }
+
+    fn copy_to_slice_big_endian(&self, slice: &mut [u8]) -> i32 {
+        let bytes = self.to_bytes_be();
+        slice[..bytes.len()].copy_from_slice(&bytes);
+        bytes.len() as i32
+    }
+
+    fn copy_to_array_big_endian_pad_right(&self, target: &mut [u8; 32]) {
+        let bytes = self.to_bytes_be();
+        if bytes.len() > 32 {
+            panic!("big uint exceeds 32 bytes");
+        }
+        target[32 - bytes.len()..].copy_from_slice(&bytes);
+    }
+
+    /// Zero is encoded as an empty byte array, same as on Arwen.
+    fn to_bytes_be(&self) -> Vec<u8> {
+        if self.0.is_zero() {
+            Vec::new()
+        } else {
+            self.0.to_bytes_be()
+        }
+    }
+
+    fn to_bytes_be_pad_right(&self, nr_bytes: usize) -> Option<Vec<u8>> {
+        let bytes = self.to_bytes_be();
+        if bytes.len() > nr_bytes {
+            return None;
+        }
+        let mut result = vec![0u8; nr_bytes];
+        result[nr_bytes - bytes.len()..].copy_from_slice(&bytes);
+        Some(result)
+    }
+
+    fn from_bytes_be(bytes: &[u8]) -> Self {
+        MockBigUint::new(BigUint::from_bytes_be(bytes))
+    }
+}
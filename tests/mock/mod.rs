@@ -0,0 +1,447 @@
+//! In-memory mock of the elrond-wasm API.
+//!
+//! The contract methods are called directly on `BUSDCoinImpl`,
+//! so arguments and results never go through the serialization layer.
+//! The mock only needs to provide storage, caller, block info and logs,
+//! and to record the async calls made through contract proxies.
+
+#![allow(dead_code)]
+
+mod big_int;
+mod big_uint;
+
+pub use big_int::MockBigInt;
+pub use big_uint::MockBigUint;
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use elrond_wasm::{Address, H256, ContractHookApi, ContractIOApi, BigUintApi, BigIntApi, OptionalArg};
+use elrond_wasm::{AsyncCallError, AsyncCallResult};
+use sc_busd_rs::*;
+
+/// A log entry, as written by the contract events.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MockLog {
+    pub identifier: [u8; 32],
+    pub topics: Vec<[u8; 32]>,
+    pub data: Vec<u8>,
+}
+
+impl MockLog {
+    /// The name of a named event, e.g. `Transfer`.
+    pub fn event_name(&self) -> String {
+        let name: Vec<u8> = self.identifier.iter().cloned().take_while(|b| *b != 0).collect();
+        assert!(!name.is_empty() && self.identifier[name.len()..].iter().all(|b| *b == 0), "not a named BUSD event");
+        String::from_utf8(name).unwrap()
+    }
+
+    /// The identifier of a legacy event, as the small number used in its `#[event(...)]` declaration.
+    pub fn event_id(&self) -> u8 {
+        assert!(self.identifier[..31].iter().all(|b| *b == 0), "not a legacy BUSD event id");
+        self.identifier[31]
+    }
+
+    pub fn topic_address(&self, index: usize) -> Address {
+        Address::from(self.topics[index])
+    }
+
+    pub fn data_big_uint(&self) -> MockBigUint {
+        MockBigUint::from_bytes_be(self.data.as_slice())
+    }
+}
+
+/// An async call made by the contract, recorded instead of being executed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MockAsyncCall {
+    pub to: Address,
+    pub amount: MockBigUint,
+    pub data: Vec<u8>,
+}
+
+#[derive(Default)]
+pub struct MockState {
+    pub storage: HashMap<Vec<u8>, Vec<u8>>,
+    pub sc_address: [u8; 32],
+    pub owner_address: [u8; 32],
+    pub caller: [u8; 32],
+    pub block_timestamp: u64,
+    pub block_nonce: u64,
+    pub block_round: u64,
+    pub block_epoch: u64,
+    pub tx_hash: [u8; 32],
+    pub logs: Vec<MockLog>,
+    pub async_calls: Vec<MockAsyncCall>,
+}
+
+/// Cheap to clone, all clones share the same state.
+#[derive(Clone, Default)]
+pub struct MockApi {
+    pub state: Rc<RefCell<MockState>>,
+}
+
+impl ContractHookApi<MockBigInt, MockBigUint> for MockApi {
+    fn get_sc_address(&self) -> Address {
+        self.state.borrow().sc_address.into()
+    }
+
+    fn get_owner_address(&self) -> Address {
+        self.state.borrow().owner_address.into()
+    }
+
+    fn get_caller(&self) -> Address {
+        self.state.borrow().caller.into()
+    }
+
+    fn get_balance(&self, _address: &Address) -> MockBigUint {
+        panic!("EGLD balances are not handled by the mock")
+    }
+
+    /// Same as on Arwen, storing an empty value erases the key.
+    fn storage_store(&self, key: &[u8], value: &[u8]) {
+        let mut state = self.state.borrow_mut();
+        if value.is_empty() {
+            state.storage.remove(key);
+        } else {
+            state.storage.insert(key.to_vec(), value.to_vec());
+        }
+    }
+
+    fn storage_load(&self, key: &[u8]) -> Vec<u8> {
+        self.state.borrow().storage.get(key).cloned().unwrap_or_default()
+    }
+
+    fn storage_load_len(&self, key: &[u8]) -> usize {
+        self.storage_load(key).len()
+    }
+
+    fn storage_store_bytes32(&self, key: &[u8], value: &[u8; 32]) {
+        self.storage_store(key, &value[..]);
+    }
+
+    fn storage_load_bytes32(&self, key: &[u8]) -> [u8; 32] {
+        let value = self.storage_load(key);
+        let mut result = [0u8; 32];
+        if !value.is_empty() {
+            result.copy_from_slice(value.as_slice());
+        }
+        result
+    }
+
+    fn storage_store_big_uint(&self, key: &[u8], value: &MockBigUint) {
+        self.storage_store(key, value.to_bytes_be().as_slice());
+    }
+
+    fn storage_load_big_uint(&self, key: &[u8]) -> MockBigUint {
+        MockBigUint::from_bytes_be(self.storage_load(key).as_slice())
+    }
+
+    fn storage_store_big_int(&self, key: &[u8], value: &MockBigInt) {
+        self.storage_store(key, value.to_signed_bytes_be().as_slice());
+    }
+
+    fn storage_load_big_int(&self, key: &[u8]) -> MockBigInt {
+        MockBigInt::from_signed_bytes_be(self.storage_load(key).as_slice())
+    }
+
+    fn storage_store_i64(&self, key: &[u8], value: i64) {
+        self.storage_store_big_int(key, &MockBigInt::from(value));
+    }
+
+    fn storage_load_i64(&self, key: &[u8]) -> Option<i64> {
+        let bytes = self.storage_load(key);
+        if bytes.len() > 8 {
+            return None;
+        }
+        Some(elrond_wasm::esd_light::bytes_to_number(bytes.as_slice(), true) as i64)
+    }
+
+    fn get_call_value_big_uint(&self) -> MockBigUint {
+        MockBigUint::zero()
+    }
+
+    fn send_tx(&self, _to: &Address, _amount: &MockBigUint, _message: &str) {
+        panic!("EGLD transfers are not handled by the mock")
+    }
+
+    fn async_call(&self, to: &Address, amount: &MockBigUint, data: &[u8]) {
+        self.state.borrow_mut().async_calls.push(MockAsyncCall {
+            to: to.clone(),
+            amount: amount.clone(),
+            data: data.to_vec(),
+        });
+    }
+
+    fn get_tx_hash(&self) -> H256 {
+        self.state.borrow().tx_hash.into()
+    }
+
+    fn get_gas_left(&self) -> i64 {
+        i64::MAX
+    }
+
+    fn get_block_timestamp(&self) -> u64 {
+        self.state.borrow().block_timestamp
+    }
+
+    fn get_block_nonce(&self) -> u64 {
+        self.state.borrow().block_nonce
+    }
+
+    fn get_block_round(&self) -> u64 {
+        self.state.borrow().block_round
+    }
+
+    fn get_block_epoch(&self) -> u64 {
+        self.state.borrow().block_epoch
+    }
+
+    fn sha256(&self, _data: &[u8]) -> [u8; 32] {
+        panic!("hashing is not handled by the mock")
+    }
+
+    fn keccak256(&self, _data: &[u8]) -> [u8; 32] {
+        panic!("hashing is not handled by the mock")
+    }
+}
+
+/// Contract methods are called directly, so none of the argument loading and result finishing is ever used.
+impl ContractIOApi<MockBigInt, MockBigUint> for MockApi {
+    fn get_num_arguments(&self) -> i32 {
+        0
+    }
+
+    fn check_not_payable(&self) {}
+
+    fn get_argument_len(&self, _arg_index: i32) -> usize {
+        unreachable!()
+    }
+
+    fn copy_argument_to_slice(&self, _arg_index: i32, _slice: &mut [u8]) {
+        unreachable!()
+    }
+
+    fn get_argument_vec(&self, _arg_index: i32) -> Vec<u8> {
+        unreachable!()
+    }
+
+    fn get_argument_bytes32(&self, _arg_index: i32) -> [u8; 32] {
+        unreachable!()
+    }
+
+    fn get_argument_big_int(&self, _arg_id: i32) -> MockBigInt {
+        unreachable!()
+    }
+
+    fn get_argument_big_uint(&self, _arg_id: i32) -> MockBigUint {
+        unreachable!()
+    }
+
+    fn get_argument_i64(&self, _arg_id: i32) -> i64 {
+        unreachable!()
+    }
+
+    fn finish_slice_u8(&self, _slice: &[u8]) {
+        unreachable!()
+    }
+
+    fn finish_bytes32(&self, _bytes: &[u8; 32]) {
+        unreachable!()
+    }
+
+    fn finish_big_int(&self, _b: &MockBigInt) {
+        unreachable!()
+    }
+
+    fn finish_big_uint(&self, _b: &MockBigUint) {
+        unreachable!()
+    }
+
+    fn finish_i64(&self, _value: i64) {
+        unreachable!()
+    }
+
+    fn signal_error(&self, message: &[u8]) -> ! {
+        panic!("signal error: {}", String::from_utf8_lossy(message))
+    }
+
+    fn write_log(&self, topics: &[[u8; 32]], data: &[u8]) {
+        self.state.borrow_mut().logs.push(MockLog {
+            identifier: topics[0],
+            topics: topics[1..].to_vec(),
+            data: data.to_vec(),
+        });
+    }
+}
+
+pub type MockBUSD = BUSDCoinImpl<MockApi, MockBigInt, MockBigUint>;
+
+/// Builds readable 32-byte addresses, padded the same way as in the Mandos scenarios.
+pub fn address(name: &str) -> Address {
+    assert!(name.len() <= 30, "address name too long");
+    let mut bytes = [b'_'; 32];
+    bytes[..name.len()].copy_from_slice(name.as_bytes());
+    bytes[30] = b's';
+    bytes[31] = b'1';
+    bytes.into()
+}
+
+pub fn big(value: u64) -> MockBigUint {
+    MockBigUint::from(value)
+}
+
+/// Hex encodes the bytes the way the async call data does.
+pub fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// The result of an `ESDTTransfer` that failed, as passed to its callback.
+pub fn failed_transfer() -> AsyncCallResult<()> {
+    AsyncCallResult::Err(AsyncCallError { err_code: 4, err_msg: b"insufficient funds".to_vec() })
+}
+
+/// A deployed contract, together with a handle to the mocked blockchain.
+pub struct BUSDTest {
+    pub api: MockApi,
+    pub contract: MockBUSD,
+}
+
+impl BUSDTest {
+    /// Deploys the contract from the `busd_owner` address.
+    pub fn deploy() -> Self {
+        let test = BUSDTest::deploy_with_logs();
+        test.clear_logs();
+        test
+    }
+
+    /// Deploys the contract like `deploy`, keeping the logs of the constructor.
+    pub fn deploy_with_logs() -> Self {
+        let test = BUSDTest::new();
+        test.init(b"Binance USD", b"BUSD", 18).unwrap();
+        test
+    }
+
+    /// Calls the constructor with the token metadata only, leaving out the optional role arguments.
+    pub fn init(&self, name: &[u8], symbol: &[u8], decimals: u32) -> Result<(), BUSDError> {
+        self.contract.init(name.to_vec(), symbol.to_vec(), decimals,
+            OptionalArg::None, OptionalArg::None, OptionalArg::None, OptionalArg::None, OptionalArg::None, OptionalArg::None)
+    }
+
+    /// Deploys the contract like `deploy`, then leaves only the storage of a v0.3.0 deployment,
+    /// which had no token metadata nor storage version.
+    pub fn deploy_v0() -> Self {
+        let test = BUSDTest::deploy();
+        {
+            let mut state = test.api.state.borrow_mut();
+            for key in [&b"name"[..], b"symbol", b"decimals", b"schema_version"].iter() {
+                state.storage.remove(*key);
+            }
+        }
+        test
+    }
+
+    /// The contract before its constructor runs, with `busd_owner` as caller.
+    pub fn new() -> Self {
+        let api = MockApi::default();
+        {
+            let mut state = api.state.borrow_mut();
+            state.sc_address = address("busd_contract").into();
+            state.owner_address = address("busd_owner").into();
+        }
+        let test = BUSDTest {
+            contract: BUSDCoinImpl::new(api.clone()),
+            api,
+        };
+        test.set_caller(&address("busd_owner"));
+        test
+    }
+
+    /// Deploys the contract, unpauses it and sets `asset_protection_role`.
+    pub fn deploy_unpaused() -> Self {
+        let test = BUSDTest::deploy();
+        test.call_as("busd_owner").unpause().unwrap();
+        test.contract.set_asset_protection_role_endpoint(&address("asset_protection_role")).unwrap();
+        test.clear_logs();
+        test
+    }
+
+    pub fn set_caller(&self, caller: &Address) {
+        self.api.state.borrow_mut().caller = caller.clone().into();
+    }
+
+    /// Sets the caller for the next contract call, chainable: `test.call_as("holder_1").transfer(...)`.
+    pub fn call_as(&self, name: &str) -> &MockBUSD {
+        self.set_caller(&address(name));
+        &self.contract
+    }
+
+    /// Runs a call as a transaction: on error, storage and logs are rolled back, same as on Arwen.
+    pub fn execute<F>(&self, caller: &str, call: F) -> Result<(), BUSDError>
+    where
+        F: FnOnce(&MockBUSD) -> Result<(), BUSDError>,
+    {
+        let (storage, logs_len) = {
+            let state = self.api.state.borrow();
+            (state.storage.clone(), state.logs.len())
+        };
+        let result = call(self.call_as(caller));
+        if result.is_err() {
+            let mut state = self.api.state.borrow_mut();
+            state.storage = storage;
+            state.logs.truncate(logs_len);
+        }
+        result
+    }
+
+    pub fn set_block_timestamp(&self, timestamp: u64) {
+        self.api.state.borrow_mut().block_timestamp = timestamp;
+    }
+
+    /// Mints tokens to an address, through the supply controller.
+    pub fn mint(&self, to: &str, amount: u64) {
+        let supply_controller = self.contract.get_supply_controller();
+        self.set_caller(&supply_controller);
+        self.contract.increase_supply(big(amount)).unwrap();
+        self.contract.transfer(address(to), big(amount)).unwrap();
+        self.clear_logs();
+    }
+
+    pub fn balance(&self, name: &str) -> MockBigUint {
+        self.contract.balance_of(&address(name))
+    }
+
+    pub fn logs(&self) -> Vec<MockLog> {
+        self.api.state.borrow().logs.clone()
+    }
+
+    pub fn clear_logs(&self) {
+        self.api.state.borrow_mut().logs.clear();
+    }
+
+    /// Names of the events emitted since the logs were last cleared.
+    pub fn event_names(&self) -> Vec<String> {
+        self.logs().iter().map(|log| log.event_name()).collect()
+    }
+
+    /// Ids of the legacy events emitted since the logs were last cleared, skipping the named ones.
+    pub fn legacy_event_ids(&self) -> Vec<u8> {
+        self.logs().iter()
+            .filter(|log| log.identifier[0] == 0)
+            .map(|log| log.event_id())
+            .collect()
+    }
+
+    /// Takes the only async call made by the contract, and the callback it registered.
+    pub fn take_async_call(&self) -> (MockAsyncCall, String) {
+        let mut state = self.api.state.borrow_mut();
+        let calls: Vec<MockAsyncCall> = state.async_calls.drain(..).collect();
+        assert_eq!(calls.len(), 1);
+        let callback = state.storage[&state.tx_hash.to_vec()].clone();
+        (calls[0].clone(), String::from_utf8(callback).unwrap())
+    }
+
+    pub fn storage_len(&self) -> usize {
+        self.api.state.borrow().storage.len()
+    }
+}
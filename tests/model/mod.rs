@@ -0,0 +1,284 @@
+//! Executable reference model of the Paxos standard token state machine.
+//!
+//! Follows the Solidity `PaxosImplementation` the contract was ported from:
+//! every `require` is checked in the same order and with the same revert reason,
+//! and the state is only written once all the checks passed, so a reverted call changes nothing.
+//! Addresses are plain names, with `ZERO` standing for `address(0)`.
+
+#![allow(dead_code)]
+
+use std::collections::{BTreeMap, BTreeSet};
+
+/// `address(0)`, which is also what unset roles hold.
+pub const ZERO: &str = "zero";
+
+pub type Revert = &'static str;
+
+/// The full observable state. Zero balances and allowances are not stored, same as in contract storage.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaxosState {
+    pub balances: BTreeMap<&'static str, u64>,
+    pub allowances: BTreeMap<(&'static str, &'static str), u64>,
+    pub frozen: BTreeSet<&'static str>,
+    pub paused: bool,
+    pub total_supply: u64,
+    pub owner: &'static str,
+    pub proposed_owner: &'static str,
+    pub asset_protection_role: &'static str,
+    pub supply_controller: &'static str,
+}
+
+impl PaxosState {
+    pub fn balance(&self, address: &str) -> u64 {
+        self.balances.get(address).cloned().unwrap_or(0)
+    }
+
+    pub fn allowance(&self, owner: &str, spender: &str) -> u64 {
+        self.allowances.get(&(owner, spender)).cloned().unwrap_or(0)
+    }
+
+    pub fn is_frozen(&self, address: &str) -> bool {
+        self.frozen.contains(address)
+    }
+
+    fn set_balance(&mut self, address: &'static str, value: u64) {
+        if value == 0 {
+            self.balances.remove(address);
+        } else {
+            self.balances.insert(address, value);
+        }
+    }
+
+    fn set_allowance(&mut self, owner: &'static str, spender: &'static str, value: u64) {
+        if value == 0 {
+            self.allowances.remove(&(owner, spender));
+        } else {
+            self.allowances.insert((owner, spender), value);
+        }
+    }
+}
+
+pub struct PaxosModel {
+    /// The address of the token contract itself, whose balance `reclaimBUSD` sweeps.
+    pub this: &'static str,
+    pub state: PaxosState,
+}
+
+impl PaxosModel {
+    /// Same as the Solidity constructor: `initialize()` followed by `pause()`.
+    pub fn deploy(this: &'static str, deployer: &'static str) -> Self {
+        PaxosModel {
+            this,
+            state: PaxosState {
+                balances: BTreeMap::new(),
+                allowances: BTreeMap::new(),
+                frozen: BTreeSet::new(),
+                paused: true,
+                total_supply: 0,
+                owner: deployer,
+                proposed_owner: ZERO,
+                asset_protection_role: ZERO,
+                supply_controller: deployer,
+            },
+        }
+    }
+
+    fn when_not_paused(&self) -> Result<(), Revert> {
+        require(!self.state.paused, "whenNotPaused")
+    }
+
+    fn only_owner(&self, caller: &str) -> Result<(), Revert> {
+        require(caller == self.state.owner, "onlyOwner")
+    }
+
+    // ERC20
+
+    pub fn transfer(&mut self, caller: &'static str, to: &'static str, value: u64) -> Result<(), Revert> {
+        self.when_not_paused()?;
+        require(to != ZERO, "cannot transfer to address zero")?;
+        require(!self.state.is_frozen(to) && !self.state.is_frozen(caller), "address frozen")?;
+        require(value <= self.state.balance(caller), "insufficient funds")?;
+
+        self.move_balance(caller, to, value);
+        Ok(())
+    }
+
+    pub fn transfer_from(&mut self, caller: &'static str, from: &'static str, to: &'static str, value: u64) -> Result<(), Revert> {
+        self.when_not_paused()?;
+        require(to != ZERO, "cannot transfer to address zero")?;
+        require(
+            !self.state.is_frozen(to) && !self.state.is_frozen(from) && !self.state.is_frozen(caller),
+            "address frozen",
+        )?;
+        require(value <= self.state.balance(from), "insufficient funds")?;
+        let allowance = self.state.allowance(from, caller);
+        require(value <= allowance, "insufficient allowance")?;
+
+        self.move_balance(from, to, value);
+        self.state.set_allowance(from, caller, allowance - value);
+        Ok(())
+    }
+
+    pub fn approve(&mut self, caller: &'static str, spender: &'static str, value: u64) -> Result<(), Revert> {
+        self.when_not_paused()?;
+        require(!self.state.is_frozen(spender) && !self.state.is_frozen(caller), "address frozen")?;
+
+        self.state.set_allowance(caller, spender, value);
+        Ok(())
+    }
+
+    fn move_balance(&mut self, from: &'static str, to: &'static str, value: u64) {
+        let from_balance = self.state.balance(from);
+        self.state.set_balance(from, from_balance - value);
+        let to_balance = self.state.balance(to);
+        self.state.set_balance(to, to_balance + value);
+    }
+
+    // OWNER FUNCTIONALITY
+
+    pub fn propose_owner(&mut self, caller: &'static str, proposed_owner: &'static str) -> Result<(), Revert> {
+        self.only_owner(caller)?;
+        require(proposed_owner != ZERO, "cannot transfer ownership to address zero")?;
+        require(caller != proposed_owner, "caller already is owner")?;
+
+        self.state.proposed_owner = proposed_owner;
+        Ok(())
+    }
+
+    pub fn disregard_proposed_owner(&mut self, caller: &'static str) -> Result<(), Revert> {
+        require(
+            caller == self.state.proposed_owner || caller == self.state.owner,
+            "only proposedOwner or owner",
+        )?;
+        require(
+            self.state.proposed_owner != ZERO,
+            "can only disregard a proposed owner that was previously set",
+        )?;
+
+        self.state.proposed_owner = ZERO;
+        Ok(())
+    }
+
+    pub fn claim_ownership(&mut self, caller: &'static str) -> Result<(), Revert> {
+        require(caller == self.state.proposed_owner, "onlyProposedOwner")?;
+
+        self.state.owner = self.state.proposed_owner;
+        self.state.proposed_owner = ZERO;
+        Ok(())
+    }
+
+    pub fn reclaim_busd(&mut self, caller: &'static str) -> Result<(), Revert> {
+        self.only_owner(caller)?;
+
+        let balance = self.state.balance(self.this);
+        self.state.set_balance(self.this, 0);
+        let owner = self.state.owner;
+        let owner_balance = self.state.balance(owner);
+        self.state.set_balance(owner, owner_balance + balance);
+        Ok(())
+    }
+
+    // PAUSABILITY FUNCTIONALITY
+
+    pub fn pause(&mut self, caller: &'static str) -> Result<(), Revert> {
+        self.only_owner(caller)?;
+        require(!self.state.paused, "already paused")?;
+
+        self.state.paused = true;
+        Ok(())
+    }
+
+    pub fn unpause(&mut self, caller: &'static str) -> Result<(), Revert> {
+        self.only_owner(caller)?;
+        require(self.state.paused, "already unpaused")?;
+
+        self.state.paused = false;
+        Ok(())
+    }
+
+    // ASSET PROTECTION FUNCTIONALITY
+
+    pub fn set_asset_protection_role(&mut self, caller: &'static str, role: &'static str) -> Result<(), Revert> {
+        require(
+            caller == self.state.asset_protection_role || caller == self.state.owner,
+            "only assetProtectionRole or Owner",
+        )?;
+
+        self.state.asset_protection_role = role;
+        Ok(())
+    }
+
+    fn only_asset_protection_role(&self, caller: &str) -> Result<(), Revert> {
+        require(caller == self.state.asset_protection_role, "onlyAssetProtectionRole")
+    }
+
+    pub fn freeze(&mut self, caller: &'static str, address: &'static str) -> Result<(), Revert> {
+        self.only_asset_protection_role(caller)?;
+        require(!self.state.is_frozen(address), "address already frozen")?;
+
+        self.state.frozen.insert(address);
+        Ok(())
+    }
+
+    pub fn unfreeze(&mut self, caller: &'static str, address: &'static str) -> Result<(), Revert> {
+        self.only_asset_protection_role(caller)?;
+        require(self.state.is_frozen(address), "address already unfrozen")?;
+
+        self.state.frozen.remove(address);
+        Ok(())
+    }
+
+    pub fn wipe_frozen_address(&mut self, caller: &'static str, address: &'static str) -> Result<(), Revert> {
+        self.only_asset_protection_role(caller)?;
+        require(self.state.is_frozen(address), "address is not frozen")?;
+
+        let balance = self.state.balance(address);
+        self.state.set_balance(address, 0);
+        self.state.total_supply -= balance;
+        Ok(())
+    }
+
+    // SUPPLY CONTROL FUNCTIONALITY
+
+    pub fn set_supply_controller(&mut self, caller: &'static str, supply_controller: &'static str) -> Result<(), Revert> {
+        require(
+            caller == self.state.supply_controller || caller == self.state.owner,
+            "only SupplyController or Owner",
+        )?;
+        require(supply_controller != ZERO, "cannot set supply controller to address zero")?;
+
+        self.state.supply_controller = supply_controller;
+        Ok(())
+    }
+
+    fn only_supply_controller(&self, caller: &str) -> Result<(), Revert> {
+        require(caller == self.state.supply_controller, "onlySupplyController")
+    }
+
+    pub fn increase_supply(&mut self, caller: &'static str, value: u64) -> Result<(), Revert> {
+        self.only_supply_controller(caller)?;
+
+        self.state.total_supply += value;
+        let balance = self.state.balance(caller);
+        self.state.set_balance(caller, balance + value);
+        Ok(())
+    }
+
+    pub fn decrease_supply(&mut self, caller: &'static str, value: u64) -> Result<(), Revert> {
+        self.only_supply_controller(caller)?;
+        let balance = self.state.balance(caller);
+        require(value <= balance, "not enough supply")?;
+
+        self.state.set_balance(caller, balance - value);
+        self.state.total_supply -= value;
+        Ok(())
+    }
+}
+
+fn require(condition: bool, reason: Revert) -> Result<(), Revert> {
+    if condition {
+        Ok(())
+    } else {
+        Err(reason)
+    }
+}
@@ -0,0 +1,162 @@
+mod mock;
+use mock::*;
+
+use elrond_wasm::{Address, OptionalArg};
+use sc_busd_rs::*;
+
+#[test]
+fn test_init() {
+    let test = BUSDTest::deploy();
+    assert_eq!(test.contract.get_contract_owner(), address("busd_owner"));
+    assert_eq!(test.contract.get_supply_controller(), address("busd_owner"));
+    assert_eq!(test.contract.get_proposed_owner(), None);
+    assert_eq!(test.contract.get_asset_protection_role(), None);
+    assert_eq!(test.contract.get_pauser(), None);
+    assert!(test.contract.is_paused());
+}
+
+#[test]
+fn test_init_roles() {
+    let test = BUSDTest::new();
+    test.set_caller(&address("deployer"));
+    test.contract.init(b"Binance USD".to_vec(), b"BUSD".to_vec(), 18,
+        OptionalArg::Some(address("busd_owner")),
+        OptionalArg::Some(address("supply_controller")),
+        OptionalArg::Some(address("asset_protection_role")),
+        OptionalArg::Some(address("pauser")),
+        OptionalArg::Some(address("holder_1")),
+        OptionalArg::Some(big(5000))).unwrap();
+
+    // nothing is left to the deployer
+    assert_eq!(test.contract.get_contract_owner(), address("busd_owner"));
+    assert_eq!(test.contract.get_supply_controller(), address("supply_controller"));
+    assert_eq!(test.contract.get_asset_protection_role(), Some(address("asset_protection_role")));
+    assert_eq!(test.contract.get_pauser(), Some(address("pauser")));
+    assert_eq!(test.balance("holder_1"), big(5000));
+    assert_eq!(test.balance("deployer"), big(0));
+    assert_eq!(test.contract.get_mut_total_supply().clone(), big(5000));
+    assert!(test.contract.is_paused());
+
+    assert_eq!(test.event_names(), vec!["Initialized", "SupplyIncreased", "Transfer"]);
+    let logs = test.logs();
+    assert_eq!(logs[0].topic_address(0), address("busd_owner"));
+    assert_eq!(logs[0].topic_address(1), address("supply_controller"));
+    assert_eq!(logs[0].topic_address(2), address("asset_protection_role"));
+    assert_eq!(logs[0].topic_address(3), address("pauser"));
+}
+
+#[test]
+fn test_init_owner_only() {
+    let test = BUSDTest::new();
+    test.set_caller(&address("deployer"));
+    test.contract.init(b"Binance USD".to_vec(), b"BUSD".to_vec(), 18,
+        OptionalArg::Some(address("busd_owner")),
+        OptionalArg::None, OptionalArg::None, OptionalArg::None, OptionalArg::None, OptionalArg::None).unwrap();
+
+    // the owner is also the supply controller
+    assert_eq!(test.contract.get_contract_owner(), address("busd_owner"));
+    assert_eq!(test.contract.get_supply_controller(), address("busd_owner"));
+    assert_eq!(test.contract.get_asset_protection_role(), None);
+    assert_eq!(test.event_names(), vec!["Initialized"]);
+}
+
+#[test]
+fn test_init_pauser_only() {
+    let test = BUSDTest::new();
+    test.set_caller(&address("deployer"));
+    test.contract.init(b"Binance USD".to_vec(), b"BUSD".to_vec(), 18,
+        OptionalArg::Some(Address::zero()),
+        OptionalArg::Some(Address::zero()),
+        OptionalArg::Some(Address::zero()),
+        OptionalArg::Some(address("pauser")),
+        OptionalArg::None, OptionalArg::None).unwrap();
+
+    // the zero address leaves a role to its default
+    assert_eq!(test.contract.get_contract_owner(), address("deployer"));
+    assert_eq!(test.contract.get_supply_controller(), address("deployer"));
+    assert_eq!(test.contract.get_asset_protection_role(), None);
+    assert_eq!(test.contract.get_pauser(), Some(address("pauser")));
+}
+
+#[test]
+fn test_init_holder_without_supply() {
+    let test = BUSDTest::new();
+    assert_eq!(test.contract.init(b"Binance USD".to_vec(), b"BUSD".to_vec(), 18,
+        OptionalArg::None, OptionalArg::None, OptionalArg::None, OptionalArg::None,
+        OptionalArg::Some(address("holder_1")),
+        OptionalArg::None), Err(BUSDError::IncompleteInitialSupply));
+    assert!(test.contract.get_contract_owner_raw().is_empty());
+}
+
+#[test]
+fn test_propose_and_claim() {
+    let test = BUSDTest::deploy();
+
+    test.call_as("busd_owner").propose_owner(address("new_owner")).unwrap();
+    assert_eq!(test.contract.get_proposed_owner(), Some(address("new_owner")));
+    assert_eq!(test.event_names(), vec!["OwnershipTransferProposed"]);
+    test.clear_logs();
+
+    assert_eq!(test.call_as("someone_else").claim_ownership(), Err(BUSDError::OnlyProposedOwnerCanClaim));
+
+    test.call_as("new_owner").claim_ownership().unwrap();
+    assert_eq!(test.contract.get_contract_owner(), address("new_owner"));
+    assert_eq!(test.contract.get_proposed_owner(), None);
+
+    let logs = test.logs();
+    assert_eq!(test.event_names(), vec!["OwnershipTransferred"]);
+    assert_eq!(logs[0].topic_address(0), address("busd_owner"));
+    assert_eq!(logs[0].topic_address(1), address("new_owner"));
+}
+
+#[test]
+fn test_propose_bad() {
+    let test = BUSDTest::deploy();
+
+    assert_eq!(
+        test.call_as("someone_else").propose_owner(address("new_owner")),
+        Err(BUSDError::OnlyOwnerCanPropose));
+    assert_eq!(
+        test.call_as("busd_owner").propose_owner(address("busd_owner")),
+        Err(BUSDError::OwnerCannotProposeItself));
+
+    test.call_as("busd_owner").propose_owner(address("new_owner")).unwrap();
+    assert_eq!(
+        test.call_as("busd_owner").propose_owner(address("new_owner")),
+        Err(BUSDError::AlreadyProposedOwner));
+}
+
+#[test]
+fn test_disregard() {
+    let test = BUSDTest::deploy();
+    assert_eq!(test.call_as("busd_owner").disregard_proposed_owner(), Err(BUSDError::NoProposedOwnerToDisregard));
+    assert_eq!(test.call_as("new_owner").claim_ownership(), Err(BUSDError::NoOwnerProposed));
+
+    test.call_as("busd_owner").propose_owner(address("new_owner")).unwrap();
+    assert_eq!(
+        test.call_as("someone_else").disregard_proposed_owner(),
+        Err(BUSDError::OnlyOwnerOrProposedOwnerCanDisregard));
+
+    // the proposed owner can also disregard itself
+    test.call_as("new_owner").disregard_proposed_owner().unwrap();
+    assert_eq!(test.contract.get_proposed_owner(), None);
+    assert_eq!(test.event_names().last().unwrap(), "OwnershipTransferDisregarded");
+}
+
+#[test]
+fn test_reclaim() {
+    let test = BUSDTest::deploy_unpaused();
+    test.mint("holder_1", 1000);
+    test.call_as("holder_1").transfer(address("busd_contract"), big(400)).unwrap();
+    test.clear_logs();
+
+    assert_eq!(test.call_as("holder_1").reclaim_busd(), Err(BUSDError::OnlyOwnerCanReclaim));
+
+    test.call_as("busd_owner").reclaim_busd().unwrap();
+    assert_eq!(test.balance("busd_contract"), big(0));
+    assert_eq!(test.balance("busd_owner"), big(400));
+    assert_eq!(test.event_names(), vec!["Reclaimed", "Transfer"]);
+    let logs = test.logs();
+    assert_eq!(logs[0].topic_address(0), address("busd_owner"));
+    assert_eq!(logs[0].data_big_uint(), big(400));
+}
@@ -0,0 +1,40 @@
+mod mock;
+use mock::*;
+
+use sc_busd_rs::*;
+
+#[test]
+fn test_pause() {
+    let test = BUSDTest::deploy();
+    assert_eq!(test.call_as("busd_owner").pause(), Err(BUSDError::AlreadyPaused));
+    // with no pauser set, only the owner can pause and unpause
+    assert_eq!(test.call_as("holder_1").unpause(), Err(BUSDError::OnlyPauserCanUnpause));
+
+    test.call_as("busd_owner").unpause().unwrap();
+    assert!(!test.contract.is_paused());
+    assert_eq!(test.call_as("busd_owner").unpause(), Err(BUSDError::AlreadyUnpaused));
+
+    assert_eq!(test.call_as("holder_1").pause(), Err(BUSDError::OnlyPauserCanPause));
+    test.call_as("busd_owner").pause().unwrap();
+    assert!(test.contract.is_paused());
+    assert_eq!(test.event_names(), vec!["Unpause", "Pause"]);
+    assert!(test.logs().iter().all(|log| log.topic_address(0) == address("busd_owner")));
+}
+
+#[test]
+fn test_pauser() {
+    let test = BUSDTest::deploy();
+    assert_eq!(test.call_as("holder_1").set_pauser_endpoint(&address("pauser")), Err(BUSDError::OnlyOwnerCanSetPauser));
+    test.call_as("busd_owner").set_pauser_endpoint(&address("pauser")).unwrap();
+    assert_eq!(test.contract.get_pauser(), Some(address("pauser")));
+    assert_eq!(test.event_names(), vec!["PauserSet"]);
+    assert_eq!(test.logs()[0].topics[0], [0u8; 32]);
+    assert_eq!(test.logs()[0].topic_address(1), address("pauser"));
+
+    // once set, only the pauser can pause and unpause
+    assert_eq!(test.call_as("busd_owner").unpause(), Err(BUSDError::OnlyPauserCanUnpause));
+    test.call_as("pauser").unpause().unwrap();
+    assert_eq!(test.call_as("busd_owner").pause(), Err(BUSDError::OnlyPauserCanPause));
+    test.call_as("pauser").pause().unwrap();
+    assert!(test.contract.is_paused());
+}
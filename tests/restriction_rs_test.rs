@@ -0,0 +1,66 @@
+mod mock;
+use mock::*;
+
+use sc_busd_rs::*;
+
+#[test]
+fn test_detect_transfer_restriction() {
+    let test = BUSDTest::deploy_unpaused();
+    test.mint("holder_1", 500);
+    test.mint("holder_frozen", 500);
+    test.call_as("asset_protection_role").freeze(&address("holder_frozen")).unwrap();
+
+    let detect = |from: &str, to: &str, amount: u64| {
+        test.contract.detect_transfer_restriction(&address(from), &address(to), &big(amount))
+    };
+    assert_eq!(detect("holder_1", "holder_2", 500), 0);
+    assert_eq!(detect("holder_frozen", "holder_2", 10), 2);
+    assert_eq!(detect("holder_1", "holder_frozen", 10), 3);
+    assert_eq!(detect("holder_1", "holder_2", 501), 6);
+
+    test.call_as("busd_owner").pause().unwrap();
+    assert_eq!(detect("holder_1", "holder_2", 10), 1);
+}
+
+#[test]
+fn test_message_for_transfer_restriction() {
+    let test = BUSDTest::deploy();
+    let expected: &[(u8, &[u8])] = &[
+        (0, b"no restriction"),
+        (1, b"transfers are paused"),
+        (2, b"sender address is frozen"),
+        (3, b"recipient address is frozen"),
+        (4, b"spender address is frozen"),
+        (5, b"amount exceeds the spender allowance"),
+        (6, b"amount exceeds the sender balance"),
+        (7, b"amount exceeds the sender daily transfer limit"),
+        (8, b"travel rule metadata required between VASPs"),
+        (9, b"unknown restriction code"),
+    ];
+    for (code, message) in expected {
+        assert_eq!(test.contract.message_for_transfer_restriction(*code), *message);
+    }
+}
+
+#[test]
+fn test_transfer_matches_detection() {
+    let test = BUSDTest::deploy_unpaused();
+    test.mint("holder_1", 500);
+
+    let code = test.contract.detect_transfer_restriction(&address("holder_1"), &address("holder_2"), &big(501));
+    let result = test.call_as("holder_1").transfer(address("holder_2"), big(501));
+    assert_eq!(code, 6);
+    assert_eq!(result.unwrap_err(), BUSDError::InsufficientFunds);
+}
+
+#[test]
+fn test_error_codes() {
+    let test = BUSDTest::deploy();
+    for error in BUSDError::ALL {
+        assert_eq!(BUSDError::from_code(error.code()), Some(*error));
+        assert_eq!(test.contract.get_error_message(error.code()), error.message().as_bytes());
+        assert_eq!(test.contract.get_error_code(error.message().as_bytes().to_vec()), error.code());
+    }
+    assert_eq!(test.contract.get_error_message(0), b"unknown error code");
+    assert_eq!(test.contract.get_error_code(b"no such error".to_vec()), 0);
+}
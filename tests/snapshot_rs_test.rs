@@ -0,0 +1,93 @@
+mod mock;
+use mock::*;
+
+use sc_busd_rs::*;
+
+fn balance_at(test: &BUSDTest, name: &str, snapshot_id: u64) -> MockBigUint {
+    test.contract.balance_of_at(&address(name), snapshot_id).unwrap()
+}
+
+fn total_supply_at(test: &BUSDTest, snapshot_id: u64) -> MockBigUint {
+    test.contract.total_supply_at(snapshot_id).unwrap()
+}
+
+#[test]
+fn test_snapshot() {
+    let test = BUSDTest::deploy_unpaused();
+    assert_eq!(test.contract.get_current_snapshot_id(), 0);
+    assert_eq!(test.call_as("holder_1").snapshot(), Err(BUSDError::OnlySupplyControllerCanSnapshot));
+
+    assert_eq!(test.call_as("busd_owner").snapshot(), Ok(1));
+    assert_eq!(test.call_as("busd_owner").snapshot(), Ok(2));
+    assert_eq!(test.contract.get_current_snapshot_id(), 2);
+    assert_eq!(test.event_names(), vec!["Snapshot", "Snapshot"]);
+    assert_eq!(test.logs()[1].data, vec![2]);
+
+    assert_eq!(test.contract.balance_of_at(&address("holder_1"), 0), Err(BUSDError::SnapshotNotFound));
+    assert_eq!(test.contract.total_supply_at(3), Err(BUSDError::SnapshotNotFound));
+}
+
+#[test]
+fn test_balance_of_at() {
+    let test = BUSDTest::deploy_unpaused();
+    test.mint("holder_1", 1000);
+    let first = test.call_as("busd_owner").snapshot().unwrap();
+
+    test.call_as("holder_1").transfer(address("holder_2"), big(100)).unwrap();
+    test.call_as("holder_1").transfer(address("holder_2"), big(100)).unwrap();
+    let second = test.call_as("busd_owner").snapshot().unwrap();
+    // nothing changes between the second and the third snapshot
+    let third = test.call_as("busd_owner").snapshot().unwrap();
+    test.call_as("holder_2").transfer(address("holder_3"), big(50)).unwrap();
+
+    // only the first change after a snapshot is saved
+    assert_eq!(balance_at(&test, "holder_1", first), big(1000));
+    assert_eq!(balance_at(&test, "holder_2", first), big(0));
+    assert_eq!(balance_at(&test, "holder_1", second), big(800));
+    assert_eq!(balance_at(&test, "holder_2", second), big(200));
+    assert_eq!(balance_at(&test, "holder_2", third), big(200));
+    assert_eq!(balance_at(&test, "holder_3", third), big(0));
+    assert_eq!(test.balance("holder_2"), big(150));
+
+    // balances that did not change since are read as they are now
+    assert_eq!(balance_at(&test, "holder_1", third), big(800));
+}
+
+#[test]
+fn test_total_supply_at() {
+    let test = BUSDTest::deploy_unpaused();
+    test.mint("holder_1", 1000);
+    let first = test.call_as("busd_owner").snapshot().unwrap();
+
+    test.call_as("busd_owner").increase_supply(big(500)).unwrap();
+    let second = test.call_as("busd_owner").snapshot().unwrap();
+    test.call_as("busd_owner").decrease_supply(big(200)).unwrap();
+    let third = test.call_as("busd_owner").snapshot().unwrap();
+
+    test.call_as("asset_protection_role").freeze(&address("holder_1")).unwrap();
+    test.call_as("asset_protection_role").wipe_frozen_address(&address("holder_1")).unwrap();
+
+    assert_eq!(total_supply_at(&test, first), big(1000));
+    assert_eq!(total_supply_at(&test, second), big(1500));
+    assert_eq!(total_supply_at(&test, third), big(1300));
+    assert_eq!(test.contract.get_mut_total_supply().clone(), big(300));
+    assert_eq!(balance_at(&test, "busd_owner", first), big(0));
+    assert_eq!(balance_at(&test, "busd_owner", second), big(500));
+    assert_eq!(balance_at(&test, "busd_owner", third), big(300));
+    assert_eq!(balance_at(&test, "holder_1", third), big(1000));
+    assert_eq!(test.balance("holder_1"), big(0));
+}
+
+#[test]
+fn test_snapshot_with_pending_transfer() {
+    let test = BUSDTest::deploy_unpaused();
+    test.mint("holder_1", 1000);
+    test.call_as("busd_owner").set_large_transfer_threshold_endpoint(big(500)).unwrap();
+    test.call_as("holder_1").transfer(address("holder_2"), big(600)).unwrap();
+    let queued = test.call_as("busd_owner").snapshot().unwrap();
+
+    // the queued amount is in no balance until the transfer is cancelled
+    test.call_as("asset_protection_role").cancel_pending_transfer(1).unwrap();
+    assert_eq!(balance_at(&test, "holder_1", queued), big(400));
+    assert_eq!(test.balance("holder_1"), big(1000));
+}
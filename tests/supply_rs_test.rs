@@ -0,0 +1,53 @@
+mod mock;
+use mock::*;
+
+use sc_busd_rs::*;
+
+#[test]
+fn test_increase_decrease_supply() {
+    let test = BUSDTest::deploy();
+
+    test.call_as("busd_owner").increase_supply(big(1000)).unwrap();
+    assert_eq!(test.balance("busd_owner"), big(1000));
+    assert_eq!(test.contract.get_mut_total_supply().clone(), big(1000));
+    assert_eq!(test.event_names(), vec!["SupplyIncreased", "Transfer"]);
+    test.clear_logs();
+
+    test.call_as("busd_owner").decrease_supply(big(400)).unwrap();
+    assert_eq!(test.balance("busd_owner"), big(600));
+    assert_eq!(test.contract.get_mut_total_supply().clone(), big(600));
+    assert_eq!(test.event_names(), vec!["SupplyDecreased", "Transfer"]);
+
+    assert_eq!(
+        test.call_as("busd_owner").decrease_supply(big(601)),
+        Err(BUSDError::NotEnoughSupplyToDecrease));
+}
+
+#[test]
+fn test_supply_bad_caller() {
+    let test = BUSDTest::deploy();
+    assert_eq!(
+        test.call_as("someone_else").increase_supply(big(1)),
+        Err(BUSDError::OnlySupplyControllerCanIncrease));
+    assert_eq!(
+        test.call_as("someone_else").decrease_supply(big(1)),
+        Err(BUSDError::OnlySupplyControllerCanDecrease));
+}
+
+#[test]
+fn test_set_supply_controller() {
+    let test = BUSDTest::deploy();
+    assert_eq!(
+        test.call_as("someone_else").set_supply_controller_endpoint(&address("supply_controller")),
+        Err(BUSDError::OnlyOwnerOrSupplyControllerCanSet));
+
+    test.call_as("busd_owner").set_supply_controller_endpoint(&address("supply_controller")).unwrap();
+    assert_eq!(test.contract.get_supply_controller(), address("supply_controller"));
+    assert_eq!(test.event_names(), vec!["SupplyControllerSet"]);
+
+    // the owner is no longer allowed to mint
+    assert_eq!(
+        test.call_as("busd_owner").increase_supply(big(1)),
+        Err(BUSDError::OnlySupplyControllerCanIncrease));
+    test.call_as("supply_controller").increase_supply(big(1)).unwrap();
+}
@@ -0,0 +1,118 @@
+mod mock;
+use mock::*;
+
+use elrond_wasm::H256;
+use sc_busd_rs::*;
+
+fn vasp_id(name: &str) -> H256 {
+    address(name)
+}
+
+fn setup() -> BUSDTest {
+    let test = BUSDTest::deploy_unpaused();
+    test.mint("vasp_a", 5000);
+    test.call_as("busd_owner").register_vasp(&address("vasp_a"), &vasp_id("vasp_a_id")).unwrap();
+    test.call_as("busd_owner").register_vasp(&address("vasp_b"), &vasp_id("vasp_b_id")).unwrap();
+    test.call_as("busd_owner").set_travel_rule_threshold_endpoint(big(1000)).unwrap();
+    test.clear_logs();
+    test
+}
+
+#[test]
+fn test_travel_rule_required() {
+    let test = setup();
+
+    test.call_as("vasp_a").transfer(address("vasp_b"), big(1000)).unwrap();
+    assert_eq!(
+        test.call_as("vasp_a").transfer(address("vasp_b"), big(1001)),
+        Err(BUSDError::TravelRuleRequired));
+    assert_eq!(test.contract.detect_transfer_restriction(&address("vasp_a"), &address("vasp_b"), &big(1001)), 8);
+
+    // not required when the recipient is not a VASP
+    test.call_as("vasp_a").transfer(address("holder_1"), big(1001)).unwrap();
+}
+
+#[test]
+fn test_transfer_with_travel_rule() {
+    let test = setup();
+    let payload_hash = address("payload_hash");
+
+    assert_eq!(
+        test.call_as("vasp_a").transfer_with_travel_rule(address("vasp_b"), big(1001), payload_hash.clone(), vasp_id("vasp_b_id")),
+        Err(BUSDError::VaspIdMismatch));
+
+    test.call_as("vasp_a").transfer_with_travel_rule(address("vasp_b"), big(1001), payload_hash.clone(), vasp_id("vasp_a_id")).unwrap();
+    assert_eq!(test.balance("vasp_b"), big(1001));
+
+    let logs = test.logs();
+    assert_eq!(test.event_names(), vec!["TravelRule", "Transfer"]);
+    assert_eq!(logs[0].topic_address(2), payload_hash);
+    assert_eq!(logs[0].topic_address(3), vasp_id("vasp_a_id"));
+    assert_eq!(logs[0].data_big_uint(), big(1001));
+}
+
+#[test]
+fn test_transfer_with_travel_rule_unregistered_sender() {
+    let test = setup();
+    test.mint("holder_1", 5000);
+    let payload_hash = address("payload_hash");
+
+    // an address that is not a VASP cannot claim to be one
+    assert_eq!(
+        test.call_as("holder_1").transfer_with_travel_rule(address("vasp_b"), big(1001), payload_hash.clone(), vasp_id("vasp_a_id")),
+        Err(BUSDError::VaspIdMismatch));
+    assert_eq!(test.logs(), vec![]);
+
+    test.call_as("holder_1").transfer_with_travel_rule(address("vasp_b"), big(1001), payload_hash, H256::zero()).unwrap();
+    assert_eq!(test.event_names(), vec!["TravelRule", "Transfer"]);
+    assert_eq!(test.logs()[0].topic_address(3), H256::zero());
+}
+
+#[test]
+fn test_transfer_with_travel_rule_queued() {
+    let test = setup();
+    let payload_hash = address("payload_hash");
+    test.set_block_timestamp(1_000_000);
+    test.call_as("busd_owner").set_large_transfer_threshold_endpoint(big(2000)).unwrap();
+    test.call_as("busd_owner").set_large_transfer_review_period_endpoint(3600).unwrap();
+
+    test.call_as("vasp_a").transfer_with_travel_rule(address("vasp_b"), big(2001), payload_hash.clone(), vasp_id("vasp_a_id")).unwrap();
+    assert_eq!(test.event_names(), vec!["LargeTransferThresholdSet", "LargeTransferReviewPeriodSet", "LargeTransferQueued"]);
+    test.clear_logs();
+
+    // the metadata is only logged once the funds reach the recipient
+    test.set_block_timestamp(1_000_000 + 3600);
+    test.call_as("someone_else").execute_pending_transfer(1).unwrap();
+    assert_eq!(test.event_names(), vec!["LargeTransferExecuted", "TravelRule", "Transfer"]);
+    let logs = test.logs();
+    assert_eq!(logs[1].topic_address(0), address("vasp_a"));
+    assert_eq!(logs[1].topic_address(2), payload_hash);
+    assert_eq!(logs[1].topic_address(3), vasp_id("vasp_a_id"));
+    assert_eq!(logs[1].data_big_uint(), big(2001));
+
+    // a cancelled transfer never logs it
+    test.call_as("vasp_a").transfer_with_travel_rule(address("vasp_b"), big(2001), payload_hash, vasp_id("vasp_a_id")).unwrap();
+    test.call_as("asset_protection_role").cancel_pending_transfer(2).unwrap();
+    assert_eq!(test.event_names(), vec!["LargeTransferExecuted", "TravelRule", "Transfer", "LargeTransferQueued", "LargeTransferCancelled"]);
+}
+
+#[test]
+fn test_vasp_registry() {
+    let test = setup();
+    assert_eq!(
+        test.call_as("someone_else").register_vasp(&address("vasp_c"), &vasp_id("vasp_c_id")),
+        Err(BUSDError::OnlyOwnerCanRegisterVasps));
+
+    test.call_as("busd_owner").unregister_vasp(&address("vasp_b")).unwrap();
+    assert_eq!(test.contract.get_vasp_id(&address("vasp_b")), None);
+    assert_eq!(
+        test.call_as("busd_owner").unregister_vasp(&address("vasp_b")),
+        Err(BUSDError::AddressNotVasp));
+
+    test.call_as("vasp_a").transfer(address("vasp_b"), big(2000)).unwrap();
+
+    test.call_as("busd_owner").remove_travel_rule_threshold().unwrap();
+    assert_eq!(
+        test.call_as("busd_owner").remove_travel_rule_threshold(),
+        Err(BUSDError::NoTravelRuleThreshold));
+}
@@ -0,0 +1,58 @@
+mod mock;
+use mock::*;
+
+use sc_busd_rs::*;
+
+#[test]
+fn test_deploy_version() {
+    let test = BUSDTest::deploy();
+    assert_eq!(test.contract.get_storage_version(), test.contract.get_latest_storage_version());
+    assert_eq!(test.call_as("busd_owner").upgrade(), Err(BUSDError::StorageUpToDate));
+}
+
+#[test]
+fn test_upgrade_from_v0() {
+    let test = BUSDTest::deploy_v0();
+    test.call_as("busd_owner").unpause().unwrap();
+    test.mint("holder_1", 5000);
+    assert_eq!(test.contract.get_storage_version(), 0);
+    assert!(test.contract.name().is_empty());
+
+    assert_eq!(test.call_as("holder_1").upgrade(), Err(BUSDError::OnlyOwnerCanUpgrade));
+    test.call_as("busd_owner").upgrade().unwrap();
+    assert_eq!(test.contract.get_storage_version(), 2);
+    assert_eq!(test.contract.name(), b"Binance USD".to_vec());
+    assert_eq!(test.contract.symbol(), b"BUSD".to_vec());
+    assert_eq!(test.contract.decimals(), 18);
+    // indexers of a v0.3.0 deployment expect the numbered events
+    assert!(test.contract.legacy_events_enabled());
+    // one step per version
+    assert_eq!(test.event_names(), vec!["LegacyEventsEnabled", "StorageMigrated", "StorageMigrated"]);
+    assert_eq!(test.logs()[1].data, vec![1]);
+    assert_eq!(test.logs()[2].data, vec![2]);
+
+    // the rest of the storage is kept
+    assert_eq!(test.balance("holder_1"), big(5000));
+    assert_eq!(test.contract.get_contract_owner(), address("busd_owner"));
+    assert_eq!(test.call_as("busd_owner").upgrade(), Err(BUSDError::StorageUpToDate));
+}
+
+#[test]
+fn test_upgrade_reruns_constructor() {
+    // upgrading the code runs the constructor again, which leaves an initialized contract as it is
+    let test = BUSDTest::deploy_v0();
+    test.call_as("busd_owner").unpause().unwrap();
+    test.set_caller(&address("someone_else"));
+    test.init(b"Other", b"OTH", 6).unwrap();
+    assert_eq!(test.contract.get_contract_owner(), address("busd_owner"));
+    assert!(!test.contract.is_paused());
+    assert!(test.contract.name().is_empty());
+    assert_eq!(test.contract.get_storage_version(), 0);
+}
+
+#[test]
+fn test_upgrade_newer_storage() {
+    let test = BUSDTest::deploy();
+    test.contract.set_storage_version(test.contract.get_latest_storage_version() + 1);
+    assert_eq!(test.call_as("busd_owner").upgrade(), Err(BUSDError::StorageVersionTooNew));
+}
@@ -0,0 +1,110 @@
+mod mock;
+use mock::*;
+
+use sc_busd_rs::*;
+
+const HOUR: u64 = 60 * 60;
+const DAY: u64 = 24 * HOUR;
+
+#[test]
+fn test_daily_limit() {
+    let test = BUSDTest::deploy_unpaused();
+    test.mint("holder_1", 1000);
+    test.set_block_timestamp(1_000_000);
+
+    test.call_as("asset_protection_role").set_daily_limit(&address("holder_1"), big(300)).unwrap();
+    assert_eq!(test.contract.get_daily_limit(&address("holder_1")), Some(big(300)));
+    assert_eq!(test.event_names(), vec!["DailyLimitSet"]);
+
+    test.call_as("holder_1").transfer(address("holder_2"), big(200)).unwrap();
+    assert_eq!(test.contract.remaining_daily_capacity(&address("holder_1")), Some(big(100)));
+    assert_eq!(
+        test.call_as("holder_1").transfer(address("holder_2"), big(101)),
+        Err(BUSDError::DailyLimitExceeded));
+    test.call_as("holder_1").transfer(address("holder_2"), big(100)).unwrap();
+
+    // still counted at the end of the 24 hours
+    test.set_block_timestamp(1_000_000 + DAY);
+    assert_eq!(
+        test.call_as("holder_1").transfer(address("holder_2"), big(1)),
+        Err(BUSDError::DailyLimitExceeded));
+
+    // released once the hour of the transfers is entirely more than 24 hours old
+    test.set_block_timestamp(1_000_000 + DAY + HOUR);
+    assert_eq!(test.contract.get_daily_spent(&address("holder_1")), big(0));
+    test.call_as("holder_1").transfer(address("holder_2"), big(300)).unwrap();
+    assert_eq!(test.contract.remaining_daily_capacity(&address("holder_1")), Some(big(0)));
+}
+
+#[test]
+fn test_daily_limit_rolls() {
+    let test = BUSDTest::deploy_unpaused();
+    test.mint("holder_1", 1000);
+    test.call_as("asset_protection_role").set_daily_limit(&address("holder_1"), big(300)).unwrap();
+
+    test.set_block_timestamp(1_000_000);
+    test.call_as("holder_1").transfer(address("holder_2"), big(100)).unwrap();
+    test.set_block_timestamp(1_000_000 + 12 * HOUR);
+    test.call_as("holder_1").transfer(address("holder_2"), big(200)).unwrap();
+
+    // the first transfer leaves the window, the second one still counts
+    test.set_block_timestamp(1_000_000 + DAY + HOUR);
+    assert_eq!(test.contract.get_daily_spent(&address("holder_1")), big(200));
+    assert_eq!(
+        test.call_as("holder_1").transfer(address("holder_2"), big(101)),
+        Err(BUSDError::DailyLimitExceeded));
+    test.call_as("holder_1").transfer(address("holder_2"), big(100)).unwrap();
+
+    // spending the limit right before a day ends does not free it right after
+    test.set_block_timestamp(1_000_000 + 2 * DAY + 2 * HOUR);
+    assert_eq!(test.contract.get_daily_spent(&address("holder_1")), big(0));
+    test.call_as("holder_1").transfer(address("holder_2"), big(300)).unwrap();
+    test.set_block_timestamp(1_000_000 + 3 * DAY - 1);
+    assert_eq!(
+        test.call_as("holder_1").transfer(address("holder_2"), big(1)),
+        Err(BUSDError::DailyLimitExceeded));
+    test.set_block_timestamp(1_000_000 + 3 * DAY + 1);
+    assert_eq!(
+        test.call_as("holder_1").transfer(address("holder_2"), big(1)),
+        Err(BUSDError::DailyLimitExceeded));
+}
+
+#[test]
+fn test_default_daily_limit() {
+    let test = BUSDTest::deploy_unpaused();
+    test.mint("holder_1", 1000);
+    test.mint("holder_2", 1000);
+
+    test.call_as("asset_protection_role").set_default_daily_limit_endpoint(big(100)).unwrap();
+    test.call_as("asset_protection_role").set_daily_limit(&address("holder_2"), big(500)).unwrap();
+
+    assert_eq!(
+        test.call_as("holder_1").transfer(address("holder_3"), big(101)),
+        Err(BUSDError::DailyLimitExceeded));
+    test.call_as("holder_2").transfer(address("holder_3"), big(500)).unwrap();
+
+    // falls back to the default once the specific limit is removed
+    test.call_as("asset_protection_role").remove_daily_limit(&address("holder_2")).unwrap();
+    assert_eq!(test.contract.get_daily_limit(&address("holder_2")), Some(big(100)));
+
+    test.call_as("asset_protection_role").remove_default_daily_limit().unwrap();
+    assert_eq!(test.contract.remaining_daily_capacity(&address("holder_1")), None);
+    test.call_as("holder_1").transfer(address("holder_3"), big(1000)).unwrap();
+}
+
+#[test]
+fn test_daily_limit_errors() {
+    let test = BUSDTest::deploy_unpaused();
+    assert_eq!(
+        test.call_as("busd_owner").set_daily_limit(&address("holder_1"), big(1)),
+        Err(BUSDError::OnlyAssetProtectionCanSetDailyLimits));
+    assert_eq!(
+        test.call_as("busd_owner").remove_daily_limit(&address("holder_1")),
+        Err(BUSDError::OnlyAssetProtectionCanRemoveDailyLimits));
+    assert_eq!(
+        test.call_as("asset_protection_role").remove_daily_limit(&address("holder_1")),
+        Err(BUSDError::NoAddressDailyLimit));
+    assert_eq!(
+        test.call_as("asset_protection_role").remove_default_daily_limit(),
+        Err(BUSDError::NoDefaultDailyLimit));
+}